@@ -3,16 +3,23 @@ use std::sync::Arc;
 use anyhow::Result;
 
 use breez_sdk_core::{
-    mnemonic_to_seed as sdk_mnemonic_to_seed, parse as sdk_parse_input,
-    parse_invoice as sdk_parse_invoice, AesSuccessActionDataDecrypted, BitcoinAddressData,
-    BreezEvent, BreezServices, ChannelState, ClosedChannelPaymentDetails, Config, CurrencyInfo,
-    EnvironmentType, EventListener, FeeratePreset, FiatCurrency, GreenlightCredentials, InputType,
-    InvoicePaidDetails, LNInvoice, LnPaymentDetails, LnUrlAuthRequestData, LnUrlCallbackStatus,
-    LnUrlErrorData, LnUrlPayRequestData, LnUrlPayResult, LnUrlWithdrawRequestData, LocaleOverrides,
-    LocalizedName, LogEntry, LspInformation, MessageSuccessActionData, MetadataItem, Network,
-    NodeState, Payment, PaymentDetails, PaymentFailedData, PaymentType, PaymentTypeFilter, Rate,
-    RecommendedFees, RouteHint, RouteHintHop, SuccessActionProcessed, SwapInfo, SwapStatus, Symbol,
-    UnspentTransactionOutput, UrlSuccessActionData,
+    map_storage_error, mnemonic_to_seed as sdk_mnemonic_to_seed, parse as sdk_parse_input,
+    parse_amount as sdk_parse_amount, parse_invoice as sdk_parse_invoice, AesDecryptedOrError,
+    AesSuccessActionDataDecrypted, AmountContext, AmountUnit, AmountViolation, BitcoinAddressData,
+    BreezEvent, BreezServices, BuyBitcoinProvider, ChainBackend, ChannelInfo, ChannelState,
+    ClockSkewWarning, ClosedChannelPaymentDetails, Config, ConnectionStatus, CurrencyInfo,
+    CurrencySymbolEntry, Diagnostics, EnvironmentType, EventListener, FeeTier, FeeratePreset,
+    FiatCurrency, GreenlightCredentials, InputType, InvoicePaidDetails, LNInvoice, LNOffer,
+    LnPaymentDetails, LnUrlAuthLogin, LnUrlAuthRequestData, LnUrlCallbackStatus,
+    LnUrlChannelRequestData, LnUrlErrorData, LnUrlPayRequestData, LnUrlPayResult,
+    LnUrlWithdrawRequestData, LocaleOverrides, LocalizedName, LogEntry, LspInformation,
+    MessageSuccessActionData, MetadataItem, Network, NodeState, OfferAmount, OpeningFeeParams,
+    OverpaidSwapHandling, ParsedMetadata, Payment, PaymentDetails, PaymentFailedData,
+    PaymentPendingData, PaymentRouteHop, PaymentType, PaymentTypeFilter, PeerInfo, Rate,
+    ReceiveCost, ReceiveLimits, ReceivePaymentResponse, RecommendedFees, RefundPreview, RouteHint,
+    RouteHintHop, ServiceStatus, ServiceType, StorageError, SuccessActionProcessed,
+    SwapDiagnostics, SwapInfo, SwapStatus, SweepPreview, SweepResult, Symbol, TemplateValue,
+    TlvEntry, UnspentTransactionOutput, UrlSuccessActionData,
 };
 use log::LevelFilter;
 use log::Metadata;
@@ -45,10 +52,10 @@ impl log::Log for BindingLogger {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            self.log_stream.log(LogEntry {
-                line: record.args().to_string(),
-                level: record.level().as_str().to_string(),
-            });
+            self.log_stream.log(LogEntry::new(
+                record.args().to_string(),
+                record.level().as_str().to_string(),
+            ));
         }
     }
     fn flush(&self) {}
@@ -58,12 +65,30 @@ impl log::Log for BindingLogger {
 pub enum SDKError {
     #[error("Breez SDK error: {err}")]
     Error { err: String },
+    #[error("Breez SDK storage constraint violation: {err}")]
+    StorageConstraintViolation { err: String },
+    #[error("Breez SDK storage busy: {err}")]
+    StorageBusy { err: String },
+    #[error("Breez SDK storage corrupt: {err}")]
+    StorageCorrupt { err: String },
 }
 
 impl From<anyhow::Error> for SDKError {
     fn from(err: anyhow::Error) -> Self {
-        SDKError::Error {
-            err: err.to_string(),
+        let err = map_storage_error(err);
+        match err.downcast_ref::<StorageError>() {
+            Some(StorageError::ConstraintViolation(_)) => SDKError::StorageConstraintViolation {
+                err: err.to_string(),
+            },
+            Some(StorageError::Busy(_)) => SDKError::StorageBusy {
+                err: err.to_string(),
+            },
+            Some(StorageError::Corrupt(_)) => SDKError::StorageCorrupt {
+                err: err.to_string(),
+            },
+            _ => SDKError::Error {
+                err: err.to_string(),
+            },
         }
     }
 }
@@ -100,8 +125,8 @@ pub fn recover_node(network: Network, seed: Vec<u8>) -> Result<GreenlightCredent
     Ok(creds)
 }
 
-pub fn default_config(env_type: EnvironmentType) -> Config {
-    BreezServices::default_config(env_type)
+pub fn default_config(env_type: EnvironmentType, network: Network) -> Config {
+    BreezServices::default_config(env_type, network)
 }
 
 /// init_services initialized the global NodeService, schedule the node to run in the cloud and
@@ -147,20 +172,31 @@ impl BlockingBreezServices {
         &self,
         bolt11: String,
         amount_sats: Option<u64>,
+        max_fee_sat: Option<u64>,
+        timeout_sec: Option<u32>,
     ) -> Result<Payment, SDKError> {
-        rt().block_on(self.breez_services.send_payment(bolt11, amount_sats))
-            .map_err(|e| e.into())
+        rt().block_on(self.breez_services.send_payment(
+            bolt11,
+            amount_sats,
+            max_fee_sat,
+            timeout_sec,
+        ))
+        .map_err(|e| e.into())
     }
 
     pub fn send_spontaneous_payment(
         &self,
         node_id: String,
         amount_sats: u64,
+        timeout_sec: Option<u32>,
+        extra_tlvs: Option<Vec<TlvEntry>>,
     ) -> Result<Payment, SDKError> {
-        rt().block_on(
-            self.breez_services
-                .send_spontaneous_payment(node_id, amount_sats),
-        )
+        rt().block_on(self.breez_services.send_spontaneous_payment(
+            node_id,
+            amount_sats,
+            timeout_sec,
+            extra_tlvs,
+        ))
         .map_err(|e| e.into())
     }
 
@@ -168,28 +204,126 @@ impl BlockingBreezServices {
         &self,
         amount_sats: u64,
         description: String,
-    ) -> Result<LNInvoice, SDKError> {
+        expiry_secs: Option<u32>,
+        preimage: Option<Vec<u8>>,
+        include_lsp_hint: Option<bool>,
+        fallback_address: Option<String>,
+    ) -> Result<ReceivePaymentResponse, SDKError> {
+        rt().block_on(self.breez_services.receive_payment(
+            amount_sats,
+            description,
+            expiry_secs,
+            preimage,
+            include_lsp_hint,
+            fallback_address,
+        ))
+        .map_err(|e| e.into())
+    }
+
+    pub fn receive_payment_with_template(
+        &self,
+        amount_sats: u64,
+        description_template: String,
+        values: Vec<TemplateValue>,
+    ) -> Result<ReceivePaymentResponse, SDKError> {
+        rt().block_on(self.breez_services.receive_payment_with_template(
+            amount_sats,
+            description_template,
+            values,
+        ))
+        .map_err(|e| e.into())
+    }
+
+    pub fn receive_payment_with_description_hash(
+        &self,
+        amount_sats: u64,
+        description: String,
+        description_hash: Vec<u8>,
+    ) -> Result<ReceivePaymentResponse, SDKError> {
+        rt().block_on(self.breez_services.receive_payment_with_description_hash(
+            amount_sats,
+            description,
+            description_hash,
+        ))
+        .map_err(|e| e.into())
+    }
+
+    pub fn receive_payment_split(
+        &self,
+        amount_sats: u64,
+        description: String,
+    ) -> Result<Vec<LNInvoice>, SDKError> {
         rt().block_on(
             self.breez_services
-                .receive_payment(amount_sats, description),
+                .receive_payment_split(amount_sats, description),
         )
         .map_err(|e| e.into())
     }
 
+    pub fn receive_donation(&self, description: String) -> Result<LNInvoice, SDKError> {
+        rt().block_on(self.breez_services.receive_donation(description))
+            .map_err(|e| e.into())
+    }
+
+    pub fn receive_cost_estimate(&self, amount_msat: u64) -> Result<ReceiveCost, SDKError> {
+        rt().block_on(self.breez_services.receive_cost_estimate(amount_msat))
+            .map_err(|e| e.into())
+    }
+
+    pub fn receive_payment_limits(&self) -> Result<ReceiveLimits, SDKError> {
+        rt().block_on(self.breez_services.receive_payment_limits())
+            .map_err(|e| e.into())
+    }
+
+    pub fn validate_amount(
+        &self,
+        amount_msat: u64,
+        context: AmountContext,
+    ) -> Result<Vec<AmountViolation>, SDKError> {
+        rt().block_on(self.breez_services.validate_amount(amount_msat, context))
+            .map_err(|e| e.into())
+    }
+
+    pub fn list_peers(&self) -> Result<Vec<PeerInfo>, SDKError> {
+        rt().block_on(self.breez_services.list_peers())
+            .map_err(|e| e.into())
+    }
+
+    pub fn list_channels(&self) -> Result<Vec<ChannelInfo>, SDKError> {
+        rt().block_on(self.breez_services.list_channels())
+            .map_err(|e| e.into())
+    }
+
     pub fn node_info(&self) -> Result<Option<NodeState>, SDKError> {
         self.breez_services.node_info().map_err(|e| e.into())
     }
 
+    pub fn connection_status(&self) -> ConnectionStatus {
+        self.breez_services.connection_status()
+    }
+
+    pub fn payment_by_hash(&self, payment_hash: String) -> Result<Option<Payment>, SDKError> {
+        rt().block_on(self.breez_services.payment_by_hash(payment_hash))
+            .map_err(|e| e.into())
+    }
+
     pub fn list_payments(
         &self,
         filter: PaymentTypeFilter,
         from_timestamp: Option<i64>,
         to_timestamp: Option<i64>,
+        min_amount_msat: Option<u64>,
+        max_amount_msat: Option<u64>,
+        description_contains: Option<String>,
     ) -> Result<Vec<Payment>, SDKError> {
-        rt().block_on(
-            self.breez_services
-                .list_payments(filter, from_timestamp, to_timestamp),
-        )
+        rt().block_on(self.breez_services.list_payments(
+            filter,
+            from_timestamp,
+            to_timestamp,
+            min_amount_msat,
+            max_amount_msat,
+            description_contains,
+        ))
         .map_err(|e| e.into())
     }
 
@@ -219,6 +353,18 @@ impl BlockingBreezServices {
         .map_err(|e| e.into())
     }
 
+    pub fn withdraw_lnurl_max(
+        &self,
+        req_data: LnUrlWithdrawRequestData,
+        description: Option<String>,
+    ) -> Result<LnUrlCallbackStatus, SDKError> {
+        rt().block_on(
+            self.breez_services
+                .lnurl_withdraw_max(req_data, description),
+        )
+        .map_err(|e| e.into())
+    }
+
     pub fn lnurl_auth(
         &self,
         req_data: LnUrlAuthRequestData,
@@ -227,24 +373,78 @@ impl BlockingBreezServices {
             .map_err(|e| e.into())
     }
 
-    pub fn sweep(&self, to_address: String, fee_rate_sats_per_byte: u64) -> Result<(), SDKError> {
+    pub fn list_lnurl_auth_logins(&self) -> Result<Vec<LnUrlAuthLogin>, SDKError> {
+        rt().block_on(self.breez_services.list_lnurl_auth_logins())
+            .map_err(|e| e.into())
+    }
+
+    pub fn sweep(
+        &self,
+        to_address: String,
+        sat_per_vbyte: u32,
+        utxos: Option<Vec<String>>,
+    ) -> Result<SweepResult, SDKError> {
+        rt().block_on(self.breez_services.sweep(to_address, sat_per_vbyte, utxos))
+            .map_err(|e| e.into())
+    }
+
+    pub fn prepare_sweep(
+        &self,
+        to_address: String,
+        sat_per_vbyte: u32,
+        utxos: Option<Vec<String>>,
+    ) -> Result<SweepPreview, SDKError> {
         rt().block_on(
             self.breez_services
-                .sweep(to_address, fee_rate_sats_per_byte),
+                .prepare_sweep(to_address, sat_per_vbyte, utxos),
         )
         .map_err(|e| e.into())
     }
 
+    pub fn list_sweeps(&self) -> Result<Vec<SweepResult>, SDKError> {
+        rt().block_on(self.breez_services.list_sweeps())
+            .map_err(|e| e.into())
+    }
+
     pub fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, SDKError> {
         rt().block_on(self.breez_services.fetch_fiat_rates())
             .map_err(|e| e.into())
     }
 
+    pub fn fiat_rate(&self, coin: String) -> Result<Option<Rate>, SDKError> {
+        rt().block_on(self.breez_services.fiat_rate(coin))
+            .map_err(|e| e.into())
+    }
+
+    pub fn fiat_rate_at(&self, coin: String, timestamp: i64) -> Result<Option<Rate>, SDKError> {
+        rt().block_on(self.breez_services.fiat_rate_at(coin, timestamp))
+            .map_err(|e| e.into())
+    }
+
+    pub fn preferred_fiat_currency(&self) -> Result<Option<String>, SDKError> {
+        self.breez_services
+            .preferred_fiat_currency()
+            .map_err(|e| e.into())
+    }
+
+    pub fn select_fiat_currency(&self, id: String) -> Result<Rate, SDKError> {
+        rt().block_on(self.breez_services.select_fiat_currency(id))
+            .map_err(|e| e.into())
+    }
+
     pub fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>, SDKError> {
         rt().block_on(self.breez_services.list_fiat_currencies())
             .map_err(|e| e.into())
     }
 
+    pub fn list_fiat_currency_symbols(
+        &self,
+        locale: String,
+    ) -> Result<Vec<CurrencySymbolEntry>, SDKError> {
+        rt().block_on(self.breez_services.list_fiat_currency_symbols(locale))
+            .map_err(|e| e.into())
+    }
+
     pub fn list_lsps(&self) -> Result<Vec<LspInformation>, SDKError> {
         rt().block_on(self.breez_services.list_lsps())
             .map_err(|e| e.into())
@@ -261,6 +461,10 @@ impl BlockingBreezServices {
             .map_err(|e| e.into())
     }
 
+    pub fn lsp_liquidity_available(&self, amount_msat: u64) -> bool {
+        rt().block_on(self.breez_services.lsp_liquidity_available(amount_msat))
+    }
+
     pub fn lsp_id(&self) -> Result<Option<String>, SDKError> {
         rt().block_on(self.breez_services.lsp_id())
             .map_err(|e| e.into())
@@ -303,8 +507,39 @@ impl BlockingBreezServices {
         .map_err(|e| e.into())
     }
 
-    pub fn execute_dev_command(&self, command: String) -> Result<String> {
-        rt().block_on(self.breez_services.execute_dev_command(command))
+    pub fn prepare_refund(
+        &self,
+        swap_address: String,
+        to_address: String,
+        sat_per_vbyte: u32,
+    ) -> Result<RefundPreview, SDKError> {
+        rt().block_on(
+            self.breez_services
+                .prepare_refund(swap_address, to_address, sat_per_vbyte),
+        )
+        .map_err(|e| e.into())
+    }
+
+    pub fn refundable_amount(
+        &self,
+        swap_address: String,
+        sat_per_vbyte: u32,
+    ) -> Result<u64, SDKError> {
+        rt().block_on(
+            self.breez_services
+                .refundable_amount(swap_address, sat_per_vbyte),
+        )
+        .map_err(|e| e.into())
+    }
+
+    pub fn prune_terminal_swaps(&self, older_than_sec: u64) -> Result<u32, SDKError> {
+        self.breez_services
+            .prune_terminal_swaps(older_than_sec)
+            .map_err(|e| e.into())
+    }
+
+    pub fn execute_dev_command(&self, command: String, dry_run: bool) -> Result<String> {
+        rt().block_on(self.breez_services.execute_dev_command(command, dry_run))
     }
 
     pub fn sync(&self) -> Result<(), SDKError> {
@@ -312,16 +547,104 @@ impl BlockingBreezServices {
             .map_err(|e| e.into())
     }
 
+    pub fn wait_for_block_height(&self, height: u32, timeout_sec: u64) -> Result<u32, SDKError> {
+        rt().block_on(
+            self.breez_services
+                .wait_for_block_height(height, timeout_sec),
+        )
+        .map_err(|e| e.into())
+    }
+
+    pub fn full_resync(&self) -> Result<(), SDKError> {
+        rt().block_on(self.breez_services.full_resync())
+            .map_err(|e| e.into())
+    }
+
+    pub fn optimize_storage(&self) -> Result<(), SDKError> {
+        self.breez_services.optimize_storage().map_err(|e| e.into())
+    }
+
+    pub fn backup(&self) -> Result<Vec<u8>, SDKError> {
+        self.breez_services.backup().map_err(|e| e.into())
+    }
+
+    pub fn restore(&self, data: Vec<u8>) -> Result<(), SDKError> {
+        self.breez_services.restore(data).map_err(|e| e.into())
+    }
+
+    pub fn set_setting(&self, key: String, value: String) -> Result<(), SDKError> {
+        self.breez_services
+            .set_setting(key, value)
+            .map_err(|e| e.into())
+    }
+
+    pub fn get_setting(&self, key: String) -> Result<Option<String>, SDKError> {
+        self.breez_services.get_setting(key).map_err(|e| e.into())
+    }
+
     pub fn recommended_fees(&self) -> Result<RecommendedFees, SDKError> {
         rt().block_on(self.breez_services.recommended_fees())
             .map_err(|e| e.into())
     }
+
+    pub fn preferred_fee_tier(&self) -> Result<FeeTier, SDKError> {
+        self.breez_services
+            .preferred_fee_tier()
+            .map_err(|e| e.into())
+    }
+
+    pub fn set_preferred_fee_tier(&self, tier: FeeTier) -> Result<(), SDKError> {
+        self.breez_services
+            .set_preferred_fee_tier(tier)
+            .map_err(|e| e.into())
+    }
+
+    pub fn resolve_fee_rate(&self) -> Result<u64, SDKError> {
+        rt().block_on(self.breez_services.resolve_fee_rate())
+            .map_err(|e| e.into())
+    }
+
+    pub fn diagnostics(&self) -> Result<Diagnostics, SDKError> {
+        rt().block_on(self.breez_services.diagnostics())
+            .map_err(|e| e.into())
+    }
+
+    pub fn connectivity_check(&self) -> Vec<ServiceStatus> {
+        rt().block_on(self.breez_services.connectivity_check())
+    }
+
+    pub fn check_clock_skew(&self) -> Result<Option<ClockSkewWarning>, SDKError> {
+        rt().block_on(self.breez_services.check_clock_skew())
+            .map_err(|e| e.into())
+    }
+
+    pub fn buy_bitcoin(
+        &self,
+        provider: BuyBitcoinProvider,
+        base_currency_code: Option<String>,
+    ) -> Result<String, SDKError> {
+        rt().block_on(
+            self.breez_services
+                .buy_bitcoin(provider, base_currency_code),
+        )
+        .map_err(|e| e.into())
+    }
 }
 
 pub fn parse_invoice(invoice: String) -> Result<LNInvoice, SDKError> {
     sdk_parse_invoice(&invoice).map_err(|e| e.into())
 }
 
+pub fn parse_amount(input: String, default_unit: AmountUnit) -> Result<u64, SDKError> {
+    sdk_parse_amount(&input, default_unit).map_err(|e| e.into())
+}
+
+pub fn extract_lnurl_pay_metadata(
+    req_data: LnUrlPayRequestData,
+) -> Result<ParsedMetadata, SDKError> {
+    req_data.extract_metadata().map_err(|e| e.into())
+}
+
 pub fn parse_input(s: String) -> Result<InputType, SDKError> {
     rt().block_on(sdk_parse_input(&s)).map_err(|e| e.into())
 }
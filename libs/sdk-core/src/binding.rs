@@ -1,33 +1,39 @@
 //! Bindings for the Dart integration
 
 use crate::breez_services::{self, BreezEvent, EventListener};
-use crate::chain::RecommendedFees;
-use crate::fiat::{FiatCurrency, Rate};
-use crate::input_parser::{LnUrlAuthRequestData, LnUrlPayRequestData, LnUrlWithdrawRequestData};
+use crate::chain::{FeeTier, RecommendedFees};
+use crate::fiat::{CurrencySymbolEntry, FiatCurrency, Rate};
+use crate::input_parser::{
+    AmountUnit, LnUrlAuthRequestData, LnUrlPayRequestData, LnUrlWithdrawRequestData, ParsedMetadata,
+};
 use crate::lsp::LspInformation;
 use crate::models::LogEntry;
+use crate::persist::error::map_storage_error;
 use crate::LnUrlCallbackStatus;
 use anyhow::{anyhow, Result};
 use flutter_rust_bridge::StreamSink;
 use log::{Level, LevelFilter, Metadata, Record};
 use once_cell::sync::{Lazy, OnceCell};
 use std::future::Future;
-use std::sync::Arc;
-use tokio::sync::mpsc;
+use std::sync::{Arc, Mutex};
 
 use crate::breez_services::BreezServices;
 use crate::invoice::LNInvoice;
 use crate::models::{
-    Config, EnvironmentType, GreenlightCredentials, Network, NodeState, Payment, PaymentTypeFilter,
-    SwapInfo,
+    AmountContext, AmountViolation, BuyBitcoinProvider, ChannelInfo, ClockSkewWarning, Config,
+    ConnectionStatus, Diagnostics, EnvironmentType, GreenlightCredentials, LnUrlAuthLogin, Network,
+    NodeState, Payment, PaymentTypeFilter, PeerInfo, ReceiveCost, ReceiveLimits,
+    ReceivePaymentResponse, RefundPreview, ServiceStatus, SwapInfo, SweepPreview, SweepResult,
+    TemplateValue, TlvEntry,
 };
 
 use crate::input_parser::InputType;
 use crate::invoice::{self};
 use crate::lnurl::pay::model::LnUrlPayResult;
 
-static BREEZ_SERVICES_INSTANCE: OnceCell<Arc<BreezServices>> = OnceCell::new();
-static BREEZ_SERVICES_SHUTDOWN: OnceCell<mpsc::Sender<()>> = OnceCell::new();
+/// The instance created by [init_services], released by [stop_node]
+static BREEZ_SERVICES_INSTANCE: Lazy<Mutex<Option<Arc<BreezServices>>>> =
+    Lazy::new(|| Mutex::new(None));
 static NOTIFICATION_STREAM: OnceCell<StreamSink<BreezEvent>> = OnceCell::new();
 static LOG_STREAM: OnceCell<StreamSink<LogEntry>> = OnceCell::new();
 static RT: Lazy<tokio::runtime::Runtime> = Lazy::new(|| tokio::runtime::Runtime::new().unwrap());
@@ -49,10 +55,10 @@ impl log::Log for BindingLogger {
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
             if let Some(s) = LOG_STREAM.get() {
-                s.add(LogEntry {
-                    line: record.args().to_string(),
-                    level: record.level().as_str().to_string(),
-                });
+                s.add(LogEntry::new(
+                    record.args().to_string(),
+                    record.level().as_str().to_string(),
+                ));
             }
         };
     }
@@ -71,7 +77,7 @@ impl EventListener for BindingEventListener {
 
 /// Check whether node service is initialized or not
 pub fn initialized() -> bool {
-    block_on(async { get_breez_services().is_ok() })
+    rt().block_on(async { get_breez_services().is_ok() })
 }
 
 /// Register a new node in the cloud and return credentials to interact with it
@@ -130,9 +136,11 @@ pub fn init_services(config: Config, seed: Vec<u8>, creds: GreenlightCredentials
         let breez_services =
             BreezServices::init_services(config, seed, creds, Box::new(BindingEventListener {}))
                 .await?;
-        BREEZ_SERVICES_INSTANCE
-            .set(breez_services)
-            .map_err(|_| anyhow!("static node services already set"))?;
+        let mut instance = BREEZ_SERVICES_INSTANCE.lock().unwrap();
+        if instance.is_some() {
+            return Err(anyhow!("static node services already set"));
+        }
+        *instance = Some(breez_services);
 
         Ok(())
     })
@@ -140,18 +148,29 @@ pub fn init_services(config: Config, seed: Vec<u8>, creds: GreenlightCredentials
 
 /// See [BreezServices::start]
 pub fn start_node() -> Result<()> {
-    block_on(async {
-        BreezServices::start(
-            rt(),
-            BREEZ_SERVICES_INSTANCE
-                .get()
-                .ok_or_else(|| anyhow!("breez services instance was not initialized"))?,
-        )
-        .await
-    })
+    block_on(async { BreezServices::start(rt(), &get_breez_services()?).await })
+}
+
+/// Registers the stream of [BreezEvent]s.
+///
+/// With `replay`, the most recent events are sent first, see [Config::event_buffer_size].
+pub fn breez_events_stream(s: StreamSink<BreezEvent>, replay: bool) -> Result<()> {
+    let breez_services = match get_breez_services() {
+        Ok(breez_services) if replay => breez_services,
+        _ => return set_events_stream(s),
+    };
+
+    let mut res = Ok(());
+    breez_services.replay_events(|events| {
+        for e in events {
+            s.add(e);
+        }
+        res = set_events_stream(s);
+    });
+    res
 }
 
-pub fn breez_events_stream(s: StreamSink<BreezEvent>) -> Result<()> {
+fn set_events_stream(s: StreamSink<BreezEvent>) -> Result<()> {
     NOTIFICATION_STREAM
         .set(s)
         .map_err(|_| anyhow!("events stream already created"))?;
@@ -166,58 +185,175 @@ pub fn breez_log_stream(s: StreamSink<LogEntry>) -> Result<()> {
     Ok(())
 }
 
-/// Cleanup node resources and stop the signer.
+/// Cleanup node resources and stop the signer, see [BreezServices::stop].
+///
+/// The instance is released even if it wasn't started, so [init_services] can be called again.
 pub fn stop_node() -> Result<()> {
     block_on(async {
-        let shutdown_handler = BREEZ_SERVICES_SHUTDOWN.get();
-        match shutdown_handler {
-            None => Err(anyhow!("Background processing is not running")),
-            Some(s) => s.send(()).await.map_err(anyhow::Error::msg),
-        }
+        let res = get_breez_services()?.stop().await;
+        BREEZ_SERVICES_INSTANCE.lock().unwrap().take();
+        res
     })
 }
 
 /// See [BreezServices::send_payment]
-pub fn send_payment(bolt11: String, amount_sats: Option<u64>) -> Result<Payment> {
+pub fn send_payment(
+    bolt11: String,
+    amount_sats: Option<u64>,
+    max_fee_sat: Option<u64>,
+    timeout_sec: Option<u32>,
+) -> Result<Payment> {
     block_on(async {
         get_breez_services()?
-            .send_payment(bolt11, amount_sats)
+            .send_payment(bolt11, amount_sats, max_fee_sat, timeout_sec)
             .await
     })
 }
 
 /// See [BreezServices::send_spontaneous_payment]
-pub fn send_spontaneous_payment(node_id: String, amount_sats: u64) -> Result<Payment> {
+pub fn send_spontaneous_payment(
+    node_id: String,
+    amount_sats: u64,
+    timeout_sec: Option<u32>,
+    extra_tlvs: Option<Vec<TlvEntry>>,
+) -> Result<Payment> {
     block_on(async {
         get_breez_services()?
-            .send_spontaneous_payment(node_id, amount_sats)
+            .send_spontaneous_payment(node_id, amount_sats, timeout_sec, extra_tlvs)
             .await
     })
 }
 
 /// See [BreezServices::receive_payment]
-pub fn receive_payment(amount_sats: u64, description: String) -> Result<LNInvoice> {
+pub fn receive_payment(
+    amount_sats: u64,
+    description: String,
+    expiry_secs: Option<u32>,
+    preimage: Option<Vec<u8>>,
+    include_lsp_hint: Option<bool>,
+    fallback_address: Option<String>,
+) -> Result<ReceivePaymentResponse> {
+    block_on(async {
+        get_breez_services()?
+            .receive_payment(
+                amount_sats,
+                description.to_string(),
+                expiry_secs,
+                preimage,
+                include_lsp_hint,
+                fallback_address,
+            )
+            .await
+    })
+}
+
+/// See [BreezServices::receive_payment_with_template]
+pub fn receive_payment_with_template(
+    amount_sats: u64,
+    description_template: String,
+    values: Vec<TemplateValue>,
+) -> Result<ReceivePaymentResponse> {
+    block_on(async {
+        get_breez_services()?
+            .receive_payment_with_template(amount_sats, description_template, values)
+            .await
+    })
+}
+
+/// See [BreezServices::receive_payment_with_description_hash]
+pub fn receive_payment_with_description_hash(
+    amount_sats: u64,
+    description: String,
+    description_hash: Vec<u8>,
+) -> Result<ReceivePaymentResponse> {
+    block_on(async {
+        get_breez_services()?
+            .receive_payment_with_description_hash(amount_sats, description, description_hash)
+            .await
+    })
+}
+
+/// See [BreezServices::receive_payment_split]
+pub fn receive_payment_split(amount_sats: u64, description: String) -> Result<Vec<LNInvoice>> {
+    block_on(async {
+        get_breez_services()?
+            .receive_payment_split(amount_sats, description)
+            .await
+    })
+}
+
+/// See [BreezServices::receive_donation]
+pub fn receive_donation(description: String) -> Result<LNInvoice> {
+    block_on(async { get_breez_services()?.receive_donation(description).await })
+}
+
+/// See [BreezServices::validate_amount]
+pub fn validate_amount(amount_msat: u64, context: AmountContext) -> Result<Vec<AmountViolation>> {
+    block_on(async {
+        get_breez_services()?
+            .validate_amount(amount_msat, context)
+            .await
+    })
+}
+
+/// See [BreezServices::receive_cost_estimate]
+pub fn receive_cost_estimate(amount_msat: u64) -> Result<ReceiveCost> {
     block_on(async {
         get_breez_services()?
-            .receive_payment(amount_sats, description.to_string())
+            .receive_cost_estimate(amount_msat)
             .await
     })
 }
 
+/// See [BreezServices::receive_payment_limits]
+pub fn receive_payment_limits() -> Result<ReceiveLimits> {
+    block_on(async { get_breez_services()?.receive_payment_limits().await })
+}
+
+/// See [BreezServices::list_peers]
+pub fn list_peers() -> Result<Vec<PeerInfo>> {
+    block_on(async { get_breez_services()?.list_peers().await })
+}
+
+/// See [BreezServices::list_channels]
+pub fn list_channels() -> Result<Vec<ChannelInfo>> {
+    block_on(async { get_breez_services()?.list_channels().await })
+}
+
 /// See [BreezServices::node_info]
 pub fn node_info() -> Result<Option<NodeState>> {
     block_on(async { get_breez_services()?.node_info() })
 }
 
+/// See [BreezServices::connection_status]
+pub fn connection_status() -> Result<ConnectionStatus> {
+    block_on(async { Ok(get_breez_services()?.connection_status()) })
+}
+
+/// See [BreezServices::payment_by_hash]
+pub fn payment_by_hash(payment_hash: String) -> Result<Option<Payment>> {
+    block_on(async { get_breez_services()?.payment_by_hash(payment_hash).await })
+}
+
 /// See [BreezServices::list_payments]
 pub fn list_payments(
     filter: PaymentTypeFilter,
     from_timestamp: Option<i64>,
     to_timestamp: Option<i64>,
+    min_amount_msat: Option<u64>,
+    max_amount_msat: Option<u64>,
+    description_contains: Option<String>,
 ) -> Result<Vec<Payment>> {
     block_on(async {
         get_breez_services()?
-            .list_payments(filter, from_timestamp, to_timestamp)
+            .list_payments(
+                filter,
+                from_timestamp,
+                to_timestamp,
+                min_amount_msat,
+                max_amount_msat,
+                description_contains,
+            )
             .await
     })
 }
@@ -237,6 +373,15 @@ pub fn fetch_lsp_info(id: String) -> Result<Option<LspInformation>> {
     block_on(async { get_breez_services()?.fetch_lsp_info(id).await })
 }
 
+/// See [BreezServices::lsp_liquidity_available]
+pub fn lsp_liquidity_available(amount_msat: u64) -> Result<bool> {
+    block_on(async {
+        Ok(get_breez_services()?
+            .lsp_liquidity_available(amount_msat)
+            .await)
+    })
+}
+
 /// See [BreezServices::lsp_id]
 pub fn lsp_id() -> Result<Option<String>> {
     block_on(async { get_breez_services()?.lsp_id().await })
@@ -247,25 +392,76 @@ pub fn fetch_fiat_rates() -> Result<Vec<Rate>> {
     block_on(async { get_breez_services()?.fetch_fiat_rates().await })
 }
 
+/// See [BreezServices::fiat_rate]
+pub fn fiat_rate(coin: String) -> Result<Option<Rate>> {
+    block_on(async { get_breez_services()?.fiat_rate(coin).await })
+}
+
+/// See [BreezServices::fiat_rate_at]
+pub fn fiat_rate_at(coin: String, timestamp: i64) -> Result<Option<Rate>> {
+    block_on(async { get_breez_services()?.fiat_rate_at(coin, timestamp).await })
+}
+
+/// See [BreezServices::preferred_fiat_currency]
+pub fn preferred_fiat_currency() -> Result<Option<String>> {
+    get_breez_services()?.preferred_fiat_currency()
+}
+
+/// See [BreezServices::select_fiat_currency]
+pub fn select_fiat_currency(id: String) -> Result<Rate> {
+    block_on(async { get_breez_services()?.select_fiat_currency(id).await })
+}
+
 /// See [BreezServices::list_fiat_currencies]
 pub fn list_fiat_currencies() -> Result<Vec<FiatCurrency>> {
     block_on(async { get_breez_services()?.list_fiat_currencies().await })
 }
 
+/// See [BreezServices::list_fiat_currency_symbols]
+pub fn list_fiat_currency_symbols(locale: String) -> Result<Vec<CurrencySymbolEntry>> {
+    block_on(async {
+        get_breez_services()?
+            .list_fiat_currency_symbols(locale)
+            .await
+    })
+}
+
 /// See [BreezServices::close_lsp_channels]
 pub fn close_lsp_channels() -> Result<()> {
     block_on(async { get_breez_services()?.close_lsp_channels().await })
 }
 
 /// See [BreezServices::sweep]
-pub fn sweep(to_address: String, fee_rate_sats_per_byte: u64) -> Result<()> {
+pub fn sweep(
+    to_address: String,
+    sat_per_vbyte: u32,
+    utxos: Option<Vec<String>>,
+) -> Result<SweepResult> {
+    block_on(async {
+        get_breez_services()?
+            .sweep(to_address, sat_per_vbyte, utxos)
+            .await
+    })
+}
+
+/// See [BreezServices::prepare_sweep]
+pub fn prepare_sweep(
+    to_address: String,
+    sat_per_vbyte: u32,
+    utxos: Option<Vec<String>>,
+) -> Result<SweepPreview> {
     block_on(async {
         get_breez_services()?
-            .sweep(to_address, fee_rate_sats_per_byte)
+            .prepare_sweep(to_address, sat_per_vbyte, utxos)
             .await
     })
 }
 
+/// See [BreezServices::list_sweeps]
+pub fn list_sweeps() -> Result<Vec<SweepResult>> {
+    block_on(async { get_breez_services()?.list_sweeps().await })
+}
+
 /// See [BreezServices::receive_onchain]
 pub fn receive_onchain() -> Result<SwapInfo> {
     block_on(async { get_breez_services()?.receive_onchain().await })
@@ -289,25 +485,96 @@ pub fn refund(swap_address: String, to_address: String, sat_per_vbyte: u32) -> R
     })
 }
 
+/// See [BreezServices::prepare_refund]
+pub fn prepare_refund(
+    swap_address: String,
+    to_address: String,
+    sat_per_vbyte: u32,
+) -> Result<RefundPreview> {
+    block_on(async {
+        get_breez_services()?
+            .prepare_refund(swap_address, to_address, sat_per_vbyte)
+            .await
+    })
+}
+
+/// See [BreezServices::refundable_amount]
+pub fn refundable_amount(swap_address: String, sat_per_vbyte: u32) -> Result<u64> {
+    block_on(async {
+        get_breez_services()?
+            .refundable_amount(swap_address, sat_per_vbyte)
+            .await
+    })
+}
+
+/// See [BreezServices::prune_terminal_swaps]
+pub fn prune_terminal_swaps(older_than_sec: u64) -> Result<u32> {
+    block_on(async { get_breez_services()?.prune_terminal_swaps(older_than_sec) })
+}
+
 /// See [BreezServices::execute_dev_command]
-pub fn execute_command(command: String) -> Result<String> {
-    block_on(async { get_breez_services()?.execute_dev_command(command).await })
+pub fn execute_command(command: String, dry_run: bool) -> Result<String> {
+    block_on(async {
+        get_breez_services()?
+            .execute_dev_command(command, dry_run)
+            .await
+    })
 }
 
 pub fn sync_node() -> Result<()> {
     block_on(async { get_breez_services()?.sync().await })
 }
 
-fn get_breez_services() -> Result<&'static BreezServices> {
-    let n = BREEZ_SERVICES_INSTANCE.get();
-    match n {
-        Some(a) => Ok(a),
-        None => Err(anyhow!("Node service was not initialized")),
-    }
+/// See [BreezServices::wait_for_block_height]
+pub fn wait_for_block_height(height: u32, timeout_sec: u64) -> Result<u32> {
+    block_on(async {
+        get_breez_services()?
+            .wait_for_block_height(height, timeout_sec)
+            .await
+    })
+}
+
+/// See [BreezServices::full_resync]
+pub fn full_resync() -> Result<()> {
+    block_on(async { get_breez_services()?.full_resync().await })
+}
+
+/// See [BreezServices::optimize_storage]
+pub fn optimize_storage() -> Result<()> {
+    block_on(async { get_breez_services()?.optimize_storage() })
+}
+
+/// See [BreezServices::backup]
+pub fn backup() -> Result<Vec<u8>> {
+    block_on(async { get_breez_services()?.backup() })
+}
+
+/// See [BreezServices::restore]
+pub fn restore(data: Vec<u8>) -> Result<()> {
+    block_on(async { get_breez_services()?.restore(data) })
+}
+
+/// See [BreezServices::set_setting]
+pub fn set_setting(key: String, value: String) -> Result<()> {
+    block_on(async { get_breez_services()?.set_setting(key, value) })
+}
+
+/// See [BreezServices::get_setting]
+pub fn get_setting(key: String) -> Result<Option<String>> {
+    block_on(async { get_breez_services()?.get_setting(key) })
+}
+
+fn get_breez_services() -> Result<Arc<BreezServices>> {
+    BREEZ_SERVICES_INSTANCE
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| anyhow!("Node service was not initialized"))
 }
 
-fn block_on<F: Future>(future: F) -> F::Output {
-    rt().block_on(future)
+/// Runs `future` to completion, replacing raw storage errors with a [crate::StorageError]
+fn block_on<T, F: Future<Output = Result<T>>>(future: F) -> Result<T> {
+    rt().block_on(future).map_err(map_storage_error)
 }
 
 pub(crate) fn rt() -> &'static tokio::runtime::Runtime {
@@ -320,6 +587,15 @@ pub fn parse_invoice(invoice: String) -> Result<LNInvoice> {
     invoice::parse_invoice(&invoice)
 }
 
+pub fn parse_amount(input: String, default_unit: AmountUnit) -> Result<u64> {
+    crate::input_parser::parse_amount(&input, default_unit)
+}
+
+/// See [LnUrlPayRequestData::extract_metadata]
+pub fn extract_lnurl_pay_metadata(req_data: LnUrlPayRequestData) -> Result<ParsedMetadata> {
+    req_data.extract_metadata()
+}
+
 pub fn parse(s: String) -> Result<InputType> {
     block_on(async { crate::input_parser::parse(&s).await })
 }
@@ -350,11 +626,28 @@ pub fn lnurl_withdraw(
     })
 }
 
+/// See [BreezServices::lnurl_withdraw_max]
+pub fn lnurl_withdraw_max(
+    req_data: LnUrlWithdrawRequestData,
+    description: Option<String>,
+) -> Result<LnUrlCallbackStatus> {
+    block_on(async {
+        get_breez_services()?
+            .lnurl_withdraw_max(req_data, description)
+            .await
+    })
+}
+
 /// See [BreezServices::lnurl_auth]
 pub fn lnurl_auth(req_data: LnUrlAuthRequestData) -> Result<LnUrlCallbackStatus> {
     block_on(async { get_breez_services()?.lnurl_auth(req_data).await })
 }
 
+/// See [BreezServices::list_lnurl_auth_logins]
+pub fn list_lnurl_auth_logins() -> Result<Vec<LnUrlAuthLogin>> {
+    block_on(async { get_breez_services()?.list_lnurl_auth_logins().await })
+}
+
 /// See [breez_services::mnemonic_to_seed]
 pub fn mnemonic_to_seed(phrase: String) -> Result<Vec<u8>> {
     breez_services::mnemonic_to_seed(phrase)
@@ -365,7 +658,49 @@ pub fn recommended_fees() -> Result<RecommendedFees> {
     block_on(async { get_breez_services()?.recommended_fees().await })
 }
 
+/// See [BreezServices::preferred_fee_tier]
+pub fn preferred_fee_tier() -> Result<FeeTier> {
+    get_breez_services()?.preferred_fee_tier()
+}
+
+/// See [BreezServices::set_preferred_fee_tier]
+pub fn set_preferred_fee_tier(tier: FeeTier) -> Result<()> {
+    get_breez_services()?.set_preferred_fee_tier(tier)
+}
+
+/// See [BreezServices::resolve_fee_rate]
+pub fn resolve_fee_rate() -> Result<u64> {
+    block_on(async { get_breez_services()?.resolve_fee_rate().await })
+}
+
+/// See [BreezServices::buy_bitcoin]
+pub fn buy_bitcoin(
+    provider: BuyBitcoinProvider,
+    base_currency_code: Option<String>,
+) -> Result<String> {
+    block_on(async {
+        get_breez_services()?
+            .buy_bitcoin(provider, base_currency_code)
+            .await
+    })
+}
+
+/// See [BreezServices::diagnostics]
+pub fn diagnostics() -> Result<Diagnostics> {
+    block_on(async { get_breez_services()?.diagnostics().await })
+}
+
+/// See [BreezServices::connectivity_check]
+pub fn connectivity_check() -> Result<Vec<ServiceStatus>> {
+    block_on(async { Ok(get_breez_services()?.connectivity_check().await) })
+}
+
+/// See [BreezServices::check_clock_skew]
+pub fn check_clock_skew() -> Result<Option<ClockSkewWarning>> {
+    block_on(async { get_breez_services()?.check_clock_skew().await })
+}
+
 /// See [BreezServices::default_config]
-pub fn default_config(config_type: EnvironmentType) -> Config {
-    BreezServices::default_config(config_type)
+pub fn default_config(config_type: EnvironmentType, network: Network) -> Config {
+    BreezServices::default_config(config_type, network)
 }
@@ -1,13 +1,21 @@
-use std::cmp::max;
+use std::cmp::min;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::future::Future;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Result};
 use bip39::*;
+use bitcoin::consensus::deserialize;
 use bitcoin::hashes::{sha256, Hash};
+use bitcoin::{Address, Transaction};
+use once_cell::sync::Lazy;
 use tokio::runtime::Runtime;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{mpsc, watch, Mutex};
 use tokio::time::{sleep, Duration};
 use tonic::codegen::InterceptedService;
 use tonic::metadata::{Ascii, MetadataValue};
@@ -15,33 +23,45 @@ use tonic::service::Interceptor;
 use tonic::transport::{Channel, Uri};
 use tonic::{Request, Status};
 
-use crate::chain::{ChainService, MempoolSpace, RecommendedFees};
-use crate::fiat::{FiatCurrency, Rate};
+use crate::buy::BuyBitcoinService;
+use crate::chain::{
+    ChainService, EsploraChainService, FeeTier, MempoolSpace, RecommendedFees,
+    ThrottledChainService,
+};
+use crate::fiat::{to_symbol_entries, CachedFiatAPI, CurrencySymbolEntry, FiatCurrency, Rate};
 use crate::greenlight::Greenlight;
 use crate::grpc::channel_opener_client::ChannelOpenerClient;
 use crate::grpc::fund_manager_client::FundManagerClient;
 use crate::grpc::information_client::InformationClient;
 use crate::grpc::signer_client::SignerClient;
 use crate::grpc::PaymentInformation;
-use crate::input_parser::LnUrlPayRequestData;
-use crate::invoice::{add_lsp_routing_hints, parse_invoice, LNInvoice, RouteHint, RouteHintHop};
+use crate::input_parser::{validate_address_network, LnUrlPayRequestData};
+use crate::invoice::{
+    add_fallback_address, add_lsp_routing_hints, parse_fallback_address, parse_invoice,
+    render_description_template, InvoiceError, LNInvoice, RouteHint, RouteHintHop,
+    EXPIRY_CLOCK_SKEW_SECS, MAX_INVOICE_EXPIRY_SECS, MIN_INVOICE_EXPIRY_SECS,
+};
 use crate::lnurl::auth::perform_lnurl_auth;
 use crate::lnurl::pay::model::SuccessAction::Aes;
 use crate::lnurl::pay::model::{
     LnUrlPayResult, SuccessAction, SuccessActionProcessed, ValidatedCallbackResponse,
 };
 use crate::lnurl::pay::validate_lnurl_pay;
-use crate::lnurl::withdraw::validate_lnurl_withdraw;
+use crate::lnurl::withdraw::{validate_lnurl_withdraw, validate_withdraw_amount};
 use crate::lsp::LspInformation;
 use crate::models::{
-    parse_short_channel_id, ChannelState, ClosedChannelPaymentDetails, Config, EnvironmentType,
-    FiatAPI, GreenlightCredentials, LnUrlCallbackStatus, LspAPI, Network, NodeAPI, NodeState,
-    Payment, PaymentDetails, PaymentType, PaymentTypeFilter, SwapInfo, SwapperAPI,
+    parse_short_channel_id, set_redact_logs, AmountContext, AmountViolation, ChainBackend,
+    ChannelInfo, ChannelState, ClockSkewWarning, ClosedChannelPaymentDetails, Config,
+    ConnectionStatus, Diagnostics, EnvironmentType, FiatAPI, GreenlightCredentials, LnUrlAuthLogin,
+    LnUrlCallbackStatus, LspAPI, Network, NodeAPI, NodeState, OpeningFeeParams,
+    OverpaidSwapHandling, Payment, PaymentDetails, PaymentType, PaymentTypeFilter, PeerInfo,
+    ReceiveCost, ReceiveLimits, ReceivePaymentResponse, RefundPreview, SatPerVbyte, ServiceStatus,
+    ServiceType, SwapDiagnostics, SwapInfo, SwapStatus, SwapperAPI, SweepPreview, SweepResult,
+    TemplateValue, TlvEntry, UnspentTransactionOutput, CUSTOM_TLV_MIN_TYPE,
 };
 use crate::moonpay::MoonPayApi;
 use crate::persist::db::SqliteStorage;
 use crate::swap::BTCReceiveSwap;
-use crate::BuyBitcoinProvider::MoonPay;
 use crate::{BuyBitcoinProvider, LnUrlAuthRequestData, LnUrlWithdrawRequestData, PaymentResponse};
 
 /// Trait that can be used to react to various [BreezEvent]s emitted by the SDK.
@@ -64,6 +84,47 @@ pub enum BreezEvent {
     PaymentSucceed { details: Payment },
     /// Indicates that an outgoing payment has been failed to complete
     PaymentFailed { details: PaymentFailedData },
+    /// Indicates that an already settled invoice was reported as paid again. The duplicate
+    /// settlement is ignored and not counted towards the received amount.
+    DuplicateIncomingPayment { details: InvoicePaidDetails },
+    /// Indicates that the SDK connected to the selected LSP on startup
+    LspConnected { lsp_id: String },
+    /// Indicates that an outgoing payment passed validation and is being dispatched to the node.
+    /// It is emitted before the node attempts any route, and is followed by either a
+    /// [BreezEvent::PaymentSucceed] or a [BreezEvent::PaymentFailed].
+    PaymentPending { details: PaymentPendingData },
+    /// Indicates that the confirmed balance of a swap address changed, either because a deposit
+    /// confirmed or because a confirmed deposit was reorged out of the chain
+    SwapUpdated { details: SwapInfo },
+    /// Indicates that the streams of node events were lost, or that they were reopened and the
+    /// local state sync-ed after a connectivity loss
+    ConnectionStatusChanged { connected: bool },
+    /// Indicates that more than the maximum allowed deposit was confirmed on a swap address, and
+    /// how the deposit is handled
+    SwapOverpaid {
+        details: SwapInfo,
+        handling: OverpaidSwapHandling,
+    },
+    /// Indicates that swaps which expired while the SDK was offline were marked as expired on
+    /// startup, see [Config::reconcile_expired_swaps_on_start]
+    SwapsReconciled { expired: Vec<SwapInfo> },
+    /// Indicates how many of the `total` payments pulled by [BreezServices::sync] are stored so
+    /// far. It is first emitted with `processed` 0 once the node state and channels are updated,
+    /// and the sync ends with a [BreezEvent::Synced]. These events aren't replayed.
+    SyncProgress { processed: u32, total: u32 },
+}
+
+/// Details of an outgoing payment being dispatched, included as payload in an emitted [BreezEvent]
+#[derive(Clone, Debug)]
+pub struct PaymentPendingData {
+    pub node_id: String,
+    /// The invoice being paid, absent for spontaneous payments
+    pub bolt11: Option<String>,
+    /// The hash of the invoice being paid, to match the pending payment with the
+    /// [BreezEvent::PaymentSucceed] or [BreezEvent::PaymentFailed] that follows. Absent for
+    /// spontaneous payments, whose preimage is only picked by the node.
+    pub payment_hash: Option<String>,
+    pub amount_msat: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -97,33 +158,77 @@ async fn start_threads(
             .await;
     });
 
-    // sync with remote state
+    // connect to the selected lsp, then sync with remote state
     let breez_cloned = breez_services.clone();
-    breez_cloned.sync().await?;
+    breez_cloned.start_node().await?;
+    if let Err(err) = breez_cloned.auto_connect_lsp().await {
+        warn!("Failed to connect to the LSP on startup: {:?}", err);
+    }
+    breez_cloned.pull_changes().await?;
+
+    // mark the swaps that expired while offline, before the next block does
+    if breez_cloned.config.reconcile_expired_swaps_on_start {
+        if let Err(err) = breez_cloned.reconcile_expired_swaps().await {
+            warn!(
+                "Failed to reconcile the expired swaps on startup: {:?}",
+                err
+            );
+        }
+    }
+
+    // warm up the fiat rates cache for the default currency
+    if let Err(err) = breez_cloned.preload_default_fiat_rate().await {
+        warn!(
+            "Failed to preload the default fiat rate on startup: {:?}",
+            err
+        );
+    }
 
     // create a shutdown channel (sender and receiver)
     let (stop_sender, mut stop_receiver) = mpsc::channel(1);
 
     // poll sdk events
+    let max_backoff =
+        Duration::from_secs(breez_services.config.max_reconnect_backoff_sec.max(1) as u64);
     rt.spawn(async move {
         let current_block: u32 = 0;
+        let mut backoff = ReconnectBackoff::new(max_backoff);
+        let mut connected = true;
         loop {
+            breez_services.set_connection_status(ConnectionStatus::Connecting);
             tokio::select! {
 
-              poll_result = poll_events(breez_services.clone(), current_block) => {
+              poll_result = poll_events(breez_services.clone(), current_block, &mut connected) => {
                match poll_result {
                 Ok(()) => {
                  return;
                 },
                 Err(err) => {
-                 debug!("poll_events returned with error: {:?} waiting...", err);
-                 sleep(Duration::from_secs(1)).await;
-                 continue
+                 breez_services.set_connection_status(ConnectionStatus::Disconnected);
+                 if connected {
+                  connected = false;
+                  backoff.reset();
+                  breez_services.emit_event(BreezEvent::ConnectionStatusChanged { connected });
+                 }
+                 let delay = backoff.next_delay();
+                 debug!("poll_events returned with error: {:?} retrying in {:?}", err, delay);
+                 tokio::select! {
+                  _ = sleep(delay) => continue,
+                  _ = stop_receiver.recv() => {
+                   _ = shutdown_signer_sender.send(()).await;
+                   shutdown_signer_sender.closed().await;
+                   breez_services.set_connection_status(ConnectionStatus::Disconnected);
+                   debug!("Received the signal to exit event polling loop");
+                   return;
+                  }
+                 }
                 }
                }
               },
               _ = stop_receiver.recv() => {
                _ = shutdown_signer_sender.send(()).await;
+               shutdown_signer_sender.closed().await;
+               breez_services.set_connection_status(ConnectionStatus::Disconnected);
                debug!("Received the signal to exit event polling loop");
                return;
              }
@@ -133,18 +238,39 @@ async fn start_threads(
     Ok(stop_sender)
 }
 
+/// The events waiting to be delivered to the event listener, and the delivered ones kept for replay
+#[derive(Default)]
+struct EventQueue {
+    pending: VecDeque<BreezEvent>,
+    /// The most recent delivered events, see [Config::event_buffer_size]
+    recent: VecDeque<BreezEvent>,
+    /// Whether a thread is delivering the pending events
+    delivering: bool,
+}
+
 /// BreezServices is a facade and the single entry point for the SDK.
 pub struct BreezServices {
+    config: Config,
     node_api: Arc<dyn NodeAPI>,
     lsp_api: Arc<dyn LspAPI>,
     fiat_api: Arc<dyn FiatAPI>,
-    moonpay_api: Arc<dyn MoonPayApi>,
+    buy_bitcoin_service: Arc<BuyBitcoinService>,
     chain_service: Arc<dyn ChainService>,
     persister: Arc<SqliteStorage>,
     payment_receiver: Arc<PaymentReceiver>,
     btc_receive_swapper: Arc<BTCReceiveSwap>,
     event_listener: Option<Box<dyn EventListener>>,
+    events: std::sync::Mutex<EventQueue>,
+    /// Notified whenever the queued events are all delivered
+    events_delivered: std::sync::Condvar,
+    connection_status: std::sync::Mutex<ConnectionStatus>,
+    /// Highest block height seen, from the node state or a [BreezEvent::NewBlock]
+    block_height: watch::Sender<u32>,
+    /// Offset of the device clock found by [BreezServices::check_clock_skew]
+    clock_offset_secs: AtomicI64,
     shutdown_sender: Mutex<Option<mpsc::Sender<()>>>,
+    /// Held from [BreezServices::init_services] until [BreezServices::stop]
+    working_dir_guard: std::sync::Mutex<Option<WorkingDirGuard>>,
 }
 
 impl BreezServices {
@@ -164,16 +290,29 @@ impl BreezServices {
     }
 
     /// Create and initialize the node services instance
+    ///
+    /// Fails with [InitServicesError::AlreadyInitialized] while another instance uses the same
+    /// [Config::working_dir], until that instance is stopped.
     pub async fn init_services(
         config: Config,
         seed: Vec<u8>,
         creds: GreenlightCredentials,
         event_listener: Box<dyn EventListener>,
     ) -> Result<Arc<BreezServices>> {
-        BreezServicesBuilder::new(config)
-            .greenlight_credentials(creds, seed)
-            .build(Some(event_listener))
-            .await
+        let mut builder = BreezServicesBuilder::new(config);
+        builder.greenlight_credentials(creds, seed);
+        Self::init_with_builder(&builder, event_listener).await
+    }
+
+    /// Builds the instance, which holds [Config::working_dir] until it is stopped
+    async fn init_with_builder(
+        builder: &BreezServicesBuilder,
+        event_listener: Box<dyn EventListener>,
+    ) -> Result<Arc<BreezServices>> {
+        let working_dir_guard = WorkingDirGuard::acquire(&builder.config.working_dir)?;
+        let breez_services = builder.build(Some(event_listener)).await?;
+        *breez_services.working_dir_guard.lock().unwrap() = Some(working_dir_guard);
+        Ok(breez_services)
     }
 
     /// Starts the BreezServices background threads for this instance.
@@ -193,14 +332,24 @@ impl BreezServices {
         Ok(())
     }
 
-    /// Trigger the stopping of BreezServices background threads for this instance.
+    /// Stops the BreezServices background threads for this instance, and waits for them and the
+    /// signer to exit.
+    ///
+    /// This also releases the working dir, so a new instance can be initialized with it, even if
+    /// the instance was never started.
     pub async fn stop(&self) -> Result<()> {
-        let unlocked = self.shutdown_sender.lock().await;
-        if unlocked.is_none() {
-            return Err(anyhow!("node has not been started"));
-        }
-        let sender = unlocked.as_ref().unwrap();
-        sender.send(()).await.map_err(anyhow::Error::msg)
+        let sender = self.shutdown_sender.lock().await.clone();
+        let res = match sender {
+            None => Err(anyhow!("node has not been started")),
+            Some(sender) => {
+                let res = sender.send(()).await.map_err(anyhow::Error::msg);
+                // the polling loop drops the receiver once the signer exited
+                sender.closed().await;
+                res
+            }
+        };
+        self.working_dir_guard.lock().unwrap().take();
+        res
     }
 
     /// Pay a bolt11 invoice
@@ -210,13 +359,44 @@ impl BreezServices {
     /// # Arguments
     ///
     /// * `bolt11` - The bolt11 invoice
-    /// * `amount_sats` - The amount to pay in satoshis
-    pub async fn send_payment(&self, bolt11: String, amount_sats: Option<u64>) -> Result<Payment> {
+    /// * `amount_sats` - The amount to pay in satoshis, required by amountless invoices, see
+    ///   [PaymentError]
+    /// * `max_fee_sat` - Optional absolute cap on the routing fee, applied on top of
+    ///   [Config::maxfee_percent]: the lower of the two limits is used
+    /// * `timeout_sec` - Optional override of [Config::payment_timeout_sec] for this payment
+    pub async fn send_payment(
+        &self,
+        bolt11: String,
+        amount_sats: Option<u64>,
+        max_fee_sat: Option<u64>,
+        timeout_sec: Option<u32>,
+    ) -> Result<Payment> {
+        let timeout_sec = payment_timeout_sec(timeout_sec, self.config.payment_timeout_sec)?;
         self.start_node().await?;
         let parsed_invoice = parse_invoice(bolt11.as_str())?;
+        parsed_invoice.validate_network(self.config.network)?;
+        parsed_invoice.validate_not_expired_at(self.now_secs()?)?;
+        if let Some(node_state) = self.persister.get_node_state()? {
+            if parsed_invoice.payee_pubkey == node_state.id {
+                return Err(InvoiceError::SelfPaymentNotAllowed.into());
+            }
+        }
+        let amount_sats = payment_amount_override(&parsed_invoice, amount_sats)?;
+        self.notify_event_listeners(BreezEvent::PaymentPending {
+            details: PaymentPendingData {
+                node_id: parsed_invoice.payee_pubkey.clone(),
+                bolt11: Some(bolt11.clone()),
+                payment_hash: Some(parsed_invoice.payment_hash.clone()),
+                amount_msat: parsed_invoice
+                    .amount_msat
+                    .or(amount_sats.map(|a| a * 1000))
+                    .unwrap_or_default(),
+            },
+        })
+        .await?;
         let payment_res = self
             .node_api
-            .send_payment(bolt11.clone(), amount_sats)
+            .send_payment(bolt11.clone(), amount_sats, max_fee_sat, timeout_sec)
             .await;
         self.on_payment_completed(
             parsed_invoice.payee_pubkey.clone(),
@@ -232,15 +412,31 @@ impl BreezServices {
     ///
     /// * `node_id` - The destination node_id
     /// * `amount_sats` - The amount to pay in satoshis
+    /// * `timeout_sec` - Optional override of [Config::payment_timeout_sec] for this payment
+    /// * `extra_tlvs` - Optional custom records added to the payment onion, see [TlvEntry]
     pub async fn send_spontaneous_payment(
         &self,
         node_id: String,
         amount_sats: u64,
+        timeout_sec: Option<u32>,
+        extra_tlvs: Option<Vec<TlvEntry>>,
     ) -> Result<Payment> {
+        let timeout_sec = payment_timeout_sec(timeout_sec, self.config.payment_timeout_sec)?;
+        let extra_tlvs = extra_tlvs.unwrap_or_default();
+        validate_extra_tlvs(&extra_tlvs)?;
         self.start_node().await?;
+        self.notify_event_listeners(BreezEvent::PaymentPending {
+            details: PaymentPendingData {
+                node_id: node_id.clone(),
+                bolt11: None,
+                payment_hash: None,
+                amount_msat: amount_sats * 1000,
+            },
+        })
+        .await?;
         let payment_res = self
             .node_api
-            .send_spontaneous_payment(node_id.clone(), amount_sats)
+            .send_spontaneous_payment(node_id.clone(), amount_sats, timeout_sec, extra_tlvs)
             .await;
         self.on_payment_completed(node_id, None, payment_res).await
     }
@@ -264,7 +460,7 @@ impl BreezServices {
                 Ok(LnUrlPayResult::EndpointError { data: e })
             }
             ValidatedCallbackResponse::EndpointSuccess { data: cb } => {
-                let payment = self.send_payment(cb.pr, None).await?;
+                let payment = self.send_payment(cb.pr, None, None, None).await?;
                 let details = match &payment.details {
                     PaymentDetails::ClosedChannel { .. } => {
                         return Err(anyhow!("Payment lookup found unexpected payment type"));
@@ -275,13 +471,15 @@ impl BreezServices {
                 let maybe_sa_processed: Option<SuccessActionProcessed> = match cb.success_action {
                     Some(sa) => {
                         let processed_sa = match sa {
-                            // For AES, we decrypt the contents on the fly
+                            // For AES, we decrypt the contents on the fly. A failed decryption is
+                            // reported in the result, the payment itself succeeded.
                             Aes(data) => {
                                 let preimage = sha256::Hash::from_str(&details.payment_preimage)?;
                                 let preimage_arr: [u8; 32] = preimage.into_inner();
 
-                                let decrypted = (data, &preimage_arr).try_into()?;
-                                SuccessActionProcessed::Aes { data: decrypted }
+                                SuccessActionProcessed::Aes {
+                                    data: (data, &preimage_arr).into(),
+                                }
                             }
                             SuccessAction::Message(data) => {
                                 SuccessActionProcessed::Message { data }
@@ -301,8 +499,14 @@ impl BreezServices {
                     req_data.ln_address,
                 )?;
 
+                // Return the stored record, which includes the LNURL info stored above
+                let payment = self
+                    .persister
+                    .get_payment_by_hash(&details.payment_hash)?
+                    .unwrap_or(payment);
                 Ok(LnUrlPayResult::EndpointSuccess {
                     data: maybe_sa_processed,
+                    payment,
                 })
             }
         }
@@ -314,16 +518,33 @@ impl BreezServices {
     /// This call will validate the given `amount_sats` against the parameters
     /// of the LNURL endpoint (`req_data`). If they match the endpoint requirements, the LNURL withdraw
     /// request is made. A successful result here means the endpoint started the payment.
+    ///
+    /// Without a `description`, the invoice uses the `default_description` of the endpoint.
     pub async fn lnurl_withdraw(
         &self,
         req_data: LnUrlWithdrawRequestData,
         amount_sats: u64,
         description: Option<String>,
     ) -> Result<LnUrlCallbackStatus> {
+        // validate the amount before creating an invoice the endpoint would reject
+        validate_withdraw_amount(&req_data, amount_sats * 1000)?;
+        let description = description.unwrap_or_else(|| req_data.default_description.clone());
         let invoice = self
-            .receive_payment(amount_sats, description.unwrap_or_default())
+            .receive_payment(amount_sats, description, None, None, None, None)
             .await?;
-        validate_lnurl_withdraw(req_data, invoice).await
+        validate_lnurl_withdraw(req_data, invoice.ln_invoice).await
+    }
+
+    /// Like [BreezServices::lnurl_withdraw], withdrawing the maximum amount the LNURL endpoint
+    /// allows.
+    pub async fn lnurl_withdraw_max(
+        &self,
+        req_data: LnUrlWithdrawRequestData,
+        description: Option<String>,
+    ) -> Result<LnUrlCallbackStatus> {
+        let amount_sats = req_data.max_withdrawable_sats();
+        self.lnurl_withdraw(req_data, amount_sats, description)
+            .await
     }
 
     /// Third and last step of LNURL-auth. The first step is `parse()`, which also validates the LNURL destination
@@ -331,8 +552,26 @@ impl BreezServices {
     ///
     /// This call will sign `k1` of the LNURL endpoint (`req_data`) on `secp256k1` using `linkingPrivKey` and DER-encodes the signature.
     /// If they match the endpoint requirements, the LNURL auth request is made. A successful result here means the client signature is verified.
+    ///
+    /// Every attempt is recorded and can be listed with [BreezServices::list_lnurl_auth_logins].
     pub async fn lnurl_auth(&self, req_data: LnUrlAuthRequestData) -> Result<LnUrlCallbackStatus> {
-        perform_lnurl_auth(self.node_api.clone(), req_data).await
+        let login = LnUrlAuthLogin {
+            domain: req_data.domain.clone(),
+            k1: req_data.k1.clone(),
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
+            success: false,
+        };
+        let res = perform_lnurl_auth(self.node_api.clone(), req_data).await;
+        self.persister.insert_lnurl_auth_login(&LnUrlAuthLogin {
+            success: matches!(res, Ok(LnUrlCallbackStatus::Ok)),
+            ..login
+        })?;
+        res
+    }
+
+    /// List the LNURL-auth attempts made with [BreezServices::lnurl_auth], most recent first
+    pub async fn list_lnurl_auth_logins(&self) -> Result<Vec<LnUrlAuthLogin>> {
+        self.persister.list_lnurl_auth_logins()
     }
 
     /// Creates an bolt11 payment request.
@@ -344,13 +583,215 @@ impl BreezServices {
     ///
     /// * `description` - The bolt11 payment request description
     /// * `amount_sats` - The amount to receive in satoshis
+    /// * `expiry_secs` - The expiry of the invoice, between [MIN_INVOICE_EXPIRY_SECS] and
+    ///   [MAX_INVOICE_EXPIRY_SECS] seconds. Without it the node default is used.
+    /// * `preimage` - The 32 bytes preimage of the invoice, for a payment hash agreed out-of-band.
+    ///   Without it a random preimage is used. A preimage can't be reused across invoices.
+    /// * `include_lsp_hint` - Whether the route hint through the LSP is added to the invoice,
+    ///   `true` by default. Without it the invoice can only be paid over the node's own route hints
+    ///   or by a direct peer, so amounts that need a new channel are rejected with
+    ///   [ReceivePaymentError::LspHintRequired]. Route hints added by the node itself are kept.
+    /// * `fallback_address` - An on-chain address added as fallback to the invoice, for payers who
+    ///   prefer paying on-chain. It has to be a segwit address of the configured network.
+    ///
+    /// Amounts above [NodeState::max_single_payment_amount_msat] are rejected with
+    /// [ReceivePaymentError::AmountAboveSinglePaymentMax], see [BreezServices::receive_payment_split].
+    ///
+    /// When a channel has to be opened, the response includes the fee the LSP deducts from the
+    /// received amount, so it can be shown before the invoice is shared.
     pub async fn receive_payment(
         &self,
         amount_sats: u64,
         description: String,
-    ) -> Result<LNInvoice> {
+        expiry_secs: Option<u32>,
+        preimage: Option<Vec<u8>>,
+        include_lsp_hint: Option<bool>,
+        fallback_address: Option<String>,
+    ) -> Result<ReceivePaymentResponse> {
+        let fallback_address = fallback_address
+            .map(|address| parse_fallback_address(&address, self.config.network))
+            .transpose()?;
+        if let Some(preimage) = &preimage {
+            if preimage.len() != 32 {
+                return Err(anyhow!("The preimage must be 32 bytes long"));
+            }
+            let payment_hash = sha256::Hash::hash(preimage);
+            if self.node_api.has_invoice(payment_hash.to_vec()).await? {
+                return Err(anyhow!("An invoice already exists for this preimage"));
+            }
+        }
+        if let Some(expiry_secs) = expiry_secs {
+            if !(MIN_INVOICE_EXPIRY_SECS..=MAX_INVOICE_EXPIRY_SECS).contains(&expiry_secs) {
+                return Err(anyhow!(
+                    "The invoice expiry must be between {MIN_INVOICE_EXPIRY_SECS} and \
+                     {MAX_INVOICE_EXPIRY_SECS} seconds"
+                ));
+            }
+        }
+        self.payment_receiver
+            .receive(
+                amount_sats,
+                description,
+                preimage,
+                None,
+                fallback_address,
+                expiry_secs,
+                include_lsp_hint.unwrap_or(true),
+            )
+            .await
+    }
+
+    /// Like [BreezServices::receive_payment], creating an invoice that commits to the SHA256
+    /// `description_hash` instead of containing the description, for example the hash of the
+    /// metadata of a LNURL-pay endpoint. `description` is only kept in the node's own record of
+    /// the invoice.
+    pub async fn receive_payment_with_description_hash(
+        &self,
+        amount_sats: u64,
+        description: String,
+        description_hash: Vec<u8>,
+    ) -> Result<ReceivePaymentResponse> {
+        let description_hash = sha256::Hash::from_slice(&description_hash)
+            .map_err(|_| anyhow!("The description hash must be a 32 bytes SHA256 hash"))?;
+        self.payment_receiver
+            .receive(
+                amount_sats,
+                description,
+                None,
+                Some(description_hash),
+                None,
+                None,
+                true,
+            )
+            .await
+    }
+
+    /// Creates bolt11 payment requests for `amount_sats`, split over several invoices when the
+    /// amount is above [NodeState::max_single_payment_amount_msat].
+    ///
+    /// Splitting has to be enabled with [Config::allow_receive_split], otherwise such an amount
+    /// is rejected like in [BreezServices::receive_payment]. The description of each invoice of a
+    /// split ends with its position, for example `"Rent (2/3)"`.
+    pub async fn receive_payment_split(
+        &self,
+        amount_sats: u64,
+        description: String,
+    ) -> Result<Vec<LNInvoice>> {
+        let node_state = self
+            .persister
+            .get_node_state()?
+            .ok_or("Failed to retrieve node state")
+            .map_err(|err| anyhow!(err))?;
+        let max_msat = node_state.max_single_payment_amount_msat;
+        let parts = split_receive_amount(amount_sats, max_msat / 1000);
+        if parts.len() == 1 {
+            return Ok(vec![
+                self.receive_payment(amount_sats, description, None, None, None, None)
+                    .await?
+                    .ln_invoice,
+            ]);
+        }
+        if !self.config.allow_receive_split {
+            return Err(ReceivePaymentError::AmountAboveSinglePaymentMax { max_msat }.into());
+        }
+
+        let mut invoices = vec![];
+        for (i, part_sats) in parts.iter().enumerate() {
+            let part_description = format!("{description} ({}/{})", i + 1, parts.len());
+            invoices.push(
+                self.receive_payment(*part_sats, part_description, None, None, None, None)
+                    .await?
+                    .ln_invoice,
+            );
+        }
+        Ok(invoices)
+    }
+
+    /// Creates a bolt11 payment request without an amount, which the payer chooses, for example
+    /// for donations or tips. The received payment records the amount actually paid.
+    ///
+    /// Since the amount is unknown upfront, the LSP can't open a channel for it: the payment has
+    /// to fit in the current inbound liquidity, see [NodeState::inbound_liquidity_msats].
+    pub async fn receive_donation(&self, description: String) -> Result<LNInvoice> {
+        self.payment_receiver.receive_any_amount(description).await
+    }
+
+    /// Estimates the cost of receiving `amount_msat` over lightning.
+    ///
+    /// If the current inbound liquidity can't accommodate the amount, a new channel has to be
+    /// opened by the LSP, which charges a fee that is deducted from the received amount.
+    pub async fn receive_cost_estimate(&self, amount_msat: u64) -> Result<ReceiveCost> {
         self.payment_receiver
-            .receive_payment(amount_sats, description, None)
+            .receive_cost_estimate(amount_msat)
+            .await
+    }
+
+    /// Checks an amount the user entered against every limit that applies to `context`: the
+    /// balance and the maximum single payment when sending, the receivable amount and the LSP
+    /// channel opening fee when receiving, and the deposit limits of the current swap address
+    /// when swapping in. No swap address is created here, so when swapping in it has to be
+    /// created first with [BreezServices::receive_onchain].
+    ///
+    /// Returns all the violated limits, an empty list means the amount can be used.
+    pub async fn validate_amount(
+        &self,
+        amount_msat: u64,
+        context: AmountContext,
+    ) -> Result<Vec<AmountViolation>> {
+        let node_state = self
+            .persister
+            .get_node_state()?
+            .ok_or("Failed to retrieve node state")
+            .map_err(|err| anyhow!(err))?;
+        let lsp_info = match context {
+            AmountContext::Receive => Some(self.lsp_info().await?),
+            _ => None,
+        };
+        let swap_info = match context {
+            AmountContext::SwapIn => Some(
+                self.btc_receive_swapper
+                    .current_swap()
+                    .await?
+                    .ok_or_else(|| anyhow!("No swap address, create one with receive_onchain"))?,
+            ),
+            _ => None,
+        };
+        Ok(amount_violations(
+            amount_msat,
+            context,
+            &node_state,
+            lsp_info.as_ref(),
+            swap_info.as_ref(),
+        ))
+    }
+
+    /// The smallest and largest amounts that can be received over lightning, taking the LSP fee
+    /// for opening a channel into account when the inbound liquidity doesn't suffice.
+    pub async fn receive_payment_limits(&self) -> Result<ReceiveLimits> {
+        let node_state = self
+            .persister
+            .get_node_state()?
+            .ok_or("Failed to retrieve node state")
+            .map_err(|err| anyhow!(err))?;
+        Ok(receive_limits(&node_state, &self.lsp_info().await?))
+    }
+
+    /// Creates an bolt11 payment request whose description is rendered from a template.
+    ///
+    /// Every `{key}` placeholder in `description_template` is replaced with the matching entry
+    /// of `values`, for example `"Order #{id} at {store}"`. The length limit of the description is
+    /// enforced on the rendered result. The invoice is then created like in
+    /// [BreezServices::receive_payment].
+    pub async fn receive_payment_with_template(
+        &self,
+        amount_sats: u64,
+        description_template: String,
+        values: Vec<TemplateValue>,
+    ) -> Result<ReceivePaymentResponse> {
+        let values: HashMap<String, String> =
+            values.into_iter().map(|v| (v.key, v.value)).collect();
+        let description = render_description_template(&description_template, &values)?;
+        self.receive_payment(amount_sats, description, None, None, None, None)
             .await
     }
 
@@ -359,31 +800,262 @@ impl BreezServices {
         self.persister.get_node_state()
     }
 
+    /// List the peers of the node, with their connection status and number of channels
+    pub async fn list_peers(&self) -> Result<Vec<PeerInfo>> {
+        Ok(self
+            .node_api
+            .list_peers()
+            .await?
+            .into_iter()
+            .map(PeerInfo::from)
+            .collect())
+    }
+
+    /// List the channels of the node, including the closed ones.
+    ///
+    /// The state and balances of the channels the node currently reports are taken from the node,
+    /// the others from the channels persisted during [BreezServices::sync].
+    pub async fn list_channels(&self) -> Result<Vec<ChannelInfo>> {
+        let mut live: Vec<ChannelInfo> = self
+            .node_api
+            .list_peers()
+            .await?
+            .into_iter()
+            .flat_map(|p| p.channels)
+            .map(ChannelInfo::from)
+            .collect();
+        let mut channels = vec![];
+        for persisted in self.persister.list_channels()? {
+            match live
+                .iter()
+                .position(|c| c.funding_txid == persisted.funding_txid)
+            {
+                Some(index) => channels.push(live.remove(index)),
+                None => channels.push(persisted.into()),
+            }
+        }
+        // Channels opened since the last sync
+        channels.extend(live);
+        Ok(channels)
+    }
+
+    /// Whether the streams of node events are currently open, as maintained by the background
+    /// event polling. See also [BreezEvent::ConnectionStatusChanged].
+    pub fn connection_status(&self) -> ConnectionStatus {
+        *self.connection_status.lock().unwrap()
+    }
+
+    fn set_connection_status(&self, status: ConnectionStatus) {
+        *self.connection_status.lock().unwrap() = status;
+    }
+
+    /// Waits until the node reaches the block `height`, returning the height reached. Fails if it
+    /// isn't reached within `timeout_sec` seconds.
+    pub async fn wait_for_block_height(&self, height: u32, timeout_sec: u64) -> Result<u32> {
+        let mut block_height = self.block_height.subscribe();
+        let reached = async {
+            loop {
+                let current = *block_height.borrow_and_update();
+                if current >= height {
+                    return Ok::<_, anyhow::Error>(current);
+                }
+                block_height.changed().await?;
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(timeout_sec), reached)
+            .await
+            .map_err(|_| anyhow!("Block {height} not reached within {timeout_sec} seconds"))?
+    }
+
+    /// Marks as expired the swaps that expired by the current block height, after checking their
+    /// address on-chain, emitting a [BreezEvent::SwapsReconciled] if there were any
+    async fn reconcile_expired_swaps(&self) -> Result<()> {
+        let tip = *self.block_height.borrow();
+        let expired = self
+            .btc_receive_swapper
+            .reconcile_expired_swaps(tip)
+            .await?;
+        if !expired.is_empty() {
+            info!("Marked {} swaps as expired on startup", expired.len());
+            self.emit_event(BreezEvent::SwapsReconciled { expired });
+        }
+        Ok(())
+    }
+
+    fn update_block_height(&self, height: u32) {
+        self.block_height.send_if_modified(|current| {
+            let higher = height > *current;
+            if higher {
+                *current = height;
+            }
+            higher
+        });
+    }
+
+    /// Fetch a single payment by its hash from persistent storage, for example to check the status
+    /// of a payment made with [BreezServices::send_payment]
+    pub async fn payment_by_hash(&self, payment_hash: String) -> Result<Option<Payment>> {
+        self.persister.get_payment_by_hash(&payment_hash)
+    }
+
     /// List payments matching the given filters, as retrieved from persistent storage
+    ///
+    /// The optional amount range is inclusive and the description search is case-insensitive.
     pub async fn list_payments(
         &self,
         filter: PaymentTypeFilter,
         from_timestamp: Option<i64>,
         to_timestamp: Option<i64>,
+        min_amount_msat: Option<u64>,
+        max_amount_msat: Option<u64>,
+        description_contains: Option<String>,
     ) -> Result<Vec<Payment>> {
-        self.persister
-            .list_payments(filter, from_timestamp, to_timestamp)
-            .map_err(|err| anyhow!(err))
+        self.persister.list_payments(
+            filter,
+            from_timestamp,
+            to_timestamp,
+            min_amount_msat,
+            max_amount_msat,
+            description_contains,
+        )
     }
 
-    /// Sweep on-chain funds to the specified on-chain address, with the given feerate
-    pub async fn sweep(&self, to_address: String, fee_rate_sats_per_byte: u64) -> Result<()> {
+    /// Sweep on-chain funds to the specified on-chain address, with the given feerate in sat/vbyte
+    ///
+    /// With `utxos`, only the UTXOs with these outpoints, formatted as `txid:vout` (see
+    /// [UnspentTransactionOutput::outpoint]), are spent. They must all be listed in
+    /// [NodeState::utxos] and not be reserved.
+    ///
+    /// The resulting transaction is recorded and can be listed with [BreezServices::list_sweeps]
+    pub async fn sweep(
+        &self,
+        to_address: String,
+        sat_per_vbyte: u32,
+        utxos: Option<Vec<String>>,
+    ) -> Result<SweepResult> {
+        let fee_rate = SatPerVbyte::new(sat_per_vbyte)?;
+        validate_address_network(&to_address, self.config.network)?;
+        let known_utxos = self.persisted_node_state()?.utxos;
+        let selected_utxos = match utxos {
+            Some(outpoints) => select_utxos(&known_utxos, &outpoints)?,
+            None => vec![],
+        };
         self.start_node().await?;
-        self.node_api
-            .sweep(to_address, fee_rate_sats_per_byte)
+        let response = self
+            .node_api
+            .sweep(to_address.clone(), fee_rate, selected_utxos)
             .await?;
+        let tx: Transaction = deserialize(&response.tx)?;
+        let destination_script = Address::from_str(&to_address)?.script_pubkey();
+        let result = SweepResult {
+            txid: tx.txid().to_string(),
+            amount_sat: tx
+                .output
+                .iter()
+                .filter(|o| o.script_pubkey == destination_script)
+                .map(|o| o.value)
+                .sum(),
+            fee_sat: onchain_tx_fee(&tx, &known_utxos).unwrap_or_else(|err| {
+                warn!("Falling back to the estimated sweep fee: {err}");
+                fee_rate.fee_for_vsize(tx.vsize() as u64)
+            }),
+            destination: to_address,
+        };
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        self.persister.insert_sweep(&result, now)?;
         self.sync().await?;
-        Ok(())
+        Ok(result)
+    }
+
+    /// Estimates the outcome of [BreezServices::sweep] with the same arguments, without broadcasting
+    /// anything. The estimate assumes all the spendable on-chain funds of the node, see
+    /// [Config::onchain_min_confirmations], are sent in a single output to `to_address`.
+    pub async fn prepare_sweep(
+        &self,
+        to_address: String,
+        sat_per_vbyte: u32,
+        utxos: Option<Vec<String>>,
+    ) -> Result<SweepPreview> {
+        let fee_rate = SatPerVbyte::new(sat_per_vbyte)?;
+        validate_address_network(&to_address, self.config.network)?;
+        let node_state = self.persisted_node_state()?;
+        let destination_script = Address::from_str(&to_address)?.script_pubkey();
+        let utxos = match utxos {
+            Some(outpoints) => select_utxos(&node_state.utxos, &outpoints)?,
+            None => node_state
+                .utxos
+                .into_iter()
+                .filter(|u| u.is_spendable(self.config.onchain_min_confirmations))
+                .collect(),
+        };
+        estimate_sweep(&utxos, destination_script.len() as u64, &fee_rate)
+    }
+
+    fn persisted_node_state(&self) -> Result<NodeState> {
+        self.persister
+            .get_node_state()?
+            .ok_or("Failed to retrieve node state")
+            .map_err(|err| anyhow!(err))
+    }
+
+    /// List the on-chain sweeps made with [BreezServices::sweep], most recent first
+    pub async fn list_sweeps(&self) -> Result<Vec<SweepResult>> {
+        self.persister.list_sweeps()
     }
 
     /// Fetch live rates of fiat currencies
     pub async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
-        self.fiat_api.fetch_fiat_rates().await
+        let rates = self.fiat_api.fetch_fiat_rates().await?;
+
+        // Keep a snapshot of the rates for historical lookups, at most one per cache period
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let last_snapshot = self.persister.last_fiat_rates_timestamp()?.unwrap_or(0);
+        if now - last_snapshot >= self.config.fiat_rate_cache_ttl_sec as i64 {
+            self.persister.insert_fiat_rates(&rates, now)?;
+        }
+        Ok(rates)
+    }
+
+    /// Get the live rate of a single fiat currency, served from the cache when fresh.
+    ///
+    /// The rate of [BreezServices::preferred_fiat_currency] is preloaded when the SDK starts.
+    pub async fn fiat_rate(&self, coin: String) -> Result<Option<Rate>> {
+        let rates = self.fetch_fiat_rates().await?;
+        Ok(rates.into_iter().find(|r| r.coin == coin))
+    }
+
+    /// Returns the rate of `coin` that was fetched closest in time to `timestamp`.
+    ///
+    /// Rates are recorded whenever [BreezServices::fetch_fiat_rates] runs, so this can be used to
+    /// display the fiat value of a payment at its `payment_time`. The recorded rates are kept hourly
+    /// for the last 30 days and daily before that.
+    pub async fn fiat_rate_at(&self, coin: String, timestamp: i64) -> Result<Option<Rate>> {
+        self.persister.get_fiat_rate_at(&coin, timestamp)
+    }
+
+    /// The fiat currency selected with [BreezServices::select_fiat_currency], defaulting to
+    /// [Config::default_fiat_currency]
+    pub fn preferred_fiat_currency(&self) -> Result<Option<String>> {
+        Ok(self
+            .persister
+            .get_preferred_fiat_currency()?
+            .or_else(|| self.config.default_fiat_currency.clone()))
+    }
+
+    /// Selects the fiat currency `id` as [BreezServices::preferred_fiat_currency] and refreshes
+    /// its rate, bypassing the cache, so it can be displayed right away.
+    ///
+    /// The refreshed rates are also recorded for [BreezServices::fiat_rate_at].
+    pub async fn select_fiat_currency(&self, id: String) -> Result<Rate> {
+        let rates = self.fiat_api.refresh_fiat_rates().await?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        self.persister.insert_fiat_rates(&rates, now)?;
+        let rate = rates
+            .into_iter()
+            .find(|r| r.coin == id)
+            .ok_or_else(|| anyhow!("No rate is known for the fiat currency {id}"))?;
+        self.persister.set_preferred_fiat_currency(id)?;
+        Ok(rate)
     }
 
     /// List all supported fiat currencies for which there is a known exchange rate.
@@ -391,6 +1063,16 @@ impl BreezServices {
         self.fiat_api.list_fiat_currencies().await
     }
 
+    /// Lightweight version of [BreezServices::list_fiat_currencies], with only the id, symbol and
+    /// name of each currency, localized for the given locale and sorted by name.
+    pub async fn list_fiat_currency_symbols(
+        &self,
+        locale: String,
+    ) -> Result<Vec<CurrencySymbolEntry>> {
+        let currencies = self.fiat_api.list_fiat_currencies().await?;
+        Ok(to_symbol_entries(&currencies, &locale))
+    }
+
     /// List available LSPs that can be selected by the user
     pub async fn list_lsps(&self) -> Result<Vec<LspInformation>> {
         self.lsp_api
@@ -401,6 +1083,7 @@ impl BreezServices {
     /// Select the LSP to be used and provide inbound liquidity
     pub async fn connect_lsp(&self, lsp_id: String) -> Result<()> {
         self.persister.set_lsp_id(lsp_id)?;
+        self.connect_lsp_peer().await?;
         self.sync().await?;
         Ok(())
     }
@@ -465,6 +1148,16 @@ impl BreezServices {
         self.btc_receive_swapper.list_refundables()
     }
 
+    /// Deletes the completed swaps whose expiry passed more than `older_than_sec` seconds ago,
+    /// returning how many were deleted. Swaps still being monitored are never deleted, and neither
+    /// are refunded swaps, as they explain the refund transactions, nor swaps that were never paid,
+    /// as funds sent to their address later can only be refunded with their keys.
+    pub fn prune_terminal_swaps(&self, older_than_sec: u64) -> Result<u32> {
+        let expired_before = self.now_secs()?.saturating_sub(older_than_sec) as i64;
+        self.btc_receive_swapper
+            .prune_terminal_swaps(expired_before)
+    }
+
     /// Construct and broadcast a refund transaction for a failed/expired swap
     pub async fn refund(
         &self,
@@ -472,15 +1165,42 @@ impl BreezServices {
         to_address: String,
         sat_per_vbyte: u32,
     ) -> Result<String> {
+        validate_address_network(&to_address, self.config.network)?;
+        self.btc_receive_swapper
+            .refund_swap(swap_address, to_address, SatPerVbyte::new(sat_per_vbyte)?)
+            .await
+    }
+
+    /// Estimates the fee and amount of [BreezServices::refund], without broadcasting anything.
+    ///
+    /// All the confirmed UTXOs of the swap address are included, as in the actual refund.
+    pub async fn prepare_refund(
+        &self,
+        swap_address: String,
+        to_address: String,
+        sat_per_vbyte: u32,
+    ) -> Result<RefundPreview> {
+        validate_address_network(&to_address, self.config.network)?;
+        self.btc_receive_swapper
+            .prepare_refund(swap_address, to_address, SatPerVbyte::new(sat_per_vbyte)?)
+            .await
+    }
+
+    /// The amount a refund of `swap_address` would currently return at `sat_per_vbyte`, after the
+    /// fee. Zero when nothing is refundable.
+    pub async fn refundable_amount(&self, swap_address: String, sat_per_vbyte: u32) -> Result<u64> {
         self.btc_receive_swapper
-            .refund_swap(swap_address, to_address, sat_per_vbyte)
+            .refundable_amount(swap_address, SatPerVbyte::new(sat_per_vbyte)?)
             .await
     }
 
     /// Execute a command directly on the NodeAPI interface.
     /// Mainly used to debugging.
-    pub async fn execute_dev_command(&self, command: String) -> Result<String> {
-        self.node_api.execute_command(command).await
+    ///
+    /// With `dry_run`, commands changing the node state, such as closing channels, are only
+    /// validated and described. Read-only commands run either way.
+    pub async fn execute_dev_command(&self, command: String, dry_run: bool) -> Result<String> {
+        self.node_api.execute_command(command, dry_run).await
     }
 
     /// This method sync the local state with the remote node state.
@@ -491,7 +1211,11 @@ impl BreezServices {
     pub async fn sync(&self) -> Result<()> {
         self.start_node().await?;
         self.connect_lsp_peer().await?;
+        self.pull_changes().await
+    }
 
+    /// Pulls the changes from the node since the last sync, without connecting to the LSP
+    async fn pull_changes(&self) -> Result<()> {
         // First query the changes since last sync time.
         let since_timestamp = self.persister.last_payment_timestamp().unwrap_or(0);
         let new_data = &self.node_api.pull_changed(since_timestamp).await?;
@@ -503,6 +1227,7 @@ impl BreezServices {
 
         // update node state and channels state
         self.persister.set_node_state(&new_data.node_state)?;
+        self.update_block_height(new_data.node_state.block_height);
         self.persister.update_channels(&new_data.channels)?;
 
         //fetch closed_channel and convert them to Payment items.
@@ -517,13 +1242,91 @@ impl BreezServices {
         // update both closed channels and lightning transaction payments
         let mut payments = closed_channel_payments_res?;
         payments.extend(new_data.payments.clone());
-        self.persister.insert_payments(&payments)?;
+        let total = payments.len() as u32;
+        let mut processed = 0;
+        self.emit_event(BreezEvent::SyncProgress { processed, total });
+        for batch in payments.chunks(SYNC_PROGRESS_BATCH_SIZE) {
+            self.persister.insert_payments(batch)?;
+            processed += batch.len() as u32;
+            self.emit_event(BreezEvent::SyncProgress { processed, total });
+        }
+        if let Some(retention_sec) = self.config.swap_retention_sec {
+            self.prune_terminal_swaps(retention_sec)?;
+        }
         self.notify_event_listeners(BreezEvent::Synced).await?;
         Ok(())
     }
 
-    /// Connects to the selected LSP, if any
-    async fn connect_lsp_peer(&self) -> Result<()> {
+    /// Rebuilds the local payments and channels from the node, for when they got out of sync.
+    ///
+    /// Unlike [BreezServices::sync], which only pulls the changes since the last payment, this
+    /// deletes everything derived from the node and pulls it again. Swaps and their keys, settings
+    /// and LNURL payment metadata are kept.
+    pub async fn full_resync(&self) -> Result<()> {
+        self.persister.delete_node_derived_data()?;
+        self.sync().await
+    }
+
+    /// Reclaims the space left in the local storage by deleted and replaced data.
+    ///
+    /// This is an optional maintenance operation that locks the storage while it runs, so it
+    /// should only be called while the app is idle, not during a payment or a sync.
+    pub fn optimize_storage(&self) -> Result<()> {
+        self.persister.compact()
+    }
+
+    /// Exports a consistent snapshot of the local storage, to move it to another device with
+    /// [BreezServices::restore]. It contains the swaps with their keys, so it has to be kept as
+    /// safe as the mnemonic.
+    pub fn backup(&self) -> Result<Vec<u8>> {
+        self.persister.backup()
+    }
+
+    /// Replaces the local storage with a snapshot exported by [BreezServices::backup].
+    ///
+    /// A snapshot of another node is rejected. Call [BreezServices::sync] afterwards to pull
+    /// what changed on the node since the snapshot was taken.
+    pub fn restore(&self, data: Vec<u8>) -> Result<()> {
+        self.persister.restore(data)
+    }
+
+    /// Stores a small app preference next to the SDK data, replacing any previous value of `key`.
+    ///
+    /// App settings are kept apart from the ones of the SDK, so any key can be used without
+    /// affecting the SDK.
+    pub fn set_setting(&self, key: String, value: String) -> Result<()> {
+        self.persister.set_app_setting(key, value)
+    }
+
+    /// The value stored with [BreezServices::set_setting] for `key`, if any
+    pub fn get_setting(&self, key: String) -> Result<Option<String>> {
+        self.persister.get_app_setting(key)
+    }
+
+    /// Connects to the selected LSP if [Config::auto_connect_lsp] is enabled, emitting a
+    /// [BreezEvent::LspConnected] once connected.
+    async fn auto_connect_lsp(&self) -> Result<()> {
+        if !self.config.auto_connect_lsp {
+            return Ok(());
+        }
+
+        if let Some(lsp_id) = self.connect_lsp_peer().await? {
+            self.notify_event_listeners(BreezEvent::LspConnected { lsp_id })
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Fetches the rates into the cache if [BreezServices::preferred_fiat_currency] is set
+    async fn preload_default_fiat_rate(&self) -> Result<()> {
+        if let Some(coin) = self.preferred_fiat_currency()? {
+            self.fiat_rate(coin).await?;
+        }
+        Ok(())
+    }
+
+    /// Connects to the selected LSP, returning its id if one is selected
+    async fn connect_lsp_peer(&self) -> Result<Option<String>> {
         let lsp = self.lsp_info().await.ok();
         if lsp.is_some() {
             let lsp_info = lsp.unwrap().clone();
@@ -535,8 +1338,9 @@ impl BreezServices {
                 .await
                 .map_err(anyhow::Error::msg)?;
             debug!("connected to lsp {}@{}", node_id.clone(), address.clone());
+            return Ok(Some(lsp_info.id));
         }
-        Ok(())
+        Ok(None)
     }
 
     async fn on_payment_completed(
@@ -570,64 +1374,346 @@ impl BreezServices {
         }
     }
 
-    async fn on_event(&self, e: BreezEvent) -> Result<()> {
-        debug!("breez services got event {:?}", e);
-        self.notify_event_listeners(e.clone()).await
-    }
+    /// Handles the settlement of an incoming payment.
+    ///
+    /// Only the first settlement of a payment hash is recorded, any later settlement of the
+    /// same hash is reported through a [BreezEvent::DuplicateIncomingPayment].
+    async fn on_invoice_paid(
+        &self,
+        payment: Option<Payment>,
+        details: InvoicePaidDetails,
+    ) -> Result<()> {
+        let settled_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        if !self
+            .persister
+            .insert_incoming_settlement(&details.payment_hash, settled_at)?
+        {
+            warn!(
+                "Ignoring duplicate settlement of payment {}",
+                details.payment_hash
+            );
+            return self
+                .notify_event_listeners(BreezEvent::DuplicateIncomingPayment { details })
+                .await;
+        }
+
+        if let Some(payment) = payment {
+            let res = self.persister.insert_payments(&[payment]);
+            debug!("paid invoice was added to payments list {:?}", res);
+        }
+        self.on_event(BreezEvent::InvoicePaid { details }).await?;
+        _ = self.sync().await;
+        Ok(())
+    }
+
+    async fn on_event(&self, e: BreezEvent) -> Result<()> {
+        debug!("breez services got event {:?}", e);
+        self.notify_event_listeners(e.clone()).await
+    }
 
     async fn notify_event_listeners(&self, e: BreezEvent) -> Result<()> {
-        if let Err(err) = self.btc_receive_swapper.on_event(e.clone()).await {
-            debug!(
-                "btc_receive_swapper failed to processed event {:?}: {:?}",
-                e, err
-            )
+        let swap_events = match self.btc_receive_swapper.on_event(e.clone()).await {
+            Ok(swap_events) => swap_events,
+            Err(err) => {
+                debug!(
+                    "btc_receive_swapper failed to processed event {:?}: {:?}",
+                    e, err
+                );
+                vec![]
+            }
         };
 
-        if self.event_listener.is_some() {
-            self.event_listener.as_ref().unwrap().on_event(e.clone())
+        self.emit_event(e);
+        for swap_event in swap_events {
+            self.emit_event(swap_event);
         }
         Ok(())
     }
 
+    /// Queues the event, then delivers the queued events to the event listener in order.
+    ///
+    /// The listener is called without holding any lock, so it can call back into the SDK. An event
+    /// emitted while another thread, or the listener itself, is delivering is left to that
+    /// delivery loop and this returns right away.
+    fn emit_event(&self, e: BreezEvent) {
+        if let BreezEvent::NewBlock { block } = e {
+            self.update_block_height(block);
+        }
+
+        let mut events = self.events.lock().unwrap();
+        events.pending.push_back(e);
+        if events.delivering {
+            return;
+        }
+        events.delivering = true;
+        while let Some(e) = events.pending.pop_front() {
+            // progress is only meaningful while the sync runs
+            if !matches!(e, BreezEvent::SyncProgress { .. }) {
+                events.recent.push_back(e.clone());
+                while events.recent.len() > self.config.event_buffer_size as usize {
+                    events.recent.pop_front();
+                }
+            }
+            drop(events);
+            if let Some(listener) = self.event_listener.as_ref() {
+                listener.on_event(e);
+            }
+            events = self.events.lock().unwrap();
+        }
+        events.delivering = false;
+        self.events_delivered.notify_all();
+    }
+
+    /// Calls `subscribe` with the most recent events, oldest first, see
+    /// [Config::event_buffer_size].
+    ///
+    /// It waits for the events being delivered, and no event is delivered while `subscribe` runs,
+    /// so a subscriber registered from it receives every event after the replayed ones, without
+    /// gaps or duplicates. It must not be called from the event listener.
+    pub(crate) fn replay_events<F: FnOnce(Vec<BreezEvent>)>(&self, subscribe: F) {
+        let events = self
+            .events_delivered
+            .wait_while(self.events.lock().unwrap(), |events| events.delivering)
+            .unwrap();
+        subscribe(events.recent.iter().cloned().collect());
+    }
+
     /// Convenience method to look up LSP info based on current LSP ID
     pub async fn lsp_info(&self) -> Result<LspInformation> {
         get_lsp(self.persister.clone(), self.lsp_api.clone()).await
     }
 
+    /// Whether the current LSP can open a channel to receive `amount_msat`.
+    ///
+    /// The LSP doesn't report its available liquidity, so this is inferred from its
+    /// [LspInformation]. When that can't be fetched, for example without a selected LSP or when
+    /// the LSP is unreachable, `false` is returned.
+    pub async fn lsp_liquidity_available(&self, amount_msat: u64) -> bool {
+        match self.lsp_info().await {
+            Ok(lsp_info) => lsp_info.can_open_channel_for(amount_msat),
+            Err(err) => {
+                warn!("Failed to get the LSP info to check its liquidity: {err}");
+                false
+            }
+        }
+    }
+
     pub(crate) async fn start_node(&self) -> Result<()> {
         self.node_api.start().await
     }
 
     /// Get the recommended fees for onchain transactions
+    ///
+    /// The last fetched fees are cached, and returned when fetching them fails.
     pub async fn recommended_fees(&self) -> Result<RecommendedFees> {
-        self.chain_service.recommended_fees().await
+        match self.chain_service.recommended_fees().await {
+            Ok(fees) => {
+                if let Err(err) = self.persister.set_recommended_fees(&fees) {
+                    warn!("Failed to cache the recommended fees: {err}");
+                }
+                Ok(fees)
+            }
+            Err(err) => match self.persister.get_recommended_fees()? {
+                Some(fees) => {
+                    warn!("Failed to fetch the recommended fees, using the cached ones: {err}");
+                    Ok(fees)
+                }
+                None => Err(err),
+            },
+        }
+    }
+
+    /// The fee tier the user prefers for onchain transactions, [FeeTier::HalfHour] unless set
+    /// with [BreezServices::set_preferred_fee_tier]
+    pub fn preferred_fee_tier(&self) -> Result<FeeTier> {
+        Ok(self.persister.get_preferred_fee_tier()?.unwrap_or_default())
+    }
+
+    /// Stores the fee tier the user prefers for onchain transactions
+    pub fn set_preferred_fee_tier(&self, tier: FeeTier) -> Result<()> {
+        self.persister.set_preferred_fee_tier(&tier)
+    }
+
+    /// The fee rate of the preferred fee tier, in sat/vByte. The recommended fees are only fetched
+    /// when the preferred tier isn't [FeeTier::Custom].
+    pub async fn resolve_fee_rate(&self) -> Result<u64> {
+        match self.preferred_fee_tier()? {
+            FeeTier::Custom { sat_per_vbyte } => Ok(sat_per_vbyte),
+            tier => Ok(self.recommended_fees().await?.fee_rate(&tier)),
+        }
+    }
+
+    /// Generates a snapshot of the SDK state that can be attached to support requests.
+    ///
+    /// Secrets such as keys, preimages or the API key are never included.
+    pub async fn diagnostics(&self) -> Result<Diagnostics> {
+        let node_state = self.node_info()?;
+        let swaps = self.persister.list_swaps()?;
+        let count = |f: fn(&SwapInfo) -> bool| swaps.iter().filter(|s| f(s)).count() as u32;
+        let last_errors = swaps
+            .iter()
+            .filter_map(|s| {
+                s.last_redeem_error
+                    .as_ref()
+                    .map(|e| format!("swap {}: {e}", s.bitcoin_address))
+            })
+            .collect();
+
+        Ok(Diagnostics {
+            sdk_version: env!("CARGO_PKG_VERSION").to_string(),
+            network: self.config.network,
+            node_id: node_state.as_ref().map(|n| n.id.clone()),
+            block_height: node_state.as_ref().map(|n| n.block_height),
+            lsp_id: self.persister.get_lsp_id()?,
+            channels_balance_msat: node_state.as_ref().map_or(0, |n| n.channels_balance_msat),
+            onchain_balance_msat: node_state.as_ref().map_or(0, |n| n.onchain_balance_msat),
+            connected_peers: node_state
+                .as_ref()
+                .map_or(0, |n| n.connected_peers.len() as u32),
+            swaps: SwapDiagnostics {
+                total: swaps.len() as u32,
+                unused: count(SwapInfo::unused),
+                in_progress: count(SwapInfo::in_progress),
+                redeemable: count(SwapInfo::redeemable),
+                refundable: count(SwapInfo::refundable),
+                expired: count(|s| s.status == SwapStatus::Expired),
+            },
+            last_errors,
+            background_tasks_running: self.shutdown_sender.lock().await.is_some(),
+        })
+    }
+
+    /// Checks whether the external services the SDK relies on are reachable, reporting the
+    /// latency of each one.
+    ///
+    /// The services are checked concurrently, each with a short timeout.
+    pub async fn connectivity_check(&self) -> Vec<ServiceStatus> {
+        let node_pubkey = self
+            .persister
+            .get_node_state()
+            .ok()
+            .flatten()
+            .map(|n| n.id)
+            .unwrap_or_default();
+        let (greenlight, lsp, mempool, fiat, moonpay) = tokio::join!(
+            check_service(ServiceType::Greenlight, self.node_api.list_peers()),
+            check_service(ServiceType::Lsp, self.lsp_api.list_lsps(node_pubkey)),
+            check_service(ServiceType::Mempool, self.chain_service.current_tip()),
+            check_service(ServiceType::Fiat, self.fiat_api.fetch_fiat_rates()),
+            check_service(
+                ServiceType::MoonPay,
+                self.buy_bitcoin_service
+                    .check_reachable(BuyBitcoinProvider::MoonPay)
+            ),
+        );
+        vec![greenlight, lsp, mempool, fiat, moonpay]
+    }
+
+    /// Compares the device clock against the time reported by the chain service.
+    ///
+    /// Returns a [ClockSkewWarning] if they differ by more than the [EXPIRY_CLOCK_SKEW_SECS]
+    /// tolerated by expiry checks. The offset is kept, so that invoice expiry checks account for it when
+    /// [Config::correct_clock_skew] is set.
+    pub async fn check_clock_skew(&self) -> Result<Option<ClockSkewWarning>> {
+        let started = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        let server_time = self.chain_service.server_time().await?;
+        let finished = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        // The server time is taken somewhere during the request, compare it against the midpoint
+        let local_time = ((started + finished) / 2).as_secs() as i64;
+        let offset_secs = server_time - local_time;
+        self.clock_offset_secs.store(offset_secs, Ordering::Relaxed);
+
+        if offset_secs.unsigned_abs() <= EXPIRY_CLOCK_SKEW_SECS {
+            return Ok(None);
+        }
+        warn!("Device clock is off by {offset_secs}s");
+        Ok(Some(ClockSkewWarning { offset_secs }))
     }
 
-    /// Get the full default config for a specific environment type
-    pub fn default_config(env_type: EnvironmentType) -> Config {
-        match env_type {
+    /// The current unix time, corrected by the offset found in [BreezServices::check_clock_skew]
+    /// if [Config::correct_clock_skew] is set
+    fn now_secs(&self) -> Result<u64> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        if !self.config.correct_clock_skew {
+            return Ok(now as u64);
+        }
+        Ok((now + self.clock_offset_secs.load(Ordering::Relaxed)).max(0) as u64)
+    }
+
+    /// Get the full default config for a specific environment type and network
+    pub fn default_config(env_type: EnvironmentType, network: Network) -> Config {
+        let config = match env_type {
             EnvironmentType::Production => Config::production(),
             EnvironmentType::Staging => Config::staging(),
-        }
+        };
+        config.for_network(network)
     }
 
     /// Generates an url that can be used by a third part provider to buy Bitcoin with fiat currency
-    pub async fn buy_bitcoin(&self, provider: BuyBitcoinProvider) -> Result<String> {
-        let url = match provider {
-            MoonPay => {
-                self.moonpay_api
-                    .buy_bitcoin_url(&self.receive_onchain().await?)
-                    .await?
-            }
-        };
-        Ok(url)
+    ///
+    /// The optional `base_currency_code` (e.g. "EUR") selects the fiat currency the user pays
+    /// with. When not set, the provider default (USD) is used.
+    pub async fn buy_bitcoin(
+        &self,
+        provider: BuyBitcoinProvider,
+        base_currency_code: Option<String>,
+    ) -> Result<String> {
+        let swap_info = self.receive_onchain().await?;
+        self.buy_bitcoin_service
+            .buy_bitcoin(provider, &swap_info, base_currency_code)
+            .await
+    }
+}
+
+/// Delays between the attempts to reopen the node streams, doubling after every failed attempt
+/// up to a maximum
+struct ReconnectBackoff {
+    next: Duration,
+    max: Duration,
+}
+
+impl ReconnectBackoff {
+    const INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+    fn new(max: Duration) -> Self {
+        ReconnectBackoff {
+            next: Self::INITIAL_DELAY.min(max),
+            max,
+        }
+    }
+
+    fn next_delay(&mut self) -> Duration {
+        let delay = self.next;
+        self.next = (self.next * 2).min(self.max);
+        delay
+    }
+
+    fn reset(&mut self) {
+        self.next = Self::INITIAL_DELAY.min(self.max);
     }
 }
 
-async fn poll_events(breez_services: Arc<BreezServices>, mut current_block: u32) -> Result<()> {
+/// Handles the node and chain events until one of the node streams can't be reopened.
+///
+/// `connected` is set once the streams are open. If they were reopened after a connectivity loss,
+/// the local state is sync-ed and a [BreezEvent::ConnectionStatusChanged] emitted.
+async fn poll_events(
+    breez_services: Arc<BreezServices>,
+    mut current_block: u32,
+    connected: &mut bool,
+) -> Result<()> {
     let mut interval = tokio::time::interval(Duration::from_secs(30));
     let mut invoice_stream = breez_services.node_api.stream_incoming_payments().await?;
     let mut log_stream = breez_services.node_api.stream_log_messages().await?;
+    breez_services.set_connection_status(ConnectionStatus::Connected);
+    if !*connected {
+        *connected = true;
+        info!("Node streams reopened");
+        if let Err(err) = breez_services.sync().await {
+            warn!("Failed to sync after reconnecting: {:?}", err);
+        }
+        breez_services.emit_event(BreezEvent::ConnectionStatusChanged { connected: true });
+    }
 
     loop {
         tokio::select! {
@@ -655,15 +1741,13 @@ async fn poll_events(breez_services: Arc<BreezServices>, mut current_block: u32)
             debug!("invoice stream got new invoice");
             if let Some(gl_client::pb::incoming_payment::Details::Offchain(p)) = i.details {
              let payment: Option<crate::models::Payment> = p.clone().try_into().ok();
-             if payment.is_some() {
-              let res = breez_services.persister.insert_payments(&vec![payment.unwrap()]);
-              debug!("paid invoice was added to payments list {:?}", res);
-             }
-             _  = breez_services.on_event(BreezEvent::InvoicePaid{details: InvoicePaidDetails {
+             let details = InvoicePaidDetails {
                  payment_hash: hex::encode(p.payment_hash),
                  bolt11: p.bolt11,
-             }}).await;
-             _ = breez_services.sync().await;
+             };
+             if let Err(err) = breez_services.on_invoice_paid(payment, details).await {
+              debug!("failed to process paid invoice {:?}", err);
+             }
             }
            }
            // stream is closed, renew it
@@ -730,6 +1814,7 @@ struct BreezServicesBuilder {
     persister: Option<Arc<SqliteStorage>>,
     swapper_api: Option<Arc<dyn SwapperAPI>>,
     moonpay_api: Option<Arc<dyn MoonPayApi>>,
+    chain_service: Option<Arc<dyn ChainService>>,
 }
 
 #[allow(dead_code)]
@@ -745,6 +1830,7 @@ impl BreezServicesBuilder {
             persister: None,
             swapper_api: None,
             moonpay_api: None,
+            chain_service: None,
         }
     }
 
@@ -778,6 +1864,11 @@ impl BreezServicesBuilder {
         self
     }
 
+    pub fn chain_service(&mut self, chain_service: Arc<dyn ChainService>) -> &mut Self {
+        self.chain_service = Some(chain_service);
+        self
+    }
+
     pub fn greenlight_credentials(
         &mut self,
         creds: GreenlightCredentials,
@@ -798,6 +1889,8 @@ impl BreezServicesBuilder {
             ));
         }
 
+        set_redact_logs(self.config.redact_logs);
+
         let mut node_api = self.node_api.clone();
         if node_api.is_none() {
             if self.creds.is_none() || self.seed.is_none() {
@@ -822,17 +1915,41 @@ impl BreezServicesBuilder {
         ));
 
         // mempool space is used to monitor the chain
-        let chain_service = Arc::new(MempoolSpace::from_base_url(
-            self.config.mempoolspace_url.clone(),
+        let chain_service: Arc<dyn ChainService> = match self.chain_service.clone() {
+            Some(chain_service) => chain_service,
+            None => match (
+                self.config.chain_backend,
+                self.config.mempoolspace_api_token.clone(),
+            ) {
+                (ChainBackend::Esplora, _) => Arc::new(EsploraChainService::from_base_url(
+                    self.config.mempoolspace_url.clone(),
+                )),
+                (ChainBackend::MempoolSpace, Some(token)) => {
+                    Arc::new(MempoolSpace::from_base_url_with_token(
+                        self.config.mempoolspace_url.clone(),
+                        token,
+                    )?)
+                }
+                (ChainBackend::MempoolSpace, None) => Arc::new(MempoolSpace::from_base_url(
+                    self.config.mempoolspace_url.clone(),
+                )),
+            },
+        };
+        let chain_service = Arc::new(ThrottledChainService::new(
+            chain_service,
+            self.config.max_concurrent_chain_requests,
         ));
 
         // The storage is implemented via sqlite.
-        let persister = self
-            .persister
-            .clone()
-            .unwrap_or_else(|| Arc::new(SqliteStorage::new(self.config.working_dir.clone())));
+        let persister = self.persister.clone().unwrap_or_else(|| {
+            Arc::new(
+                SqliteStorage::new(self.config.working_dir.clone())
+                    .with_encryption_key(self.config.db_encryption_key.clone()),
+            )
+        });
 
-        persister.init().unwrap();
+        // Fails with a wrong encryption key
+        persister.init()?;
         let current_lsp_id = persister.get_lsp_id()?;
         if current_lsp_id.is_none() && self.config.default_lsp_id.is_some() {
             persister.set_lsp_id(self.config.default_lsp_id.clone().unwrap())?;
@@ -852,26 +1969,42 @@ impl BreezServicesBuilder {
             persister.clone(),
             chain_service.clone(),
             payment_receiver.clone(),
+            self.config.overpaid_swap_handling,
         ));
 
+        let current_block_height = persister
+            .get_node_state()?
+            .map(|state| state.block_height)
+            .unwrap_or_default();
+
         // Create the node services and it them statically
         let breez_services = Arc::new(BreezServices {
+            config: self.config.clone(),
             node_api: unwrapped_node_api.clone(),
             lsp_api: self.lsp_api.clone().unwrap_or_else(|| breez_server.clone()),
-            fiat_api: self
-                .fiat_api
-                .clone()
-                .unwrap_or_else(|| breez_server.clone()),
-            moonpay_api: self
-                .moonpay_api
-                .clone()
-                .unwrap_or_else(|| breez_server.clone()),
+            fiat_api: Arc::new(CachedFiatAPI::new(
+                self.fiat_api
+                    .clone()
+                    .unwrap_or_else(|| breez_server.clone()),
+                Duration::from_secs(self.config.fiat_rate_cache_ttl_sec),
+            )),
+            buy_bitcoin_service: Arc::new(BuyBitcoinService::new(
+                self.moonpay_api
+                    .clone()
+                    .unwrap_or_else(|| breez_server.clone()),
+            )),
             chain_service,
             persister,
             btc_receive_swapper,
             payment_receiver,
             event_listener: listener,
+            events: std::sync::Mutex::new(EventQueue::default()),
+            events_delivered: std::sync::Condvar::new(),
+            connection_status: std::sync::Mutex::new(ConnectionStatus::Disconnected),
+            block_height: watch::channel(current_block_height).0,
+            clock_offset_secs: AtomicI64::new(0),
             shutdown_sender: Mutex::new(None),
+            working_dir_guard: std::sync::Mutex::new(None),
         });
 
         Ok(breez_services)
@@ -951,6 +2084,172 @@ pub fn mnemonic_to_seed(phrase: String) -> Result<Vec<u8>> {
     Ok(seed.as_bytes().to_vec())
 }
 
+/// Errors returned by [BreezServices::init_services]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InitServicesError {
+    /// Another [BreezServices] instance of this process uses the same working dir
+    AlreadyInitialized { working_dir: String },
+}
+
+impl fmt::Display for InitServicesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitServicesError::AlreadyInitialized { working_dir } => write!(
+                f,
+                "The working dir {working_dir} is already used by another instance, stop it first"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InitServicesError {}
+
+/// Working dirs of the running [BreezServices] instances
+static LOCKED_WORKING_DIRS: Lazy<std::sync::Mutex<HashSet<PathBuf>>> = Lazy::new(Default::default);
+
+/// Marks a working dir as used until dropped, as two instances sharing a working dir would
+/// corrupt its database
+struct WorkingDirGuard {
+    path: PathBuf,
+}
+
+impl WorkingDirGuard {
+    /// Locks `working_dir`, creating it first so that every path to it resolves to the same key
+    fn acquire(working_dir: &str) -> Result<Self> {
+        std::fs::create_dir_all(working_dir)?;
+        let path = std::fs::canonicalize(working_dir)?;
+        if !LOCKED_WORKING_DIRS.lock().unwrap().insert(path.clone()) {
+            return Err(InitServicesError::AlreadyInitialized {
+                working_dir: working_dir.to_string(),
+            }
+            .into());
+        }
+        Ok(Self { path })
+    }
+}
+
+impl Drop for WorkingDirGuard {
+    fn drop(&mut self) {
+        LOCKED_WORKING_DIRS.lock().unwrap().remove(&self.path);
+    }
+}
+
+/// Errors returned by [BreezServices::send_payment] when the amount to pay doesn't fit the invoice
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PaymentError {
+    /// The invoice is amountless, so the amount has to be given
+    AmountRequired,
+    /// The given amount differs from the one of the invoice
+    AmountMismatch {
+        invoice_amount_msat: u64,
+        amount_msat: u64,
+    },
+}
+
+impl fmt::Display for PaymentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaymentError::AmountRequired => write!(f, "An amount is required for this invoice"),
+            PaymentError::AmountMismatch {
+                invoice_amount_msat,
+                amount_msat,
+            } => write!(
+                f,
+                "The amount of {amount_msat} msat differs from the invoice amount of \
+                 {invoice_amount_msat} msat"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PaymentError {}
+
+/// Returns the amount to pass to the node when paying `invoice` with the `amount_sats` given by
+/// the user, which only amountless invoices need.
+fn payment_amount_override(invoice: &LNInvoice, amount_sats: Option<u64>) -> Result<Option<u64>> {
+    match (invoice.amount_msat, amount_sats) {
+        (None, None) => Err(PaymentError::AmountRequired.into()),
+        (None, Some(amount_sats)) => Ok(Some(amount_sats)),
+        (Some(_), None) => Ok(None),
+        (Some(invoice_amount_msat), Some(amount_sats)) => {
+            if amount_sats * 1000 != invoice_amount_msat {
+                return Err(PaymentError::AmountMismatch {
+                    invoice_amount_msat,
+                    amount_msat: amount_sats * 1000,
+                }
+                .into());
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// The timeout of a payment, `timeout_sec` if given or else the configured `default_sec`
+fn payment_timeout_sec(timeout_sec: Option<u32>, default_sec: u32) -> Result<u32> {
+    match timeout_sec {
+        Some(0) => Err(anyhow!("The payment timeout must be greater than 0")),
+        Some(timeout_sec) => Ok(timeout_sec),
+        None => Ok(default_sec),
+    }
+}
+
+/// Checks that `extra_tlvs` only use distinct odd types of the custom range, which the nodes that
+/// don't know them ignore
+fn validate_extra_tlvs(extra_tlvs: &[TlvEntry]) -> Result<()> {
+    let mut types = HashSet::new();
+    for tlv in extra_tlvs {
+        if tlv.type_num < CUSTOM_TLV_MIN_TYPE || tlv.type_num % 2 == 0 {
+            return Err(anyhow!(
+                "TLV type {} must be odd and at least {CUSTOM_TLV_MIN_TYPE}",
+                tlv.type_num
+            ));
+        }
+        if !types.insert(tlv.type_num) {
+            return Err(anyhow!("Duplicate TLV type {}", tlv.type_num));
+        }
+    }
+    Ok(())
+}
+
+/// Errors returned when no invoice can be created for the requested amount
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReceivePaymentError {
+    /// The amount can't be received in a single payment, see
+    /// [NodeState::max_single_payment_amount_msat]. It can still be received over several
+    /// invoices with [BreezServices::receive_payment_split].
+    AmountAboveSinglePaymentMax { max_msat: u64 },
+    /// There is no inbound liquidity and no connected LSP that could open a channel, so an
+    /// invoice couldn't be paid. Funds can still be received with [BreezServices::receive_onchain].
+    NoLspConnected,
+    /// The amount is above the inbound liquidity, so the LSP has to open a channel, which needs
+    /// the route hint through the LSP in the invoice.
+    LspHintRequired,
+}
+
+impl fmt::Display for ReceivePaymentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReceivePaymentError::AmountAboveSinglePaymentMax { max_msat } => write!(
+                f,
+                "Amount is above the maximum of {max_msat} msat for a single payment, \
+                 request several smaller payments or enable Config::allow_receive_split"
+            ),
+            ReceivePaymentError::NoLspConnected => write!(
+                f,
+                "No inbound liquidity and no LSP connected to open a channel, \
+                 connect to an LSP or receive on-chain with receive_onchain"
+            ),
+            ReceivePaymentError::LspHintRequired => write!(
+                f,
+                "Receiving this amount needs a new channel from the LSP, \
+                 which requires the LSP route hint in the invoice"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReceivePaymentError {}
+
 #[tonic::async_trait]
 pub trait Receiver: Send + Sync {
     async fn receive_payment(
@@ -958,7 +2257,7 @@ pub trait Receiver: Send + Sync {
         amount_sats: u64,
         description: String,
         preimage: Option<Vec<u8>>,
-    ) -> Result<LNInvoice>;
+    ) -> Result<ReceivePaymentResponse>;
 }
 
 pub(crate) struct PaymentReceiver {
@@ -967,6 +2266,73 @@ pub(crate) struct PaymentReceiver {
     persister: Arc<SqliteStorage>,
 }
 
+impl PaymentReceiver {
+    /// The id of the open channel with the LSP, if any
+    async fn lsp_channel_id(&self, lsp_info: &LspInformation) -> Result<Option<u64>> {
+        info!("Finding channel ID for routing hint");
+        for peer in self.node_api.list_peers().await? {
+            if hex::encode(peer.id) == lsp_info.pubkey && !peer.channels.is_empty() {
+                let active_channel = peer
+                    .channels
+                    .iter()
+                    .find(|&c| c.state == "CHANNELD_NORMAL")
+                    .ok_or("No open channel found")
+                    .map_err(|err| anyhow!(err))?;
+                let hint = match active_channel.clone().alias {
+                    Some(aliases) => aliases.remote,
+                    _ => active_channel.clone().short_channel_id,
+                };
+
+                let short_channel_id = parse_short_channel_id(&hint)?;
+                info!(
+                    "Found channel ID: {} {:?}",
+                    short_channel_id, active_channel
+                );
+                return Ok(Some(short_channel_id));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Creates an invoice without an amount.
+    ///
+    /// The LSP can't open a channel for an amount it doesn't know, so the payment has to fit in
+    /// the inbound liquidity of the existing channel with the LSP.
+    async fn receive_any_amount(&self, description: String) -> Result<LNInvoice> {
+        self.node_api.start().await?;
+        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
+        let node_state = self
+            .persister
+            .get_node_state()?
+            .ok_or("Failed to retrieve node state")
+            .map_err(|err| anyhow!(err))?;
+        if node_state.inbound_liquidity_msats == 0 {
+            return Err(anyhow!(
+                "Receiving an unspecified amount requires inbound liquidity, receive a payment with an amount first"
+            ));
+        }
+
+        info!("Creating zero-amount invoice on NodeAPI");
+        let bolt11 = self
+            .node_api
+            .create_invoice(None, description, None, None)
+            .await?;
+        let parsed_invoice = parse_invoice(&bolt11)?;
+        if has_lsp_hint(&parsed_invoice, &lsp_info) {
+            return Ok(parsed_invoice);
+        }
+
+        // Without a hint through the LSP, the payer may not find a route to us
+        let lsp_hint = self
+            .lsp_channel_id(&lsp_info)
+            .await?
+            .map(|short_channel_id| lsp_route_hint(&lsp_info, short_channel_id));
+        let raw_invoice_with_hint = add_lsp_routing_hints(bolt11, lsp_hint, None, None, None)?;
+        let signed_invoice_with_hint = self.node_api.sign_invoice(raw_invoice_with_hint)?;
+        parse_invoice(&signed_invoice_with_hint)
+    }
+}
+
 #[tonic::async_trait]
 impl Receiver for PaymentReceiver {
     async fn receive_payment(
@@ -974,110 +2340,109 @@ impl Receiver for PaymentReceiver {
         amount_sats: u64,
         description: String,
         preimage: Option<Vec<u8>>,
-    ) -> Result<LNInvoice> {
+    ) -> Result<ReceivePaymentResponse> {
+        self.receive(amount_sats, description, preimage, None, None, None, true)
+            .await
+    }
+}
+
+impl PaymentReceiver {
+    /// Creates the invoice for [Receiver::receive_payment], committing to `description_hash`
+    /// instead of `description`, with the on-chain `fallback_address` and expiring after
+    /// `expiry_secs` when set. The route hint through the LSP is only added with `include_lsp_hint`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn receive(
+        &self,
+        amount_sats: u64,
+        description: String,
+        preimage: Option<Vec<u8>>,
+        description_hash: Option<sha256::Hash>,
+        fallback_address: Option<Address>,
+        expiry_secs: Option<u32>,
+        include_lsp_hint: bool,
+    ) -> Result<ReceivePaymentResponse> {
         self.node_api.start().await?;
-        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
         let node_state = self
             .persister
             .get_node_state()?
             .ok_or("Failed to retrieve node state")
             .map_err(|err| anyhow!(err))?;
+        // Without inbound liquidity, only a channel opened by the LSP can make the invoice payable
+        let no_inbound_liquidity = node_state.inbound_liquidity_msats == 0;
+        if no_inbound_liquidity && self.persister.get_lsp_id()?.is_none() {
+            return Err(ReceivePaymentError::NoLspConnected.into());
+        }
+        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
+        if no_inbound_liquidity && !node_state.connected_peers.contains(&lsp_info.pubkey) {
+            return Err(ReceivePaymentError::NoLspConnected.into());
+        }
 
         let amount_msats = amount_sats * 1000;
+        if amount_msats > node_state.max_single_payment_amount_msat {
+            return Err(ReceivePaymentError::AmountAboveSinglePaymentMax {
+                max_msat: node_state.max_single_payment_amount_msat,
+            }
+            .into());
+        }
 
         let mut short_channel_id = parse_short_channel_id("1x0x0")?;
         let mut destination_invoice_amount_sats = amount_sats;
+        let mut opening_fee_msat = None;
 
         // check if we need to open channel
         let open_channel_needed = node_state.inbound_liquidity_msats < amount_msats;
+        if open_channel_needed && !include_lsp_hint {
+            return Err(ReceivePaymentError::LspHintRequired.into());
+        }
         if open_channel_needed {
             info!("We need to open a channel");
 
             // we need to open channel so we are calculating the fees for the LSP
-            let channel_fees_msat_calculated =
-                amount_msats * lsp_info.channel_fee_permyriad as u64 / 10_000 / 1_000_000;
-            let channel_fees_msat = max(
-                channel_fees_msat_calculated,
-                lsp_info.channel_minimum_fee_msat as u64,
-            );
-
-            if amount_msats < channel_fees_msat + 1000 {
-                return Err(anyhow!(
-                    "requestPayment: Amount should be more than the minimum fees {} sats",
-                    lsp_info.channel_minimum_fee_msat / 1000
-                ));
-            }
+            let channel_fees_msat = lsp_info.channel_fees_msat(amount_msats);
+            ensure_amount_covers_channel_fees(&lsp_info, amount_msats, channel_fees_msat)?;
 
             // remove the fees from the amount to get the small amount on the current node invoice.
             destination_invoice_amount_sats = amount_sats - channel_fees_msat / 1000;
-        } else {
+            opening_fee_msat = Some(channel_fees_msat);
+        } else if !include_lsp_hint {
+            info!("Not adding the LSP routing hint");
+        } else if let Some(channel_id) = self.lsp_channel_id(&lsp_info).await? {
             // not opening a channel so we need to get the real channel id into the routing hints
-            info!("Finding channel ID for routing hint");
-            for peer in self.node_api.list_peers().await? {
-                if hex::encode(peer.id) == lsp_info.pubkey && !peer.channels.is_empty() {
-                    let active_channel = peer
-                        .channels
-                        .iter()
-                        .find(|&c| c.state == "CHANNELD_NORMAL")
-                        .ok_or("No open channel found")
-                        .map_err(|err| anyhow!(err))?;
-                    let hint = match active_channel.clone().alias {
-                        Some(aliases) => aliases.remote,
-                        _ => active_channel.clone().short_channel_id,
-                    };
-
-                    short_channel_id = parse_short_channel_id(&hint)?;
-                    info!(
-                        "Found channel ID: {} {:?}",
-                        short_channel_id, active_channel
-                    );
-                    break;
-                }
-            }
+            short_channel_id = channel_id;
         }
 
         info!("Creating invoice on NodeAPI");
-        let invoice = &self
+        let bolt11 = self
             .node_api
-            .create_invoice(destination_invoice_amount_sats, description, preimage)
+            .create_invoice(
+                Some(destination_invoice_amount_sats),
+                description,
+                preimage,
+                expiry_secs,
+            )
             .await?;
-        info!("Invoice created {}", invoice.bolt11);
-
-        let mut parsed_invoice = parse_invoice(&invoice.bolt11)?;
+        info!("Invoice created {}", bolt11);
 
-        // check if the lsp hint already exists
-        info!("Existing routing hints {:?}", parsed_invoice.routing_hints);
-        info!("lsp info pubkey = {:?}", lsp_info.pubkey.clone());
-        let has_lsp_hint = parsed_invoice.routing_hints.iter().any(|h| {
-            h.hops
-                .iter()
-                .any(|h| h.src_node_id == lsp_info.pubkey.clone())
-        });
+        let mut parsed_invoice = parse_invoice(&bolt11)?;
 
         // We only add routing hint if we need to open a channel
         // or if the invoice doesn't have any routing hints that points to the lsp
         let mut lsp_hint: Option<RouteHint> = None;
-        if !has_lsp_hint || open_channel_needed {
-            info!("Adding routing hint");
-            let lsp_hop = RouteHintHop {
-                src_node_id: lsp_info.pubkey.clone(), // TODO correct?
-                short_channel_id,
-                fees_base_msat: lsp_info.base_fee_msat as u32,
-                fees_proportional_millionths: 10, // TODO
-                cltv_expiry_delta: lsp_info.time_lock_delta as u64,
-                htlc_minimum_msat: Some(lsp_info.min_htlc_msat as u64), // TODO correct?
-                htlc_maximum_msat: Some(1000000000),                    // TODO ?
-            };
-
-            info!("lsp hop = {:?}", lsp_hop);
-            lsp_hint = Some(RouteHint {
-                hops: vec![lsp_hop],
-            });
+        if include_lsp_hint && (!has_lsp_hint(&parsed_invoice, &lsp_info) || open_channel_needed) {
+            lsp_hint = Some(lsp_route_hint(&lsp_info, short_channel_id));
         }
 
         // create the large amount invoice
-        let raw_invoice_with_hint =
-            add_lsp_routing_hints(invoice.bolt11.clone(), lsp_hint, amount_sats * 1000)?;
+        let mut raw_invoice_with_hint = add_lsp_routing_hints(
+            bolt11,
+            lsp_hint,
+            Some(amount_sats * 1000),
+            description_hash,
+            expiry_secs,
+        )?;
+        if let Some(address) = fallback_address {
+            raw_invoice_with_hint = add_fallback_address(raw_invoice_with_hint, &address)?;
+        }
 
         info!("Routing hint added");
         let signed_invoice_with_hint = self.node_api.sign_invoice(raw_invoice_with_hint)?;
@@ -1103,35 +2468,325 @@ impl Receiver for PaymentReceiver {
         }
 
         // return the signed, converted invoice with hints
-        Ok(parsed_invoice)
+        Ok(ReceivePaymentResponse {
+            ln_invoice: parsed_invoice,
+            opening_fee_msat,
+            opening_fee_params: opening_fee_msat.map(|_| OpeningFeeParams::from(&lsp_info)),
+        })
     }
-}
 
-/// Convenience method to look up LSP info based on current LSP ID
-async fn get_lsp(persister: Arc<SqliteStorage>, lsp: Arc<dyn LspAPI>) -> Result<LspInformation> {
-    let lsp_id = persister
-        .get_lsp_id()?
-        .ok_or("No LSP ID found")
-        .map_err(|err| anyhow!(err))?;
+    /// Estimates the cost of receiving `amount_msat`, see [BreezServices::receive_cost_estimate]
+    pub(crate) async fn receive_cost_estimate(&self, amount_msat: u64) -> Result<ReceiveCost> {
+        let node_state = self
+            .persister
+            .get_node_state()?
+            .ok_or("Failed to retrieve node state")
+            .map_err(|err| anyhow!(err))?;
 
-    get_lsp_by_id(persister, lsp, lsp_id.as_str())
-        .await?
-        .ok_or_else(|| anyhow!("No LSP found for id {}", lsp_id))
+        // no channel needs to be opened, so the LSP doesn't charge anything
+        if node_state.inbound_liquidity_msats >= amount_msat {
+            return Ok(ReceiveCost {
+                lsp_fee_msat: 0,
+                net_received_msat: amount_msat,
+            });
+        }
+
+        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
+        let lsp_fee_msat = lsp_info.channel_fees_msat(amount_msat);
+        ensure_amount_covers_channel_fees(&lsp_info, amount_msat, lsp_fee_msat)?;
+        Ok(ReceiveCost {
+            lsp_fee_msat,
+            net_received_msat: amount_msat - lsp_fee_msat,
+        })
+    }
 }
 
-async fn get_lsp_by_id(
-    persister: Arc<SqliteStorage>,
-    lsp: Arc<dyn LspAPI>,
-    lsp_id: &str,
-) -> Result<Option<LspInformation>> {
-    let node_pubkey = persister
-        .get_node_state()?
-        .ok_or("No NodeState found")
-        .map_err(|err| anyhow!(err))?
-        .id;
+/// How many payments [BreezServices::sync] stores between two [BreezEvent::SyncProgress]
+const SYNC_PROGRESS_BATCH_SIZE: usize = 100;
+
+/// How long [BreezServices::connectivity_check] waits for each service
+const CONNECTIVITY_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+async fn check_service<T>(
+    service: ServiceType,
+    request: impl Future<Output = Result<T>>,
+) -> ServiceStatus {
+    let start = Instant::now();
+    let res = tokio::time::timeout(CONNECTIVITY_CHECK_TIMEOUT, request).await;
+    let latency_ms = start.elapsed().as_millis() as u64;
+    let error = match res {
+        Ok(Ok(_)) => None,
+        Ok(Err(err)) => Some(err.to_string()),
+        Err(_) => Some(format!(
+            "Timed out after {}s",
+            CONNECTIVITY_CHECK_TIMEOUT.as_secs()
+        )),
+    };
+    ServiceStatus {
+        service,
+        reachable: error.is_none(),
+        latency_ms,
+        error,
+    }
+}
 
-    Ok(lsp
-        .list_lsps(node_pubkey)
+/// Virtual size of the parts of a transaction spending P2WPKH outputs to a single output: the
+/// fixed fields (version, locktime, counts and segwit marker), each input, and the output without
+/// its script
+const TX_OVERHEAD_VSIZE: u64 = 11;
+const P2WPKH_INPUT_VSIZE: u64 = 68;
+const OUTPUT_BASE_VSIZE: u64 = 9;
+
+/// Finds the UTXOs with the given `outpoints`, formatted as `txid:vout`, among the `available` ones.
+///
+/// Fails listing the outpoints that are unknown, or reserved and so can't be spent.
+fn select_utxos(
+    available: &[UnspentTransactionOutput],
+    outpoints: &[String],
+) -> Result<Vec<UnspentTransactionOutput>> {
+    if outpoints.is_empty() {
+        return Err(anyhow!("No UTXOs selected"));
+    }
+    let mut selected: Vec<UnspentTransactionOutput> = vec![];
+    let mut unknown = vec![];
+    let mut reserved = vec![];
+    for outpoint in outpoints {
+        match available.iter().find(|u| &u.outpoint() == outpoint) {
+            Some(u) if u.reserved => reserved.push(outpoint.as_str()),
+            Some(u) if !selected.contains(u) => selected.push(u.clone()),
+            Some(_) => {}
+            None => unknown.push(outpoint.as_str()),
+        }
+    }
+    if !unknown.is_empty() {
+        return Err(anyhow!("Unknown UTXOs: {}", unknown.join(", ")));
+    }
+    if !reserved.is_empty() {
+        return Err(anyhow!(
+            "Reserved UTXOs can't be swept: {}",
+            reserved.join(", ")
+        ));
+    }
+    Ok(selected)
+}
+
+/// The fee paid by `tx`, its inputs minus its outputs, when all its inputs are in `utxos`
+fn onchain_tx_fee(tx: &Transaction, utxos: &[UnspentTransactionOutput]) -> Result<u64> {
+    let mut inputs_sat = 0;
+    for input in &tx.input {
+        let outpoint = input.previous_output.to_string();
+        let utxo = utxos
+            .iter()
+            .find(|u| u.outpoint() == outpoint)
+            .ok_or_else(|| anyhow!("Unknown input {outpoint}"))?;
+        inputs_sat += utxo.amount_millisatoshi / 1000;
+    }
+    let outputs_sat: u64 = tx.output.iter().map(|o| o.value).sum();
+    inputs_sat
+        .checked_sub(outputs_sat)
+        .ok_or_else(|| anyhow!("The outputs are worth more than the inputs"))
+}
+
+/// Estimates a transaction sending all the `utxos` to a single output with a script of
+/// `output_script_len` bytes
+fn estimate_sweep(
+    utxos: &[UnspentTransactionOutput],
+    output_script_len: u64,
+    fee_rate: &SatPerVbyte,
+) -> Result<SweepPreview> {
+    if utxos.is_empty() {
+        return Err(anyhow!("No on-chain funds to sweep"));
+    }
+    let total_sat: u64 = utxos.iter().map(|u| u.amount_millisatoshi / 1000).sum();
+    let tx_vsize = TX_OVERHEAD_VSIZE
+        + utxos.len() as u64 * P2WPKH_INPUT_VSIZE
+        + OUTPUT_BASE_VSIZE
+        + output_script_len;
+    let fee_sat = fee_rate.fee_for_vsize(tx_vsize);
+    if fee_sat >= total_sat {
+        return Err(anyhow!(
+            "The fee of {fee_sat} sat is higher than the {total_sat} sat available to sweep"
+        ));
+    }
+    Ok(SweepPreview {
+        tx_vsize,
+        fee_sat,
+        amount_sat: total_sat - fee_sat,
+    })
+}
+
+/// Splits `amount_sats` into as few parts as possible, each at most `max_sats`, with amounts
+/// differing by at most 1 sat
+fn split_receive_amount(amount_sats: u64, max_sats: u64) -> Vec<u64> {
+    if amount_sats <= max_sats || max_sats == 0 {
+        return vec![amount_sats];
+    }
+    let parts = (amount_sats + max_sats - 1) / max_sats;
+    let (base, remainder) = (amount_sats / parts, amount_sats % parts);
+    (0..parts)
+        .map(|i| if i < remainder { base + 1 } else { base })
+        .collect()
+}
+
+/// Whether `invoice` already has a routing hint through the LSP
+fn has_lsp_hint(invoice: &LNInvoice, lsp_info: &LspInformation) -> bool {
+    info!("Existing routing hints {:?}", invoice.routing_hints);
+    info!("lsp info pubkey = {:?}", lsp_info.pubkey.clone());
+    invoice
+        .routing_hints
+        .iter()
+        .any(|h| h.hops.iter().any(|h| h.src_node_id == lsp_info.pubkey))
+}
+
+/// The routing hint through the LSP, over the channel `short_channel_id`
+fn lsp_route_hint(lsp_info: &LspInformation, short_channel_id: u64) -> RouteHint {
+    info!("Adding routing hint");
+    let lsp_hop = RouteHintHop {
+        src_node_id: lsp_info.pubkey.clone(), // TODO correct?
+        short_channel_id,
+        fees_base_msat: lsp_info.base_fee_msat as u32,
+        fees_proportional_millionths: 10, // TODO
+        cltv_expiry_delta: lsp_info.time_lock_delta as u64,
+        htlc_minimum_msat: Some(lsp_info.min_htlc_msat as u64), // TODO correct?
+        htlc_maximum_msat: Some(1000000000),                    // TODO ?
+    };
+
+    info!("lsp hop = {:?}", lsp_hop);
+    RouteHint {
+        hops: vec![lsp_hop],
+    }
+}
+
+fn ensure_amount_covers_channel_fees(
+    lsp_info: &LspInformation,
+    amount_msats: u64,
+    channel_fees_msat: u64,
+) -> Result<()> {
+    if amount_msats < channel_fees_msat + 1000 {
+        return Err(anyhow!(
+            "requestPayment: Amount should be more than the minimum fees {} sats",
+            lsp_info.channel_minimum_fee_msat / 1000
+        ));
+    }
+    Ok(())
+}
+
+/// The receivable amounts given the node state and the LSP fees, see
+/// [BreezServices::receive_payment_limits]
+fn receive_limits(node_state: &NodeState, lsp_info: &LspInformation) -> ReceiveLimits {
+    let max_receivable_msat = min(
+        node_state.max_receivable_msat,
+        node_state.max_single_payment_amount_msat,
+    );
+    let max_receivable_without_fee_msat =
+        min(node_state.inbound_liquidity_msats, max_receivable_msat);
+    // invoices are created for whole sats, and above the inbound liquidity the amount has to
+    // cover the channel opening fee, see [ensure_amount_covers_channel_fees]
+    let min_receivable_msat = if max_receivable_without_fee_msat >= 1000 {
+        1000
+    } else {
+        lsp_info.channel_fees_msat(0) + 1000
+    };
+    let max_receivable_fee_msat = if max_receivable_msat > max_receivable_without_fee_msat {
+        lsp_info.channel_fees_msat(max_receivable_msat)
+    } else {
+        0
+    };
+    ReceiveLimits {
+        min_receivable_msat,
+        max_receivable_msat,
+        max_receivable_without_fee_msat,
+        max_receivable_fee_msat,
+    }
+}
+
+/// The limits broken by `amount_msat` in `context`, see [BreezServices::validate_amount].
+///
+/// `lsp_info` is only used when receiving and `swap_info` only when swapping in.
+fn amount_violations(
+    amount_msat: u64,
+    context: AmountContext,
+    node_state: &NodeState,
+    lsp_info: Option<&LspInformation>,
+    swap_info: Option<&SwapInfo>,
+) -> Vec<AmountViolation> {
+    let mut violations = vec![];
+    match context {
+        AmountContext::Send => {
+            if amount_msat == 0 {
+                violations.push(AmountViolation::BelowMinimum { min_msat: 1 });
+            }
+            if amount_msat > node_state.max_single_payment_amount_msat {
+                violations.push(AmountViolation::AboveMaximum {
+                    max_msat: node_state.max_single_payment_amount_msat,
+                });
+            }
+            if amount_msat > node_state.max_payable_msat {
+                violations.push(AmountViolation::InsufficientBalance {
+                    max_payable_msat: node_state.max_payable_msat,
+                });
+            }
+        }
+        AmountContext::Receive => {
+            // a new channel is opened when the inbound liquidity doesn't suffice, and the amount
+            // has to cover the LSP fee
+            if let Some(lsp_info) = lsp_info {
+                if amount_msat > node_state.inbound_liquidity_msats {
+                    let min_msat = lsp_info.channel_fees_msat(amount_msat) + 1000;
+                    if amount_msat < min_msat {
+                        violations.push(AmountViolation::BelowMinimum { min_msat });
+                    }
+                }
+            }
+            let max_msat = min(
+                node_state.max_receivable_msat,
+                node_state.max_single_payment_amount_msat,
+            );
+            if amount_msat > max_msat {
+                violations.push(AmountViolation::AboveMaximum { max_msat });
+            }
+        }
+        AmountContext::SwapIn => {
+            if let Some(swap_info) = swap_info {
+                let min_msat = swap_info.min_allowed_deposit as u64 * 1000;
+                let max_msat = swap_info.max_allowed_deposit as u64 * 1000;
+                if amount_msat < min_msat {
+                    violations.push(AmountViolation::BelowMinimum { min_msat });
+                }
+                if amount_msat > max_msat {
+                    violations.push(AmountViolation::AboveMaximum { max_msat });
+                }
+            }
+        }
+    }
+    violations
+}
+
+/// Convenience method to look up LSP info based on current LSP ID
+async fn get_lsp(persister: Arc<SqliteStorage>, lsp: Arc<dyn LspAPI>) -> Result<LspInformation> {
+    let lsp_id = persister
+        .get_lsp_id()?
+        .ok_or("No LSP ID found")
+        .map_err(|err| anyhow!(err))?;
+
+    get_lsp_by_id(persister, lsp, lsp_id.as_str())
+        .await?
+        .ok_or_else(|| anyhow!("No LSP found for id {}", lsp_id))
+}
+
+async fn get_lsp_by_id(
+    persister: Arc<SqliteStorage>,
+    lsp: Arc<dyn LspAPI>,
+    lsp_id: &str,
+) -> Result<Option<LspInformation>> {
+    let node_pubkey = persister
+        .get_node_state()?
+        .ok_or("No NodeState found")
+        .map_err(|err| anyhow!(err))?
+        .id;
+
+    Ok(lsp
+        .list_lsps(node_pubkey)
         .await?
         .iter()
         .find(|&lsp| lsp.id.as_str() == lsp_id)
@@ -1141,22 +2796,44 @@ async fn get_lsp_by_id(
 #[cfg(test)]
 pub(crate) mod tests {
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
     use anyhow::{anyhow, Result};
+    use bitcoin::consensus::deserialize;
+    use bitcoin::hashes::{sha256, Hash};
+    use bitcoin::Transaction;
+    use lightning_invoice::Currency;
     use reqwest::Url;
+    use tokio::runtime::Runtime;
 
     use regex::Regex;
 
-    use crate::breez_services::{BreezServices, BreezServicesBuilder};
-    use crate::fiat::Rate;
+    use crate::breez_services::{
+        amount_violations, estimate_sweep, poll_events, receive_limits, split_receive_amount,
+        BreezEvent, BreezServices, BreezServicesBuilder, EventListener, InitServicesError,
+        InvoicePaidDetails, PaymentError, ReceivePaymentError, ReconnectBackoff, WorkingDirGuard,
+    };
+    use crate::chain::{ChainService, FeeTier, OnchainTx, RecommendedFees};
+    use crate::fiat::{FiatCurrency, Rate};
     use crate::lnurl::pay::model::MessageSuccessActionData;
     use crate::lnurl::pay::model::SuccessActionProcessed;
-    use crate::models::{LnPaymentDetails, NodeState, Payment, PaymentDetails, PaymentTypeFilter};
+    use crate::lsp::LspInformation;
+    use crate::models::{
+        AmountContext, AmountViolation, Channel, ChannelInfo, ChannelState, Config,
+        ConnectionStatus, EnvironmentType, LnPaymentDetails, Network, NodeState, OpeningFeeParams,
+        Payment, PaymentDetails, PaymentRouteHop, PaymentTypeFilter, ReceiveLimits, SatPerVbyte,
+        SwapInfo, SwapStatus, SweepPreview, TemplateValue, UnspentTransactionOutput,
+        MAX_SAT_PER_VBYTE,
+    };
+    use crate::persist::db::SqliteStorage;
+    use crate::persist::error::{map_storage_error, StorageError};
     use crate::{
-        input_parser, parse_short_channel_id, test_utils::*, BuyBitcoinProvider, InputType,
+        input_parser, parse_short_channel_id, test_utils::*, AddressError, BuyBitcoinProvider,
+        InputType, InvoiceError, LnUrlAuthRequestData,
     };
-    use crate::{NodeAPI, PaymentType};
+    use crate::{FiatAPI, LspAPI, NodeAPI, PaymentType};
 
     use super::{PaymentReceiver, Receiver};
 
@@ -1196,6 +2873,8 @@ pub(crate) mod tests {
                         lnurl_success_action: None,
                         lnurl_metadata: None,
                         ln_address: None,
+                        route: vec![],
+                        custom_tlvs: vec![],
                     },
                 },
             },
@@ -1218,6 +2897,8 @@ pub(crate) mod tests {
                         lnurl_success_action: Some(sa.clone()),
                         lnurl_metadata: Some(lnurl_metadata.to_string()),
                         ln_address: Some(test_ln_address.to_string()),
+                        route: vec![],
+                        custom_tlvs: vec![],
                     },
                 },
             },
@@ -1252,7 +2933,7 @@ pub(crate) mod tests {
         assert_eq!(fetched_state, dummy_node_state);
 
         let all = breez_services
-            .list_payments(PaymentTypeFilter::All, None, None)
+            .list_payments(PaymentTypeFilter::All, None, None, None, None, None)
             .await?;
         let mut cloned = all.clone();
 
@@ -1261,12 +2942,12 @@ pub(crate) mod tests {
         assert_eq!(dummy_transactions, cloned);
 
         let received = breez_services
-            .list_payments(PaymentTypeFilter::Received, None, None)
+            .list_payments(PaymentTypeFilter::Received, None, None, None, None, None)
             .await?;
         assert_eq!(received, vec![cloned[0].clone()]);
 
         let sent = breez_services
-            .list_payments(PaymentTypeFilter::Sent, None, None)
+            .list_payments(PaymentTypeFilter::Sent, None, None, None, None, None)
             .await?;
         assert_eq!(sent, vec![cloned[1].clone()]);
         assert!(matches!(
@@ -1277,6 +2958,25 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_list_payments_storage_busy() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+
+        // Another connection holds an exclusive lock on the databases
+        let mut locking = breez_services.persister.get_connection()?;
+        let _lock = locking.transaction_with_behavior(rusqlite::TransactionBehavior::Exclusive)?;
+
+        let err = breez_services
+            .list_payments(PaymentTypeFilter::All, None, None, None, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            map_storage_error(err).downcast_ref::<StorageError>(),
+            Some(StorageError::Busy(_))
+        ));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_receive_with_open_channel() -> Result<(), Box<dyn std::error::Error>> {
         let config = create_test_config();
@@ -1293,12 +2993,23 @@ pub(crate) mod tests {
 
         let receiver: Arc<dyn Receiver> = Arc::new(PaymentReceiver {
             node_api,
-            persister,
+            persister: persister.clone(),
             lsp: breez_server.clone(),
         });
-        let ln_invoice = receiver
+        let res = receiver
             .receive_payment(3000, "should populate lsp hints".to_string(), None)
             .await?;
+        // the dummy node has no inbound liquidity, so the LSP opens a channel
+        let lsp_info = breez_server.list_lsps("".to_string()).await?.remove(0);
+        assert_eq!(
+            res.opening_fee_msat,
+            Some(lsp_info.channel_fees_msat(3_000_000))
+        );
+        assert_eq!(
+            res.opening_fee_params,
+            Some(OpeningFeeParams::from(&lsp_info))
+        );
+        let ln_invoice = res.ln_invoice;
         assert_eq!(ln_invoice.routing_hints[0].hops.len(), 1);
         let lsp_hop = &ln_invoice.routing_hints[0].hops[0];
         assert_eq!(lsp_hop.src_node_id, breez_server.clone().lsp_pub_key());
@@ -1306,65 +3017,1962 @@ pub(crate) mod tests {
             lsp_hop.short_channel_id,
             parse_short_channel_id("1x0x0").unwrap()
         );
+
+        // No fee when the inbound liquidity suffices
+        persister.set_node_state(&NodeState {
+            inbound_liquidity_msats: 3_000_000,
+            ..dummy_node_state
+        })?;
+        let res = receiver
+            .receive_payment(3000, "no channel needed".to_string(), None)
+            .await?;
+        assert_eq!(res.opening_fee_msat, None);
+        assert_eq!(res.opening_fee_params, None);
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_list_lsps() -> Result<(), Box<dyn std::error::Error>> {
-        let storage_path = format!("{}/storage.sql", get_test_working_dir());
-        std::fs::remove_file(storage_path).ok();
+    async fn test_lsp_liquidity_available() -> Result<()> {
+        let breez_services = breez_services().await?;
+
+        // Unknown without a selected LSP
+        assert!(!breez_services.lsp_liquidity_available(10_000_000).await);
+
+        // The mock LSP opens channels of 1_000_000 sats, with a minimum fee of 1 msat
+        breez_services
+            .persister
+            .set_lsp_id(MockBreezServer {}.lsp_id())?;
+        assert!(breez_services.lsp_liquidity_available(10_000_000).await);
+        assert!(breez_services.lsp_liquidity_available(1_000_000_000).await);
+        assert!(!breez_services.lsp_liquidity_available(1_000_000_001).await);
+        assert!(!breez_services.lsp_liquidity_available(1000).await);
+        Ok(())
+    }
 
+    #[tokio::test]
+    async fn test_receive_without_lsp_hint() -> Result<(), Box<dyn std::error::Error>> {
         let breez_services = breez_services().await?;
-        breez_services.sync().await?;
+        let breez_server = MockBreezServer {};
+        breez_services.persister.set_lsp_id(breez_server.lsp_id())?;
+        breez_services.persister.set_node_state(&NodeState {
+            inbound_liquidity_msats: 3_000_000,
+            ..get_dummy_node_state()
+        })?;
+        let has_lsp_hop = |ln_invoice: &LNInvoice| {
+            ln_invoice
+                .routing_hints
+                .iter()
+                .flat_map(|hint| &hint.hops)
+                .any(|hop| hop.src_node_id == breez_server.lsp_pub_key())
+        };
 
-        let node_pubkey = breez_services
-            .node_info()?
-            .ok_or("No NodeState found")
-            .map_err(|err| anyhow!(err))?
-            .id;
-        let lsps = breez_services.lsp_api.list_lsps(node_pubkey).await?;
-        assert_eq!(lsps.len(), 1);
+        let ln_invoice = breez_services
+            .receive_payment(3000, "default".to_string(), None, None, None, None)
+            .await?
+            .ln_invoice;
+        assert!(has_lsp_hop(&ln_invoice));
+
+        let ln_invoice = breez_services
+            .receive_payment(
+                3000,
+                "direct peer".to_string(),
+                None,
+                None,
+                Some(false),
+                None,
+            )
+            .await?
+            .ln_invoice;
+        assert!(!has_lsp_hop(&ln_invoice));
+
+        // Without the hint, the LSP can't open a channel for amounts above the inbound liquidity
+        let err = breez_services
+            .receive_payment(
+                4000,
+                "direct peer".to_string(),
+                None,
+                None,
+                Some(false),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ReceivePaymentError>(),
+            Some(&ReceivePaymentError::LspHintRequired)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_with_description_template() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let breez_server = MockBreezServer {};
+        breez_services.persister.set_lsp_id(breez_server.lsp_id())?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
 
+        let values = vec![
+            TemplateValue {
+                key: "id".to_string(),
+                value: "1001".to_string(),
+            },
+            TemplateValue {
+                key: "store".to_string(),
+                value: "Main St".to_string(),
+            },
+        ];
+        let ln_invoice = breez_services
+            .receive_payment_with_template(3000, "Order #{id} at {store}".to_string(), values)
+            .await?
+            .ln_invoice;
+        assert_eq!(
+            ln_invoice.description,
+            Some("Order #1001 at Main St".to_string())
+        );
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_fetch_rates() -> Result<(), Box<dyn std::error::Error>> {
+    async fn test_receive_with_description_hash() -> Result<(), Box<dyn std::error::Error>> {
         let breez_services = breez_services().await?;
-        breez_services.sync().await?;
+        let breez_server = MockBreezServer {};
+        breez_services.persister.set_lsp_id(breez_server.lsp_id())?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        let metadata_hash = sha256::Hash::hash(b"[[\"text/plain\",\"metadata\"]]");
+        let ln_invoice = breez_services
+            .receive_payment_with_description_hash(
+                3000,
+                "metadata".to_string(),
+                metadata_hash.into_inner().to_vec(),
+            )
+            .await?
+            .ln_invoice;
+        assert_eq!(ln_invoice.description, None);
+        assert_eq!(ln_invoice.description_hash, Some(metadata_hash.to_string()));
+
+        // The hash has to be a SHA256 hash
+        assert!(breez_services
+            .receive_payment_with_description_hash(3000, "metadata".to_string(), vec![1, 2, 3])
+            .await
+            .is_err());
+        Ok(())
+    }
 
-        let rates = breez_services.fiat_api.fetch_fiat_rates().await?;
-        assert_eq!(rates.len(), 1);
+    #[tokio::test]
+    async fn test_receive_with_expiry() -> Result<(), Box<dyn std::error::Error>> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let breez_services = breez_services_with(Some(node_api.clone()), vec![]).await?;
+        let breez_server = MockBreezServer {};
+        breez_services.persister.set_lsp_id(breez_server.lsp_id())?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        let ln_invoice = breez_services
+            .receive_payment(
+                3000,
+                "point of sale".to_string(),
+                Some(300),
+                None,
+                None,
+                None,
+            )
+            .await?
+            .ln_invoice;
+        assert_eq!(ln_invoice.expiry, 300);
+        // The node's own copy of the invoice expires at the same time
+        assert_eq!(*node_api.invoice_expiries.lock().await, vec![Some(300)]);
+
+        for expiry_secs in [MIN_INVOICE_EXPIRY_SECS - 1, MAX_INVOICE_EXPIRY_SECS + 1] {
+            assert!(breez_services
+                .receive_payment(
+                    3000,
+                    "point of sale".to_string(),
+                    Some(expiry_secs),
+                    None,
+                    None,
+                    None
+                )
+                .await
+                .is_err());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_with_fallback_address() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let breez_server = MockBreezServer {};
+        breez_services.persister.set_lsp_id(breez_server.lsp_id())?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        let address = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let ln_invoice = breez_services
+            .receive_payment(
+                3000,
+                "on-chain too".to_string(),
+                None,
+                None,
+                None,
+                Some(address.to_string()),
+            )
+            .await?
+            .ln_invoice;
+        let invoice = ln_invoice.bolt11.parse::<lightning_invoice::Invoice>()?;
+        assert_eq!(invoice.fallbacks().len(), 1);
+
+        // The address is checked before anything is created, and no swap address is allocated
+        for invalid in [
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+        ] {
+            assert!(breez_services
+                .receive_payment(
+                    3000,
+                    "on-chain too".to_string(),
+                    None,
+                    None,
+                    None,
+                    Some(invalid.to_string()),
+                )
+                .await
+                .is_err());
+        }
+        assert!(breez_services.persister.list_swaps()?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_without_lsp() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let breez_server = MockBreezServer {};
+        let mut node_state = get_dummy_node_state();
+        node_state.inbound_liquidity_msats = 0;
+        breez_services.persister.set_node_state(&node_state)?;
+
+        let err = breez_services
+            .receive_payment(3000, "no lsp".to_string(), None, None, None, None)
+            .await
+            .unwrap_err();
         assert_eq!(
-            rates[0],
-            Rate {
-                coin: "USD".to_string(),
-                value: 20_000.00
-            }
+            err.downcast_ref::<ReceivePaymentError>(),
+            Some(&ReceivePaymentError::NoLspConnected)
+        );
+
+        // An LSP is selected, but the node isn't connected to it
+        breez_services.persister.set_lsp_id(breez_server.lsp_id())?;
+        let err = breez_services
+            .receive_payment(3000, "no lsp".to_string(), None, None, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ReceivePaymentError>(),
+            Some(&ReceivePaymentError::NoLspConnected)
         );
 
+        node_state.connected_peers = vec![breez_server.lsp_pub_key()];
+        breez_services.persister.set_node_state(&node_state)?;
+        breez_services
+            .receive_payment(3000, "no lsp".to_string(), None, None, None, None)
+            .await?;
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_buy_bitcoin_with_moonpay() -> Result<(), Box<dyn std::error::Error>> {
+    async fn test_receive_with_preimage() -> Result<(), Box<dyn std::error::Error>> {
         let breez_services = breez_services().await?;
-        breez_services.sync().await?;
+        let breez_server = MockBreezServer {};
+        breez_services.persister.set_lsp_id(breez_server.lsp_id())?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        let preimage = vec![7; 32];
+        let ln_invoice = breez_services
+            .receive_payment(
+                3000,
+                "lnurl".to_string(),
+                None,
+                Some(preimage.clone()),
+                None,
+                None,
+            )
+            .await?
+            .ln_invoice;
+        assert_eq!(
+            ln_invoice.payment_hash,
+            sha256::Hash::hash(&preimage).to_string()
+        );
 
-        let moonpay_url = breez_services
-            .buy_bitcoin(BuyBitcoinProvider::MoonPay)
+        // The preimage of an existing invoice can't be reused
+        assert!(breez_services
+            .receive_payment(3000, "lnurl".to_string(), None, Some(preimage), None, None)
+            .await
+            .is_err());
+
+        // The preimage has to be 32 bytes long
+        assert!(breez_services
+            .receive_payment(
+                3000,
+                "lnurl".to_string(),
+                None,
+                Some(vec![7; 16]),
+                None,
+                None
+            )
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_payment_split() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(split_receive_amount(3000, 5000), vec![3000]);
+        assert_eq!(split_receive_amount(12_000, 5000), vec![4000, 4000, 4000]);
+        assert_eq!(split_receive_amount(10_001, 5000), vec![3334, 3334, 3333]);
+
+        let node_state = NodeState {
+            max_single_payment_amount_msat: 5_000_000,
+            ..get_dummy_node_state()
+        };
+        let max_exceeded = ReceivePaymentError::AmountAboveSinglePaymentMax {
+            max_msat: 5_000_000,
+        };
+        for allow_receive_split in [false, true] {
+            let config = Config {
+                allow_receive_split,
+                ..create_test_config()
+            };
+            let events = Arc::new(std::sync::Mutex::new(vec![]));
+            let breez_services = breez_services_with_listener(config, events).await?;
+            breez_services
+                .persister
+                .set_lsp_id(MockBreezServer {}.lsp_id())?;
+            breez_services.persister.set_node_state(&node_state)?;
+
+            // A single invoice can't be above the maximum, whatever the config
+            let err = breez_services
+                .receive_payment(12_000, "Rent".to_string(), None, None, None, None)
+                .await
+                .unwrap_err();
+            assert_eq!(err.downcast_ref(), Some(&max_exceeded));
+
+            let res = breez_services
+                .receive_payment_split(12_000, "Rent".to_string())
+                .await;
+            if allow_receive_split {
+                let descriptions: Vec<_> = res?.into_iter().filter_map(|i| i.description).collect();
+                assert_eq!(descriptions, vec!["Rent (1/3)", "Rent (2/3)", "Rent (3/3)"]);
+            } else {
+                assert_eq!(res.unwrap_err().downcast_ref(), Some(&max_exceeded));
+            }
+
+            // Amounts below the maximum always get a single invoice
+            let invoices = breez_services
+                .receive_payment_split(3000, "Rent".to_string())
+                .await?;
+            assert_eq!(invoices.len(), 1);
+            assert_eq!(invoices[0].description, Some("Rent".to_string()));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_donation() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        breez_services
+            .persister
+            .set_lsp_id(MockBreezServer {}.lsp_id())?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        let invoice = breez_services
+            .receive_donation("Tip jar".to_string())
             .await?;
-        let parsed = Url::parse(&moonpay_url)?;
-        let query_pairs = parsed.query_pairs().into_owned().collect::<HashMap<_, _>>();
+        assert_eq!(invoice.amount_msat, None);
+        assert_eq!(invoice.description, Some("Tip jar".to_string()));
+
+        // Without inbound liquidity, the LSP would have to open a channel for an unknown amount
+        breez_services.persister.set_node_state(&NodeState {
+            inbound_liquidity_msats: 0,
+            ..get_dummy_node_state()
+        })?;
+        assert!(breez_services
+            .receive_donation("Tip jar".to_string())
+            .await
+            .is_err());
+        Ok(())
+    }
 
-        assert_eq!(parsed.host_str(), Some("mock.moonpay"));
-        assert_eq!(parsed.path(), "/");
+    #[tokio::test]
+    async fn test_receive_cost_estimate() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let breez_server = MockBreezServer {};
+        breez_services.persister.set_lsp_id(breez_server.lsp_id())?;
+        let node_state = get_dummy_node_state();
+        breez_services.persister.set_node_state(&node_state)?;
+
+        // The inbound liquidity is enough, no channel needs to be opened
+        let cost = breez_services
+            .receive_cost_estimate(node_state.inbound_liquidity_msats)
+            .await?;
+        assert_eq!(cost.lsp_fee_msat, 0);
+        assert_eq!(cost.net_received_msat, node_state.inbound_liquidity_msats);
+
+        // The inbound liquidity is not enough, the LSP fee is deducted
+        let amount_msat = 50_000_000;
+        let lsp_info = &breez_server.list_lsps(node_state.id.clone()).await?[0];
+        let expected_fee_msat = lsp_info.channel_fees_msat(amount_msat);
+        let cost = breez_services.receive_cost_estimate(amount_msat).await?;
+        assert!(cost.lsp_fee_msat > 0);
+        assert_eq!(cost.lsp_fee_msat, expected_fee_msat);
+        assert_eq!(cost.net_received_msat, amount_msat - expected_fee_msat);
+        Ok(())
+    }
 
-        let wallet_address = input_parser::parse(query_pairs.get("wa").unwrap()).await?;
-        assert!(matches!(wallet_address, InputType::BitcoinAddress { .. }));
+    struct RecordingEventListener {
+        events: Arc<std::sync::Mutex<Vec<BreezEvent>>>,
+    }
 
-        let max_amount = query_pairs.get("ma").unwrap();
-        assert!(Regex::new(r"^\d+\.\d{8}$").unwrap().is_match(max_amount));
+    impl EventListener for RecordingEventListener {
+        fn on_event(&self, e: BreezEvent) {
+            self.events.lock().unwrap().push(e);
+        }
+    }
+
+    /// Forwards events only once subscribed, like the events stream of the bindings
+    struct LateSubscriber {
+        stream: Arc<std::sync::Mutex<Option<Vec<BreezEvent>>>>,
+    }
+
+    impl EventListener for LateSubscriber {
+        fn on_event(&self, e: BreezEvent) {
+            if let Some(stream) = self.stream.lock().unwrap().as_mut() {
+                stream.push(e);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_events() -> Result<(), Box<dyn std::error::Error>> {
+        let mut config = create_test_config();
+        config.event_buffer_size = 2;
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init()?;
+        let stream = Arc::new(std::sync::Mutex::new(None));
+        let breez_services = BreezServicesBuilder::new(config)
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(Arc::new(MockBreezServer {}))
+            .moonpay_api(Arc::new(MockBreezServer {}))
+            .persister(persister)
+            .node_api(Arc::new(MockNodeAPI::new(get_dummy_node_state())))
+            .build(Some(Box::new(LateSubscriber {
+                stream: stream.clone(),
+            })))
+            .await?;
+
+        for block in 1..=3 {
+            breez_services
+                .notify_event_listeners(BreezEvent::NewBlock { block })
+                .await?;
+        }
+        breez_services.replay_events(|events| *stream.lock().unwrap() = Some(events));
+        breez_services
+            .notify_event_listeners(BreezEvent::NewBlock { block: 4 })
+            .await?;
+
+        // Only the last two events are buffered, and they come before the live one
+        let received: Vec<u32> = stream
+            .lock()
+            .unwrap()
+            .clone()
+            .unwrap()
+            .into_iter()
+            .map(|e| match e {
+                BreezEvent::NewBlock { block } => block,
+                _ => 0,
+            })
+            .collect();
+        assert_eq!(received, vec![2, 3, 4]);
+        Ok(())
+    }
+
+    /// Emits a second event from within the listener, like an app reacting to an event
+    struct ReentrantListener {
+        breez_services: Arc<std::sync::Mutex<Option<std::sync::Weak<BreezServices>>>>,
+        events: Arc<std::sync::Mutex<Vec<BreezEvent>>>,
+    }
+
+    impl EventListener for ReentrantListener {
+        fn on_event(&self, e: BreezEvent) {
+            if let BreezEvent::NewBlock { block: 1 } = e {
+                let breez_services = self.breez_services.lock().unwrap().clone();
+                if let Some(breez_services) = breez_services.and_then(|s| s.upgrade()) {
+                    breez_services.emit_event(BreezEvent::NewBlock { block: 2 });
+                }
+            }
+            self.events.lock().unwrap().push(e);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_emit_event_from_listener() -> Result<(), Box<dyn std::error::Error>> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init()?;
+        let services_ref = Arc::new(std::sync::Mutex::new(None));
+        let events = Arc::new(std::sync::Mutex::new(vec![]));
+        let breez_services = BreezServicesBuilder::new(config)
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(Arc::new(MockBreezServer {}))
+            .moonpay_api(Arc::new(MockBreezServer {}))
+            .persister(persister)
+            .node_api(Arc::new(MockNodeAPI::new(get_dummy_node_state())))
+            .build(Some(Box::new(ReentrantListener {
+                breez_services: services_ref.clone(),
+                events: events.clone(),
+            })))
+            .await?;
+        *services_ref.lock().unwrap() = Some(Arc::downgrade(&breez_services));
+
+        // The nested event is delivered once the listener returns, in order
+        breez_services
+            .notify_event_listeners(BreezEvent::NewBlock { block: 1 })
+            .await?;
+        let blocks: Vec<u32> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|e| match e {
+                BreezEvent::NewBlock { block } => Some(*block),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(blocks, vec![1, 2]);
+
+        let mut replayed = vec![];
+        breez_services.replay_events(|events| replayed = events);
+        assert_eq!(replayed.len(), 2);
+        Ok(())
+    }
+
+    async fn breez_services_with_listener(
+        config: Config,
+        events: Arc<std::sync::Mutex<Vec<BreezEvent>>>,
+    ) -> Result<Arc<BreezServices>> {
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init()?;
+
+        BreezServicesBuilder::new(config)
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(Arc::new(MockBreezServer {}))
+            .moonpay_api(Arc::new(MockBreezServer {}))
+            .persister(persister)
+            .node_api(Arc::new(MockNodeAPI::new(get_dummy_node_state())))
+            .chain_service(Arc::new(MockChainService::default()))
+            .build(Some(Box::new(RecordingEventListener { events })))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_incoming_payment() -> Result<(), Box<dyn std::error::Error>> {
+        let events = Arc::new(std::sync::Mutex::new(vec![]));
+        let breez_services =
+            breez_services_with_listener(create_test_config(), events.clone()).await?;
+
+        let details = InvoicePaidDetails {
+            payment_hash: "1111".to_string(),
+            bolt11: "1111".to_string(),
+        };
+        breez_services
+            .on_invoice_paid(None, details.clone())
+            .await?;
+        breez_services.on_invoice_paid(None, details).await?;
+
+        let events = events.lock().unwrap();
+        let paid = events
+            .iter()
+            .filter(|e| matches!(e, BreezEvent::InvoicePaid { .. }))
+            .count();
+        let duplicates: Vec<&BreezEvent> = events
+            .iter()
+            .filter(|e| matches!(e, BreezEvent::DuplicateIncomingPayment { .. }))
+            .collect();
+        assert_eq!(paid, 1);
+        assert_eq!(duplicates.len(), 1);
+        assert!(matches!(
+            duplicates[0],
+            BreezEvent::DuplicateIncomingPayment { details } if details.payment_hash == "1111"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_progress_events() -> Result<(), Box<dyn std::error::Error>> {
+        let events = Arc::new(std::sync::Mutex::new(vec![]));
+        let breez_services =
+            breez_services_with_listener(create_test_config(), events.clone()).await?;
+        events.lock().unwrap().clear();
+
+        breez_services.sync().await?;
+        let events = events.lock().unwrap();
+        let progress: Vec<(u32, u32)> = events
+            .iter()
+            .filter_map(|e| match e {
+                BreezEvent::SyncProgress { processed, total } => Some((*processed, *total)),
+                _ => None,
+            })
+            .collect();
+        let (_, total) = progress[0];
+        assert_eq!(progress[0], (0, total));
+        assert_eq!(progress.last(), Some(&(total, total)));
+        assert!(progress.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert!(matches!(events.last(), Some(BreezEvent::Synced)));
+
+        // Progress isn't kept for replay
+        breez_services.replay_events(|replayed| {
+            assert!(!replayed
+                .iter()
+                .any(|e| matches!(e, BreezEvent::SyncProgress { .. })))
+        });
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_payment_pending_event() -> Result<(), Box<dyn std::error::Error>> {
+        let recorded_events = Arc::new(std::sync::Mutex::new(vec![]));
+        let breez_services =
+            breez_services_with_listener(create_test_config(), recorded_events.clone()).await?;
+
+        let payment = breez_services
+            .send_spontaneous_payment("node-id".to_string(), 100, None, None)
+            .await?;
+
+        let events = recorded_events.lock().unwrap();
+        let pending_index = events
+            .iter()
+            .position(|e| {
+                matches!(e, BreezEvent::PaymentPending { details }
+                    if details.node_id == "node-id" && details.bolt11.is_none()
+                        && details.payment_hash.is_none() && details.amount_msat == 100_000)
+            })
+            .ok_or("No PaymentPending event")?;
+        let succeeded_index = events
+            .iter()
+            .position(
+                |e| matches!(e, BreezEvent::PaymentSucceed { details } if details.id == payment.id),
+            )
+            .ok_or("No PaymentSucceed event")?;
+        assert!(pending_index < succeeded_index);
+        drop(events);
+
+        // Invoice payments carry the payment hash, which the settled payment is stored under
+        let invoice = create_invoice("test".into(), 1000, vec![], None);
+        let payment = breez_services
+            .send_payment(invoice.bolt11.clone(), None, None, None)
+            .await?;
+        assert_eq!(payment.id, invoice.payment_hash);
+        assert!(recorded_events.lock().unwrap().iter().any(|e| {
+            matches!(e, BreezEvent::PaymentPending { details }
+                if details.payment_hash.as_deref() == Some(invoice.payment_hash.as_str()))
+        }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_payment_network_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        let events = Arc::new(std::sync::Mutex::new(vec![]));
+        let breez_services =
+            breez_services_with_listener(create_test_config(), events.clone()).await?;
+        let invoice = create_invoice_for_currency(
+            Currency::BitcoinTestnet,
+            "test".into(),
+            1000,
+            vec![],
+            None,
+        );
+
+        let err = breez_services
+            .send_payment(invoice.bolt11, None, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<InvoiceError>(),
+            Some(&InvoiceError::NetworkMismatch {
+                expected: Network::Bitcoin,
+                found: Network::Testnet,
+            })
+        );
+        assert!(!events
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|e| matches!(e, BreezEvent::PaymentPending { .. })));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_swap_in_amount() -> Result<(), Box<dyn std::error::Error>> {
+        let events = Arc::new(std::sync::Mutex::new(vec![]));
+        let breez_services = breez_services_with_listener(create_test_config(), events).await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        // Without a swap address there are no limits to check, and none is created
+        assert!(breez_services
+            .validate_amount(3_000_000, AmountContext::SwapIn)
+            .await
+            .is_err());
+        assert!(breez_services.persister.list_swaps()?.is_empty());
+
+        breez_services.persister.insert_swap(SwapInfo {
+            bitcoin_address: "swap-address".to_string(),
+            created_at: 0,
+            lock_height: 100,
+            payment_hash: vec![1],
+            preimage: vec![2],
+            private_key: vec![3],
+            public_key: vec![4],
+            swapper_public_key: vec![5],
+            script: vec![6],
+            bolt11: None,
+            paid_sats: 0,
+            unconfirmed_sats: 0,
+            confirmed_sats: 0,
+            status: SwapStatus::Initial,
+            refund_tx_ids: vec![],
+            unconfirmed_tx_ids: vec![],
+            confirmed_tx_ids: vec![],
+            min_allowed_deposit: 3_000,
+            max_allowed_deposit: 4_000_000,
+            last_redeem_error: None,
+            expiry_block: 0,
+            expires_at: 0,
+        })?;
+        assert!(breez_services
+            .validate_amount(3_000_000, AmountContext::SwapIn)
+            .await?
+            .is_empty());
+        assert_eq!(
+            breez_services
+                .validate_amount(2_999_000, AmountContext::SwapIn)
+                .await?,
+            vec![AmountViolation::BelowMinimum {
+                min_msat: 3_000_000
+            }]
+        );
+        assert_eq!(breez_services.persister.list_swaps()?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_expired_swaps() -> Result<(), Box<dyn std::error::Error>> {
+        let events = Arc::new(std::sync::Mutex::new(vec![]));
+        let breez_services =
+            breez_services_with_listener(create_test_config(), events.clone()).await?;
+        breez_services.persister.insert_swap(SwapInfo {
+            bitcoin_address: "swap-address".to_string(),
+            created_at: 0,
+            lock_height: 100,
+            payment_hash: vec![1],
+            preimage: vec![2],
+            private_key: vec![3],
+            public_key: vec![4],
+            swapper_public_key: vec![5],
+            script: vec![6],
+            bolt11: None,
+            paid_sats: 0,
+            unconfirmed_sats: 0,
+            confirmed_sats: 0,
+            status: SwapStatus::Initial,
+            refund_tx_ids: vec![],
+            unconfirmed_tx_ids: vec![],
+            confirmed_tx_ids: vec![],
+            min_allowed_deposit: 0,
+            max_allowed_deposit: 100,
+            last_redeem_error: None,
+            expiry_block: 5,
+            expires_at: 0,
+        })?;
+        breez_services.update_block_height(10);
+
+        breez_services.reconcile_expired_swaps().await?;
+        let swap = breez_services
+            .persister
+            .get_swap_info_by_address("swap-address".to_string())?
+            .unwrap();
+        assert_eq!(swap.status, SwapStatus::Expired);
+        assert!(events.lock().unwrap().iter().any(|e| matches!(
+            e,
+            BreezEvent::SwapsReconciled { expired } if expired == &vec![swap.clone()]
+        )));
+
+        // Nothing is left to reconcile, so no event is emitted again
+        events.lock().unwrap().clear();
+        breez_services.reconcile_expired_swaps().await?;
+        assert!(events.lock().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_payment_to_self() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let invoice = create_invoice("test".into(), 1000, vec![], None);
+        breez_services.persister.set_node_state(&NodeState {
+            id: invoice.payee_pubkey.clone(),
+            ..get_dummy_node_state()
+        })?;
+
+        let err = breez_services
+            .send_payment(invoice.bolt11, None, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<InvoiceError>(),
+            Some(&InvoiceError::SelfPaymentNotAllowed)
+        );
+        assert!(breez_services
+            .list_payments(PaymentTypeFilter::Sent, None, None, None, None, None)
+            .await?
+            .is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_spontaneous_payment_tlvs() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let tlv = |type_num| TlvEntry {
+            type_num,
+            value: b"episode".to_vec(),
+        };
+        breez_services
+            .send_spontaneous_payment("node-id".to_string(), 100, None, Some(vec![tlv(7629169)]))
+            .await?;
+
+        for invalid in [
+            vec![tlv(7629168)],
+            vec![tlv(CUSTOM_TLV_MIN_TYPE - 1)],
+            vec![tlv(7629169), tlv(7629169)],
+        ] {
+            assert!(breez_services
+                .send_spontaneous_payment("node-id".to_string(), 100, None, Some(invalid))
+                .await
+                .is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_payment_timeout_sec() {
+        assert_eq!(payment_timeout_sec(None, 60).unwrap(), 60);
+        assert_eq!(payment_timeout_sec(Some(300), 60).unwrap(), 300);
+        assert!(payment_timeout_sec(Some(0), 60).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_payment_amount() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        let amountless = create_invoice("test".into(), 0, vec![], None);
+        assert!(amountless.is_amountless());
+        let err = breez_services
+            .send_payment(amountless.bolt11, None, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&PaymentError::AmountRequired));
+
+        let invoice = create_invoice("test".into(), 5000, vec![], None);
+        assert!(!invoice.is_amountless());
+        let err = breez_services
+            .send_payment(invoice.bolt11.clone(), Some(6), None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref(),
+            Some(&PaymentError::AmountMismatch {
+                invoice_amount_msat: 5000,
+                amount_msat: 6000
+            })
+        );
+        assert!(breez_services
+            .list_payments(PaymentTypeFilter::Sent, None, None, None, None, None)
+            .await?
+            .is_empty());
+
+        // Repeating the invoice amount is allowed
+        breez_services
+            .send_payment(invoice.bolt11, Some(5), None, None)
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_limits() -> Result<(), Box<dyn std::error::Error>> {
+        let node_state = NodeState {
+            max_receivable_msat: 1_000_000,
+            max_single_payment_amount_msat: 500_000,
+            inbound_liquidity_msats: 10_000,
+            ..get_dummy_node_state()
+        };
+        let lsp_info = LspInformation {
+            channel_fee_permyriad: 1000,
+            channel_minimum_fee_msat: 20_000,
+            ..MockBreezServer {}
+                .list_lsps("".to_string())
+                .await?
+                .remove(0)
+        };
+        assert_eq!(
+            receive_limits(&node_state, &lsp_info),
+            ReceiveLimits {
+                min_receivable_msat: 1000,
+                max_receivable_msat: 500_000,
+                max_receivable_without_fee_msat: 10_000,
+                max_receivable_fee_msat: 20_000,
+            }
+        );
+
+        // Without inbound liquidity, every amount has to cover the channel opening fee
+        let node_state = NodeState {
+            inbound_liquidity_msats: 0,
+            ..node_state
+        };
+        let limits = receive_limits(&node_state, &lsp_info);
+        assert_eq!(limits.min_receivable_msat, 21_000);
+        assert_eq!(limits.max_receivable_without_fee_msat, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_amount_violations() -> Result<(), Box<dyn std::error::Error>> {
+        let node_state = NodeState {
+            max_payable_msat: 50_000,
+            max_receivable_msat: 1_000_000,
+            max_single_payment_amount_msat: 100_000,
+            inbound_liquidity_msats: 10_000,
+            ..get_dummy_node_state()
+        };
+
+        // Send
+        let violations = |amount_msat| {
+            amount_violations(amount_msat, AmountContext::Send, &node_state, None, None)
+        };
+        assert!(violations(50_000).is_empty());
+        assert_eq!(
+            violations(0),
+            vec![AmountViolation::BelowMinimum { min_msat: 1 }]
+        );
+        assert_eq!(
+            violations(60_000),
+            vec![AmountViolation::InsufficientBalance {
+                max_payable_msat: 50_000
+            }]
+        );
+        assert_eq!(
+            violations(200_000),
+            vec![
+                AmountViolation::AboveMaximum { max_msat: 100_000 },
+                AmountViolation::InsufficientBalance {
+                    max_payable_msat: 50_000
+                }
+            ]
+        );
+
+        // Receive
+        let lsp_info = MockBreezServer {}
+            .list_lsps("".to_string())
+            .await?
+            .remove(0);
+        let violations = |amount_msat| {
+            amount_violations(
+                amount_msat,
+                AmountContext::Receive,
+                &node_state,
+                Some(&lsp_info),
+                None,
+            )
+        };
+        assert!(violations(500).is_empty());
+        assert!(violations(100_000).is_empty());
+        assert_eq!(
+            violations(200_000),
+            vec![AmountViolation::AboveMaximum { max_msat: 100_000 }]
+        );
+        // Opening a channel requires the amount to cover the LSP fee
+        let lsp_info = LspInformation {
+            channel_minimum_fee_msat: 20_000,
+            ..lsp_info
+        };
+        assert_eq!(
+            amount_violations(
+                15_000,
+                AmountContext::Receive,
+                &node_state,
+                Some(&lsp_info),
+                None
+            ),
+            vec![AmountViolation::BelowMinimum { min_msat: 21_000 }]
+        );
+
+        // SwapIn
+        let swap_info = SwapInfo {
+            bitcoin_address: "swap-address".to_string(),
+            created_at: 0,
+            lock_height: 100,
+            payment_hash: vec![1],
+            preimage: vec![2],
+            private_key: vec![3],
+            public_key: vec![4],
+            swapper_public_key: vec![5],
+            script: vec![6],
+            bolt11: None,
+            paid_sats: 0,
+            unconfirmed_sats: 0,
+            confirmed_sats: 0,
+            status: SwapStatus::Initial,
+            refund_tx_ids: vec![],
+            unconfirmed_tx_ids: vec![],
+            confirmed_tx_ids: vec![],
+            min_allowed_deposit: 3_000,
+            max_allowed_deposit: 4_000_000,
+            last_redeem_error: None,
+            expiry_block: 0,
+            expires_at: 0,
+        };
+        let violations = |amount_msat| {
+            amount_violations(
+                amount_msat,
+                AmountContext::SwapIn,
+                &node_state,
+                None,
+                Some(&swap_info),
+            )
+        };
+        assert!(violations(3_000_000).is_empty());
+        assert!(violations(4_000_000_000).is_empty());
+        assert_eq!(
+            violations(2_999_000),
+            vec![AmountViolation::BelowMinimum {
+                min_msat: 3_000_000
+            }]
+        );
+        assert_eq!(
+            violations(4_000_001_000),
+            vec![AmountViolation::AboveMaximum {
+                max_msat: 4_000_000_000
+            }]
+        );
+        Ok(())
+    }
+
+    #[derive(Default)]
+    struct CountingFiatAPI {
+        fetches: AtomicUsize,
+    }
+
+    #[tonic::async_trait]
+    impl FiatAPI for CountingFiatAPI {
+        async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
+            Ok(vec![])
+        }
+
+        async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            MockBreezServer {}.fetch_fiat_rates().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preload_default_fiat_rate() -> Result<(), Box<dyn std::error::Error>> {
+        let config = create_test_config();
+        assert_eq!(config.default_fiat_currency, Some("USD".to_string()));
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init()?;
+        let fiat_api = Arc::new(CountingFiatAPI::default());
+        let breez_services = BreezServicesBuilder::new(config)
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(fiat_api.clone())
+            .moonpay_api(Arc::new(MockBreezServer {}))
+            .persister(persister)
+            .node_api(Arc::new(MockNodeAPI::new(get_dummy_node_state())))
+            .build(None)
+            .await?;
+
+        breez_services.preload_default_fiat_rate().await?;
+        assert_eq!(fiat_api.fetches.load(Ordering::SeqCst), 1);
+
+        // The default currency rate is now served from the cache
+        let rate = breez_services.fiat_rate("USD".to_string()).await?;
+        assert_eq!(rate.map(|r| r.value), Some(20_000.00));
+        assert_eq!(fiat_api.fetches.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_config_networks() {
+        let mainnet = BreezServices::default_config(EnvironmentType::Production, Network::Bitcoin);
+        assert_eq!(mainnet.network, Network::Bitcoin);
+        assert_eq!(mainnet.mempoolspace_url, "https://mempool.space");
+        assert!(mainnet.default_lsp_id.is_some());
+
+        let testnet = BreezServices::default_config(EnvironmentType::Production, Network::Testnet);
+        assert_eq!(testnet.network, Network::Testnet);
+        assert_eq!(testnet.mempoolspace_url, "https://mempool.space/testnet");
+        assert_eq!(testnet.default_lsp_id, None);
+
+        let signet = BreezServices::default_config(EnvironmentType::Staging, Network::Signet);
+        assert_eq!(signet.network, Network::Signet);
+        assert_eq!(signet.mempoolspace_url, "https://mempool.space/signet");
+        assert_eq!(signet.default_lsp_id, None);
+
+        let regtest = BreezServices::default_config(EnvironmentType::Staging, Network::Regtest);
+        assert_eq!(regtest.network, Network::Regtest);
+        assert_eq!(regtest.mempoolspace_url, "http://localhost:8999");
+        assert_eq!(regtest.default_lsp_id, None);
+
+        // The environment specific defaults are kept
+        assert_eq!(regtest.breezserver, Config::staging().breezserver);
+    }
+
+    #[test]
+    fn test_working_dir_guard() -> Result<()> {
+        let working_dir = get_test_working_dir();
+        let guard = WorkingDirGuard::acquire(&working_dir)?;
+
+        // A second instance can't use the same working dir, even through another path
+        let err = WorkingDirGuard::acquire(&format!("{working_dir}/."))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.downcast_ref(),
+            Some(&InitServicesError::AlreadyInitialized {
+                working_dir: format!("{working_dir}/.")
+            })
+        );
+        let _other = WorkingDirGuard::acquire(&get_test_working_dir())?;
+
+        // Once released, the working dir can be used again
+        drop(guard);
+        WorkingDirGuard::acquire(&working_dir)?;
+
+        // A working dir that doesn't exist yet is locked under the same path it has once created
+        let new_working_dir = format!("{}/new", get_test_working_dir());
+        let _guard = WorkingDirGuard::acquire(&new_working_dir)?;
+        assert!(std::path::Path::new(&new_working_dir).is_dir());
+        assert!(WorkingDirGuard::acquire(&format!("{new_working_dir}/.")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_services_after_stop() -> Result<(), Box<dyn std::error::Error>> {
+        let rt = Runtime::new()?;
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init()?;
+        let mut builder = BreezServicesBuilder::new(config.clone());
+        builder
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(Arc::new(MockBreezServer {}))
+            .moonpay_api(Arc::new(MockBreezServer {}))
+            .persister(persister)
+            .chain_service(Arc::new(MockChainService::default()))
+            .node_api(Arc::new(MockNodeAPI::new(get_dummy_node_state())));
+        let init = || {
+            BreezServices::init_with_builder(
+                &builder,
+                Box::new(RecordingEventListener {
+                    events: Arc::new(std::sync::Mutex::new(vec![])),
+                }),
+            )
+        };
+
+        rt.block_on(async {
+            let breez_services = init().await?;
+            BreezServices::start(&rt, &breez_services).await?;
+
+            // The working dir is held while the instance runs
+            let err = init().await.err().unwrap();
+            assert_eq!(
+                err.downcast_ref(),
+                Some(&InitServicesError::AlreadyInitialized {
+                    working_dir: config.working_dir.clone()
+                })
+            );
+
+            // Stopping waits for the background threads, then releases it
+            breez_services.stop().await?;
+            assert_eq!(
+                breez_services.connection_status(),
+                ConnectionStatus::Disconnected
+            );
+            let restarted = init().await?;
+
+            // It is released too when stopping an instance that was never started
+            assert!(restarted.stop().await.is_err());
+            init().await?;
+            Ok(())
+        })
+    }
+
+    #[tokio::test]
+    async fn test_select_fiat_currency() -> Result<(), Box<dyn std::error::Error>> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init()?;
+        let fiat_api = Arc::new(CountingFiatAPI::default());
+        let breez_services = BreezServicesBuilder::new(config)
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(fiat_api.clone())
+            .moonpay_api(Arc::new(MockBreezServer {}))
+            .persister(persister.clone())
+            .node_api(Arc::new(MockNodeAPI::new(get_dummy_node_state())))
+            .build(None)
+            .await?;
+
+        // Unknown currencies aren't selected
+        assert!(breez_services
+            .select_fiat_currency("XYZ".to_string())
+            .await
+            .is_err());
+        assert_eq!(fiat_api.fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(persister.get_preferred_fiat_currency()?, None);
+
+        // Selecting a currency refreshes the rates even though the cache is fresh
+        let rate = breez_services
+            .select_fiat_currency("USD".to_string())
+            .await?;
+        assert_eq!(rate.value, 20_000.00);
+        assert_eq!(fiat_api.fetches.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            breez_services.preferred_fiat_currency()?,
+            Some("USD".to_string())
+        );
+        assert_eq!(
+            persister.get_preferred_fiat_currency()?,
+            Some("USD".to_string())
+        );
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let recorded = breez_services.fiat_rate_at("USD".to_string(), now).await?;
+        assert_eq!(recorded.map(|r| r.value), Some(20_000.00));
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_connect_lsp() -> Result<(), Box<dyn std::error::Error>> {
+        let rt = Runtime::new()?;
+        for auto_connect_lsp in [true, false] {
+            let mut config = create_test_config();
+            config.auto_connect_lsp = auto_connect_lsp;
+            let events = Arc::new(std::sync::Mutex::new(vec![]));
+            rt.block_on(async {
+                let breez_services = breez_services_with_listener(config, events.clone()).await?;
+                breez_services
+                    .persister
+                    .set_lsp_id(MockBreezServer {}.lsp_id())?;
+                breez_services
+                    .persister
+                    .set_node_state(&get_dummy_node_state())?;
+
+                BreezServices::start(&rt, &breez_services).await?;
+                breez_services.stop().await
+            })?;
+
+            let connected = events
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|e| matches!(e, BreezEvent::LspConnected { lsp_id } if lsp_id == "1"));
+            assert_eq!(connected, auto_connect_lsp);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_connectivity_check() -> Result<(), Box<dyn std::error::Error>> {
+        let persister = Arc::new(create_test_persister(create_test_config()));
+        persister.init()?;
+        let breez_services =
+            breez_services_with_chain_service(persister, Arc::new(UnreachableChainService {}))
+                .await?;
+
+        let statuses = breez_services.connectivity_check().await;
+        let services: Vec<_> = statuses.iter().map(|s| s.service).collect();
+        assert_eq!(
+            services,
+            vec![
+                ServiceType::Greenlight,
+                ServiceType::Lsp,
+                ServiceType::Mempool,
+                ServiceType::Fiat,
+                ServiceType::MoonPay
+            ]
+        );
+        for status in statuses {
+            if status.service == ServiceType::Mempool {
+                assert!(!status.reachable);
+                assert_eq!(status.error, Some("Unreachable".to_string()));
+            } else {
+                assert!(status.reachable);
+                assert_eq!(status.error, None);
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_connection_status() -> Result<()> {
+        let breez_services = breez_services().await?;
+        assert_eq!(
+            breez_services.connection_status(),
+            ConnectionStatus::Disconnected
+        );
+
+        // The streams of the mock node can't be opened
+        let mut connected = true;
+        assert!(poll_events(breez_services.clone(), 0, &mut connected)
+            .await
+            .is_err());
+        assert_ne!(
+            breez_services.connection_status(),
+            ConnectionStatus::Connected
+        );
+
+        breez_services.set_connection_status(ConnectionStatus::Connected);
+        assert_eq!(
+            breez_services.connection_status(),
+            ConnectionStatus::Connected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconnect_backoff() {
+        let mut backoff = ReconnectBackoff::new(Duration::from_secs(10));
+        let delays: Vec<u64> = (0..6).map(|_| backoff.next_delay().as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 10, 10]);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
+
+        // A maximum below the initial delay caps it as well
+        let mut backoff = ReconnectBackoff::new(Duration::from_millis(500));
+        assert_eq!(backoff.next_delay(), Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_check_clock_skew() -> Result<(), Box<dyn std::error::Error>> {
+        let persister = Arc::new(create_test_persister(create_test_config()));
+        persister.init()?;
+        let in_sync = MockChainService::default();
+        let breez_services =
+            breez_services_with_chain_service(persister, Arc::new(in_sync)).await?;
+        assert_eq!(breez_services.check_clock_skew().await?, None);
+
+        // The device clock is an hour behind
+        let skewed = Arc::new(MockChainService {
+            server_time_offset: 3600,
+            ..Default::default()
+        });
+        let persister = Arc::new(create_test_persister(create_test_config()));
+        persister.init()?;
+        let breez_services = breez_services_with_chain_service(persister, skewed.clone()).await?;
+        let warning = breez_services.check_clock_skew().await?.unwrap();
+        assert!((3599..=3601).contains(&warning.offset_secs));
+
+        // The offset is only used by expiry checks when enabled
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        assert!(breez_services.now_secs()? - now <= 1);
+
+        let mut config = create_test_config();
+        config.correct_clock_skew = true;
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init()?;
+        let breez_services = BreezServicesBuilder::new(config)
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(Arc::new(MockBreezServer {}))
+            .moonpay_api(Arc::new(MockBreezServer {}))
+            .chain_service(skewed)
+            .persister(persister)
+            .node_api(Arc::new(MockNodeAPI::new(get_dummy_node_state())))
+            .build(None)
+            .await?;
+        breez_services.check_clock_skew().await?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        assert!((3599..=3602).contains(&(breez_services.now_secs()? - now)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_block_height() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let waiting = tokio::spawn({
+            let breez_services = breez_services.clone();
+            async move { breez_services.wait_for_block_height(5, 10).await }
+        });
+
+        for block in 2..=6 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            breez_services
+                .notify_event_listeners(BreezEvent::NewBlock { block })
+                .await?;
+        }
+        assert_eq!(waiting.await??, 5);
+
+        // An already reached height returns right away, a later one times out
+        assert_eq!(breez_services.wait_for_block_height(3, 0).await?, 6);
+        assert!(breez_services.wait_for_block_height(7, 1).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_full_resync() -> Result<(), Box<dyn std::error::Error>> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let invoice = create_invoice("resync".to_string(), 1000, vec![], None);
+        let node_payment = node_api.add_dummy_payment_for(invoice.bolt11, None).await?;
+        let stale_payment = Payment {
+            id: "stale".to_string(),
+            ..node_payment.clone()
+        };
+        let breez_services = breez_services_with(Some(node_api), vec![stale_payment]).await?;
+        breez_services.persister.insert_swap(SwapInfo {
+            bitcoin_address: "swap-address".to_string(),
+            created_at: 0,
+            lock_height: 100,
+            payment_hash: vec![1],
+            preimage: vec![2],
+            private_key: vec![3],
+            public_key: vec![4],
+            swapper_public_key: vec![5],
+            script: vec![6],
+            bolt11: None,
+            paid_sats: 0,
+            unconfirmed_sats: 0,
+            confirmed_sats: 0,
+            status: SwapStatus::Initial,
+            refund_tx_ids: vec![],
+            unconfirmed_tx_ids: vec![],
+            confirmed_tx_ids: vec![],
+            min_allowed_deposit: 0,
+            max_allowed_deposit: 100,
+            last_redeem_error: None,
+            expiry_block: 0,
+            expires_at: 0,
+        })?;
+
+        // An incremental sync keeps the payment the node doesn't know about
+        breez_services.sync().await?;
+        let list_payment_ids = || async {
+            let payments = breez_services
+                .list_payments(PaymentTypeFilter::All, None, None, None, None, None)
+                .await?;
+            Ok::<_, anyhow::Error>(payments.into_iter().map(|p| p.id).collect::<Vec<_>>())
+        };
+        assert_eq!(list_payment_ids().await?.len(), 2);
+
+        breez_services.full_resync().await?;
+        assert_eq!(list_payment_ids().await?, vec![node_payment.id]);
+        let swap = breez_services
+            .persister
+            .get_swap_info_by_address("swap-address".to_string())?
+            .unwrap();
+        assert_eq!(swap.private_key, vec![3]);
+        assert_eq!(swap.preimage, vec![2]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_channels() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let channel = Channel {
+            funding_txid: "funding".to_string(),
+            short_channel_id: "1x2x3".to_string(),
+            state: ChannelState::Opened,
+            spendable_msat: 100,
+            receivable_msat: 900,
+            closed_at: None,
+        };
+        breez_services
+            .persister
+            .update_channels(&[channel.clone()])?;
+
+        // The mock node reports no channels, so the persisted ones are listed as they are
+        let channels = breez_services.list_channels().await?;
+        assert_eq!(
+            channels,
+            vec![ChannelInfo {
+                short_channel_id: "1x2x3".to_string(),
+                funding_txid: "funding".to_string(),
+                state: ChannelState::Opened,
+                capacity_msat: None,
+                local_balance_msat: 100,
+                remote_balance_msat: 900,
+                closed_at: None,
+            }]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_settings() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let key = "myapp.last_currency".to_string();
+        assert_eq!(breez_services.get_setting(key.clone())?, None);
+
+        breez_services.set_setting(key.clone(), "USD".to_string())?;
+        assert_eq!(
+            breez_services.get_setting(key.clone())?,
+            Some("USD".to_string())
+        );
+        breez_services.set_setting(key.clone(), "EUR".to_string())?;
+        assert_eq!(breez_services.get_setting(key)?, Some("EUR".to_string()));
+
+        // A key also used by the SDK doesn't touch the SDK setting
+        breez_services.persister.set_lsp_id("lsp-id".to_string())?;
+        breez_services.set_setting("lsp".to_string(), "app-value".to_string())?;
+        assert_eq!(
+            breez_services.persister.get_lsp_id()?,
+            Some("lsp-id".to_string())
+        );
+        assert_eq!(
+            breez_services.get_setting("lsp".to_string())?,
+            Some("app-value".to_string())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_payment_by_hash() -> Result<(), Box<dyn std::error::Error>> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let invoice = create_invoice("lookup".to_string(), 1000, vec![], None);
+        let payment = node_api.add_dummy_payment_for(invoice.bolt11, None).await?;
+        let breez_services = breez_services_with(Some(node_api), vec![]).await?;
+        breez_services.sync().await?;
+
+        let found = breez_services
+            .payment_by_hash(invoice.payment_hash)
+            .await?
+            .unwrap();
+        assert_eq!(found.id, payment.id);
+        assert_eq!(found.description, Some("lookup".to_string()));
+        assert!(breez_services
+            .payment_by_hash("unknown".to_string())
+            .await?
+            .is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_payment_route() -> Result<(), Box<dyn std::error::Error>> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let routed = create_invoice("routed".to_string(), 1000, vec![], None);
+        let routed_payment = node_api.add_dummy_payment_for(routed.bolt11, None).await?;
+        let unrouted = create_invoice("unrouted".to_string(), 1000, vec![], None);
+        let unrouted_payment = node_api
+            .add_dummy_payment_for(unrouted.bolt11, None)
+            .await?;
+        let route = vec![
+            PaymentRouteHop {
+                node_id: "hop1".to_string(),
+                short_channel_id: "1x2x3".to_string(),
+                fee_msat: 10,
+            },
+            PaymentRouteHop {
+                node_id: "hop2".to_string(),
+                short_channel_id: "4x5x6".to_string(),
+                fee_msat: 0,
+            },
+        ];
+        node_api
+            .set_payment_route(routed_payment.id.clone(), route.clone())
+            .await;
+        let breez_services = breez_services_with(Some(node_api), vec![]).await?;
+        breez_services.sync().await?;
+
+        let route_of = |id: &str| {
+            let payment = breez_services
+                .persister
+                .get_payment_by_hash(&id.to_string())?
+                .unwrap();
+            match payment.details {
+                PaymentDetails::Ln { data } => Ok::<_, anyhow::Error>(data.route),
+                _ => Err(anyhow!("Not a lightning payment")),
+            }
+        };
+        assert_eq!(route_of(&routed_payment.id)?, route);
+        assert!(route_of(&unrouted_payment.id)?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let node_state = get_dummy_node_state();
+        breez_services.persister.set_node_state(&node_state)?;
+        breez_services
+            .persister
+            .set_lsp_id(MockBreezServer {}.lsp_id())?;
+        breez_services.persister.insert_swap(SwapInfo {
+            bitcoin_address: "swap-address".to_string(),
+            created_at: 0,
+            lock_height: 100,
+            payment_hash: vec![1],
+            preimage: vec![2],
+            private_key: vec![3],
+            public_key: vec![4],
+            swapper_public_key: vec![5],
+            script: vec![6],
+            bolt11: None,
+            paid_sats: 0,
+            unconfirmed_sats: 0,
+            confirmed_sats: 0,
+            status: SwapStatus::Initial,
+            refund_tx_ids: vec![],
+            unconfirmed_tx_ids: vec![],
+            confirmed_tx_ids: vec![],
+            min_allowed_deposit: 0,
+            max_allowed_deposit: 100,
+            last_redeem_error: None,
+            expiry_block: 0,
+            expires_at: 0,
+        })?;
+        breez_services
+            .persister
+            .update_swap_redeem_error("swap-address".to_string(), "no route".to_string())?;
+
+        let diagnostics = breez_services.diagnostics().await?;
+        assert_eq!(diagnostics.node_id, Some(node_state.id));
+        assert_eq!(diagnostics.lsp_id, Some(MockBreezServer {}.lsp_id()));
+        assert_eq!(
+            diagnostics.channels_balance_msat,
+            node_state.channels_balance_msat
+        );
+        assert_eq!(diagnostics.swaps.total, 1);
+        assert_eq!(diagnostics.swaps.unused, 1);
+        assert_eq!(diagnostics.last_errors, vec!["swap swap-address: no route"]);
+        assert!(!diagnostics.background_tasks_running);
+
+        let serialized = serde_json::to_string(&diagnostics)?;
+        for secret in ["preimage", "private_key", "api_key", "script"] {
+            assert!(!serialized.contains(secret));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_lsps() -> Result<(), Box<dyn std::error::Error>> {
+        let storage_path = format!("{}/storage.sql", get_test_working_dir());
+        std::fs::remove_file(storage_path).ok();
+
+        let breez_services = breez_services().await?;
+        breez_services.sync().await?;
+
+        let node_pubkey = breez_services
+            .node_info()?
+            .ok_or("No NodeState found")
+            .map_err(|err| anyhow!(err))?
+            .id;
+        let lsps = breez_services.lsp_api.list_lsps(node_pubkey).await?;
+        assert_eq!(lsps.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_rates() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        breez_services.sync().await?;
+
+        let rates = breez_services.fiat_api.fetch_fiat_rates().await?;
+        assert_eq!(rates.len(), 1);
+        assert_eq!(
+            rates[0],
+            Rate {
+                coin: "USD".to_string(),
+                value: 20_000.00
+            }
+        );
+
+        breez_services.fetch_fiat_rates().await?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let historical_rate = breez_services.fiat_rate_at("USD".to_string(), now).await?;
+        assert_eq!(historical_rate, Some(rates[0].clone()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_buy_bitcoin_with_moonpay() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        breez_services.sync().await?;
+
+        let moonpay_url = breez_services
+            .buy_bitcoin(BuyBitcoinProvider::MoonPay, Some("EUR".to_string()))
+            .await?;
+        let parsed = Url::parse(&moonpay_url)?;
+        let query_pairs = parsed.query_pairs().into_owned().collect::<HashMap<_, _>>();
+
+        assert_eq!(parsed.host_str(), Some("mock.moonpay"));
+        assert_eq!(parsed.path(), "/");
+
+        let wallet_address = input_parser::parse(query_pairs.get("wa").unwrap()).await?;
+        assert!(matches!(wallet_address, InputType::BitcoinAddress { .. }));
+
+        let max_amount = query_pairs.get("ma").unwrap();
+        assert!(Regex::new(r"^\d+\.\d{8}$").unwrap().is_match(max_amount));
+
+        assert_eq!(query_pairs.get("bc"), Some(&"EUR".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sweep() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+        let to_address = "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".to_string();
+
+        // The mock node always builds the same transaction for the same destination. Its input
+        // isn't a known UTXO, so the fee falls back to the estimate.
+        let broadcast = MockNodeAPI::new(get_dummy_node_state())
+            .sweep(to_address.clone(), SatPerVbyte::new(2)?, vec![])
+            .await?;
+        let broadcast_tx: Transaction = deserialize(&broadcast.tx)?;
+
+        let result = breez_services.sweep(to_address.clone(), 2, None).await?;
+        assert_eq!(result.txid, broadcast_tx.txid().to_string());
+        assert_eq!(result.amount_sat, broadcast_tx.output[0].value);
+        assert_eq!(result.fee_sat, broadcast_tx.vsize() as u64 * 2);
+        assert_eq!(result.destination, to_address);
+
+        assert_eq!(breez_services.list_sweeps().await?, vec![result]);
+
+        // The mock node reports no UTXOs, so there is nothing left to sweep
+        assert!(breez_services
+            .prepare_sweep(to_address.clone(), 2, None)
+            .await
+            .is_err());
+
+        // Zero or absurdly high fee rates are rejected before reaching the node
+        assert!(breez_services
+            .sweep(to_address.clone(), 0, None)
+            .await
+            .is_err());
+        assert!(breez_services
+            .sweep(to_address.clone(), MAX_SAT_PER_VBYTE + 1, None)
+            .await
+            .is_err());
+        // Addresses of another network are rejected too
+        let err = breez_services
+            .sweep(
+                "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string(),
+                2,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AddressError>(),
+            Some(AddressError::NetworkMismatch {
+                expected: Network::Bitcoin,
+                found: Network::Testnet
+            })
+        ));
+        assert_eq!(breez_services.list_sweeps().await?.len(), 1);
+
+        // Only UTXOs known to the node and not reserved can be selected
+        let utxo = |txid: u8, reserved: bool| UnspentTransactionOutput {
+            txid: vec![txid; 32],
+            outnum: 1,
+            amount_millisatoshi: 10_000_000,
+            address: to_address.clone(),
+            reserved,
+            reserved_to_block: 0,
+            confirmed: true,
+        };
+        let (free, reserved) = (utxo(1, false), utxo(2, true));
+        breez_services.persister.set_node_state(&NodeState {
+            utxos: vec![free.clone(), reserved.clone()],
+            ..get_dummy_node_state()
+        })?;
+        let unknown = format!("{}:0", free.outpoint().split(':').next().unwrap());
+        let err = breez_services
+            .sweep(
+                to_address.clone(),
+                2,
+                Some(vec![free.outpoint(), unknown.clone()]),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), format!("Unknown UTXOs: {unknown}"));
+        let err = breez_services
+            .sweep(to_address.clone(), 2, Some(vec![reserved.outpoint()]))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains(&reserved.outpoint()));
+        let preview = breez_services
+            .prepare_sweep(to_address.clone(), 2, Some(vec![free.outpoint()]))
+            .await?;
+        assert_eq!(preview.amount_sat + preview.fee_sat, 10_000);
+        // The fee of a sweep of known UTXOs is what the transaction actually pays
+        let result = breez_services
+            .sweep(to_address, 2, Some(vec![free.outpoint()]))
+            .await?;
+        assert_eq!(result.amount_sat, 10_000 - MOCK_SWEEP_FEE_SAT);
+        assert_eq!(result.fee_sat, MOCK_SWEEP_FEE_SAT);
+        assert_eq!(breez_services.list_sweeps().await?.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_sweep() -> Result<()> {
+        let utxo = |amount_sat: u64| UnspentTransactionOutput {
+            txid: vec![],
+            outnum: 0,
+            amount_millisatoshi: amount_sat * 1000,
+            address: "".to_string(),
+            reserved: false,
+            reserved_to_block: 0,
+            confirmed: true,
+        };
+        let fee_rate = SatPerVbyte::new(2)?;
+
+        // One P2WPKH input, one P2WPKH output: 11 + 68 + 9 + 22 vbytes
+        let preview = estimate_sweep(&[utxo(10_000)], 22, &fee_rate)?;
+        assert_eq!(
+            preview,
+            SweepPreview {
+                tx_vsize: 110,
+                fee_sat: 220,
+                amount_sat: 9780,
+            }
+        );
+
+        // Each extra input adds to the size, and its amount to the output
+        let preview = estimate_sweep(&[utxo(10_000), utxo(5000)], 22, &fee_rate)?;
+        assert_eq!(preview.tx_vsize, 178);
+        assert_eq!(preview.amount_sat, 15_000 - 356);
+
+        // Nothing to sweep, or not enough to cover the fee
+        assert!(estimate_sweep(&[], 22, &fee_rate).is_err());
+        assert!(estimate_sweep(&[utxo(200)], 22, &fee_rate).is_err());
+        Ok(())
+    }
+
+    struct UnreachableChainService {}
+
+    #[tonic::async_trait]
+    impl ChainService for UnreachableChainService {
+        async fn recommended_fees(&self) -> Result<RecommendedFees> {
+            Err(anyhow!("Unreachable"))
+        }
+
+        async fn address_transactions(&self, _address: String) -> Result<Vec<OnchainTx>> {
+            Err(anyhow!("Unreachable"))
+        }
+
+        async fn current_tip(&self) -> Result<u32> {
+            Err(anyhow!("Unreachable"))
+        }
+
+        async fn broadcast_transaction(&self, _tx: Vec<u8>) -> Result<String> {
+            Err(anyhow!("Unreachable"))
+        }
+
+        async fn server_time(&self) -> Result<i64> {
+            Err(anyhow!("Unreachable"))
+        }
+
+        async fn transaction_confirmations(&self, _txid: String) -> Result<u32> {
+            Err(anyhow!("Unreachable"))
+        }
+    }
+
+    async fn breez_services_with_chain_service(
+        persister: Arc<SqliteStorage>,
+        chain_service: Arc<dyn ChainService>,
+    ) -> Result<Arc<BreezServices>> {
+        BreezServicesBuilder::new(create_test_config())
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .fiat_api(Arc::new(MockBreezServer {}))
+            .moonpay_api(Arc::new(MockBreezServer {}))
+            .chain_service(chain_service)
+            .persister(persister)
+            .node_api(Arc::new(MockNodeAPI::new(get_dummy_node_state())))
+            .build(None)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_resolve_fee_rate() -> Result<()> {
+        let persister = Arc::new(create_test_persister(create_test_config()));
+        persister.init()?;
+        let chain_service = MockChainService {
+            recommended_fees: serde_json::from_str(
+                r#"{"fastestFee":50,"halfHourFee":40,"hourFee":30,"economyFee":20,"minimumFee":10}"#,
+            )?,
+            ..Default::default()
+        };
+        let breez_services =
+            breez_services_with_chain_service(persister.clone(), Arc::new(chain_service)).await?;
+
+        // The half hour tier is used until another one is preferred
+        assert_eq!(breez_services.preferred_fee_tier()?, FeeTier::HalfHour);
+        assert_eq!(breez_services.resolve_fee_rate().await?, 40);
+        breez_services.set_preferred_fee_tier(FeeTier::Economy)?;
+        assert_eq!(breez_services.resolve_fee_rate().await?, 20);
+
+        // When the fees can't be fetched, the last fetched ones are used
+        let offline_services =
+            breez_services_with_chain_service(persister, Arc::new(UnreachableChainService {}))
+                .await?;
+        assert_eq!(offline_services.preferred_fee_tier()?, FeeTier::Economy);
+        assert_eq!(offline_services.resolve_fee_rate().await?, 20);
+        offline_services.set_preferred_fee_tier(FeeTier::Custom { sat_per_vbyte: 7 })?;
+        assert_eq!(offline_services.resolve_fee_rate().await?, 7);
+
+        // Without cached fees, the fetch error is returned
+        let persister = Arc::new(create_test_persister(create_test_config()));
+        persister.init()?;
+        let offline_services =
+            breez_services_with_chain_service(persister, Arc::new(UnreachableChainService {}))
+                .await?;
+        assert!(offline_services.resolve_fee_rate().await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lnurl_auth_history() -> Result<()> {
+        let breez_services = breez_services().await?;
+        assert!(breez_services.list_lnurl_auth_logins().await?.is_empty());
+
+        // The mock node can't derive the linking keys, so the attempt fails but is still recorded
+        let req_data = LnUrlAuthRequestData {
+            k1: "1a".repeat(32),
+            action: Some("login".to_string()),
+            domain: "localhost".to_string(),
+            url: "https://localhost/lnurl-login?tag=login&k1=".to_string() + &"1a".repeat(32),
+        };
+        assert!(breez_services.lnurl_auth(req_data.clone()).await.is_err());
+
+        let logins = breez_services.list_lnurl_auth_logins().await?;
+        assert_eq!(logins.len(), 1);
+        assert_eq!(logins[0].domain, req_data.domain);
+        assert_eq!(logins[0].k1, req_data.k1);
+        assert!(!logins[0].success);
 
         Ok(())
     }
@@ -1411,7 +5019,7 @@ pub(crate) mod tests {
             utxos: vec![],
             max_payable_msat: 95,
             max_receivable_msat: 1000,
-            max_single_payment_amount_msat: 1000,
+            max_single_payment_amount_msat: 4_294_967_000,
             max_chan_reserve_msats: 0,
             connected_peers: vec!["1111".to_string()],
             inbound_liquidity_msats: 2000,
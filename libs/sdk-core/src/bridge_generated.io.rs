@@ -51,8 +51,8 @@ pub extern "C" fn wire_start_node(port_: i64) {
 }
 
 #[no_mangle]
-pub extern "C" fn wire_breez_events_stream(port_: i64) {
-    wire_breez_events_stream_impl(port_)
+pub extern "C" fn wire_breez_events_stream(port_: i64, replay: bool) {
+    wire_breez_events_stream_impl(port_, replay)
 }
 
 #[no_mangle]
@@ -70,8 +70,10 @@ pub extern "C" fn wire_send_payment(
     port_: i64,
     bolt11: *mut wire_uint_8_list,
     amount_sats: *mut u64,
+    max_fee_sat: *mut u64,
+    timeout_sec: *mut u32,
 ) {
-    wire_send_payment_impl(port_, bolt11, amount_sats)
+    wire_send_payment_impl(port_, bolt11, amount_sats, max_fee_sat, timeout_sec)
 }
 
 #[no_mangle]
@@ -79,8 +81,10 @@ pub extern "C" fn wire_send_spontaneous_payment(
     port_: i64,
     node_id: *mut wire_uint_8_list,
     amount_sats: u64,
+    timeout_sec: *mut u32,
+    extra_tlvs: *mut wire_list_tlv_entry,
 ) {
-    wire_send_spontaneous_payment_impl(port_, node_id, amount_sats)
+    wire_send_spontaneous_payment_impl(port_, node_id, amount_sats, timeout_sec, extra_tlvs)
 }
 
 #[no_mangle]
@@ -88,8 +92,84 @@ pub extern "C" fn wire_receive_payment(
     port_: i64,
     amount_sats: u64,
     description: *mut wire_uint_8_list,
+    expiry_secs: *mut u32,
+    preimage: *mut wire_uint_8_list,
+    include_lsp_hint: *mut bool,
+    fallback_address: *mut wire_uint_8_list,
 ) {
-    wire_receive_payment_impl(port_, amount_sats, description)
+    wire_receive_payment_impl(
+        port_,
+        amount_sats,
+        description,
+        expiry_secs,
+        preimage,
+        include_lsp_hint,
+        fallback_address,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_payment_with_template(
+    port_: i64,
+    amount_sats: u64,
+    description_template: *mut wire_uint_8_list,
+    values: *mut wire_list_template_value,
+) {
+    wire_receive_payment_with_template_impl(port_, amount_sats, description_template, values)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_payment_with_description_hash(
+    port_: i64,
+    amount_sats: u64,
+    description: *mut wire_uint_8_list,
+    description_hash: *mut wire_uint_8_list,
+) {
+    wire_receive_payment_with_description_hash_impl(
+        port_,
+        amount_sats,
+        description,
+        description_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_payment_split(
+    port_: i64,
+    amount_sats: u64,
+    description: *mut wire_uint_8_list,
+) {
+    wire_receive_payment_split_impl(port_, amount_sats, description)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_donation(port_: i64, description: *mut wire_uint_8_list) {
+    wire_receive_donation_impl(port_, description)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_validate_amount(port_: i64, amount_msat: u64, context: i32) {
+    wire_validate_amount_impl(port_, amount_msat, context)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_cost_estimate(port_: i64, amount_msat: u64) {
+    wire_receive_cost_estimate_impl(port_, amount_msat)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_payment_limits(port_: i64) {
+    wire_receive_payment_limits_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_peers(port_: i64) {
+    wire_list_peers_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_channels(port_: i64) {
+    wire_list_channels_impl(port_)
 }
 
 #[no_mangle]
@@ -97,14 +177,35 @@ pub extern "C" fn wire_node_info(port_: i64) {
     wire_node_info_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_connection_status(port_: i64) {
+    wire_connection_status_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_payment_by_hash(port_: i64, payment_hash: *mut wire_uint_8_list) {
+    wire_payment_by_hash_impl(port_, payment_hash)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_list_payments(
     port_: i64,
     filter: i32,
     from_timestamp: *mut i64,
     to_timestamp: *mut i64,
+    min_amount_msat: *mut u64,
+    max_amount_msat: *mut u64,
+    description_contains: *mut wire_uint_8_list,
 ) {
-    wire_list_payments_impl(port_, filter, from_timestamp, to_timestamp)
+    wire_list_payments_impl(
+        port_,
+        filter,
+        from_timestamp,
+        to_timestamp,
+        min_amount_msat,
+        max_amount_msat,
+        description_contains,
+    )
 }
 
 #[no_mangle]
@@ -122,6 +223,11 @@ pub extern "C" fn wire_fetch_lsp_info(port_: i64, id: *mut wire_uint_8_list) {
     wire_fetch_lsp_info_impl(port_, id)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_lsp_liquidity_available(port_: i64, amount_msat: u64) {
+    wire_lsp_liquidity_available_impl(port_, amount_msat)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_lsp_id(port_: i64) {
     wire_lsp_id_impl(port_)
@@ -132,11 +238,36 @@ pub extern "C" fn wire_fetch_fiat_rates(port_: i64) {
     wire_fetch_fiat_rates_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_fiat_rate(port_: i64, coin: *mut wire_uint_8_list) {
+    wire_fiat_rate_impl(port_, coin)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_fiat_rate_at(port_: i64, coin: *mut wire_uint_8_list, timestamp: i64) {
+    wire_fiat_rate_at_impl(port_, coin, timestamp)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_preferred_fiat_currency(port_: i64) {
+    wire_preferred_fiat_currency_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_select_fiat_currency(port_: i64, id: *mut wire_uint_8_list) {
+    wire_select_fiat_currency_impl(port_, id)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_list_fiat_currencies(port_: i64) {
     wire_list_fiat_currencies_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_list_fiat_currency_symbols(port_: i64, locale: *mut wire_uint_8_list) {
+    wire_list_fiat_currency_symbols_impl(port_, locale)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_close_lsp_channels(port_: i64) {
     wire_close_lsp_channels_impl(port_)
@@ -146,9 +277,25 @@ pub extern "C" fn wire_close_lsp_channels(port_: i64) {
 pub extern "C" fn wire_sweep(
     port_: i64,
     to_address: *mut wire_uint_8_list,
-    fee_rate_sats_per_byte: u64,
+    sat_per_vbyte: u32,
+    utxos: *mut wire_StringList,
 ) {
-    wire_sweep_impl(port_, to_address, fee_rate_sats_per_byte)
+    wire_sweep_impl(port_, to_address, sat_per_vbyte, utxos)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_prepare_sweep(
+    port_: i64,
+    to_address: *mut wire_uint_8_list,
+    sat_per_vbyte: u32,
+    utxos: *mut wire_StringList,
+) {
+    wire_prepare_sweep_impl(port_, to_address, sat_per_vbyte, utxos)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_sweeps(port_: i64) {
+    wire_list_sweeps_impl(port_)
 }
 
 #[no_mangle]
@@ -177,8 +324,32 @@ pub extern "C" fn wire_refund(
 }
 
 #[no_mangle]
-pub extern "C" fn wire_execute_command(port_: i64, command: *mut wire_uint_8_list) {
-    wire_execute_command_impl(port_, command)
+pub extern "C" fn wire_prepare_refund(
+    port_: i64,
+    swap_address: *mut wire_uint_8_list,
+    to_address: *mut wire_uint_8_list,
+    sat_per_vbyte: u32,
+) {
+    wire_prepare_refund_impl(port_, swap_address, to_address, sat_per_vbyte)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_refundable_amount(
+    port_: i64,
+    swap_address: *mut wire_uint_8_list,
+    sat_per_vbyte: u32,
+) {
+    wire_refundable_amount_impl(port_, swap_address, sat_per_vbyte)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_prune_terminal_swaps(port_: i64, older_than_sec: u64) {
+    wire_prune_terminal_swaps_impl(port_, older_than_sec)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_execute_command(port_: i64, command: *mut wire_uint_8_list, dry_run: bool) {
+    wire_execute_command_impl(port_, command, dry_run)
 }
 
 #[no_mangle]
@@ -186,11 +357,63 @@ pub extern "C" fn wire_sync_node(port_: i64) {
     wire_sync_node_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_wait_for_block_height(port_: i64, height: u32, timeout_sec: u64) {
+    wire_wait_for_block_height_impl(port_, height, timeout_sec)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_full_resync(port_: i64) {
+    wire_full_resync_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_optimize_storage(port_: i64) {
+    wire_optimize_storage_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_backup(port_: i64) {
+    wire_backup_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_restore(port_: i64, data: *mut wire_uint_8_list) {
+    wire_restore_impl(port_, data)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_setting(
+    port_: i64,
+    key: *mut wire_uint_8_list,
+    value: *mut wire_uint_8_list,
+) {
+    wire_set_setting_impl(port_, key, value)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_get_setting(port_: i64, key: *mut wire_uint_8_list) {
+    wire_get_setting_impl(port_, key)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_parse_invoice(port_: i64, invoice: *mut wire_uint_8_list) {
     wire_parse_invoice_impl(port_, invoice)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_parse_amount(port_: i64, input: *mut wire_uint_8_list, default_unit: i32) {
+    wire_parse_amount_impl(port_, input, default_unit)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_extract_lnurl_pay_metadata(
+    port_: i64,
+    req_data: *mut wire_LnUrlPayRequestData,
+) {
+    wire_extract_lnurl_pay_metadata_impl(port_, req_data)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_parse(port_: i64, s: *mut wire_uint_8_list) {
     wire_parse_impl(port_, s)
@@ -216,11 +439,25 @@ pub extern "C" fn wire_lnurl_withdraw(
     wire_lnurl_withdraw_impl(port_, req_data, amount_sats, description)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_lnurl_withdraw_max(
+    port_: i64,
+    req_data: *mut wire_LnUrlWithdrawRequestData,
+    description: *mut wire_uint_8_list,
+) {
+    wire_lnurl_withdraw_max_impl(port_, req_data, description)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_lnurl_auth(port_: i64, req_data: *mut wire_LnUrlAuthRequestData) {
     wire_lnurl_auth_impl(port_, req_data)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_list_lnurl_auth_logins(port_: i64) {
+    wire_list_lnurl_auth_logins_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_mnemonic_to_seed(port_: i64, phrase: *mut wire_uint_8_list) {
     wire_mnemonic_to_seed_impl(port_, phrase)
@@ -232,17 +469,75 @@ pub extern "C" fn wire_recommended_fees(port_: i64) {
 }
 
 #[no_mangle]
-pub extern "C" fn wire_default_config(port_: i64, config_type: i32) {
-    wire_default_config_impl(port_, config_type)
+pub extern "C" fn wire_preferred_fee_tier(port_: i64) {
+    wire_preferred_fee_tier_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_preferred_fee_tier(port_: i64, tier: *mut wire_FeeTier) {
+    wire_set_preferred_fee_tier_impl(port_, tier)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_resolve_fee_rate(port_: i64) {
+    wire_resolve_fee_rate_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_buy_bitcoin(
+    port_: i64,
+    provider: i32,
+    base_currency_code: *mut wire_uint_8_list,
+) {
+    wire_buy_bitcoin_impl(port_, provider, base_currency_code)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_diagnostics(port_: i64) {
+    wire_diagnostics_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_connectivity_check(port_: i64) {
+    wire_connectivity_check_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_check_clock_skew(port_: i64) {
+    wire_check_clock_skew_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_default_config(port_: i64, config_type: i32, network: i32) {
+    wire_default_config_impl(port_, config_type, network)
 }
 
 // Section: allocate functions
 
+#[no_mangle]
+pub extern "C" fn new_StringList_0(len: i32) -> *mut wire_StringList {
+    let wrap = wire_StringList {
+        ptr: support::new_leak_vec_ptr(<*mut wire_uint_8_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_bool_0(value: bool) -> *mut bool {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_config_0() -> *mut wire_Config {
     support::new_leak_box_ptr(wire_Config::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_fee_tier_0() -> *mut wire_FeeTier {
+    support::new_leak_box_ptr(wire_FeeTier::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_greenlight_credentials_0() -> *mut wire_GreenlightCredentials {
     support::new_leak_box_ptr(wire_GreenlightCredentials::new_with_null_ptr())
@@ -269,11 +564,34 @@ pub extern "C" fn new_box_autoadd_ln_url_withdraw_request_data_0(
     support::new_leak_box_ptr(wire_LnUrlWithdrawRequestData::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_u32_0(value: u32) -> *mut u32 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_u64_0(value: u64) -> *mut u64 {
     support::new_leak_box_ptr(value)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_template_value_0(len: i32) -> *mut wire_list_template_value {
+    let wrap = wire_list_template_value {
+        ptr: support::new_leak_vec_ptr(<wire_TemplateValue>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_tlv_entry_0(len: i32) -> *mut wire_list_tlv_entry {
+    let wrap = wire_list_tlv_entry {
+        ptr: support::new_leak_vec_ptr(<wire_TlvEntry>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_uint_8_list_0(len: i32) -> *mut wire_uint_8_list {
     let ans = wire_uint_8_list {
@@ -293,12 +611,33 @@ impl Wire2Api<String> for *mut wire_uint_8_list {
         String::from_utf8_lossy(&vec).into_owned()
     }
 }
+impl Wire2Api<Vec<String>> for *mut wire_StringList {
+    fn wire2api(self) -> Vec<String> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+
+impl Wire2Api<bool> for *mut bool {
+    fn wire2api(self) -> bool {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
 impl Wire2Api<Config> for *mut wire_Config {
     fn wire2api(self) -> Config {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<Config>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<FeeTier> for *mut wire_FeeTier {
+    fn wire2api(self) -> FeeTier {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<FeeTier>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<GreenlightCredentials> for *mut wire_GreenlightCredentials {
     fn wire2api(self) -> GreenlightCredentials {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -328,11 +667,17 @@ impl Wire2Api<LnUrlWithdrawRequestData> for *mut wire_LnUrlWithdrawRequestData {
         Wire2Api::<LnUrlWithdrawRequestData>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<u32> for *mut u32 {
+    fn wire2api(self) -> u32 {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
 impl Wire2Api<u64> for *mut u64 {
     fn wire2api(self) -> u64 {
         unsafe { *support::box_from_leak_ptr(self) }
     }
 }
+
 impl Wire2Api<Config> for wire_Config {
     fn wire2api(self) -> Config {
         Config {
@@ -344,10 +689,46 @@ impl Wire2Api<Config> for wire_Config {
             default_lsp_id: self.default_lsp_id.wire2api(),
             api_key: self.api_key.wire2api(),
             maxfee_percent: self.maxfee_percent.wire2api(),
+            auto_connect_lsp: self.auto_connect_lsp.wire2api(),
+            fiat_rate_cache_ttl_sec: self.fiat_rate_cache_ttl_sec.wire2api(),
+            max_concurrent_chain_requests: self.max_concurrent_chain_requests.wire2api(),
+            onchain_min_confirmations: self.onchain_min_confirmations.wire2api(),
+            default_fiat_currency: self.default_fiat_currency.wire2api(),
+            event_buffer_size: self.event_buffer_size.wire2api(),
+            allow_receive_split: self.allow_receive_split.wire2api(),
+            correct_clock_skew: self.correct_clock_skew.wire2api(),
+            redact_logs: self.redact_logs.wire2api(),
+            max_reconnect_backoff_sec: self.max_reconnect_backoff_sec.wire2api(),
+            overpaid_swap_handling: self.overpaid_swap_handling.wire2api(),
+            mempoolspace_api_token: self.mempoolspace_api_token.wire2api(),
+            swap_retention_sec: self.swap_retention_sec.wire2api(),
+            chain_backend: self.chain_backend.wire2api(),
+            sync_workers: self.sync_workers.wire2api(),
+            reconcile_expired_swaps_on_start: self.reconcile_expired_swaps_on_start.wire2api(),
+            db_encryption_key: self.db_encryption_key.wire2api(),
         }
     }
 }
 
+impl Wire2Api<FeeTier> for wire_FeeTier {
+    fn wire2api(self) -> FeeTier {
+        match self.tag {
+            0 => FeeTier::Fastest,
+            1 => FeeTier::HalfHour,
+            2 => FeeTier::Hour,
+            3 => FeeTier::Economy,
+            4 => FeeTier::Minimum,
+            5 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Custom);
+                FeeTier::Custom {
+                    sat_per_vbyte: ans.sat_per_vbyte.wire2api(),
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
 impl Wire2Api<GreenlightCredentials> for wire_GreenlightCredentials {
     fn wire2api(self) -> GreenlightCredentials {
         GreenlightCredentials {
@@ -357,6 +738,24 @@ impl Wire2Api<GreenlightCredentials> for wire_GreenlightCredentials {
     }
 }
 
+impl Wire2Api<Vec<TemplateValue>> for *mut wire_list_template_value {
+    fn wire2api(self) -> Vec<TemplateValue> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+impl Wire2Api<Vec<TlvEntry>> for *mut wire_list_tlv_entry {
+    fn wire2api(self) -> Vec<TlvEntry> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
 impl Wire2Api<LnUrlAuthRequestData> for wire_LnUrlAuthRequestData {
     fn wire2api(self) -> LnUrlAuthRequestData {
         LnUrlAuthRequestData {
@@ -392,6 +791,23 @@ impl Wire2Api<LnUrlWithdrawRequestData> for wire_LnUrlWithdrawRequestData {
     }
 }
 
+impl Wire2Api<TemplateValue> for wire_TemplateValue {
+    fn wire2api(self) -> TemplateValue {
+        TemplateValue {
+            key: self.key.wire2api(),
+            value: self.value.wire2api(),
+        }
+    }
+}
+impl Wire2Api<TlvEntry> for wire_TlvEntry {
+    fn wire2api(self) -> TlvEntry {
+        TlvEntry {
+            type_num: self.type_num.wire2api(),
+            value: self.value.wire2api(),
+        }
+    }
+}
+
 impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
     fn wire2api(self) -> Vec<u8> {
         unsafe {
@@ -402,6 +818,13 @@ impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
 }
 // Section: wire structs
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_StringList {
+    ptr: *mut *mut wire_uint_8_list,
+    len: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_Config {
@@ -413,6 +836,66 @@ pub struct wire_Config {
     default_lsp_id: *mut wire_uint_8_list,
     api_key: *mut wire_uint_8_list,
     maxfee_percent: f64,
+    auto_connect_lsp: bool,
+    fiat_rate_cache_ttl_sec: u64,
+    max_concurrent_chain_requests: u32,
+    onchain_min_confirmations: u32,
+    default_fiat_currency: *mut wire_uint_8_list,
+    event_buffer_size: u32,
+    allow_receive_split: bool,
+    correct_clock_skew: bool,
+    redact_logs: bool,
+    max_reconnect_backoff_sec: u32,
+    overpaid_swap_handling: i32,
+    mempoolspace_api_token: *mut wire_uint_8_list,
+    swap_retention_sec: *mut u64,
+    chain_backend: i32,
+    sync_workers: u32,
+    reconcile_expired_swaps_on_start: bool,
+    db_encryption_key: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FeeTier {
+    tag: i32,
+    kind: *mut FeeTierKind,
+}
+
+#[repr(C)]
+pub union FeeTierKind {
+    Fastest: *mut wire_FeeTier_Fastest,
+    HalfHour: *mut wire_FeeTier_HalfHour,
+    Hour: *mut wire_FeeTier_Hour,
+    Economy: *mut wire_FeeTier_Economy,
+    Minimum: *mut wire_FeeTier_Minimum,
+    Custom: *mut wire_FeeTier_Custom,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FeeTier_Fastest {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FeeTier_HalfHour {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FeeTier_Hour {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FeeTier_Economy {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FeeTier_Minimum {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FeeTier_Custom {
+    sat_per_vbyte: u64,
 }
 
 #[repr(C)]
@@ -422,6 +905,20 @@ pub struct wire_GreenlightCredentials {
     device_cert: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_template_value {
+    ptr: *mut wire_TemplateValue,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_tlv_entry {
+    ptr: *mut wire_TlvEntry,
+    len: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_LnUrlAuthRequestData {
@@ -453,6 +950,20 @@ pub struct wire_LnUrlWithdrawRequestData {
     max_withdrawable: u64,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_TemplateValue {
+    key: *mut wire_uint_8_list,
+    value: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_TlvEntry {
+    type_num: u64,
+    value: *mut wire_uint_8_list,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_uint_8_list {
@@ -483,6 +994,23 @@ impl NewWithNullPtr for wire_Config {
             default_lsp_id: core::ptr::null_mut(),
             api_key: core::ptr::null_mut(),
             maxfee_percent: Default::default(),
+            auto_connect_lsp: Default::default(),
+            fiat_rate_cache_ttl_sec: Default::default(),
+            max_concurrent_chain_requests: Default::default(),
+            onchain_min_confirmations: Default::default(),
+            default_fiat_currency: core::ptr::null_mut(),
+            event_buffer_size: Default::default(),
+            allow_receive_split: Default::default(),
+            correct_clock_skew: Default::default(),
+            redact_logs: Default::default(),
+            max_reconnect_backoff_sec: Default::default(),
+            overpaid_swap_handling: Default::default(),
+            mempoolspace_api_token: core::ptr::null_mut(),
+            swap_retention_sec: core::ptr::null_mut(),
+            chain_backend: Default::default(),
+            sync_workers: Default::default(),
+            reconcile_expired_swaps_on_start: Default::default(),
+            db_encryption_key: core::ptr::null_mut(),
         }
     }
 }
@@ -493,6 +1021,30 @@ impl Default for wire_Config {
     }
 }
 
+impl NewWithNullPtr for wire_FeeTier {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_FeeTier {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_FeeTier_Custom() -> *mut FeeTierKind {
+    support::new_leak_box_ptr(FeeTierKind {
+        Custom: support::new_leak_box_ptr(wire_FeeTier_Custom {
+            sat_per_vbyte: Default::default(),
+        }),
+    })
+}
+
 impl NewWithNullPtr for wire_GreenlightCredentials {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -563,6 +1115,36 @@ impl Default for wire_LnUrlWithdrawRequestData {
     }
 }
 
+impl NewWithNullPtr for wire_TemplateValue {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            key: core::ptr::null_mut(),
+            value: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_TemplateValue {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_TlvEntry {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            type_num: Default::default(),
+            value: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_TlvEntry {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 // Section: sync execution mode utility
 
 #[no_mangle]
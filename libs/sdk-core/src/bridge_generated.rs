@@ -22,44 +22,77 @@ use std::sync::Arc;
 use crate::breez_services::BreezEvent;
 use crate::breez_services::InvoicePaidDetails;
 use crate::breez_services::PaymentFailedData;
+use crate::breez_services::PaymentPendingData;
+use crate::chain::FeeTier;
 use crate::chain::RecommendedFees;
 use crate::fiat::CurrencyInfo;
+use crate::fiat::CurrencySymbolEntry;
 use crate::fiat::FiatCurrency;
 use crate::fiat::LocaleOverrides;
 use crate::fiat::LocalizedName;
 use crate::fiat::Rate;
 use crate::fiat::Symbol;
+use crate::input_parser::AmountUnit;
 use crate::input_parser::BitcoinAddressData;
 use crate::input_parser::InputType;
 use crate::input_parser::LnUrlAuthRequestData;
+use crate::input_parser::LnUrlChannelRequestData;
 use crate::input_parser::LnUrlErrorData;
 use crate::input_parser::LnUrlPayRequestData;
 use crate::input_parser::LnUrlWithdrawRequestData;
+use crate::input_parser::ParsedMetadata;
 use crate::invoice::LNInvoice;
+use crate::invoice::LNOffer;
+use crate::invoice::OfferAmount;
 use crate::invoice::RouteHint;
 use crate::invoice::RouteHintHop;
+use crate::lnurl::pay::model::AesDecryptedOrError;
 use crate::lnurl::pay::model::AesSuccessActionDataDecrypted;
 use crate::lnurl::pay::model::LnUrlPayResult;
 use crate::lnurl::pay::model::MessageSuccessActionData;
 use crate::lnurl::pay::model::SuccessActionProcessed;
 use crate::lnurl::pay::model::UrlSuccessActionData;
 use crate::lsp::LspInformation;
+use crate::models::AmountContext;
+use crate::models::AmountViolation;
+use crate::models::BuyBitcoinProvider;
+use crate::models::ChainBackend;
+use crate::models::ChannelInfo;
 use crate::models::ChannelState;
+use crate::models::ClockSkewWarning;
 use crate::models::ClosedChannelPaymentDetails;
 use crate::models::Config;
+use crate::models::ConnectionStatus;
+use crate::models::Diagnostics;
 use crate::models::EnvironmentType;
 use crate::models::GreenlightCredentials;
 use crate::models::LnPaymentDetails;
+use crate::models::LnUrlAuthLogin;
 use crate::models::LnUrlCallbackStatus;
 use crate::models::LogEntry;
 use crate::models::Network;
 use crate::models::NodeState;
+use crate::models::OpeningFeeParams;
+use crate::models::OverpaidSwapHandling;
 use crate::models::Payment;
 use crate::models::PaymentDetails;
+use crate::models::PaymentRouteHop;
 use crate::models::PaymentType;
 use crate::models::PaymentTypeFilter;
+use crate::models::PeerInfo;
+use crate::models::ReceiveCost;
+use crate::models::ReceiveLimits;
+use crate::models::ReceivePaymentResponse;
+use crate::models::RefundPreview;
+use crate::models::ServiceStatus;
+use crate::models::ServiceType;
+use crate::models::SwapDiagnostics;
 use crate::models::SwapInfo;
 use crate::models::SwapStatus;
+use crate::models::SweepPreview;
+use crate::models::SweepResult;
+use crate::models::TemplateValue;
+use crate::models::TlvEntry;
 use crate::models::UnspentTransactionOutput;
 
 // Section: wire functions
@@ -156,14 +189,17 @@ fn wire_start_node_impl(port_: MessagePort) {
         move || move |task_callback| start_node(),
     )
 }
-fn wire_breez_events_stream_impl(port_: MessagePort) {
+fn wire_breez_events_stream_impl(port_: MessagePort, replay: impl Wire2Api<bool> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
             debug_name: "breez_events_stream",
             port: Some(port_),
             mode: FfiCallMode::Stream,
         },
-        move || move |task_callback| breez_events_stream(task_callback.stream_sink()),
+        move || {
+            let api_replay = replay.wire2api();
+            move |task_callback| breez_events_stream(task_callback.stream_sink(), api_replay)
+        },
     )
 }
 fn wire_breez_log_stream_impl(port_: MessagePort) {
@@ -190,6 +226,8 @@ fn wire_send_payment_impl(
     port_: MessagePort,
     bolt11: impl Wire2Api<String> + UnwindSafe,
     amount_sats: impl Wire2Api<Option<u64>> + UnwindSafe,
+    max_fee_sat: impl Wire2Api<Option<u64>> + UnwindSafe,
+    timeout_sec: impl Wire2Api<Option<u32>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -200,7 +238,16 @@ fn wire_send_payment_impl(
         move || {
             let api_bolt11 = bolt11.wire2api();
             let api_amount_sats = amount_sats.wire2api();
-            move |task_callback| send_payment(api_bolt11, api_amount_sats)
+            let api_max_fee_sat = max_fee_sat.wire2api();
+            let api_timeout_sec = timeout_sec.wire2api();
+            move |task_callback| {
+                send_payment(
+                    api_bolt11,
+                    api_amount_sats,
+                    api_max_fee_sat,
+                    api_timeout_sec,
+                )
+            }
         },
     )
 }
@@ -208,6 +255,8 @@ fn wire_send_spontaneous_payment_impl(
     port_: MessagePort,
     node_id: impl Wire2Api<String> + UnwindSafe,
     amount_sats: impl Wire2Api<u64> + UnwindSafe,
+    timeout_sec: impl Wire2Api<Option<u32>> + UnwindSafe,
+    extra_tlvs: impl Wire2Api<Option<Vec<TlvEntry>>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -218,7 +267,16 @@ fn wire_send_spontaneous_payment_impl(
         move || {
             let api_node_id = node_id.wire2api();
             let api_amount_sats = amount_sats.wire2api();
-            move |task_callback| send_spontaneous_payment(api_node_id, api_amount_sats)
+            let api_timeout_sec = timeout_sec.wire2api();
+            let api_extra_tlvs = extra_tlvs.wire2api();
+            move |task_callback| {
+                send_spontaneous_payment(
+                    api_node_id,
+                    api_amount_sats,
+                    api_timeout_sec,
+                    api_extra_tlvs,
+                )
+            }
         },
     )
 }
@@ -226,6 +284,10 @@ fn wire_receive_payment_impl(
     port_: MessagePort,
     amount_sats: impl Wire2Api<u64> + UnwindSafe,
     description: impl Wire2Api<String> + UnwindSafe,
+    expiry_secs: impl Wire2Api<Option<u32>> + UnwindSafe,
+    preimage: impl Wire2Api<Option<Vec<u8>>> + UnwindSafe,
+    include_lsp_hint: impl Wire2Api<Option<bool>> + UnwindSafe,
+    fallback_address: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -236,8 +298,164 @@ fn wire_receive_payment_impl(
         move || {
             let api_amount_sats = amount_sats.wire2api();
             let api_description = description.wire2api();
-            move |task_callback| receive_payment(api_amount_sats, api_description)
+            let api_expiry_secs = expiry_secs.wire2api();
+            let api_preimage = preimage.wire2api();
+            let api_include_lsp_hint = include_lsp_hint.wire2api();
+            let api_fallback_address = fallback_address.wire2api();
+            move |task_callback| {
+                receive_payment(
+                    api_amount_sats,
+                    api_description,
+                    api_expiry_secs,
+                    api_preimage,
+                    api_include_lsp_hint,
+                    api_fallback_address,
+                )
+            }
+        },
+    )
+}
+fn wire_receive_payment_with_template_impl(
+    port_: MessagePort,
+    amount_sats: impl Wire2Api<u64> + UnwindSafe,
+    description_template: impl Wire2Api<String> + UnwindSafe,
+    values: impl Wire2Api<Vec<TemplateValue>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "receive_payment_with_template",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_amount_sats = amount_sats.wire2api();
+            let api_description_template = description_template.wire2api();
+            let api_values = values.wire2api();
+            move |task_callback| {
+                receive_payment_with_template(api_amount_sats, api_description_template, api_values)
+            }
+        },
+    )
+}
+fn wire_receive_payment_with_description_hash_impl(
+    port_: MessagePort,
+    amount_sats: impl Wire2Api<u64> + UnwindSafe,
+    description: impl Wire2Api<String> + UnwindSafe,
+    description_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "receive_payment_with_description_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_amount_sats = amount_sats.wire2api();
+            let api_description = description.wire2api();
+            let api_description_hash = description_hash.wire2api();
+            move |task_callback| {
+                receive_payment_with_description_hash(
+                    api_amount_sats,
+                    api_description,
+                    api_description_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_receive_payment_split_impl(
+    port_: MessagePort,
+    amount_sats: impl Wire2Api<u64> + UnwindSafe,
+    description: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "receive_payment_split",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_amount_sats = amount_sats.wire2api();
+            let api_description = description.wire2api();
+            move |task_callback| receive_payment_split(api_amount_sats, api_description)
+        },
+    )
+}
+fn wire_receive_donation_impl(port_: MessagePort, description: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "receive_donation",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_description = description.wire2api();
+            move |task_callback| receive_donation(api_description)
+        },
+    )
+}
+fn wire_validate_amount_impl(
+    port_: MessagePort,
+    amount_msat: impl Wire2Api<u64> + UnwindSafe,
+    context: impl Wire2Api<AmountContext> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "validate_amount",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_amount_msat = amount_msat.wire2api();
+            let api_context = context.wire2api();
+            move |task_callback| validate_amount(api_amount_msat, api_context)
+        },
+    )
+}
+fn wire_receive_cost_estimate_impl(
+    port_: MessagePort,
+    amount_msat: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "receive_cost_estimate",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_amount_msat = amount_msat.wire2api();
+            move |task_callback| receive_cost_estimate(api_amount_msat)
+        },
+    )
+}
+fn wire_receive_payment_limits_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "receive_payment_limits",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| receive_payment_limits(),
+    )
+}
+fn wire_list_peers_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_peers",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_peers(),
+    )
+}
+fn wire_list_channels_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_channels",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
+        move || move |task_callback| list_channels(),
     )
 }
 fn wire_node_info_impl(port_: MessagePort) {
@@ -250,11 +468,37 @@ fn wire_node_info_impl(port_: MessagePort) {
         move || move |task_callback| node_info(),
     )
 }
+fn wire_connection_status_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "connection_status",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| connection_status(),
+    )
+}
+fn wire_payment_by_hash_impl(port_: MessagePort, payment_hash: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "payment_by_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_payment_hash = payment_hash.wire2api();
+            move |task_callback| payment_by_hash(api_payment_hash)
+        },
+    )
+}
 fn wire_list_payments_impl(
     port_: MessagePort,
     filter: impl Wire2Api<PaymentTypeFilter> + UnwindSafe,
     from_timestamp: impl Wire2Api<Option<i64>> + UnwindSafe,
     to_timestamp: impl Wire2Api<Option<i64>> + UnwindSafe,
+    min_amount_msat: impl Wire2Api<Option<u64>> + UnwindSafe,
+    max_amount_msat: impl Wire2Api<Option<u64>> + UnwindSafe,
+    description_contains: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -266,7 +510,19 @@ fn wire_list_payments_impl(
             let api_filter = filter.wire2api();
             let api_from_timestamp = from_timestamp.wire2api();
             let api_to_timestamp = to_timestamp.wire2api();
-            move |task_callback| list_payments(api_filter, api_from_timestamp, api_to_timestamp)
+            let api_min_amount_msat = min_amount_msat.wire2api();
+            let api_max_amount_msat = max_amount_msat.wire2api();
+            let api_description_contains = description_contains.wire2api();
+            move |task_callback| {
+                list_payments(
+                    api_filter,
+                    api_from_timestamp,
+                    api_to_timestamp,
+                    api_min_amount_msat,
+                    api_max_amount_msat,
+                    api_description_contains,
+                )
+            }
         },
     )
 }
@@ -306,6 +562,22 @@ fn wire_fetch_lsp_info_impl(port_: MessagePort, id: impl Wire2Api<String> + Unwi
         },
     )
 }
+fn wire_lsp_liquidity_available_impl(
+    port_: MessagePort,
+    amount_msat: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "lsp_liquidity_available",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_amount_msat = amount_msat.wire2api();
+            move |task_callback| lsp_liquidity_available(api_amount_msat)
+        },
+    )
+}
 fn wire_lsp_id_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -326,6 +598,60 @@ fn wire_fetch_fiat_rates_impl(port_: MessagePort) {
         move || move |task_callback| fetch_fiat_rates(),
     )
 }
+fn wire_fiat_rate_impl(port_: MessagePort, coin: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "fiat_rate",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin = coin.wire2api();
+            move |task_callback| fiat_rate(api_coin)
+        },
+    )
+}
+fn wire_fiat_rate_at_impl(
+    port_: MessagePort,
+    coin: impl Wire2Api<String> + UnwindSafe,
+    timestamp: impl Wire2Api<i64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "fiat_rate_at",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin = coin.wire2api();
+            let api_timestamp = timestamp.wire2api();
+            move |task_callback| fiat_rate_at(api_coin, api_timestamp)
+        },
+    )
+}
+fn wire_preferred_fiat_currency_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "preferred_fiat_currency",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| preferred_fiat_currency(),
+    )
+}
+fn wire_select_fiat_currency_impl(port_: MessagePort, id: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "select_fiat_currency",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_id = id.wire2api();
+            move |task_callback| select_fiat_currency(api_id)
+        },
+    )
+}
 fn wire_list_fiat_currencies_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -336,6 +662,22 @@ fn wire_list_fiat_currencies_impl(port_: MessagePort) {
         move || move |task_callback| list_fiat_currencies(),
     )
 }
+fn wire_list_fiat_currency_symbols_impl(
+    port_: MessagePort,
+    locale: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_fiat_currency_symbols",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_locale = locale.wire2api();
+            move |task_callback| list_fiat_currency_symbols(api_locale)
+        },
+    )
+}
 fn wire_close_lsp_channels_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -349,7 +691,8 @@ fn wire_close_lsp_channels_impl(port_: MessagePort) {
 fn wire_sweep_impl(
     port_: MessagePort,
     to_address: impl Wire2Api<String> + UnwindSafe,
-    fee_rate_sats_per_byte: impl Wire2Api<u64> + UnwindSafe,
+    sat_per_vbyte: impl Wire2Api<u32> + UnwindSafe,
+    utxos: impl Wire2Api<Option<Vec<String>>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -359,11 +702,42 @@ fn wire_sweep_impl(
         },
         move || {
             let api_to_address = to_address.wire2api();
-            let api_fee_rate_sats_per_byte = fee_rate_sats_per_byte.wire2api();
-            move |task_callback| sweep(api_to_address, api_fee_rate_sats_per_byte)
+            let api_sat_per_vbyte = sat_per_vbyte.wire2api();
+            let api_utxos = utxos.wire2api();
+            move |task_callback| sweep(api_to_address, api_sat_per_vbyte, api_utxos)
+        },
+    )
+}
+fn wire_prepare_sweep_impl(
+    port_: MessagePort,
+    to_address: impl Wire2Api<String> + UnwindSafe,
+    sat_per_vbyte: impl Wire2Api<u32> + UnwindSafe,
+    utxos: impl Wire2Api<Option<Vec<String>>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "prepare_sweep",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_to_address = to_address.wire2api();
+            let api_sat_per_vbyte = sat_per_vbyte.wire2api();
+            let api_utxos = utxos.wire2api();
+            move |task_callback| prepare_sweep(api_to_address, api_sat_per_vbyte, api_utxos)
         },
     )
 }
+fn wire_list_sweeps_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_sweeps",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_sweeps(),
+    )
+}
 fn wire_receive_onchain_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -414,7 +788,65 @@ fn wire_refund_impl(
         },
     )
 }
-fn wire_execute_command_impl(port_: MessagePort, command: impl Wire2Api<String> + UnwindSafe) {
+fn wire_prepare_refund_impl(
+    port_: MessagePort,
+    swap_address: impl Wire2Api<String> + UnwindSafe,
+    to_address: impl Wire2Api<String> + UnwindSafe,
+    sat_per_vbyte: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "prepare_refund",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_swap_address = swap_address.wire2api();
+            let api_to_address = to_address.wire2api();
+            let api_sat_per_vbyte = sat_per_vbyte.wire2api();
+            move |task_callback| prepare_refund(api_swap_address, api_to_address, api_sat_per_vbyte)
+        },
+    )
+}
+fn wire_refundable_amount_impl(
+    port_: MessagePort,
+    swap_address: impl Wire2Api<String> + UnwindSafe,
+    sat_per_vbyte: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "refundable_amount",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_swap_address = swap_address.wire2api();
+            let api_sat_per_vbyte = sat_per_vbyte.wire2api();
+            move |task_callback| refundable_amount(api_swap_address, api_sat_per_vbyte)
+        },
+    )
+}
+fn wire_prune_terminal_swaps_impl(
+    port_: MessagePort,
+    older_than_sec: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "prune_terminal_swaps",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_older_than_sec = older_than_sec.wire2api();
+            move |task_callback| prune_terminal_swaps(api_older_than_sec)
+        },
+    )
+}
+fn wire_execute_command_impl(
+    port_: MessagePort,
+    command: impl Wire2Api<String> + UnwindSafe,
+    dry_run: impl Wire2Api<bool> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
             debug_name: "execute_command",
@@ -423,7 +855,8 @@ fn wire_execute_command_impl(port_: MessagePort, command: impl Wire2Api<String>
         },
         move || {
             let api_command = command.wire2api();
-            move |task_callback| execute_command(api_command)
+            let api_dry_run = dry_run.wire2api();
+            move |task_callback| execute_command(api_command, api_dry_run)
         },
     )
 }
@@ -437,6 +870,98 @@ fn wire_sync_node_impl(port_: MessagePort) {
         move || move |task_callback| sync_node(),
     )
 }
+fn wire_wait_for_block_height_impl(
+    port_: MessagePort,
+    height: impl Wire2Api<u32> + UnwindSafe,
+    timeout_sec: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "wait_for_block_height",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_height = height.wire2api();
+            let api_timeout_sec = timeout_sec.wire2api();
+            move |task_callback| wait_for_block_height(api_height, api_timeout_sec)
+        },
+    )
+}
+fn wire_full_resync_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "full_resync",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| full_resync(),
+    )
+}
+fn wire_optimize_storage_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "optimize_storage",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| optimize_storage(),
+    )
+}
+fn wire_backup_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "backup",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| backup(),
+    )
+}
+fn wire_restore_impl(port_: MessagePort, data: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "restore",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_data = data.wire2api();
+            move |task_callback| restore(api_data)
+        },
+    )
+}
+fn wire_set_setting_impl(
+    port_: MessagePort,
+    key: impl Wire2Api<String> + UnwindSafe,
+    value: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_setting",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key = key.wire2api();
+            let api_value = value.wire2api();
+            move |task_callback| set_setting(api_key, api_value)
+        },
+    )
+}
+fn wire_get_setting_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_setting",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_key = key.wire2api();
+            move |task_callback| get_setting(api_key)
+        },
+    )
+}
 fn wire_parse_invoice_impl(port_: MessagePort, invoice: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -450,6 +975,40 @@ fn wire_parse_invoice_impl(port_: MessagePort, invoice: impl Wire2Api<String> +
         },
     )
 }
+fn wire_parse_amount_impl(
+    port_: MessagePort,
+    input: impl Wire2Api<String> + UnwindSafe,
+    default_unit: impl Wire2Api<AmountUnit> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "parse_amount",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_input = input.wire2api();
+            let api_default_unit = default_unit.wire2api();
+            move |task_callback| parse_amount(api_input, api_default_unit)
+        },
+    )
+}
+fn wire_extract_lnurl_pay_metadata_impl(
+    port_: MessagePort,
+    req_data: impl Wire2Api<LnUrlPayRequestData> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "extract_lnurl_pay_metadata",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req_data = req_data.wire2api();
+            move |task_callback| extract_lnurl_pay_metadata(api_req_data)
+        },
+    )
+}
 fn wire_parse_impl(port_: MessagePort, s: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -503,6 +1062,24 @@ fn wire_lnurl_withdraw_impl(
         },
     )
 }
+fn wire_lnurl_withdraw_max_impl(
+    port_: MessagePort,
+    req_data: impl Wire2Api<LnUrlWithdrawRequestData> + UnwindSafe,
+    description: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "lnurl_withdraw_max",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req_data = req_data.wire2api();
+            let api_description = description.wire2api();
+            move |task_callback| lnurl_withdraw_max(api_req_data, api_description)
+        },
+    )
+}
 fn wire_lnurl_auth_impl(
     port_: MessagePort,
     req_data: impl Wire2Api<LnUrlAuthRequestData> + UnwindSafe,
@@ -519,6 +1096,16 @@ fn wire_lnurl_auth_impl(
         },
     )
 }
+fn wire_list_lnurl_auth_logins_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_lnurl_auth_logins",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_lnurl_auth_logins(),
+    )
+}
 fn wire_mnemonic_to_seed_impl(port_: MessagePort, phrase: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -535,16 +1122,98 @@ fn wire_mnemonic_to_seed_impl(port_: MessagePort, phrase: impl Wire2Api<String>
 fn wire_recommended_fees_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "recommended_fees",
+            debug_name: "recommended_fees",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| recommended_fees(),
+    )
+}
+fn wire_preferred_fee_tier_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "preferred_fee_tier",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| preferred_fee_tier(),
+    )
+}
+fn wire_set_preferred_fee_tier_impl(port_: MessagePort, tier: impl Wire2Api<FeeTier> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_preferred_fee_tier",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_tier = tier.wire2api();
+            move |task_callback| set_preferred_fee_tier(api_tier)
+        },
+    )
+}
+fn wire_resolve_fee_rate_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "resolve_fee_rate",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| resolve_fee_rate(),
+    )
+}
+fn wire_buy_bitcoin_impl(
+    port_: MessagePort,
+    provider: impl Wire2Api<BuyBitcoinProvider> + UnwindSafe,
+    base_currency_code: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "buy_bitcoin",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_provider = provider.wire2api();
+            let api_base_currency_code = base_currency_code.wire2api();
+            move |task_callback| buy_bitcoin(api_provider, api_base_currency_code)
+        },
+    )
+}
+fn wire_diagnostics_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "diagnostics",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| diagnostics(),
+    )
+}
+fn wire_connectivity_check_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "connectivity_check",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| connectivity_check(),
+    )
+}
+fn wire_check_clock_skew_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "check_clock_skew",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| recommended_fees(),
+        move || move |task_callback| check_clock_skew(),
     )
 }
 fn wire_default_config_impl(
     port_: MessagePort,
     config_type: impl Wire2Api<EnvironmentType> + UnwindSafe,
+    network: impl Wire2Api<Network> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -554,7 +1223,8 @@ fn wire_default_config_impl(
         },
         move || {
             let api_config_type = config_type.wire2api();
-            move |task_callback| Ok(default_config(api_config_type))
+            let api_network = network.wire2api();
+            move |task_callback| Ok(default_config(api_config_type, api_network))
         },
     )
 }
@@ -581,6 +1251,50 @@ where
     }
 }
 
+impl Wire2Api<AmountContext> for i32 {
+    fn wire2api(self) -> AmountContext {
+        match self {
+            0 => AmountContext::Send,
+            1 => AmountContext::Receive,
+            2 => AmountContext::SwapIn,
+            _ => unreachable!("Invalid variant for AmountContext: {}", self),
+        }
+    }
+}
+impl Wire2Api<AmountUnit> for i32 {
+    fn wire2api(self) -> AmountUnit {
+        match self {
+            0 => AmountUnit::Btc,
+            1 => AmountUnit::Sat,
+            2 => AmountUnit::Msat,
+            _ => unreachable!("Invalid variant for AmountUnit: {}", self),
+        }
+    }
+}
+impl Wire2Api<bool> for bool {
+    fn wire2api(self) -> bool {
+        self
+    }
+}
+
+impl Wire2Api<BuyBitcoinProvider> for i32 {
+    fn wire2api(self) -> BuyBitcoinProvider {
+        match self {
+            0 => BuyBitcoinProvider::MoonPay,
+            _ => unreachable!("Invalid variant for BuyBitcoinProvider: {}", self),
+        }
+    }
+}
+impl Wire2Api<ChainBackend> for i32 {
+    fn wire2api(self) -> ChainBackend {
+        match self {
+            0 => ChainBackend::MempoolSpace,
+            1 => ChainBackend::Esplora,
+            _ => unreachable!("Invalid variant for ChainBackend: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<EnvironmentType> for i32 {
     fn wire2api(self) -> EnvironmentType {
         match self {
@@ -619,6 +1333,15 @@ impl Wire2Api<Network> for i32 {
     }
 }
 
+impl Wire2Api<OverpaidSwapHandling> for i32 {
+    fn wire2api(self) -> OverpaidSwapHandling {
+        match self {
+            0 => OverpaidSwapHandling::Refund,
+            1 => OverpaidSwapHandling::Redeem,
+            _ => unreachable!("Invalid variant for OverpaidSwapHandling: {}", self),
+        }
+    }
+}
 impl Wire2Api<PaymentTypeFilter> for i32 {
     fn wire2api(self) -> PaymentTypeFilter {
         match self {
@@ -629,6 +1352,7 @@ impl Wire2Api<PaymentTypeFilter> for i32 {
         }
     }
 }
+
 impl Wire2Api<u16> for u16 {
     fn wire2api(self) -> u16 {
         self
@@ -652,6 +1376,27 @@ impl Wire2Api<u8> for u8 {
 
 // Section: impl IntoDart
 
+impl support::IntoDart for AesDecryptedOrError {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Decrypted { data } => vec![0.into_dart(), data.into_dart()],
+            Self::ErrorStatus {
+                reason,
+                description,
+                ciphertext,
+                iv,
+            } => vec![
+                1.into_dart(),
+                reason.into_dart(),
+                description.into_dart(),
+                ciphertext.into_dart(),
+                iv.into_dart(),
+            ],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for AesDecryptedOrError {}
 impl support::IntoDart for AesSuccessActionDataDecrypted {
     fn into_dart(self) -> support::DartAbi {
         vec![self.description.into_dart(), self.plaintext.into_dart()].into_dart()
@@ -659,6 +1404,19 @@ impl support::IntoDart for AesSuccessActionDataDecrypted {
 }
 impl support::IntoDartExceptPrimitive for AesSuccessActionDataDecrypted {}
 
+impl support::IntoDart for AmountViolation {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::BelowMinimum { min_msat } => vec![0.into_dart(), min_msat.into_dart()],
+            Self::AboveMaximum { max_msat } => vec![1.into_dart(), max_msat.into_dart()],
+            Self::InsufficientBalance { max_payable_msat } => {
+                vec![2.into_dart(), max_payable_msat.into_dart()]
+            }
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for AmountViolation {}
 impl support::IntoDart for BitcoinAddressData {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -681,11 +1439,51 @@ impl support::IntoDart for BreezEvent {
             Self::Synced => vec![2.into_dart()],
             Self::PaymentSucceed { details } => vec![3.into_dart(), details.into_dart()],
             Self::PaymentFailed { details } => vec![4.into_dart(), details.into_dart()],
+            Self::DuplicateIncomingPayment { details } => vec![5.into_dart(), details.into_dart()],
+            Self::LspConnected { lsp_id } => vec![6.into_dart(), lsp_id.into_dart()],
+            Self::PaymentPending { details } => vec![7.into_dart(), details.into_dart()],
+            Self::SwapUpdated { details } => vec![8.into_dart(), details.into_dart()],
+            Self::ConnectionStatusChanged { connected } => {
+                vec![9.into_dart(), connected.into_dart()]
+            }
+            Self::SwapOverpaid { details, handling } => {
+                vec![10.into_dart(), details.into_dart(), handling.into_dart()]
+            }
+            Self::SwapsReconciled { expired } => vec![11.into_dart(), expired.into_dart()],
+            Self::SyncProgress { processed, total } => {
+                vec![12.into_dart(), processed.into_dart(), total.into_dart()]
+            }
         }
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for BreezEvent {}
+impl support::IntoDart for ChainBackend {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::MempoolSpace => 0,
+            Self::Esplora => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ChainBackend {}
+impl support::IntoDart for ChannelInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.short_channel_id.into_dart(),
+            self.funding_txid.into_dart(),
+            self.state.into_dart(),
+            self.capacity_msat.into_dart(),
+            self.local_balance_msat.into_dart(),
+            self.remote_balance_msat.into_dart(),
+            self.closed_at.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ChannelInfo {}
+
 impl support::IntoDart for ChannelState {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -698,6 +1496,13 @@ impl support::IntoDart for ChannelState {
     }
 }
 impl support::IntoDartExceptPrimitive for ChannelState {}
+impl support::IntoDart for ClockSkewWarning {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.offset_secs.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ClockSkewWarning {}
+
 impl support::IntoDart for ClosedChannelPaymentDetails {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -721,12 +1526,40 @@ impl support::IntoDart for Config {
             self.default_lsp_id.into_dart(),
             self.api_key.into_dart(),
             self.maxfee_percent.into_dart(),
+            self.auto_connect_lsp.into_dart(),
+            self.fiat_rate_cache_ttl_sec.into_dart(),
+            self.max_concurrent_chain_requests.into_dart(),
+            self.onchain_min_confirmations.into_dart(),
+            self.default_fiat_currency.into_dart(),
+            self.event_buffer_size.into_dart(),
+            self.allow_receive_split.into_dart(),
+            self.correct_clock_skew.into_dart(),
+            self.redact_logs.into_dart(),
+            self.max_reconnect_backoff_sec.into_dart(),
+            self.overpaid_swap_handling.into_dart(),
+            self.mempoolspace_api_token.into_dart(),
+            self.swap_retention_sec.into_dart(),
+            self.chain_backend.into_dart(),
+            self.sync_workers.into_dart(),
+            self.reconcile_expired_swaps_on_start.into_dart(),
+            self.db_encryption_key.into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for Config {}
 
+impl support::IntoDart for ConnectionStatus {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Connected => 0,
+            Self::Connecting => 1,
+            Self::Disconnected => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ConnectionStatus {}
 impl support::IntoDart for CurrencyInfo {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -743,6 +1576,52 @@ impl support::IntoDart for CurrencyInfo {
 }
 impl support::IntoDartExceptPrimitive for CurrencyInfo {}
 
+impl support::IntoDart for CurrencySymbolEntry {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_dart(),
+            self.symbol.into_dart(),
+            self.name.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CurrencySymbolEntry {}
+
+impl support::IntoDart for Diagnostics {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.sdk_version.into_dart(),
+            self.network.into_dart(),
+            self.node_id.into_dart(),
+            self.block_height.into_dart(),
+            self.lsp_id.into_dart(),
+            self.channels_balance_msat.into_dart(),
+            self.onchain_balance_msat.into_dart(),
+            self.connected_peers.into_dart(),
+            self.swaps.into_dart(),
+            self.last_errors.into_dart(),
+            self.background_tasks_running.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Diagnostics {}
+
+impl support::IntoDart for FeeTier {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Fastest => vec![0.into_dart()],
+            Self::HalfHour => vec![1.into_dart()],
+            Self::Hour => vec![2.into_dart()],
+            Self::Economy => vec![3.into_dart()],
+            Self::Minimum => vec![4.into_dart()],
+            Self::Custom { sat_per_vbyte } => vec![5.into_dart(), sat_per_vbyte.into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FeeTier {}
 impl support::IntoDart for FiatCurrency {
     fn into_dart(self) -> support::DartAbi {
         vec![self.id.into_dart(), self.info.into_dart()].into_dart()
@@ -768,6 +1647,21 @@ impl support::IntoDart for InputType {
             Self::LnUrlWithdraw { data } => vec![5.into_dart(), data.into_dart()],
             Self::LnUrlAuth { data } => vec![6.into_dart(), data.into_dart()],
             Self::LnUrlError { data } => vec![7.into_dart(), data.into_dart()],
+            Self::LnUrlChannel { data } => vec![8.into_dart(), data.into_dart()],
+            Self::Unified { address, invoice } => {
+                vec![9.into_dart(), address.into_dart(), invoice.into_dart()]
+            }
+            Self::Bolt12Offer { offer } => vec![10.into_dart(), offer.into_dart()],
+            Self::NodeConnection {
+                node_id,
+                host,
+                port,
+            } => vec![
+                11.into_dart(),
+                node_id.into_dart(),
+                host.into_dart(),
+                port.into_dart(),
+            ],
         }
         .into_dart()
     }
@@ -793,12 +1687,27 @@ impl support::IntoDart for LNInvoice {
             self.expiry.into_dart(),
             self.routing_hints.into_dart(),
             self.payment_secret.into_dart(),
+            self.network.into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for LNInvoice {}
 
+impl support::IntoDart for LNOffer {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.bolt12.into_dart(),
+            self.signing_pubkey.into_dart(),
+            self.description.into_dart(),
+            self.amount.into_dart(),
+            self.issuer.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LNOffer {}
+
 impl support::IntoDart for LnPaymentDetails {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -811,12 +1720,27 @@ impl support::IntoDart for LnPaymentDetails {
             self.lnurl_success_action.into_dart(),
             self.ln_address.into_dart(),
             self.lnurl_metadata.into_dart(),
+            self.route.into_dart(),
+            self.custom_tlvs.into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for LnPaymentDetails {}
 
+impl support::IntoDart for LnUrlAuthLogin {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.domain.into_dart(),
+            self.k1.into_dart(),
+            self.timestamp.into_dart(),
+            self.success.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LnUrlAuthLogin {}
+
 impl support::IntoDart for LnUrlAuthRequestData {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -840,6 +1764,18 @@ impl support::IntoDart for LnUrlCallbackStatus {
     }
 }
 impl support::IntoDartExceptPrimitive for LnUrlCallbackStatus {}
+impl support::IntoDart for LnUrlChannelRequestData {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.uri.into_dart(),
+            self.callback.into_dart(),
+            self.k1.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LnUrlChannelRequestData {}
+
 impl support::IntoDart for LnUrlErrorData {
     fn into_dart(self) -> support::DartAbi {
         vec![self.reason.into_dart()].into_dart()
@@ -866,7 +1802,9 @@ impl support::IntoDartExceptPrimitive for LnUrlPayRequestData {}
 impl support::IntoDart for LnUrlPayResult {
     fn into_dart(self) -> support::DartAbi {
         match self {
-            Self::EndpointSuccess { data } => vec![0.into_dart(), data.into_dart()],
+            Self::EndpointSuccess { data, payment } => {
+                vec![0.into_dart(), data.into_dart(), payment.into_dart()]
+            }
             Self::EndpointError { data } => vec![1.into_dart(), data.into_dart()],
         }
         .into_dart()
@@ -976,6 +1914,56 @@ impl support::IntoDart for NodeState {
 }
 impl support::IntoDartExceptPrimitive for NodeState {}
 
+impl support::IntoDart for OfferAmount {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Bitcoin { amount_msat } => vec![0.into_dart(), amount_msat.into_dart()],
+            Self::Currency {
+                iso4217_code,
+                fractional_amount,
+            } => vec![
+                1.into_dart(),
+                iso4217_code.into_dart(),
+                fractional_amount.into_dart(),
+            ],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OfferAmount {}
+impl support::IntoDart for OpeningFeeParams {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.min_msat.into_dart(),
+            self.channel_fee_permyriad.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OpeningFeeParams {}
+
+impl support::IntoDart for OverpaidSwapHandling {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Refund => 0,
+            Self::Redeem => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OverpaidSwapHandling {}
+impl support::IntoDart for ParsedMetadata {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.description.into_dart(),
+            self.long_description.into_dart(),
+            self.image.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ParsedMetadata {}
+
 impl support::IntoDart for Payment {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1015,6 +2003,31 @@ impl support::IntoDart for PaymentFailedData {
 }
 impl support::IntoDartExceptPrimitive for PaymentFailedData {}
 
+impl support::IntoDart for PaymentPendingData {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.node_id.into_dart(),
+            self.bolt11.into_dart(),
+            self.payment_hash.into_dart(),
+            self.amount_msat.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentPendingData {}
+
+impl support::IntoDart for PaymentRouteHop {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.node_id.into_dart(),
+            self.short_channel_id.into_dart(),
+            self.fee_msat.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentRouteHop {}
+
 impl support::IntoDart for PaymentType {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -1026,6 +2039,19 @@ impl support::IntoDart for PaymentType {
     }
 }
 impl support::IntoDartExceptPrimitive for PaymentType {}
+impl support::IntoDart for PeerInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_dart(),
+            self.addresses.into_dart(),
+            self.connected.into_dart(),
+            self.channels_count.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PeerInfo {}
+
 impl support::IntoDart for Rate {
     fn into_dart(self) -> support::DartAbi {
         vec![self.coin.into_dart(), self.value.into_dart()].into_dart()
@@ -1033,6 +2059,42 @@ impl support::IntoDart for Rate {
 }
 impl support::IntoDartExceptPrimitive for Rate {}
 
+impl support::IntoDart for ReceiveCost {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.lsp_fee_msat.into_dart(),
+            self.net_received_msat.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ReceiveCost {}
+
+impl support::IntoDart for ReceiveLimits {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.min_receivable_msat.into_dart(),
+            self.max_receivable_msat.into_dart(),
+            self.max_receivable_without_fee_msat.into_dart(),
+            self.max_receivable_fee_msat.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ReceiveLimits {}
+
+impl support::IntoDart for ReceivePaymentResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.ln_invoice.into_dart(),
+            self.opening_fee_msat.into_dart(),
+            self.opening_fee_params.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ReceivePaymentResponse {}
+
 impl support::IntoDart for RecommendedFees {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1047,6 +2109,13 @@ impl support::IntoDart for RecommendedFees {
 }
 impl support::IntoDartExceptPrimitive for RecommendedFees {}
 
+impl support::IntoDart for RefundPreview {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.fee_sat.into_dart(), self.refund_amount_sat.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RefundPreview {}
+
 impl support::IntoDart for RouteHint {
     fn into_dart(self) -> support::DartAbi {
         vec![self.hops.into_dart()].into_dart()
@@ -1070,6 +2139,32 @@ impl support::IntoDart for RouteHintHop {
 }
 impl support::IntoDartExceptPrimitive for RouteHintHop {}
 
+impl support::IntoDart for ServiceStatus {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.service.into_dart(),
+            self.reachable.into_dart(),
+            self.latency_ms.into_dart(),
+            self.error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ServiceStatus {}
+
+impl support::IntoDart for ServiceType {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Greenlight => 0,
+            Self::Lsp => 1,
+            Self::Mempool => 2,
+            Self::Fiat => 3,
+            Self::MoonPay => 4,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ServiceType {}
 impl support::IntoDart for SuccessActionProcessed {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -1081,6 +2176,21 @@ impl support::IntoDart for SuccessActionProcessed {
     }
 }
 impl support::IntoDartExceptPrimitive for SuccessActionProcessed {}
+impl support::IntoDart for SwapDiagnostics {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.total.into_dart(),
+            self.unused.into_dart(),
+            self.in_progress.into_dart(),
+            self.redeemable.into_dart(),
+            self.refundable.into_dart(),
+            self.expired.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SwapDiagnostics {}
+
 impl support::IntoDart for SwapInfo {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1104,6 +2214,8 @@ impl support::IntoDart for SwapInfo {
             self.min_allowed_deposit.into_dart(),
             self.max_allowed_deposit.into_dart(),
             self.last_redeem_error.into_dart(),
+            self.expiry_block.into_dart(),
+            self.expires_at.into_dart(),
         ]
         .into_dart()
     }
@@ -1120,6 +2232,31 @@ impl support::IntoDart for SwapStatus {
     }
 }
 impl support::IntoDartExceptPrimitive for SwapStatus {}
+impl support::IntoDart for SweepPreview {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.tx_vsize.into_dart(),
+            self.fee_sat.into_dart(),
+            self.amount_sat.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SweepPreview {}
+
+impl support::IntoDart for SweepResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.txid.into_dart(),
+            self.amount_sat.into_dart(),
+            self.fee_sat.into_dart(),
+            self.destination.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SweepResult {}
+
 impl support::IntoDart for Symbol {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1133,6 +2270,13 @@ impl support::IntoDart for Symbol {
 }
 impl support::IntoDartExceptPrimitive for Symbol {}
 
+impl support::IntoDart for TlvEntry {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.type_num.into_dart(), self.value.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TlvEntry {}
+
 impl support::IntoDart for UnspentTransactionOutput {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1142,6 +2286,7 @@ impl support::IntoDart for UnspentTransactionOutput {
             self.address.into_dart(),
             self.reserved.into_dart(),
             self.reserved_to_block.into_dart(),
+            self.confirmed.into_dart(),
         ]
         .into_dart()
     }
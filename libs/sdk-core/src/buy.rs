@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::moonpay::{MoonPayApi, MoonPayProvider};
+use crate::{BuyBitcoinProvider, SwapInfo};
+
+/// A third party service that lets the user buy bitcoin with fiat currency.
+///
+/// Supporting a new provider boils down to implementing this trait and mapping the matching
+/// [BuyBitcoinProvider] variant to it in [BuyBitcoinService].
+#[tonic::async_trait]
+pub(crate) trait OnRampProvider: Send + Sync {
+    /// Generates the url the user should open in order to buy bitcoin that will be sent to the
+    /// given swap address.
+    async fn generate_url(
+        &self,
+        swap_info: &SwapInfo,
+        base_currency_code: Option<String>,
+    ) -> Result<String>;
+
+    /// Checks that the service the urls are generated with is reachable
+    async fn check_reachable(&self) -> Result<()>;
+}
+
+/// Dispatches buy bitcoin requests to the selected [OnRampProvider]
+pub(crate) struct BuyBitcoinService {
+    moonpay_provider: Arc<dyn OnRampProvider>,
+}
+
+impl BuyBitcoinService {
+    pub(crate) fn new(moonpay_api: Arc<dyn MoonPayApi>) -> Self {
+        Self {
+            moonpay_provider: Arc::new(MoonPayProvider::new(moonpay_api)),
+        }
+    }
+
+    fn provider(&self, provider: BuyBitcoinProvider) -> Arc<dyn OnRampProvider> {
+        match provider {
+            BuyBitcoinProvider::MoonPay => self.moonpay_provider.clone(),
+        }
+    }
+
+    pub(crate) async fn buy_bitcoin(
+        &self,
+        provider: BuyBitcoinProvider,
+        swap_info: &SwapInfo,
+        base_currency_code: Option<String>,
+    ) -> Result<String> {
+        self.provider(provider)
+            .generate_url(swap_info, base_currency_code)
+            .await
+    }
+
+    pub(crate) async fn check_reachable(&self, provider: BuyBitcoinProvider) -> Result<()> {
+        self.provider(provider).check_reachable().await
+    }
+}
@@ -1,5 +1,9 @@
-use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
 
 #[tonic::async_trait]
 pub trait ChainService: Send + Sync {
@@ -7,32 +11,66 @@ pub trait ChainService: Send + Sync {
     async fn address_transactions(&self, address: String) -> Result<Vec<OnchainTx>>;
     async fn current_tip(&self) -> Result<u32>;
     async fn broadcast_transaction(&self, tx: Vec<u8>) -> Result<String>;
+    /// The current unix time according to the service, used to detect a skewed device clock
+    async fn server_time(&self) -> Result<i64>;
+    /// Number of confirmations of the transaction `txid`, 0 while it's in the mempool
+    async fn transaction_confirmations(&self, txid: String) -> Result<u32>;
 }
 
 #[derive(Clone)]
 pub(crate) struct MempoolSpace {
     pub(crate) base_url: String,
+    /// Sent as a bearer token in the `Authorization` header of every request
+    api_token: Option<String>,
 }
 
 /// Wrapper containing the result of the recommended fees query, in sat/vByte, based on mempool.space data
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct RecommendedFees {
-    #[serde(rename(deserialize = "fastestFee"))]
+    #[serde(rename(deserialize = "fastestFee"), alias = "fastest_fee")]
     pub fastest_fee: u32,
 
-    #[serde(rename(deserialize = "halfHourFee"))]
+    #[serde(rename(deserialize = "halfHourFee"), alias = "half_hour_fee")]
     pub half_hour_fee: u32,
 
-    #[serde(rename(deserialize = "hourFee"))]
+    #[serde(rename(deserialize = "hourFee"), alias = "hour_fee")]
     pub hour_fee: u32,
 
-    #[serde(rename(deserialize = "economyFee"))]
+    #[serde(rename(deserialize = "economyFee"), alias = "economy_fee")]
     pub economy_fee: u32,
 
-    #[serde(rename(deserialize = "minimumFee"))]
+    #[serde(rename(deserialize = "minimumFee"), alias = "minimum_fee")]
     pub minimum_fee: u32,
 }
 
+impl RecommendedFees {
+    /// The fee rate of `tier`, in sat/vByte
+    pub fn fee_rate(&self, tier: &FeeTier) -> u64 {
+        match tier {
+            FeeTier::Fastest => self.fastest_fee as u64,
+            FeeTier::HalfHour => self.half_hour_fee as u64,
+            FeeTier::Hour => self.hour_fee as u64,
+            FeeTier::Economy => self.economy_fee as u64,
+            FeeTier::Minimum => self.minimum_fee as u64,
+            FeeTier::Custom { sat_per_vbyte } => *sat_per_vbyte,
+        }
+    }
+}
+
+/// One of the [RecommendedFees] tiers, or a custom fee rate in sat/vByte
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FeeTier {
+    Fastest,
+    #[default]
+    HalfHour,
+    Hour,
+    Economy,
+    Minimum,
+    Custom {
+        sat_per_vbyte: u64,
+    },
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct OnchainTx {
     pub txid: String,
@@ -54,6 +92,16 @@ pub struct TxStatus {
     pub block_time: Option<u64>,
 }
 
+impl TxStatus {
+    /// Number of confirmations at the `tip` height, counting the block including the transaction
+    pub(crate) fn confirmations(&self, tip: u32) -> u32 {
+        match (self.confirmed, self.block_height) {
+            (true, Some(height)) if tip >= height => tip - height + 1,
+            _ => 0,
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct Vout {
     pub scriptpubkey: String,
@@ -79,50 +127,80 @@ impl Default for MempoolSpace {
     fn default() -> Self {
         MempoolSpace {
             base_url: "https://mempool.space".to_string(),
+            api_token: None,
         }
     }
 }
 
 impl MempoolSpace {
     pub fn from_base_url(base_url: String) -> MempoolSpace {
-        MempoolSpace { base_url }
+        MempoolSpace {
+            base_url,
+            api_token: None,
+        }
+    }
+
+    /// Connects to a mempool.space instance that requires an API token. The token is only ever
+    /// sent over https.
+    pub fn from_base_url_with_token(base_url: String, api_token: String) -> Result<MempoolSpace> {
+        let scheme = reqwest::Url::parse(&base_url)?.scheme().to_string();
+        if scheme != "https" {
+            return Err(anyhow!(
+                "An https mempool.space url is required to send an API token, got {scheme}"
+            ));
+        }
+        Ok(MempoolSpace {
+            base_url,
+            api_token: Some(api_token),
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = reqwest::Client::new().request(method, format!("{}{path}", self.base_url));
+        match &self.api_token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    fn get(&self, path: &str) -> reqwest::RequestBuilder {
+        self.request(reqwest::Method::GET, path)
     }
 }
 
 #[tonic::async_trait]
 impl ChainService for MempoolSpace {
     async fn recommended_fees(&self) -> Result<RecommendedFees> {
-        Ok(
-            reqwest::get(format!("{}/api/v1/fees/recommended", self.base_url))
-                .await?
-                .json()
-                .await?,
-        )
+        Ok(self
+            .get("/api/v1/fees/recommended")
+            .send()
+            .await?
+            .json()
+            .await?)
     }
 
     async fn address_transactions(&self, address: String) -> Result<Vec<OnchainTx>> {
-        Ok(
-            reqwest::get(format!("{}/api/address/{}/txs", self.base_url, address))
-                .await?
-                .json()
-                .await?,
-        )
+        Ok(self
+            .get(&format!("/api/address/{address}/txs"))
+            .send()
+            .await?
+            .json()
+            .await?)
     }
 
     async fn current_tip(&self) -> Result<u32> {
-        Ok(
-            reqwest::get(format!("{}/api/blocks/tip/height", self.base_url))
-                .await?
-                .text()
-                .await?
-                .parse()?,
-        )
+        Ok(self
+            .get("/api/blocks/tip/height")
+            .send()
+            .await?
+            .text()
+            .await?
+            .parse()?)
     }
 
     async fn broadcast_transaction(&self, tx: Vec<u8>) -> Result<String> {
-        let client = reqwest::Client::new();
-        let txid_or_error = client
-            .post(format!("{}/api/tx", self.base_url))
+        let txid_or_error = self
+            .request(reqwest::Method::POST, "/api/tx")
             .body(hex::encode(tx))
             .send()
             .await?
@@ -135,14 +213,345 @@ impl ChainService for MempoolSpace {
             Ok(txid_or_error)
         }
     }
+
+    async fn server_time(&self) -> Result<i64> {
+        response_date(&self.get("/api/blocks/tip/height").send().await?)
+    }
+
+    async fn transaction_confirmations(&self, txid: String) -> Result<u32> {
+        let status: TxStatus = self
+            .get(&format!("/api/tx/{txid}/status"))
+            .send()
+            .await?
+            .json()
+            .await?;
+        Ok(status.confirmations(self.current_tip().await?))
+    }
+}
+
+/// A [ChainService] backed by the REST API of an Esplora instance, e.g. blockstream.info or a
+/// self-hosted one
+#[derive(Clone)]
+pub(crate) struct EsploraChainService {
+    pub(crate) base_url: String,
+}
+
+impl EsploraChainService {
+    pub fn from_base_url(base_url: String) -> EsploraChainService {
+        EsploraChainService { base_url }
+    }
+}
+
+#[tonic::async_trait]
+impl ChainService for EsploraChainService {
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        let estimates: HashMap<u32, f64> = reqwest::get(format!("{}/fee-estimates", self.base_url))
+            .await?
+            .json()
+            .await?;
+        fees_from_estimates(&estimates)
+    }
+
+    async fn address_transactions(&self, address: String) -> Result<Vec<OnchainTx>> {
+        Ok(
+            reqwest::get(format!("{}/address/{}/txs", self.base_url, address))
+                .await?
+                .json()
+                .await?,
+        )
+    }
+
+    async fn current_tip(&self) -> Result<u32> {
+        Ok(reqwest::get(format!("{}/blocks/tip/height", self.base_url))
+            .await?
+            .text()
+            .await?
+            .parse()?)
+    }
+
+    async fn broadcast_transaction(&self, tx: Vec<u8>) -> Result<String> {
+        let response = reqwest::Client::new()
+            .post(format!("{}/tx", self.base_url))
+            .body(hex::encode(tx))
+            .send()
+            .await?;
+        let status = response.status();
+        let txid_or_error = response.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!(txid_or_error));
+        }
+        Ok(txid_or_error)
+    }
+
+    async fn server_time(&self) -> Result<i64> {
+        response_date(&reqwest::get(format!("{}/blocks/tip/height", self.base_url)).await?)
+    }
+
+    async fn transaction_confirmations(&self, txid: String) -> Result<u32> {
+        let status: TxStatus = reqwest::get(format!("{}/tx/{txid}/status", self.base_url))
+            .await?
+            .json()
+            .await?;
+        Ok(status.confirmations(self.current_tip().await?))
+    }
+}
+
+/// Maps Esplora fee estimates, in sat/vByte by confirmation target in blocks, to the
+/// [RecommendedFees] tiers. Each tier gets the lowest rate expected to confirm within its target.
+fn fees_from_estimates(estimates: &HashMap<u32, f64>) -> Result<RecommendedFees> {
+    let fee_within = |blocks: u32| -> Result<u32> {
+        estimates
+            .iter()
+            .filter(|(target, _)| **target <= blocks)
+            .map(|(_, rate)| *rate)
+            .reduce(f64::min)
+            .map(|rate| (rate.ceil() as u32).max(1))
+            .ok_or_else(|| anyhow!("No fee estimate within {blocks} blocks"))
+    };
+    Ok(RecommendedFees {
+        fastest_fee: fee_within(1)?,
+        half_hour_fee: fee_within(3)?,
+        hour_fee: fee_within(6)?,
+        economy_fee: fee_within(144)?,
+        minimum_fee: fee_within(u32::MAX)?,
+    })
+}
+
+/// The unix time of the `Date` header of `response`
+fn response_date(response: &reqwest::Response) -> Result<i64> {
+    let date = response
+        .headers()
+        .get(reqwest::header::DATE)
+        .ok_or_else(|| anyhow!("Missing Date header"))?
+        .to_str()?;
+    parse_http_date(date)
+}
+
+/// Parses an HTTP `Date` header, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`, into a unix timestamp
+fn parse_http_date(date: &str) -> Result<i64> {
+    let invalid = || anyhow!("Invalid HTTP date: {date}");
+    let (day, month, year, time) = match date.split_whitespace().collect::<Vec<_>>()[..] {
+        [_, day, month, year, time, "GMT"] => (day, month, year, time),
+        _ => return Err(invalid()),
+    };
+    let month = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ]
+    .iter()
+    .position(|m| *m == month)
+    .ok_or_else(invalid)? as i64
+        + 1;
+    let day: i64 = day.parse()?;
+    let year: i64 = year.parse()?;
+    let (hours, minutes, seconds) = match time
+        .split(':')
+        .map(|t| t.parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?[..]
+    {
+        [hours, minutes, seconds] => (hours, minutes, seconds),
+        _ => return Err(invalid()),
+    };
+
+    // Days between the epoch and the date, counting years from March so that leap days come last
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+    Ok(days * 86400 + hours * 3600 + minutes * 60 + seconds)
+}
+/// A [ChainService] that limits how many requests run concurrently against the wrapped
+/// [ChainService]. Requests over the limit wait for a slot instead of failing.
+pub(crate) struct ThrottledChainService {
+    inner: Arc<dyn ChainService>,
+    permits: Semaphore,
+}
+
+impl ThrottledChainService {
+    pub(crate) fn new(inner: Arc<dyn ChainService>, max_concurrent_requests: u32) -> Self {
+        Self {
+            inner,
+            permits: Semaphore::new(max_concurrent_requests.max(1) as usize),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ChainService for ThrottledChainService {
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        let _permit = self.permits.acquire().await?;
+        self.inner.recommended_fees().await
+    }
+
+    async fn address_transactions(&self, address: String) -> Result<Vec<OnchainTx>> {
+        let _permit = self.permits.acquire().await?;
+        self.inner.address_transactions(address).await
+    }
+
+    async fn current_tip(&self) -> Result<u32> {
+        let _permit = self.permits.acquire().await?;
+        self.inner.current_tip().await
+    }
+
+    async fn broadcast_transaction(&self, tx: Vec<u8>) -> Result<String> {
+        let _permit = self.permits.acquire().await?;
+        self.inner.broadcast_transaction(tx).await
+    }
+
+    async fn server_time(&self) -> Result<i64> {
+        let _permit = self.permits.acquire().await?;
+        self.inner.server_time().await
+    }
+
+    async fn transaction_confirmations(&self, txid: String) -> Result<u32> {
+        let _permit = self.permits.acquire().await?;
+        self.inner.transaction_confirmations(txid).await
+    }
 }
+
 #[cfg(test)]
 mod tests {
-    use crate::chain::{MempoolSpace, OnchainTx};
+    use std::collections::HashMap;
+
+    use crate::chain::{
+        fees_from_estimates, parse_http_date, EsploraChainService, FeeTier, MempoolSpace,
+        OnchainTx, RecommendedFees,
+    };
     use tokio::test;
 
     use super::ChainService;
 
+    #[test]
+    fn test_parse_http_date() -> anyhow::Result<()> {
+        assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT")?, 0);
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT")?, 784111777);
+        assert_eq!(parse_http_date("Tue, 29 Feb 2000 12:00:00 GMT")?, 951825600);
+        assert!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT").is_err());
+        assert!(parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fee_rate() -> anyhow::Result<()> {
+        let fees: RecommendedFees = serde_json::from_str(
+            r#"{"fastestFee":50,"halfHourFee":40,"hourFee":30,"economyFee":20,"minimumFee":10}"#,
+        )?;
+        assert_eq!(fees.fee_rate(&FeeTier::Fastest), 50);
+        assert_eq!(fees.fee_rate(&FeeTier::default()), 40);
+        assert_eq!(fees.fee_rate(&FeeTier::Hour), 30);
+        assert_eq!(fees.fee_rate(&FeeTier::Economy), 20);
+        assert_eq!(fees.fee_rate(&FeeTier::Minimum), 10);
+        assert_eq!(fees.fee_rate(&FeeTier::Custom { sat_per_vbyte: 7 }), 7);
+
+        // The serialized fees, as cached, can be read back
+        let cached: RecommendedFees = serde_json::from_str(&serde_json::to_string(&fees)?)?;
+        assert_eq!(cached.half_hour_fee, 40);
+        Ok(())
+    }
+
+    #[test]
+    fn test_api_token_requires_https() {
+        let ms = MempoolSpace::from_base_url_with_token(
+            "https://mempool.example.com".to_string(),
+            "token".to_string(),
+        )
+        .unwrap();
+        let request = ms.get("/api/blocks/tip/height").build().unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://mempool.example.com/api/blocks/tip/height"
+        );
+        assert_eq!(
+            request.headers()[reqwest::header::AUTHORIZATION],
+            "Bearer token"
+        );
+
+        assert!(MempoolSpace::from_base_url_with_token(
+            "http://mempool.example.com".to_string(),
+            "token".to_string(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    async fn test_fees_from_estimates() {
+        let estimates: HashMap<u32, f64> = serde_json::from_str(
+            r#"{"1": 20.3, "2": 21.0, "3": 12.1, "6": 8.0, "144": 2.5, "1008": 0.8}"#,
+        )
+        .unwrap();
+        let fees = fees_from_estimates(&estimates).unwrap();
+        assert_eq!(fees.fastest_fee, 21);
+        assert_eq!(fees.half_hour_fee, 13);
+        assert_eq!(fees.hour_fee, 8);
+        assert_eq!(fees.economy_fee, 3);
+        assert_eq!(fees.minimum_fee, 1);
+
+        assert!(fees_from_estimates(&HashMap::from([(6, 8.0)])).is_err());
+    }
+
+    #[test]
+    async fn test_transaction_confirmations() {
+        let ms = MempoolSpace::from_base_url(format!("{}/mempool", mockito::server_url()));
+        let _tip = mockito::mock("GET", "/mempool/api/blocks/tip/height")
+            .with_body("767640")
+            .create();
+
+        let _unconfirmed = mockito::mock("GET", "/mempool/api/tx/unconfirmed/status")
+            .with_body(r#"{"confirmed":false}"#)
+            .create();
+        let confirmations = ms.transaction_confirmations("unconfirmed".to_string());
+        assert_eq!(confirmations.await.unwrap(), 0);
+
+        let _confirmed = mockito::mock("GET", "/mempool/api/tx/confirmed/status")
+            .with_body(r#"{"confirmed":true,"block_height":767637,"block_hash":"00","block_time":1671174562}"#)
+            .create();
+        let confirmations = ms.transaction_confirmations("confirmed".to_string());
+        assert_eq!(confirmations.await.unwrap(), 4);
+    }
+
+    #[test]
+    async fn test_esplora() {
+        let esplora = EsploraChainService::from_base_url(mockito::server_url());
+
+        let _fees = mockito::mock("GET", "/fee-estimates")
+            .with_body(r#"{"1": 20.3, "3": 12.1, "6": 8.0, "144": 2.5, "1008": 1.0}"#)
+            .create();
+        let fees = esplora.recommended_fees().await.unwrap();
+        assert_eq!(fees.fastest_fee, 21);
+        assert_eq!(fees.minimum_fee, 1);
+
+        let _tip = mockito::mock("GET", "/blocks/tip/height")
+            .with_body("767640")
+            .create();
+        assert_eq!(esplora.current_tip().await.unwrap(), 767640);
+
+        let _txs = mockito::mock("GET", "/address/bc1qtest/txs")
+            .with_body("[]")
+            .create();
+        assert!(esplora
+            .address_transactions("bc1qtest".to_string())
+            .await
+            .unwrap()
+            .is_empty());
+
+        let broadcast = mockito::mock("POST", "/tx")
+            .match_body("0102")
+            .with_body("txid")
+            .create();
+        assert_eq!(
+            esplora.broadcast_transaction(vec![1, 2]).await.unwrap(),
+            "txid"
+        );
+        drop(broadcast);
+
+        let _rejected = mockito::mock("POST", "/tx")
+            .with_status(400)
+            .with_body("sendrawtransaction RPC error: bad-txns-inputs-missingorspent")
+            .create();
+        assert!(esplora.broadcast_transaction(vec![1, 2]).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_recommended_fees() {
         let ms = Box::new(MempoolSpace::from_base_url(
@@ -187,4 +596,61 @@ mod tests {
 
     //     assert_eq!(expected_serialized, serialized_res);
     // }
+
+    #[tokio::test]
+    async fn test_throttled_chain_service() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        use super::{RecommendedFees, ThrottledChainService};
+
+        #[derive(Default)]
+        struct CountingChainService {
+            running: AtomicUsize,
+            max_running: AtomicUsize,
+        }
+
+        #[tonic::async_trait]
+        impl ChainService for CountingChainService {
+            async fn recommended_fees(&self) -> anyhow::Result<RecommendedFees> {
+                Err(anyhow::anyhow!("Not implemented"))
+            }
+
+            async fn address_transactions(
+                &self,
+                _address: String,
+            ) -> anyhow::Result<Vec<OnchainTx>> {
+                let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max_running.fetch_max(running, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                self.running.fetch_sub(1, Ordering::SeqCst);
+                Ok(vec![])
+            }
+
+            async fn current_tip(&self) -> anyhow::Result<u32> {
+                Err(anyhow::anyhow!("Not implemented"))
+            }
+
+            async fn broadcast_transaction(&self, _tx: Vec<u8>) -> anyhow::Result<String> {
+                Err(anyhow::anyhow!("Not implemented"))
+            }
+
+            async fn server_time(&self) -> anyhow::Result<i64> {
+                Err(anyhow::anyhow!("Not implemented"))
+            }
+
+            async fn transaction_confirmations(&self, _txid: String) -> anyhow::Result<u32> {
+                Err(anyhow::anyhow!("Not implemented"))
+            }
+        }
+
+        let inner = Arc::new(CountingChainService::default());
+        let throttled = ThrottledChainService::new(inner.clone(), 3);
+        let requests = (0..10).map(|i| throttled.address_transactions(format!("address{i}")));
+        let results = futures::future::join_all(requests).await;
+
+        assert!(results.iter().all(|r| r.is_ok()));
+        assert_eq!(inner.max_running.load(Ordering::SeqCst), 3);
+    }
 }
@@ -1,10 +1,13 @@
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::breez_services::BreezServer;
 use crate::grpc::RatesRequest;
 use crate::models::FiatAPI;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
 use tonic::Request;
 
 /// Settings for the symbol representation of a currency
@@ -40,6 +43,7 @@ pub struct CurrencyInfo {
     pub spacing: Option<u32>,
     pub symbol: Option<Symbol>,
     pub uniq_symbol: Option<Symbol>,
+    #[serde(alias = "localized_name")]
     pub localized_name: Option<Vec<LocalizedName>>,
     pub locale_overrides: Option<Vec<LocaleOverrides>>,
 }
@@ -51,8 +55,55 @@ pub struct FiatCurrency {
     pub info: CurrencyInfo,
 }
 
+impl FiatCurrency {
+    /// The lightweight [CurrencySymbolEntry] of this currency, localized for the given locale
+    pub(crate) fn symbol_entry(&self, locale: &str) -> CurrencySymbolEntry {
+        let name = self
+            .info
+            .localized_name
+            .iter()
+            .flatten()
+            .find(|n| n.locale == locale)
+            .map(|n| n.name.clone())
+            .unwrap_or_else(|| self.info.name.clone());
+        let symbol = self
+            .info
+            .locale_overrides
+            .iter()
+            .flatten()
+            .find(|o| o.locale == locale)
+            .and_then(|o| o.symbol.grapheme.clone())
+            .or_else(|| self.info.symbol.as_ref().and_then(|s| s.grapheme.clone()));
+        CurrencySymbolEntry {
+            id: self.id.clone(),
+            symbol,
+            name,
+        }
+    }
+}
+
+/// Lightweight representation of a fiat currency, meant for currency pickers
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurrencySymbolEntry {
+    pub id: String,
+    pub symbol: Option<String>,
+    /// The name of the currency, localized when a translation is available
+    pub name: String,
+}
+
+/// Converts the currencies to localized [CurrencySymbolEntry]s, sorted by name
+pub(crate) fn to_symbol_entries(
+    currencies: &[FiatCurrency],
+    locale: &str,
+) -> Vec<CurrencySymbolEntry> {
+    let mut entries: Vec<CurrencySymbolEntry> =
+        currencies.iter().map(|c| c.symbol_entry(locale)).collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
 /// Denominator in an exchange rate
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Rate {
     pub coin: String,
     pub value: f64,
@@ -98,3 +149,150 @@ impl FiatAPI for BreezServer {
             .collect())
     }
 }
+
+/// A [FiatAPI] that keeps the fetched rates in memory, keyed by currency, and only fetches them
+/// again from the wrapped [FiatAPI] once they are older than the configured TTL.
+pub(crate) struct CachedFiatAPI {
+    inner: Arc<dyn FiatAPI>,
+    ttl: Duration,
+    rates: Mutex<HashMap<String, (Rate, Instant)>>,
+}
+
+impl CachedFiatAPI {
+    pub(crate) fn new(inner: Arc<dyn FiatAPI>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            rates: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn fetch(&self, force: bool) -> Result<Vec<Rate>> {
+        // The lock is held while fetching, so concurrent callers wait for a single fetch
+        let mut rates = self.rates.lock().await;
+        let fresh = !rates.is_empty()
+            && rates
+                .values()
+                .all(|(_, fetched_at)| fetched_at.elapsed() < self.ttl);
+        if force || !fresh {
+            let fetched = self.inner.fetch_fiat_rates().await?;
+            let fetched_at = Instant::now();
+            *rates = fetched
+                .into_iter()
+                .map(|r| (r.coin.clone(), (r, fetched_at)))
+                .collect();
+        }
+        Ok(rates.values().map(|(r, _)| r.clone()).collect())
+    }
+}
+
+#[tonic::async_trait]
+impl FiatAPI for CachedFiatAPI {
+    async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
+        self.inner.list_fiat_currencies().await
+    }
+
+    async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
+        self.fetch(false).await
+    }
+
+    async fn refresh_fiat_rates(&self) -> Result<Vec<Rate>> {
+        self.fetch(true).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use anyhow::Result;
+
+    use crate::fiat::{
+        to_symbol_entries, CachedFiatAPI, CurrencyInfo, CurrencySymbolEntry, FiatCurrency, Rate,
+    };
+    use crate::models::FiatAPI;
+
+    #[test]
+    fn test_currency_symbol_entries() -> Result<()> {
+        let data = include_str!("../assets/json/currencies.json");
+        let fiat_currency_map: HashMap<String, CurrencyInfo> = serde_json::from_str(data)?;
+        let currencies: Vec<FiatCurrency> = fiat_currency_map
+            .into_iter()
+            .filter(|(id, _)| ["CHF", "EUR", "USD"].contains(&id.as_str()))
+            .map(|(id, info)| FiatCurrency { id, info })
+            .collect();
+
+        let entries = to_symbol_entries(&currencies, "en");
+        assert_eq!(
+            entries,
+            vec![
+                CurrencySymbolEntry {
+                    id: "EUR".to_string(),
+                    symbol: Some("€".to_string()),
+                    name: "Euro".to_string(),
+                },
+                CurrencySymbolEntry {
+                    id: "CHF".to_string(),
+                    symbol: None,
+                    name: "Swiss Franc".to_string(),
+                },
+                CurrencySymbolEntry {
+                    id: "USD".to_string(),
+                    symbol: Some("$".to_string()),
+                    name: "US Dollar".to_string(),
+                },
+            ]
+        );
+
+        // Localized names are used when available, and the order follows them
+        let entries = to_symbol_entries(&currencies, "es");
+        assert_eq!(entries[0].id, "USD");
+        assert_eq!(entries[0].name, "Dólar Americano");
+        assert_eq!(entries[0].symbol, Some("$".to_string()));
+        assert_eq!(entries[1].name, "Euro");
+
+        Ok(())
+    }
+
+    #[derive(Default)]
+    struct CountingFiatAPI {
+        fetches: AtomicUsize,
+    }
+
+    #[tonic::async_trait]
+    impl FiatAPI for CountingFiatAPI {
+        async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
+            Ok(vec![])
+        }
+
+        async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![Rate {
+                coin: "USD".to_string(),
+                value: 20_000.00,
+            }])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_fiat_rates() -> Result<()> {
+        let inner = Arc::new(CountingFiatAPI::default());
+        let cached = CachedFiatAPI::new(inner.clone(), Duration::from_secs(60));
+        for _ in 0..3 {
+            let rates = cached.fetch_fiat_rates().await?;
+            assert_eq!(rates.len(), 1);
+            assert_eq!(rates[0].coin, "USD");
+        }
+        assert_eq!(inner.fetches.load(Ordering::SeqCst), 1);
+
+        let inner = Arc::new(CountingFiatAPI::default());
+        let expired = CachedFiatAPI::new(inner.clone(), Duration::ZERO);
+        expired.fetch_fiat_rates().await?;
+        expired.fetch_fiat_rates().await?;
+        assert_eq!(inner.fetches.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use crate::invoice::parse_invoice;
 use crate::models::{
     Config, GreenlightCredentials, LnPaymentDetails, Network, NodeAPI, NodeState, PaymentDetails,
-    PaymentType, SyncResponse, UnspentTransactionOutput,
+    PaymentType, SatPerVbyte, SyncResponse, TlvEntry, UnspentTransactionOutput,
+    CUSTOM_TLV_MIN_TYPE,
 };
 
 use anyhow::{anyhow, Result};
@@ -10,8 +11,8 @@ use bitcoin::secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
 use gl_client::pb::amount::Unit;
 
 use gl_client::pb::{
-    Amount, CloseChannelRequest, CloseChannelResponse, Invoice, InvoiceRequest, InvoiceStatus,
-    OffChainPayment, PayStatus, WithdrawResponse,
+    Amount, CloseChannelRequest, CloseChannelResponse, InvoiceStatus, OffChainPayment, PayStatus,
+    WithdrawResponse,
 };
 use gl_client::scheduler::Scheduler;
 use gl_client::signer::Signer;
@@ -261,16 +262,8 @@ impl NodeAPI for Greenlight {
             a
         });
 
-        // calculate onchain balance
-        let onchain_balance = onchain_funds.iter().fold(0, |a, b| {
-            if b.reserved {
-                return a;
-            }
-            a + amount_to_msat(&b.amount.clone().unwrap_or_default())
-        });
-
         // Collect utxos from onchain funds
-        let utxos = onchain_funds
+        let utxos: Vec<UnspentTransactionOutput> = onchain_funds
             .iter()
             .filter_map(|list_funds_output| {
                 list_funds_output
@@ -287,10 +280,18 @@ impl NodeAPI for Greenlight {
                         address: list_funds_output.address.clone(),
                         reserved: list_funds_output.reserved,
                         reserved_to_block: list_funds_output.reserved_to_block,
+                        confirmed: list_funds_output.status() == pb::OutputStatus::Confirmed,
                     })
             })
             .collect();
 
+        // calculate onchain balance from the utxos with enough confirmations
+        let onchain_balance = utxos
+            .iter()
+            .filter(|u| u.is_spendable(self.sdk_config.onchain_min_confirmations))
+            .map(|u| u.amount_millisatoshi)
+            .sum();
+
         // calculate payment limits and inbound liquidity
         let mut max_payable: u64 = 0;
         let mut max_receivable_single_channel: u64 = 0;
@@ -322,7 +323,12 @@ impl NodeAPI for Greenlight {
         };
         Ok(SyncResponse {
             node_state,
-            payments: pull_transactions(since_timestamp, client.clone()).await?,
+            payments: pull_transactions(
+                since_timestamp,
+                client.clone(),
+                self.sdk_config.sync_workers,
+            )
+            .await?,
             channels: all_channels.clone().into_iter().map(|c| c.into()).collect(),
         })
     }
@@ -338,49 +344,78 @@ impl NodeAPI for Greenlight {
 
     async fn create_invoice(
         &self,
-        amount_sats: u64,
+        amount_sats: Option<u64>,
         description: String,
         preimage: Option<Vec<u8>>,
-    ) -> Result<Invoice> {
-        let mut client = self.get_client().await?;
-
-        let request = InvoiceRequest {
-            amount: Some(Amount {
-                unit: Some(Unit::Satoshi(amount_sats)),
+        expiry_secs: Option<u32>,
+    ) -> Result<String> {
+        // The invoice call of the CLN API is used, as the Greenlight one doesn't take an expiry
+        let mut client: node::ClnClient = self.get_node_client().await?;
+        let request = pb::cln::InvoiceRequest {
+            amount_msat: Some(pb::cln::AmountOrAny {
+                value: Some(match amount_sats {
+                    Some(amount_sats) => pb::cln::amount_or_any::Value::Amount(pb::cln::Amount {
+                        msat: amount_sats * 1000,
+                    }),
+                    None => pb::cln::amount_or_any::Value::Any(true),
+                }),
             }),
             label: format!(
                 "breez-{}",
                 SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis()
             ),
             description,
-            preimage: preimage.unwrap_or_default(),
+            preimage,
+            expiry: expiry_secs.map(u64::from),
+            ..Default::default()
         };
 
-        Ok(client.create_invoice(request).await?.into_inner())
+        Ok(client.invoice(request).await?.into_inner().bolt11)
+    }
+
+    async fn has_invoice(&self, payment_hash: Vec<u8>) -> Result<bool> {
+        let mut client = self.get_client().await?;
+        Ok(client
+            .list_invoices(pb::ListInvoicesRequest::default())
+            .await?
+            .into_inner()
+            .invoices
+            .iter()
+            .any(|i| i.payment_hash == payment_hash))
     }
 
     async fn send_payment(
         &self,
         bolt11: String,
         amount_sats: Option<u64>,
+        max_fee_sat: Option<u64>,
+        timeout_sec: u32,
     ) -> Result<crate::models::PaymentResponse> {
         let mut description = None;
+        let mut amount_msat = amount_sats.map(|amt| amt * 1000);
         if !bolt11.is_empty() {
-            description = parse_invoice(&bolt11)?.description;
+            let invoice = parse_invoice(&bolt11)?;
+            description = invoice.description;
+            amount_msat = amount_msat.or(invoice.amount_msat);
         }
+        let maxfee_msat = max_fee_msat(amount_msat, self.sdk_config.maxfee_percent, max_fee_sat);
 
         let mut client: node::ClnClient = self.get_node_client().await?;
         let request = pb::cln::PayRequest {
             bolt11,
             amount_msat: amount_sats.map(|amt| gl_client::pb::cln::Amount { msat: amt * 1000 }),
-            maxfeepercent: Some(self.sdk_config.maxfee_percent),
-            retry_for: Some(self.sdk_config.payment_timeout_sec),
+            // maxfee overrides maxfeepercent, so only one of them is set
+            maxfeepercent: match maxfee_msat {
+                Some(_) => None,
+                None => Some(self.sdk_config.maxfee_percent),
+            },
+            retry_for: Some(timeout_sec),
             label: None,
             maxdelay: None,
             riskfactor: None,
             localinvreqid: None,
             exclude: vec![],
-            maxfee: None,
+            maxfee: maxfee_msat.map(|msat| gl_client::pb::cln::Amount { msat }),
             description,
             exemptfee: None,
         };
@@ -391,6 +426,8 @@ impl NodeAPI for Greenlight {
         &self,
         node_id: String,
         amount_sats: u64,
+        timeout_sec: u32,
+        extra_tlvs: Vec<TlvEntry>,
     ) -> Result<crate::models::PaymentResponse> {
         let mut client: node::ClnClient = self.get_node_client().await?;
         let request = pb::cln::KeysendRequest {
@@ -402,11 +439,22 @@ impl NodeAPI for Greenlight {
                 "breez-{}",
                 SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis()
             )),
-            extratlvs: None,
+            extratlvs: match extra_tlvs.is_empty() {
+                true => None,
+                false => Some(pb::cln::TlvStream {
+                    entries: extra_tlvs
+                        .into_iter()
+                        .map(|tlv| pb::cln::TlvEntry {
+                            r#type: tlv.type_num,
+                            value: tlv.value,
+                        })
+                        .collect(),
+                }),
+            },
             routehints: None,
             maxfeepercent: Some(self.sdk_config.maxfee_percent),
             exemptfee: None,
-            retry_for: Some(self.sdk_config.payment_timeout_sec),
+            retry_for: Some(timeout_sec),
             maxdelay: None,
         };
         client.key_send(request).await?.into_inner().try_into()
@@ -426,28 +474,40 @@ impl NodeAPI for Greenlight {
     async fn sweep(
         &self,
         to_address: String,
-        fee_rate_sats_per_byte: u64,
+        fee_rate: SatPerVbyte,
+        utxos: Vec<UnspentTransactionOutput>,
     ) -> Result<WithdrawResponse> {
         let mut client = self.get_client().await?;
 
         let request = pb::WithdrawRequest {
             feerate: Some(pb::Feerate {
-                value: Some(pb::feerate::Value::Perkb(fee_rate_sats_per_byte * 1000)),
+                value: Some(pb::feerate::Value::Perkb(fee_rate.sat_per_kvbyte())),
             }),
             amount: Some(Amount {
                 unit: Some(Unit::All(true)),
             }),
             destination: to_address,
-            minconf: None,
-            utxos: vec![],
+            minconf: Some(pb::Confirmation {
+                blocks: self.sdk_config.onchain_min_confirmations,
+            }),
+            utxos: utxos
+                .into_iter()
+                .map(|u| pb::Outpoint {
+                    txid: u.txid,
+                    outnum: u.outnum,
+                })
+                .collect(),
         };
 
         Ok(client.withdraw(request).await?.into_inner())
     }
 
-    async fn execute_command(&self, command: String) -> Result<String> {
+    async fn execute_command(&self, command: String, dry_run: bool) -> Result<String> {
         let node_cmd = NodeCommand::from_str(&command)
             .map_err(|_| anyhow!(format!("command not found: {command}")))?;
+        if dry_run && node_cmd.changes_state() {
+            return self.describe_command(node_cmd).await;
+        }
         match node_cmd {
             NodeCommand::ListPeers => {
                 let resp = self
@@ -508,6 +568,32 @@ impl NodeAPI for Greenlight {
     }
 }
 
+impl Greenlight {
+    /// Describes what a state-changing command would do, without running it
+    async fn describe_command(&self, node_cmd: NodeCommand) -> Result<String> {
+        match node_cmd {
+            NodeCommand::CloseAllChannels => {
+                let peers_res = self
+                    .get_client()
+                    .await?
+                    .list_peers(pb::ListPeersRequest::default())
+                    .await?
+                    .into_inner();
+                let peers: Vec<String> = peers_res
+                    .peers
+                    .into_iter()
+                    .filter(|p| !p.channels.is_empty())
+                    .map(|p| hex::encode(p.id))
+                    .collect();
+                Ok(format!(
+                    "Dry run: {node_cmd} would close the channels with peers {peers:?}"
+                ))
+            }
+            _ => Ok(format!("Dry run: {node_cmd} would run")),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, EnumString, Display, Deserialize, Serialize)]
 enum NodeCommand {
     #[strum(serialize = "listpeers")]
@@ -526,22 +612,39 @@ enum NodeCommand {
     CloseAllChannels,
 }
 
+impl NodeCommand {
+    /// Whether running the command changes the node state, as opposed to only reading it
+    fn changes_state(&self) -> bool {
+        match self {
+            NodeCommand::ListPeers
+            | NodeCommand::ListFunds
+            | NodeCommand::ListPayments
+            | NodeCommand::ListInvoices => false,
+            NodeCommand::CloseAllChannels => true,
+        }
+    }
+}
+
 // pulls transactions from greenlight based on last sync timestamp.
 // greenlight gives us the payments via API and for received payments we are looking for settled invoices.
 async fn pull_transactions(
     since_timestamp: i64,
     client: node::Client,
+    workers: u32,
 ) -> Result<Vec<crate::models::Payment>> {
-    let mut c = client.clone();
-
-    // list invoices
-    let invoices = c
-        .list_invoices(pb::ListInvoicesRequest::default())
-        .await?
-        .into_inner();
+    let mut invoices_client = client.clone();
+    let mut payments_client = client;
+
+    // list invoices and fetch payments from greenlight at the same time
+    let (invoices, payments) = tokio::try_join!(
+        invoices_client.list_invoices(pb::ListInvoicesRequest::default()),
+        payments_client.list_payments(pb::ListPaymentsRequest::default()),
+    )?;
+    let (invoices, payments) = (invoices.into_inner(), payments.into_inner());
+    debug!("list payments: {:?}", payments);
 
     // construct the received transactions by filtering the invoices to those paid and beyond the filter timestamp
-    let received_transations: Result<Vec<crate::models::Payment>> = invoices
+    let paid_invoices: Vec<pb::Invoice> = invoices
         .invoices
         .into_iter()
         .filter(|i| {
@@ -549,36 +652,93 @@ async fn pull_transactions(
                 && i.status() == InvoiceStatus::Paid
                 && i.payment_time as i64 > since_timestamp
         })
-        .map(TryInto::try_into)
         .collect();
+    let received_transations = convert_concurrently::<_, crate::models::Payment>(
+        paid_invoices,
+        workers,
+        TryInto::try_into,
+    );
 
-    // fetch payments from greenlight
-    let payments = c
-        .list_payments(pb::ListPaymentsRequest::default())
-        .await?
-        .into_inner();
-    debug!("list payments: {:?}", payments);
     // construct the payment transactions
-    let sent_transactions: Result<Vec<crate::models::Payment>> = payments
+    let completed_payments: Vec<pb::Payment> = payments
         .payments
         .into_iter()
         .filter(|p| p.created_at as i64 > since_timestamp && p.status() == PayStatus::Complete)
-        .map(TryInto::try_into)
         .collect();
+    let sent_transactions = convert_concurrently::<_, crate::models::Payment>(
+        completed_payments,
+        workers,
+        TryInto::try_into,
+    );
 
     let mut transactions: Vec<crate::models::Payment> = Vec::new();
-    transactions.extend(received_transations?);
-    transactions.extend(sent_transactions?);
+    transactions.extend(received_transations.await?);
+    transactions.extend(sent_transactions.await?);
 
     Ok(transactions)
 }
 
+/// Converts `items` with `convert` on up to `workers` blocking tasks, keeping their order.
+///
+/// Converting a payment parses its invoice, which adds up for nodes with a long history.
+async fn convert_concurrently<T, U>(
+    items: Vec<T>,
+    workers: u32,
+    convert: fn(T) -> Result<U>,
+) -> Result<Vec<U>>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+{
+    let workers = workers.max(1) as usize;
+    let chunk_size = items.len() / workers + 1;
+    let mut items = items.into_iter();
+    let mut tasks = vec![];
+    loop {
+        let chunk: Vec<T> = items.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            break;
+        }
+        tasks.push(tokio::task::spawn_blocking(move || {
+            chunk.into_iter().map(convert).collect::<Result<Vec<U>>>()
+        }));
+    }
+
+    let mut converted = Vec::new();
+    for task in tasks {
+        converted.extend(task.await??);
+    }
+    Ok(converted)
+}
+
+/// The TLV type carrying the preimage of a keysend payment
+const KEYSEND_PREIMAGE_TLV_TYPE: u64 = 5482373484;
+
 //pub(crate) fn offchain_payment_to_transaction
 impl TryFrom<OffChainPayment> for crate::models::Payment {
     type Error = anyhow::Error;
 
     fn try_from(p: OffChainPayment) -> std::result::Result<Self, Self::Error> {
-        let ln_invoice = parse_invoice(&p.bolt11)?;
+        // Spontaneous payments come without invoice, the payee is then our own node
+        let keysend = p.bolt11.is_empty();
+        let (description, destination_pubkey) = match keysend {
+            true => (None, String::new()),
+            false => {
+                let ln_invoice = parse_invoice(&p.bolt11)?;
+                (ln_invoice.description, ln_invoice.payee_pubkey)
+            }
+        };
+        let custom_tlvs = p
+            .extratlvs
+            .into_iter()
+            .filter(|tlv| {
+                tlv.r#type >= CUSTOM_TLV_MIN_TYPE && tlv.r#type != KEYSEND_PREIMAGE_TLV_TYPE
+            })
+            .map(|tlv| TlvEntry {
+                type_num: tlv.r#type,
+                value: tlv.value,
+            })
+            .collect();
         Ok(crate::models::Payment {
             id: hex::encode(p.payment_hash.clone()),
             payment_type: PaymentType::Received,
@@ -586,18 +746,20 @@ impl TryFrom<OffChainPayment> for crate::models::Payment {
             amount_msat: amount_to_msat(&p.amount.unwrap_or_default()),
             fee_msat: 0,
             pending: false,
-            description: ln_invoice.description,
+            description,
             details: PaymentDetails::Ln {
                 data: LnPaymentDetails {
                     payment_hash: hex::encode(p.payment_hash),
                     label: p.label,
-                    destination_pubkey: ln_invoice.payee_pubkey,
+                    destination_pubkey,
                     payment_preimage: hex::encode(p.preimage),
-                    keysend: false,
+                    keysend,
                     bolt11: p.bolt11,
                     lnurl_success_action: None, // For received payments, this is None
                     lnurl_metadata: None,       // For received payments, this is None
                     ln_address: None,
+                    route: vec![],
+                    custom_tlvs,
                 },
             },
         })
@@ -613,11 +775,16 @@ impl TryFrom<pb::Invoice> for crate::models::Payment {
 
     fn try_from(invoice: pb::Invoice) -> std::result::Result<Self, Self::Error> {
         let ln_invoice = parse_invoice(&invoice.bolt11)?;
+        // The received amount is the one actually paid, which zero-amount invoices don't specify
+        let amount_msat = match invoice.received.as_ref().map(amount_to_msat) {
+            Some(received_msat) if received_msat > 0 => received_msat,
+            _ => amount_to_msat(&invoice.amount.unwrap_or_default()),
+        };
         Ok(crate::models::Payment {
             id: hex::encode(invoice.payment_hash.clone()),
             payment_type: PaymentType::Received,
             payment_time: invoice.payment_time as i64,
-            amount_msat: amount_to_msat(&invoice.amount.unwrap_or_default()),
+            amount_msat,
             fee_msat: 0,
             pending: false,
             description: ln_invoice.description,
@@ -632,6 +799,8 @@ impl TryFrom<pb::Invoice> for crate::models::Payment {
                     lnurl_success_action: None, // For received payments, this is None
                     lnurl_metadata: None,       // For received payments, this is None
                     ln_address: None,
+                    route: vec![],
+                    custom_tlvs: vec![],
                 },
             },
         })
@@ -669,6 +838,9 @@ impl TryFrom<pb::Payment> for crate::models::Payment {
                     lnurl_success_action: None,
                     lnurl_metadata: None,
                     ln_address: None,
+                    // Greenlight doesn't report the route taken by a payment
+                    route: vec![],
+                    custom_tlvs: vec![],
                 },
             },
         })
@@ -770,11 +942,149 @@ impl From<pb::Channel> for crate::models::Channel {
     }
 }
 
+impl From<pb::Channel> for crate::models::ChannelInfo {
+    fn from(c: pb::Channel) -> Self {
+        let capacity_msat = parse_amount(c.total.clone())
+            .ok()
+            .map(|a| amount_to_msat(&a));
+        let info: crate::models::ChannelInfo = crate::models::Channel::from(c).into();
+        crate::models::ChannelInfo {
+            capacity_msat,
+            ..info
+        }
+    }
+}
+
+impl From<pb::Peer> for crate::models::PeerInfo {
+    fn from(p: pb::Peer) -> Self {
+        crate::models::PeerInfo {
+            id: hex::encode(p.id),
+            addresses: p
+                .addresses
+                .iter()
+                .map(|a| format!("{}:{}", a.addr, a.port))
+                .collect(),
+            connected: p.connected,
+            channels_count: p.channels.len() as u32,
+        }
+    }
+}
+
+/// The routing fee limit, in msat, when an absolute `max_fee_sat` cap is given: the lower of the
+/// cap and the `maxfee_percent` share of the amount, if the amount is known.
+fn max_fee_msat(
+    amount_msat: Option<u64>,
+    maxfee_percent: f64,
+    max_fee_sat: Option<u64>,
+) -> Option<u64> {
+    let cap_msat = max_fee_sat? * 1000;
+    let percent_msat = amount_msat.map(|amt| (amt as f64 * maxfee_percent / 100.0) as u64);
+    Some(percent_msat.map_or(cap_msat, |p| min(p, cap_msat)))
+}
+
 #[cfg(test)]
 mod tests {
+    use super::{convert_concurrently, max_fee_msat, NodeCommand};
     use crate::models;
-    use anyhow::Result;
+    use crate::test_utils::create_invoice;
+    use anyhow::{anyhow, Result};
     use gl_client::pb;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_convert_concurrently() -> Result<()> {
+        let items: Vec<u32> = (0..1000).collect();
+        let doubled: Vec<u32> = items.iter().map(|i| i * 2).collect();
+        for workers in [0, 1, 3, 8, 2000] {
+            let converted = convert_concurrently(items.clone(), workers, |i| Ok(i * 2)).await?;
+            assert_eq!(converted, doubled);
+        }
+        assert!(convert_concurrently(vec![], 4, |i: u32| Ok(i))
+            .await?
+            .is_empty());
+
+        let failing = convert_concurrently(items, 4, |i| match i {
+            500 => Err(anyhow!("Invalid item")),
+            _ => Ok(i),
+        });
+        assert!(failing.await.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_peer_info() {
+        let peer = pb::Peer {
+            id: vec![2, 171],
+            connected: true,
+            addresses: vec![pb::Address {
+                addr: "127.0.0.1".to_string(),
+                port: 9735,
+                ..Default::default()
+            }],
+            channels: vec![pb::Channel::default(), pb::Channel::default()],
+            ..Default::default()
+        };
+        let info: models::PeerInfo = peer.into();
+        assert_eq!(info.id, "02ab");
+        assert_eq!(info.addresses, vec!["127.0.0.1:9735"]);
+        assert!(info.connected);
+        assert_eq!(info.channels_count, 2);
+    }
+
+    #[test]
+    fn test_channel_info() {
+        let info: models::ChannelInfo = gl_channel("CHANNELD_NORMAL").into();
+        assert_eq!(info.state, models::ChannelState::Opened);
+        assert_eq!(info.capacity_msat, Some(1000));
+        assert_eq!(info.local_balance_msat, 20);
+        assert_eq!(info.remote_balance_msat, 960);
+    }
+
+    #[test]
+    fn test_node_command_changes_state() -> Result<()> {
+        for command in ["listpeers", "listfunds", "listpayments", "listinvoices"] {
+            assert!(!NodeCommand::from_str(command)?.changes_state());
+        }
+        assert!(NodeCommand::from_str("closeallchannels")?.changes_state());
+        Ok(())
+    }
+
+    #[test]
+    fn test_zero_amount_invoice_payment() -> Result<()> {
+        let msat = |amount_msat| pb::Amount {
+            unit: Some(pb::amount::Unit::Millisatoshi(amount_msat)),
+        };
+        let invoice = create_invoice("tip".to_string(), 0, vec![], None);
+        assert_eq!(invoice.amount_msat, None);
+        let gl_invoice = pb::Invoice {
+            label: "".to_string(),
+            description: "tip".to_string(),
+            amount: Some(pb::Amount {
+                unit: Some(pb::amount::Unit::Any(true)),
+            }),
+            received: Some(msat(21_000)),
+            status: pb::InvoiceStatus::Paid as i32,
+            payment_time: 0,
+            expiry_time: 0,
+            bolt11: invoice.bolt11.clone(),
+            payment_hash: hex::decode(&invoice.payment_hash)?,
+            payment_preimage: vec![],
+        };
+
+        // The payer chose the amount, which is recorded as the received amount
+        let payment: models::Payment = gl_invoice.clone().try_into()?;
+        assert_eq!(payment.amount_msat, 21_000);
+
+        // Invoices with an amount fall back to it until they are paid
+        let unpaid = pb::Invoice {
+            amount: Some(msat(5000)),
+            received: None,
+            ..gl_invoice
+        };
+        let payment: models::Payment = unpaid.try_into()?;
+        assert_eq!(payment.amount_msat, 5000);
+        Ok(())
+    }
 
     #[test]
     fn test_channel_states() -> Result<()> {
@@ -807,6 +1117,18 @@ mod tests {
         //let c =
     }
 
+    #[test]
+    fn test_max_fee_msat() {
+        // Without a cap, only maxfee_percent applies
+        assert_eq!(max_fee_msat(Some(1_000_000), 0.5, None), None);
+        // The percent limit is lower than the cap
+        assert_eq!(max_fee_msat(Some(1_000_000), 0.5, Some(10)), Some(5_000));
+        // The cap is lower than the percent limit
+        assert_eq!(max_fee_msat(Some(100_000_000), 0.5, Some(10)), Some(10_000));
+        // Unknown amount, only the cap applies
+        assert_eq!(max_fee_msat(None, 0.5, Some(10)), Some(10_000));
+    }
+
     fn gl_channel(state: &str) -> pb::Channel {
         pb::Channel {
             state: state.to_string(),
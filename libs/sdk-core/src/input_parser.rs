@@ -1,15 +1,18 @@
+use std::fmt;
+use std::net::Ipv6Addr;
 use std::str::FromStr;
 
 use anyhow::{anyhow, Result};
 use bip21::Uri;
 use bitcoin::bech32;
 use bitcoin::bech32::FromBase32;
+use bitcoin::Address;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::input_parser::InputType::*;
 use crate::input_parser::LnUrlRequestData::*;
-use crate::invoice::{parse_invoice, LNInvoice};
+use crate::invoice::{parse_invoice, parse_offer, LNInvoice, LNOffer};
 
 use crate::lnurl::maybe_replace_host_with_mockito_test_host;
 
@@ -47,9 +50,9 @@ use crate::lnurl::maybe_replace_host_with_mockito_test_host;
 ///     assert!(matches!( parse(invoice).await, Ok(Bolt11{invoice: _}) ));
 ///     assert!(matches!( parse( &format!("lightning:{}", invoice) ).await, Ok(Bolt11{invoice: _}) ));
 ///
-///     // BIP 21 with LN fallback parses to a LN invoice
+///     // BIP 21 with LN fallback parses to a unified input, carrying both the address and the LN invoice
 ///     let btc_address = "1andreas3batLhQa2FawWjeyjCqyBzypd";
-///     assert!(matches!( parse( &format!("bitcoin:{}?lightning={}", btc_address, invoice) ).await, Ok(Bolt11{invoice: _}) ));
+///     assert!(matches!( parse( &format!("bitcoin:{}?lightning={}", btc_address, invoice) ).await, Ok(Unified{address: _, invoice: _}) ));
 /// }
 /// ```
 ///
@@ -162,7 +165,10 @@ pub async fn parse(input: &str) -> Result<InputType> {
             None => Ok(BitcoinAddress {
                 address: bitcoin_addr_data,
             }),
-            Some(invoice) => Ok(Bolt11 { invoice }),
+            Some(invoice) => Ok(Unified {
+                address: bitcoin_addr_data,
+                invoice,
+            }),
         };
     }
 
@@ -170,6 +176,24 @@ pub async fn parse(input: &str) -> Result<InputType> {
         return Ok(Bolt11 { invoice });
     }
 
+    let offer_input = input.strip_prefix("lightning:").unwrap_or(input);
+    if offer_input.to_lowercase().starts_with("lno1") {
+        return Ok(Bolt12Offer {
+            offer: parse_offer(offer_input)?,
+        });
+    }
+
+    if let Some((node_id, address)) = input.split_once('@') {
+        if bitcoin::secp256k1::PublicKey::from_str(node_id).is_ok() {
+            let (host, port) = parse_host_port(address)?;
+            return Ok(NodeConnection {
+                node_id: node_id.into(),
+                host,
+                port,
+            });
+        }
+    }
+
     if let Ok(_node_id) = bitcoin::secp256k1::PublicKey::from_str(input) {
         // Public key serialized in compressed form
         return Ok(NodeId {
@@ -218,6 +242,63 @@ pub async fn parse(input: &str) -> Result<InputType> {
     Err(anyhow!("Unrecognized input type"))
 }
 
+/// The port of a node connection string that doesn't specify one
+const DEFAULT_LN_PORT: u16 = 9735;
+
+/// Splits the `host:port` part of a node connection string. IPv6 hosts have to be enclosed in
+/// brackets, like `[::1]:9735`, and the port defaults to [DEFAULT_LN_PORT].
+fn parse_host_port(address: &str) -> Result<(String, u16)> {
+    let (host, port) = match address.strip_prefix('[') {
+        Some(bracketed) => {
+            let (host, rest) = bracketed
+                .split_once(']')
+                .ok_or_else(|| anyhow!("Invalid host in {address}"))?;
+            host.parse::<Ipv6Addr>()
+                .map_err(|_| anyhow!("Invalid IPv6 host {host}"))?;
+            let port = match rest {
+                "" => None,
+                rest => Some(
+                    rest.strip_prefix(':')
+                        .ok_or_else(|| anyhow!("Invalid host in {address}"))?,
+                ),
+            };
+            (host, port)
+        }
+        None => {
+            let (host, port) = match address.split_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (address, None),
+            };
+            if !is_valid_hostname(host) {
+                return Err(anyhow!("Invalid host {host}"));
+            }
+            (host, port)
+        }
+    };
+    let port = match port {
+        None => DEFAULT_LN_PORT,
+        Some(port) => port
+            .parse::<u16>()
+            .ok()
+            .filter(|port| *port != 0)
+            .ok_or_else(|| anyhow!("Invalid port {port}"))?,
+    };
+    Ok((host.to_string(), port))
+}
+
+/// Whether `host` is a syntactically valid hostname, which includes IPv4 addresses and onion
+/// addresses
+fn is_valid_hostname(host: &str) -> bool {
+    !host.is_empty()
+        && host.len() <= 253
+        && host.split('.').all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
 /// Prepends the given prefix to the input, if the input doesn't already start with it
 fn prepend_if_missing(prefix: &str, input: &str) -> String {
     format!("{}{}", prefix, input.trim_start_matches(prefix))
@@ -346,8 +427,6 @@ pub enum InputType {
         address: BitcoinAddressData,
     },
 
-    /// Also covers URIs like `bitcoin:...&lightning=bolt11`. In this case, it returns the BOLT11
-    /// and discards all other data.
     Bolt11 {
         invoice: LNInvoice,
     },
@@ -394,6 +473,38 @@ pub enum InputType {
     LnUrlError {
         data: LnUrlErrorData,
     },
+
+    /// # Supported standards
+    ///
+    /// - LUD-01 LNURL bech32 encoding
+    /// - LUD-02 `channelRequest` spec
+    LnUrlChannel {
+        data: LnUrlChannelRequestData,
+    },
+
+    /// A BIP21 URI with a `lightning=bolt11` param, like `bitcoin:...?lightning=bolt11`.
+    ///
+    /// Both payment options are returned, so the caller can prefer the LN invoice and fall back
+    /// to the on-chain address.
+    Unified {
+        address: BitcoinAddressData,
+        invoice: LNInvoice,
+    },
+
+    /// # Supported standards
+    ///
+    /// - BOLT12 offers, which can be recognized but not paid yet
+    Bolt12Offer {
+        offer: LNOffer,
+    },
+
+    /// A node connection string, like `pubkey@host:port`, with which a peer connection can be
+    /// opened. The port defaults to 9735 if the string doesn't specify one.
+    NodeConnection {
+        node_id: String,
+        host: String,
+        port: u16,
+    },
 }
 
 /// Generic struct containing the possible LNURL payloads returned when contacting a LNURL endpoint
@@ -412,6 +523,10 @@ pub enum LnUrlRequestData {
         #[serde(flatten)]
         data: LnUrlWithdrawRequestData,
     },
+    ChannelRequest {
+        #[serde(flatten)]
+        data: LnUrlChannelRequestData,
+    },
     #[serde(rename = "login")]
     AuthRequest {
         #[serde(flatten)]
@@ -428,6 +543,7 @@ impl From<LnUrlRequestData> for InputType {
         match lnurl_data {
             PayRequest { data } => LnUrlPay { data },
             WithdrawRequest { data } => LnUrlWithdraw { data },
+            ChannelRequest { data } => LnUrlChannel { data },
             AuthRequest { data } => LnUrlAuth { data },
             Error { data } => LnUrlError { data },
         }
@@ -491,6 +607,44 @@ impl LnUrlPayRequestData {
     pub fn metadata_vec(&self) -> Result<Vec<MetadataItem>> {
         serde_json::from_str::<Vec<MetadataItem>>(&self.metadata_str).map_err(|err| anyhow!(err))
     }
+
+    /// The metadata items defined by LUD-06, with the image decoded. Items of unknown types are
+    /// ignored.
+    pub fn extract_metadata(&self) -> Result<ParsedMetadata> {
+        let mut description = None;
+        let mut long_description = None;
+        let mut image = None;
+        for item in self.metadata_vec()? {
+            match item.key.as_str() {
+                "text/plain" => description = Some(item.value),
+                "text/long-desc" => long_description = Some(item.value),
+                "image/png;base64" | "image/jpeg;base64" => {
+                    image = Some(
+                        base64::decode(&item.value)
+                            .map_err(|_| anyhow!("Invalid image in the LNURL-pay metadata"))?,
+                    )
+                }
+                _ => {}
+            }
+        }
+
+        Ok(ParsedMetadata {
+            description: description
+                .ok_or_else(|| anyhow!("Missing text/plain entry in the LNURL-pay metadata"))?,
+            long_description,
+            image,
+        })
+    }
+}
+
+/// The [LnUrlPayRequestData] metadata items shown to the user, see [LnUrlPayRequestData::extract_metadata]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ParsedMetadata {
+    /// The mandatory short description
+    pub description: String,
+    pub long_description: Option<String>,
+    /// The PNG or JPEG thumbnail
+    pub image: Option<Vec<u8>>,
 }
 
 /// Wrapped in a [LnUrlWithdraw], this is the result of [parse] when given a LNURL-withdraw endpoint.
@@ -522,6 +676,19 @@ impl LnUrlWithdrawRequestData {
     }
 }
 
+/// Wrapped in a [LnUrlChannel], this is the result of [parse] when given a LNURL-channel endpoint.
+///
+/// It represents the endpoint's parameters for the LNURL workflow.
+///
+/// See https://github.com/lnurl/luds/blob/luds/02.md
+#[derive(Deserialize, Debug, Serialize)]
+pub struct LnUrlChannelRequestData {
+    /// The node URI of the service, in the `node_id@host:port` format
+    pub uri: String,
+    pub callback: String,
+    pub k1: String,
+}
+
 /// Wrapped in a [LnUrlAuth], this is the result of [parse] when given a LNURL-auth endpoint.
 ///
 /// It represents the endpoint's parameters for the LNURL workflow.
@@ -563,6 +730,181 @@ pub struct BitcoinAddressData {
     pub message: Option<String>,
 }
 
+impl BitcoinAddressData {
+    /// Fails with [AddressError::NetworkMismatch] if the address is not for the `expected` network
+    pub fn validate_network(&self, expected: crate::models::Network) -> Result<()> {
+        validate_address_network(&self.address, expected)
+    }
+}
+
+/// Errors returned when a bitcoin address can't be used by this node
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressError {
+    /// The address belongs to a different network than the one the SDK is configured for
+    NetworkMismatch {
+        expected: crate::models::Network,
+        found: crate::models::Network,
+    },
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressError::NetworkMismatch { expected, found } => write!(
+                f,
+                "Address is for network {found:?}, but the node is configured for {expected:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+/// Parses `address` and fails with [AddressError::NetworkMismatch] if it is not valid for the
+/// `expected` network. Testnet addresses are also valid on signet, as both share their encoding.
+pub(crate) fn validate_address_network(
+    address: &str,
+    expected: crate::models::Network,
+) -> Result<()> {
+    let address = Address::from_str(address)?;
+    if !address.is_valid_for_network(expected.into()) {
+        return Err(AddressError::NetworkMismatch {
+            expected,
+            found: address.network.into(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// The unit of an amount entered by the user, see [parse_amount]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AmountUnit {
+    Btc,
+    Sat,
+    Msat,
+}
+
+impl AmountUnit {
+    fn msat_per_unit(&self) -> u64 {
+        match self {
+            AmountUnit::Btc => 100_000_000_000,
+            AmountUnit::Sat => 1_000,
+            AmountUnit::Msat => 1,
+        }
+    }
+
+    /// The decimals that still make a whole msat amount
+    fn max_decimals(&self) -> usize {
+        match self {
+            AmountUnit::Btc => 11,
+            AmountUnit::Sat => 3,
+            AmountUnit::Msat => 0,
+        }
+    }
+}
+
+/// Errors returned when [parse_amount] can't read an amount
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AmountError {
+    /// The input isn't a positive number, optionally followed by a unit
+    InvalidFormat,
+    /// The separators can't be read unambiguously, like in `1,5` or `1.000,50`
+    AmbiguousSeparator,
+    /// The unit isn't one of `btc`, `sat`, `sats`, `msat` or `msats`
+    UnknownUnit { unit: String },
+    /// The amount has more decimals than the unit allows, as it would be a fraction of a msat
+    TooManyDecimals {
+        unit: AmountUnit,
+        max_decimals: usize,
+    },
+    /// The amount doesn't fit in a u64 of msat
+    Overflow,
+}
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AmountError::InvalidFormat => write!(f, "Amount is not a valid number"),
+            AmountError::AmbiguousSeparator => write!(
+                f,
+                "Amount separators are ambiguous, use ',' for thousands and '.' for decimals"
+            ),
+            AmountError::UnknownUnit { unit } => write!(f, "Unknown amount unit {unit}"),
+            AmountError::TooManyDecimals { unit, max_decimals } => write!(
+                f,
+                "Amounts in {unit:?} can have at most {max_decimals} decimals"
+            ),
+            AmountError::Overflow => write!(f, "Amount is too large"),
+        }
+    }
+}
+
+impl std::error::Error for AmountError {}
+
+/// Parses an amount entered by the user, like `0.001 BTC`, `1000 sats` or `1,000.50`, into msat.
+/// Amounts without a unit are in `default_unit`.
+///
+/// A comma is only accepted as thousands separator, between groups of three digits, and a dot
+/// only as decimal separator.
+pub fn parse_amount(input: &str, default_unit: AmountUnit) -> Result<u64> {
+    let input = input.trim().to_lowercase();
+    let (number, unit) = input.split_at(input.find(char::is_alphabetic).unwrap_or(input.len()));
+    let unit = match unit.trim() {
+        "" => default_unit,
+        "btc" => AmountUnit::Btc,
+        "sat" | "sats" => AmountUnit::Sat,
+        "msat" | "msats" => AmountUnit::Msat,
+        unit => {
+            return Err(AmountError::UnknownUnit {
+                unit: unit.to_string(),
+            }
+            .into())
+        }
+    };
+
+    let (integer, decimals) = number.trim().split_once('.').unwrap_or((number.trim(), ""));
+    if decimals.contains(['.', ',']) {
+        return Err(AmountError::AmbiguousSeparator.into());
+    }
+    let groups: Vec<&str> = integer.split(',').collect();
+    if groups.len() > 1
+        && (groups[0].is_empty()
+            || groups[0].len() > 3
+            || groups[1..].iter().any(|group| group.len() != 3))
+    {
+        return Err(AmountError::AmbiguousSeparator.into());
+    }
+    let integer = groups.concat();
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (integer.is_empty() && decimals.is_empty()) || !is_digits(&integer) || !is_digits(decimals) {
+        return Err(AmountError::InvalidFormat.into());
+    }
+    if decimals.len() > unit.max_decimals() {
+        return Err(AmountError::TooManyDecimals {
+            unit,
+            max_decimals: unit.max_decimals(),
+        }
+        .into());
+    }
+
+    // The decimals padded to the maximum are the msat below one unit
+    let integer: u64 = match integer.is_empty() {
+        true => 0,
+        false => integer.parse().map_err(|_| AmountError::Overflow)?,
+    };
+    let fraction: u64 = match unit.max_decimals() {
+        0 => 0,
+        max_decimals => format!("{decimals:0<max_decimals$}")
+            .parse()
+            .map_err(|_| AmountError::Overflow)?,
+    };
+    integer
+        .checked_mul(unit.msat_per_unit())
+        .and_then(|msat| msat.checked_add(fraction))
+        .ok_or_else(|| AmountError::Overflow.into())
+}
+
 impl From<Uri<'_>> for BitcoinAddressData {
     fn from(uri: Uri) -> Self {
         BitcoinAddressData {
@@ -587,6 +929,60 @@ mod tests {
     use crate::input_parser::*;
     use crate::models::Network;
 
+    #[test]
+    fn test_parse_amount() -> Result<()> {
+        use AmountUnit::*;
+
+        assert_eq!(parse_amount("0.001 BTC", Sat)?, 100_000_000);
+        assert_eq!(parse_amount("0.00000000001btc", Sat)?, 1);
+        assert_eq!(parse_amount("1000 sats", Btc)?, 1_000_000);
+        assert_eq!(parse_amount("1 sat", Btc)?, 1_000);
+        assert_eq!(parse_amount("1500 msat", Btc)?, 1_500);
+        assert_eq!(parse_amount(" 1,000.50 ", Sat)?, 1_000_500);
+        assert_eq!(parse_amount("1,000,000", Msat)?, 1_000_000);
+        assert_eq!(parse_amount("2", Btc)?, 200_000_000_000);
+        assert_eq!(parse_amount(".5", Sat)?, 500);
+        assert_eq!(parse_amount("7.", Sat)?, 7_000);
+
+        let err = |input: &str, unit: AmountUnit| {
+            parse_amount(input, unit)
+                .unwrap_err()
+                .downcast::<AmountError>()
+                .unwrap()
+        };
+        assert_eq!(err("", Sat), AmountError::InvalidFormat);
+        assert_eq!(err("sats", Sat), AmountError::InvalidFormat);
+        assert_eq!(err("-5", Sat), AmountError::InvalidFormat);
+        assert_eq!(err("1 000", Sat), AmountError::InvalidFormat);
+        assert_eq!(err("1,5", Sat), AmountError::AmbiguousSeparator);
+        assert_eq!(err("1.000,50", Sat), AmountError::AmbiguousSeparator);
+        assert_eq!(err("1.000.000", Sat), AmountError::AmbiguousSeparator);
+        assert_eq!(err(",100", Sat), AmountError::AmbiguousSeparator);
+        assert_eq!(
+            err("5 bits", Sat),
+            AmountError::UnknownUnit {
+                unit: "bits".to_string()
+            }
+        );
+        assert_eq!(
+            err("1.0001 sat", Btc),
+            AmountError::TooManyDecimals {
+                unit: Sat,
+                max_decimals: 3
+            }
+        );
+        assert_eq!(
+            err("1.5", Msat),
+            AmountError::TooManyDecimals {
+                unit: Msat,
+                max_decimals: 0
+            }
+        );
+        assert_eq!(err("200000000 btc", Sat), AmountError::Overflow);
+        assert_eq!(err("99999999999999999999", Msat), AmountError::Overflow);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_generic_invalid_input() -> Result<(), Box<dyn std::error::Error>> {
         assert!(parse("invalid_input").await.is_err());
@@ -631,6 +1027,31 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_bitcoin_address_network_mismatch() -> Result<()> {
+        let address = match parse("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx").await? {
+            InputType::BitcoinAddress { address } => address,
+            _ => return Err(anyhow!("Unexpected type")),
+        };
+        assert_eq!(address.network, Network::Testnet);
+
+        let err = address.validate_network(Network::Bitcoin).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<AddressError>(),
+            Some(&AddressError::NetworkMismatch {
+                expected: Network::Bitcoin,
+                found: Network::Testnet,
+            })
+        );
+        assert!(err.to_string().contains("Testnet"));
+        assert!(err.to_string().contains("Bitcoin"));
+
+        assert!(address.validate_network(Network::Testnet).is_ok());
+        // Signet shares the testnet address encoding
+        assert!(address.validate_network(Network::Signet).is_ok());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_bitcoin_address_bip21() -> Result<()> {
         // Addresses from https://github.com/Kixunil/bip21/blob/master/src/lib.rs
@@ -713,6 +1134,26 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_bolt12_offer() -> Result<()> {
+        let bolt12 = "lno1pqps7sjqpgtyzm3qv4uxzmtsd3jjqer9wd3hy6tsw35k7msjzfpy7nz5yqcnygrfdej82um5wf5k2uckyypwa3eyt44h6txtxquqh7lz5djge4afgfjn7k4rgrkuag0jsd5xvxg";
+
+        for input in [bolt12.to_string(), format!("lightning:{bolt12}")] {
+            match parse(&input).await? {
+                InputType::Bolt12Offer { offer } => {
+                    assert_eq!(offer.bolt12, bolt12);
+                    assert_eq!(offer.description, "An example description");
+                    assert_eq!(offer.issuer, Some("BOLT 12 industries".to_string()));
+                }
+                _ => return Err(anyhow!("Unexpected type")),
+            }
+        }
+
+        // Offers are recognized by their prefix, so a malformed one is an error
+        assert!(parse("lno1qqqq").await.is_err());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_bolt11_with_fallback_bitcoin_address() -> Result<()> {
         let addr = "1andreas3batLhQa2FawWjeyjCqyBzypd";
@@ -721,18 +1162,35 @@ mod tests {
         // Address and invoice
         // BOLT11 is the first URI arg (preceded by '?')
         let addr_1 = format!("bitcoin:{addr}?lightning={bolt11}");
-        assert!(matches!(
-            parse(&addr_1).await?,
-            InputType::Bolt11 { invoice: _invoice }
-        ));
+        match parse(&addr_1).await? {
+            InputType::Unified { address, invoice } => {
+                assert_eq!(address.address, addr);
+                assert_eq!(invoice.bolt11, bolt11);
+            }
+            _ => return Err(anyhow!("Unexpected type")),
+        }
 
         // Address, amount and invoice
         // BOLT11 is not the first URI arg (preceded by '&')
         let addr_2 = format!("bitcoin:{addr}?amount=0.00002000&lightning={bolt11}");
-        assert!(matches!(
-            parse(&addr_2).await?,
-            InputType::Bolt11 { invoice: _invoice }
-        ));
+        match parse(&addr_2).await? {
+            InputType::Unified { address, invoice } => {
+                assert_eq!(address.address, addr);
+                assert_eq!(address.amount_sat, Some(2000));
+                assert_eq!(invoice.bolt11, bolt11);
+            }
+            _ => return Err(anyhow!("Unexpected type")),
+        }
+
+        // Address and amount, without an invoice
+        let addr_3 = format!("bitcoin:{addr}?amount=0.00002000");
+        match parse(&addr_3).await? {
+            InputType::BitcoinAddress { address } => {
+                assert_eq!(address.address, addr);
+                assert_eq!(address.amount_sat, Some(2000));
+            }
+            _ => return Err(anyhow!("Unexpected type")),
+        }
 
         Ok(())
     }
@@ -784,6 +1242,47 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_node_connection() -> Result<()> {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[0xab; 32])?;
+        let node_id = PublicKey::from_secret_key(&secp, &secret_key).to_string();
+
+        for (address, expected_host, expected_port) in [
+            ("node.example.com:9736", "node.example.com", 9736),
+            ("203.0.113.1", "203.0.113.1", 9735),
+            ("[2001:db8::1]:9737", "2001:db8::1", 9737),
+        ] {
+            match parse(&format!("{node_id}@{address}")).await? {
+                NodeConnection {
+                    node_id: parsed_node_id,
+                    host,
+                    port,
+                } => {
+                    assert_eq!(parsed_node_id, node_id);
+                    assert_eq!(host, expected_host);
+                    assert_eq!(port, expected_port);
+                }
+                _ => return Err(anyhow!("Unexpected type")),
+            }
+        }
+
+        for address in [
+            "",
+            "node.example.com:",
+            "node.example.com:0",
+            "node.example.com:65536",
+            "-node.example.com:9735",
+            "node..example.com",
+            "[2001:db8::zz]:9735",
+            "[2001:db8::1]9735",
+        ] {
+            assert!(parse(&format!("{node_id}@{address}")).await.is_err());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_lnurl_pay_lud_01() -> Result<()> {
         // Covers cases in LUD-01: Base LNURL encoding and decoding
@@ -899,6 +1398,54 @@ mod tests {
         Ok(())
     }
 
+    fn mock_lnurl_channel_endpoint(path: &str) -> Mock {
+        let expected_lnurl_channel_data = r#"
+{
+    "tag":"channelRequest",
+    "uri":"03864ef025fde8fb587d989186ce6a4a186895ee44a926bfc370e2c366597a3f8f@3.33.236.230:9735",
+    "callback":"https://localhost/lnurl-channel/callback/6b4bbe5f1e37c0e0ae8e5d7a4e4fcbb89e9db9c8f4f0b1e2d3c4b5a697887766",
+    "k1":"8e4b4b1e6f7a3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d"
+}
+        "#.replace('\n', "");
+
+        mockito::mock("GET", path)
+            .with_body(expected_lnurl_channel_data)
+            .create()
+    }
+
+    #[tokio::test]
+    async fn test_lnurl_channel_lud_02() -> Result<(), Box<dyn std::error::Error>> {
+        // Covers cases in LUD-02: channelRequest base spec
+        // https://github.com/lnurl/luds/blob/luds/02.md
+
+        let path =
+            "/lnurl-channel?session=c2a1b5e2bd4a1c3ae4d9d2e8cda3b9d6f7e84a0b1c2d3e4f5a6b7c8d9e0f1a2b";
+        let _m = mock_lnurl_channel_endpoint(path);
+
+        let lnurl_channel_encoded = "lnurl1dp68gurn8ghj7mr0vdskc6r0wd6z7mrww4excttrdpskumn9dslhxetnwd5k7m3avvexzvtzx4jnycnyx3snzcenv9jngepevsex2wrrv3snxc3evsmxvdm98q6xzvrzx93nyepnv56xvdtpxe3rwcecvsuk2vrxx9snycsf2yw68";
+        assert_eq!(
+            lnurl_decode(lnurl_channel_encoded)?,
+            (
+                "localhost".into(),
+                format!("https://localhost{path}"),
+                false
+            )
+        );
+
+        if let LnUrlChannel { data: cd } = parse(lnurl_channel_encoded).await? {
+            assert_eq!(cd.uri, "03864ef025fde8fb587d989186ce6a4a186895ee44a926bfc370e2c366597a3f8f@3.33.236.230:9735");
+            assert_eq!(cd.callback, "https://localhost/lnurl-channel/callback/6b4bbe5f1e37c0e0ae8e5d7a4e4fcbb89e9db9c8f4f0b1e2d3c4b5a697887766");
+            assert_eq!(
+                cd.k1,
+                "8e4b4b1e6f7a3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d"
+            );
+        } else {
+            panic!("Expected a LnUrlChannel result");
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_lnurl_auth_lud_04() -> Result<()> {
         // Covers cases in LUD-04: `auth` base spec
@@ -1100,6 +1647,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_lnurl_pay_extract_metadata() -> Result<()> {
+        let pay_req_data = |metadata_str: &str| LnUrlPayRequestData {
+            callback: "https://localhost/callback".into(),
+            min_sendable: 1000,
+            max_sendable: 1000,
+            metadata_str: metadata_str.into(),
+            comment_allowed: 0,
+            domain: "localhost".into(),
+            ln_address: None,
+        };
+
+        let metadata = pay_req_data(r#"[["text/plain","desc"],["text/identifier","a@b.c"]]"#)
+            .extract_metadata()?;
+        assert_eq!(
+            metadata,
+            ParsedMetadata {
+                description: "desc".into(),
+                long_description: None,
+                image: None,
+            }
+        );
+
+        let metadata = pay_req_data(
+            r#"[["text/plain","desc"],["text/long-desc","long desc"],["image/png;base64","iVBORw0KGgo="]]"#,
+        )
+        .extract_metadata()?;
+        assert_eq!(metadata.long_description, Some("long desc".into()));
+        assert_eq!(
+            metadata.image,
+            Some(vec![0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a])
+        );
+
+        assert!(pay_req_data(r#"[["text/long-desc","long desc"]]"#)
+            .extract_metadata()
+            .is_err());
+        assert!(
+            pay_req_data(r#"[["text/plain","desc"],["image/jpeg;base64","not base64!"]]"#)
+                .extract_metadata()
+                .is_err()
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_lnurl_pay_lud_16_ln_address() -> Result<(), Box<dyn std::error::Error>> {
         // Covers cases in LUD-16: Paying to static internet identifiers (LN Address)
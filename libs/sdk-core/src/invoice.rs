@@ -1,12 +1,32 @@
 use anyhow::{anyhow, Result};
+use bitcoin::hashes::sha256;
 use bitcoin::secp256k1::PublicKey;
+use bitcoin::util::address::{Address, Payload};
 use hex::ToHex;
+use lightning::offers::offer::Offer;
 use lightning::routing::gossip::RoutingFees;
 use lightning::routing::*;
 use lightning_invoice::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use std::str::FromStr;
-use std::time::UNIX_EPOCH;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::input_parser::validate_address_network;
+use crate::models::Network;
+
+/// Maximum length, in bytes, of a BOLT11 invoice description
+pub const MAX_DESCRIPTION_LENGTH: usize = 639;
+
+/// Seconds an invoice is still considered valid after its expiry, to tolerate clock skew
+pub const EXPIRY_CLOCK_SKEW_SECS: u64 = 60;
+
+/// Minimum expiry, in seconds, of the invoices created by [crate::BreezServices::receive_payment]
+pub const MIN_INVOICE_EXPIRY_SECS: u32 = 60;
+
+/// Maximum expiry, in seconds, of the invoices created by [crate::BreezServices::receive_payment]
+pub const MAX_INVOICE_EXPIRY_SECS: u32 = 7 * 24 * 60 * 60;
 
 /// Wrapper for a BOLT11 LN invoice
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -21,8 +41,97 @@ pub struct LNInvoice {
     pub expiry: u64,
     pub routing_hints: Vec<RouteHint>,
     pub payment_secret: Vec<u8>,
+    /// The network the invoice was created for, as encoded in its human readable part
+    pub network: Network,
 }
 
+impl LNInvoice {
+    /// Whether the invoice lets the payer choose the amount
+    pub fn is_amountless(&self) -> bool {
+        self.amount_msat.is_none()
+    }
+
+    /// Unix timestamp, in seconds, at which the invoice expires
+    pub fn expires_at(&self) -> u64 {
+        self.timestamp.saturating_add(self.expiry)
+    }
+
+    /// Whether the invoice has expired, allowing for [EXPIRY_CLOCK_SKEW_SECS] of clock skew
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        self.is_expired_at(now)
+    }
+
+    pub(crate) fn is_expired_at(&self, now: u64) -> bool {
+        now > self.expires_at().saturating_add(EXPIRY_CLOCK_SKEW_SECS)
+    }
+
+    /// Fails with [InvoiceError::Expired] if the invoice can no longer be paid
+    pub fn validate_not_expired(&self) -> Result<(), InvoiceError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        self.validate_not_expired_at(now)
+    }
+
+    pub(crate) fn validate_not_expired_at(&self, now: u64) -> Result<(), InvoiceError> {
+        if self.is_expired_at(now) {
+            return Err(InvoiceError::Expired {
+                expires_at: self.expires_at(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Fails with [InvoiceError::NetworkMismatch] if the invoice is not for the `expected` network
+    pub fn validate_network(&self, expected: Network) -> Result<(), InvoiceError> {
+        if self.network != expected {
+            return Err(InvoiceError::NetworkMismatch {
+                expected,
+                found: self.network,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Errors returned when an invoice can't be used by this node
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvoiceError {
+    /// The invoice was created for a different network than the one the SDK is configured for
+    NetworkMismatch { expected: Network, found: Network },
+    /// The invoice expired at the given unix timestamp
+    Expired { expires_at: u64 },
+    /// The invoice was created by this node, paying it would only route funds back to ourselves
+    SelfPaymentNotAllowed,
+}
+
+impl fmt::Display for InvoiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvoiceError::NetworkMismatch { expected, found } => write!(
+                f,
+                "Invoice is for network {found:?}, but the node is configured for {expected:?}"
+            ),
+            InvoiceError::Expired { expires_at } => {
+                write!(f, "Invoice expired at {expires_at}")
+            }
+            InvoiceError::SelfPaymentNotAllowed => {
+                write!(
+                    f,
+                    "Invoice was created by this node and can't be paid by it"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvoiceError {}
+
 /// Details of a specific hop in a larger route hint
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RouteHintHop {
@@ -90,26 +199,38 @@ impl RouteHint {
     }
 }
 
+/// Rebuilds `invoice` with the `lsp_hint` and the `new_amount_msats`, or without an amount if
+/// `new_amount_msats` is `None`
+///
+/// With a `description_hash`, the rebuilt invoice commits to the hash instead of the original
+/// description.
 pub fn add_lsp_routing_hints(
     invoice: String,
     lsp_hint: Option<RouteHint>,
-    new_amount_msats: u64,
+    new_amount_msats: Option<u64>,
+    description_hash: Option<sha256::Hash>,
+    expiry_secs: Option<u32>,
 ) -> Result<RawInvoice> {
     let signed = invoice.parse::<SignedRawInvoice>()?;
     let invoice = Invoice::from_signed(signed)?;
-    let description = match invoice.description() {
-        InvoiceDescription::Direct(msg) => msg.to_string(),
-        InvoiceDescription::Hash(_) => String::from(""),
+    let invoice_builder = InvoiceBuilder::new(invoice.currency());
+    let invoice_builder = match (description_hash, invoice.description()) {
+        (Some(hash), _) => invoice_builder.description_hash(hash),
+        (None, InvoiceDescription::Direct(msg)) => invoice_builder.description(msg.to_string()),
+        (None, InvoiceDescription::Hash(hash)) => invoice_builder.description_hash(hash.0),
     };
 
-    let mut invoice_builder = InvoiceBuilder::new(invoice.currency())
-        .description(description)
+    let mut invoice_builder = invoice_builder
         .payment_hash(*invoice.payment_hash())
         .timestamp(invoice.timestamp())
-        .amount_milli_satoshis(new_amount_msats)
-        .expiry_time(invoice.expiry_time())
+        .expiry_time(expiry_secs.map_or(invoice.expiry_time(), |secs| {
+            Duration::from_secs(secs.into())
+        }))
         .payment_secret(*invoice.payment_secret())
         .min_final_cltv_expiry_delta(invoice.min_final_cltv_expiry_delta());
+    if let Some(new_amount_msats) = new_amount_msats {
+        invoice_builder = invoice_builder.amount_milli_satoshis(new_amount_msats);
+    }
 
     // We make sure the hint we add does not conflict with other hints.
     // The lsp hint takes priority so in case the lsp hop is already in one of the existing hints
@@ -149,6 +270,98 @@ pub fn add_lsp_routing_hints(
     }
 }
 
+/// Parses the on-chain fallback `address` of an invoice, which has to be a segwit address valid
+/// for `network`
+pub(crate) fn parse_fallback_address(address: &str, network: Network) -> Result<Address> {
+    validate_address_network(address, network)?;
+    let address = Address::from_str(address)?;
+    if !matches!(address.payload, Payload::WitnessProgram { .. }) {
+        return Err(anyhow!("Only segwit fallback addresses are supported"));
+    }
+    Ok(address)
+}
+
+/// Adds `address` as on-chain fallback of the unsigned `invoice`, for payers who prefer paying
+/// on-chain. Only segwit addresses are supported.
+pub(crate) fn add_fallback_address(
+    mut invoice: RawInvoice,
+    address: &Address,
+) -> Result<RawInvoice> {
+    let fallback = match &address.payload {
+        Payload::WitnessProgram { version, program } => Fallback::SegWitProgram {
+            version: *version,
+            program: program.clone(),
+        },
+        _ => return Err(anyhow!("Only segwit fallback addresses are supported")),
+    };
+    invoice
+        .data
+        .tagged_fields
+        .push(RawTaggedField::KnownSemantics(TaggedField::Fallback(
+            fallback,
+        )));
+    Ok(invoice)
+}
+
+impl From<Currency> for Network {
+    fn from(currency: Currency) -> Self {
+        match currency {
+            Currency::Bitcoin => Network::Bitcoin,
+            Currency::BitcoinTestnet => Network::Testnet,
+            Currency::Signet => Network::Signet,
+            Currency::Regtest | Currency::Simnet => Network::Regtest,
+        }
+    }
+}
+
+/// Wrapper for a BOLT12 offer
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LNOffer {
+    pub bolt12: String,
+    pub signing_pubkey: String,
+    pub description: String,
+    /// The amount to pay, in bitcoin or in a fiat currency. Offers without an amount accept any.
+    pub amount: Option<OfferAmount>,
+    pub issuer: Option<String>,
+}
+
+/// The amount of an [LNOffer]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OfferAmount {
+    Bitcoin {
+        amount_msat: u64,
+    },
+    /// An amount in the smallest unit of an ISO 4217 currency, for example cents for USD
+    Currency {
+        iso4217_code: String,
+        fractional_amount: u64,
+    },
+}
+
+/// Parse a BOLT12 offer, starting with `lno1`, and return a structure containing its fields.
+pub fn parse_offer(bolt12: &str) -> Result<LNOffer> {
+    let offer = Offer::from_str(bolt12).map_err(|e| anyhow!("Invalid BOLT12 offer: {e:?}"))?;
+    let amount = offer.amount().map(|amount| match amount {
+        lightning::offers::offer::Amount::Bitcoin { amount_msats } => OfferAmount::Bitcoin {
+            amount_msat: *amount_msats,
+        },
+        lightning::offers::offer::Amount::Currency {
+            iso4217_code,
+            amount,
+        } => OfferAmount::Currency {
+            iso4217_code: String::from_utf8_lossy(iso4217_code).to_string(),
+            fractional_amount: *amount,
+        },
+    });
+    Ok(LNOffer {
+        bolt12: bolt12.to_string(),
+        signing_pubkey: offer.signing_pubkey().serialize().encode_hex::<String>(),
+        description: offer.description().to_string(),
+        amount,
+        issuer: offer.issuer().map(|issuer| issuer.to_string()),
+    })
+}
+
 /// Parse a BOLT11 payment request and return a structure contains the parsed fields.
 pub fn parse_invoice(bolt11: &str) -> Result<LNInvoice> {
     let signed = bolt11
@@ -192,13 +405,49 @@ pub fn parse_invoice(bolt11: &str) -> Result<LNInvoice> {
             InvoiceDescription::Direct(_) => None,
             InvoiceDescription::Hash(h) => Some(h.0.to_string()),
         },
+        network: invoice.currency().into(),
     };
     Ok(ln_invoice)
 }
 
+/// Renders an invoice description template by replacing every `{key}` placeholder with the
+/// corresponding value from `values`.
+///
+/// Fails if a placeholder has no matching value, if a brace is left unclosed, or if the
+/// rendered description exceeds [MAX_DESCRIPTION_LENGTH].
+pub fn render_description_template(
+    template: &str,
+    values: &HashMap<String, String>,
+) -> Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| anyhow!("Unclosed placeholder in description template"))?;
+        let key = &rest[start + 1..start + end];
+        let value = values
+            .get(key)
+            .ok_or_else(|| anyhow!("No value provided for placeholder {{{key}}}"))?;
+        rendered.push_str(value);
+        rest = &rest[start + end + 1..];
+    }
+    rendered.push_str(rest);
+
+    if rendered.len() > MAX_DESCRIPTION_LENGTH {
+        return Err(anyhow!(
+            "Description is {} bytes long, maximum allowed is {MAX_DESCRIPTION_LENGTH}",
+            rendered.len()
+        ));
+    }
+    Ok(rendered)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::invoice::*;
+    use crate::test_utils::{create_invoice, create_invoice_for_currency};
 
     #[test]
     fn test_parse_invoice() {
@@ -223,7 +472,131 @@ mod tests {
             hops: vec![hint_hop],
         };
 
-        let encoded = add_lsp_routing_hints(payreq, Some(route_hint), 100).unwrap();
+        let encoded =
+            add_lsp_routing_hints(payreq, Some(route_hint), Some(100), None, None).unwrap();
         print!("{encoded:?}");
     }
+
+    #[test]
+    fn test_parse_offer() {
+        // Example offer of the BOLT12 specification
+        let bolt12 = "lno1pqps7sjqpgtyzm3qv4uxzmtsd3jjqer9wd3hy6tsw35k7msjzfpy7nz5yqcnygrfdej82um5wf5k2uckyypwa3eyt44h6txtxquqh7lz5djge4afgfjn7k4rgrkuag0jsd5xvxg";
+        let offer = parse_offer(bolt12).unwrap();
+        assert_eq!(
+            offer,
+            LNOffer {
+                bolt12: bolt12.to_string(),
+                signing_pubkey:
+                    "02eec7245d6b7d2ccb30380bfbe2a3648cd7a942653f5aa340edcea1f283686619".to_string(),
+                description: "An example description".to_string(),
+                amount: Some(OfferAmount::Bitcoin {
+                    amount_msat: 1_000_000
+                }),
+                issuer: Some("BOLT 12 industries".to_string()),
+            }
+        );
+
+        assert!(parse_offer("lno1invalid").is_err());
+    }
+
+    #[test]
+    fn test_add_fallback_address() {
+        let invoice = create_invoice("test".into(), 1000, vec![], None);
+        let raw = add_lsp_routing_hints(invoice.bolt11, None, None, None, None).unwrap();
+        let address = Address::from_str("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq").unwrap();
+
+        let with_fallback = add_fallback_address(raw.clone(), &address).unwrap();
+        match with_fallback.fallbacks()[..] {
+            [Fallback::SegWitProgram { version, program }] => {
+                let fallback_address = Address {
+                    payload: Payload::WitnessProgram {
+                        version: *version,
+                        program: program.clone(),
+                    },
+                    network: address.network,
+                };
+                assert_eq!(fallback_address, address);
+            }
+            _ => panic!("Expected a single segwit fallback"),
+        }
+
+        // Legacy addresses aren't supported
+        let legacy = Address::from_str("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").unwrap();
+        assert!(add_fallback_address(raw, &legacy).is_err());
+    }
+
+    #[test]
+    fn test_parse_fallback_address() {
+        let segwit = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        assert_eq!(
+            parse_fallback_address(segwit, Network::Bitcoin).unwrap(),
+            Address::from_str(segwit).unwrap()
+        );
+        assert!(parse_fallback_address(segwit, Network::Testnet).is_err());
+        assert!(
+            parse_fallback_address("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", Network::Bitcoin).is_err()
+        );
+        assert!(parse_fallback_address("not an address", Network::Bitcoin).is_err());
+    }
+
+    #[test]
+    fn test_render_description_template() {
+        let values = HashMap::from([
+            ("id".to_string(), "42".to_string()),
+            ("store".to_string(), "Corner Cafe".to_string()),
+        ]);
+        let rendered = render_description_template("Order #{id} at {store}", &values).unwrap();
+        assert_eq!(rendered, "Order #42 at Corner Cafe");
+
+        assert!(render_description_template("Order #{id} for {customer}", &values).is_err());
+        assert!(render_description_template("Order #{id", &values).is_err());
+
+        let long_values = HashMap::from([("id".to_string(), "x".repeat(MAX_DESCRIPTION_LENGTH))]);
+        assert!(render_description_template("Order #{id}", &long_values).is_err());
+    }
+
+    #[test]
+    fn test_invoice_expiry() {
+        let mut invoice = create_invoice("test".into(), 1000, vec![], None);
+        invoice.timestamp = 1_000_000;
+        invoice.expiry = 3600;
+        assert_eq!(invoice.expires_at(), 1_003_600);
+
+        assert!(!invoice.is_expired_at(1_000_000));
+        assert!(!invoice.is_expired_at(1_003_600));
+        // Within the clock skew tolerance
+        assert!(!invoice.is_expired_at(1_003_600 + EXPIRY_CLOCK_SKEW_SECS));
+        assert!(invoice.is_expired_at(1_003_601 + EXPIRY_CLOCK_SKEW_SECS));
+
+        assert!(invoice.is_expired());
+        assert_eq!(
+            invoice.validate_not_expired(),
+            Err(InvoiceError::Expired {
+                expires_at: 1_003_600
+            })
+        );
+    }
+
+    #[test]
+    fn test_invoice_network() {
+        let mainnet = create_invoice("test".into(), 1000, vec![], None);
+        assert_eq!(mainnet.network, Network::Bitcoin);
+        assert!(mainnet.validate_network(Network::Bitcoin).is_ok());
+
+        let testnet = create_invoice_for_currency(
+            Currency::BitcoinTestnet,
+            "test".into(),
+            1000,
+            vec![],
+            None,
+        );
+        assert_eq!(testnet.network, Network::Testnet);
+        assert_eq!(
+            testnet.validate_network(Network::Bitcoin),
+            Err(InvoiceError::NetworkMismatch {
+                expected: Network::Bitcoin,
+                found: Network::Testnet,
+            })
+        );
+    }
 }
@@ -23,7 +23,7 @@
 //!
 //! let creds = BreezServices::register_node(Network::Bitcoin, seed.as_bytes().to_vec()).await?;
 //! let sdk = BreezServices::init_services(
-//!         BreezServices::default_config(EnvironmentType::Production),
+//!         BreezServices::default_config(EnvironmentType::Production, Network::Bitcoin),
 //!         seed.to_vec(),
 //!         creds.clone(),
 //!         Box::new(AppEventListener {}),
@@ -41,13 +41,13 @@
 //! We can now receive payments
 //!
 //! ```ignore
-//! let invoice = sdk.receive_payment(3000, "Invoice for 3000 sats".into()).await?;
+//! let invoice = sdk.receive_payment(3000, "Invoice for 3000 sats".into(), None, None, None, None).await?.ln_invoice;
 //! ```
 //!
 //! or make payments
 //! ```ignore
 //! let bolt11 = "...";
-//! sdk.send_payment(bolt11.into(), Some(3000)).await?;
+//! sdk.send_payment(bolt11.into(), Some(3000), None, None).await?;
 //! ```
 //!
 //! At any point we can fetch our balance from the Greenlight node
@@ -143,6 +143,7 @@ extern crate log;
 
 pub mod binding;
 mod breez_services;
+mod buy;
 mod chain;
 mod crypt;
 mod fiat;
@@ -160,17 +161,26 @@ mod swap;
 mod test_utils;
 
 pub use breez_services::{
-    mnemonic_to_seed, BreezEvent, BreezServices, EventListener, InvoicePaidDetails,
-    PaymentFailedData,
+    mnemonic_to_seed, BreezEvent, BreezServices, EventListener, InitServicesError,
+    InvoicePaidDetails, PaymentError, PaymentFailedData, PaymentPendingData, ReceivePaymentError,
+};
+pub use chain::{FeeTier, RecommendedFees};
+pub use fiat::{
+    CurrencyInfo, CurrencySymbolEntry, FiatCurrency, LocaleOverrides, LocalizedName, Rate, Symbol,
 };
-pub use chain::RecommendedFees;
-pub use fiat::{CurrencyInfo, FiatCurrency, LocaleOverrides, LocalizedName, Rate, Symbol};
 pub use input_parser::{
-    parse, BitcoinAddressData, InputType, LnUrlAuthRequestData, LnUrlErrorData,
-    LnUrlPayRequestData, LnUrlRequestData, LnUrlWithdrawRequestData, MetadataItem,
+    parse, parse_amount, AddressError, AmountError, AmountUnit, BitcoinAddressData, InputType,
+    LnUrlAuthRequestData, LnUrlChannelRequestData, LnUrlErrorData, LnUrlPayRequestData,
+    LnUrlRequestData, LnUrlWithdrawRequestData, MetadataItem, ParsedMetadata,
+};
+pub use invoice::{
+    parse_invoice, parse_offer, InvoiceError, LNInvoice, LNOffer, OfferAmount, RouteHint,
+    RouteHintHop,
 };
-pub use invoice::{parse_invoice, LNInvoice, RouteHint, RouteHintHop};
 
+pub use lnurl::auth::derive_linking_key;
 pub use lnurl::pay::model::*;
+pub use lnurl::withdraw::LnUrlWithdrawError;
 pub use lsp::LspInformation;
 pub use models::*;
+pub use persist::error::{map_storage_error, StorageError};
@@ -1,8 +1,8 @@
 use crate::{LnUrlAuthRequestData, LnUrlCallbackStatus, NodeAPI};
 use anyhow::{anyhow, Result};
 use bitcoin::hashes::{hex::ToHex, sha256, Hash, HashEngine, Hmac, HmacEngine};
-use bitcoin::secp256k1::{Message, Secp256k1};
-use bitcoin::util::bip32::ChildNumber;
+use bitcoin::secp256k1::{Message, Secp256k1, SecretKey};
+use bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey};
 use bitcoin::KeyPair;
 use reqwest::Url;
 use std::str::FromStr;
@@ -83,24 +83,47 @@ fn hmac_sha256(key: &[u8], input: &[u8]) -> Hmac<sha256::Hash> {
 /// https://github.com/lnurl/luds/blob/luds/05.md
 fn derive_linking_keys(node_api: Arc<dyn NodeAPI>, url: Url) -> Result<KeyPair> {
     let domain = url.domain().ok_or(anyhow!("Could not determine domain"))?;
+    let linking_key = derive_linking_key_with(domain, |path| node_api.derive_bip32_key(path))?;
 
+    Ok(linking_key.to_keypair(&Secp256k1::new()))
+}
+
+/// Derives the LUD-05 linking key for `domain` from the wallet `seed`.
+///
+/// This is the key [crate::BreezServices::lnurl_auth] signs with, exposed so the derivation can
+/// be checked independently.
+///
+/// https://github.com/lnurl/luds/blob/luds/05.md
+pub fn derive_linking_key(domain: &str, seed: &[u8]) -> Result<SecretKey> {
+    let secp = Secp256k1::new();
+    let master = ExtendedPrivKey::new_master(bitcoin::Network::Bitcoin, seed)?;
+    let linking_key = derive_linking_key_with(domain, |path| {
+        master.derive_priv(&secp, &path).map_err(|e| anyhow!(e))
+    })?;
+
+    Ok(linking_key.private_key)
+}
+
+/// Applies the LUD-05 derivation scheme, using `derive` to derive a key from the wallet master key
+fn derive_linking_key_with<F>(domain: &str, derive: F) -> Result<ExtendedPrivKey>
+where
+    F: Fn(Vec<ChildNumber>) -> Result<ExtendedPrivKey>,
+{
     // m/138'/0
-    let hashing_key = node_api.derive_bip32_key(vec![
+    let hashing_key = derive(vec![
         ChildNumber::from_hardened_idx(138)?,
         ChildNumber::from(0),
     ])?;
     let hmac = hmac_sha256(&hashing_key.to_priv().to_bytes(), domain.as_bytes());
 
     // m/138'/<long1>/<long2>/<long3>/<long4>
-    let linking_key = node_api.derive_bip32_key(vec![
+    derive(vec![
         ChildNumber::from_hardened_idx(138)?,
         ChildNumber::from(build_path_element_u32(hmac[0..4].try_into()?)),
         ChildNumber::from(build_path_element_u32(hmac[4..8].try_into()?)),
         ChildNumber::from(build_path_element_u32(hmac[8..12].try_into()?)),
         ChildNumber::from(build_path_element_u32(hmac[12..16].try_into()?)),
-    ])?;
-
-    Ok(linking_key.to_keypair(&Secp256k1::new()))
+    ])
 }
 
 fn build_path_element_u32(hmac_bytes: [u8; 4]) -> u32 {
@@ -108,3 +131,60 @@ fn build_path_element_u32(hmac_bytes: [u8; 4]) -> u32 {
     buf[..4].copy_from_slice(&hmac_bytes);
     u32::from_be_bytes(buf)
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use bitcoin::secp256k1::Secp256k1;
+    use bitcoin::util::bip32::{ChildNumber, DerivationPath, ExtendedPrivKey};
+    use std::str::FromStr;
+
+    use crate::lnurl::auth::*;
+
+    #[test]
+    fn test_derive_linking_key() -> Result<()> {
+        let seed = [7u8; 32];
+        let domain = "site.com";
+        let linking_key = derive_linking_key(domain, &seed)?;
+
+        // Same steps as LUD-05, spelled out with an explicit derivation path:
+        // the first 16 bytes of HMAC-SHA256(hashingKey, domain) become four big-endian u32
+        // path elements, which are used as-is (no hardening bit is added or removed)
+        let secp = Secp256k1::new();
+        let master = ExtendedPrivKey::new_master(bitcoin::Network::Bitcoin, &seed)?;
+        let hashing_key = master.derive_priv(&secp, &DerivationPath::from_str("m/138'/0")?)?;
+        let hmac = hmac_sha256(&hashing_key.private_key.secret_bytes(), domain.as_bytes());
+        let path: Vec<ChildNumber> = [ChildNumber::from_hardened_idx(138)?]
+            .into_iter()
+            .chain(
+                hmac[0..16]
+                    .chunks(4)
+                    .map(|c| ChildNumber::from(u32::from_be_bytes(c.try_into().unwrap()))),
+            )
+            .collect();
+        let expected = master.derive_priv(&secp, &path)?.private_key;
+        assert_eq!(linking_key, expected);
+
+        // Linking keys are deterministic, but differ per domain and per seed
+        assert_eq!(derive_linking_key(domain, &seed)?, linking_key);
+        assert_ne!(derive_linking_key("other.com", &seed)?, linking_key);
+        assert_ne!(derive_linking_key(domain, &[8u8; 32])?, linking_key);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_path_element_u32() {
+        // Path elements keep their full u32 value, including those above 2^31
+        for bytes in [
+            [0, 0, 0, 1],
+            [0x7f, 0xff, 0xff, 0xff],
+            [0x80, 0, 0, 0],
+            [0xff; 4],
+        ] {
+            let n = build_path_element_u32(bytes);
+            assert_eq!(n, u32::from_be_bytes(bytes));
+            assert_eq!(u32::from(ChildNumber::from(n)), n);
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::input_parser::*;
 use crate::invoice::parse_invoice;
 use crate::lnurl::maybe_replace_host_with_mockito_test_host;
-use crate::lnurl::pay::model::{CallbackResponse, SuccessAction, ValidatedCallbackResponse};
+use crate::lnurl::pay::model::{
+    CallbackResponse, LnUrlPayError, SuccessAction, ValidatedCallbackResponse,
+};
 use crate::LnUrlErrorData;
 use anyhow::{anyhow, Result};
 use bitcoin::hashes::{sha256, Hash};
@@ -20,7 +22,7 @@ pub(crate) async fn validate_lnurl_pay(
     req_data: LnUrlPayRequestData,
 ) -> Result<ValidatedCallbackResponse> {
     validate_user_input(
-        user_amount_sat * 1000,
+        user_amount_sat,
         &comment,
         req_data.min_sendable,
         req_data.max_sendable,
@@ -68,27 +70,29 @@ fn build_pay_callback_url(
 }
 
 fn validate_user_input(
-    user_amount_msat: u64,
+    user_amount_sat: u64,
     comment: &Option<String>,
     condition_min_amount_msat: u64,
     condition_max_amount_msat: u64,
     condition_max_comment_len: u16,
 ) -> Result<()> {
-    if user_amount_msat < condition_min_amount_msat {
-        return Err(anyhow!("Amount is smaller than the minimum allowed"));
-    }
-
-    if user_amount_msat > condition_max_amount_msat {
-        return Err(anyhow!("Amount is bigger than the maximum allowed"));
+    // The user can only choose whole sats, so the limits are rounded inwards: the smallest
+    // payable amount is the min rounded up and the largest is the max rounded down
+    let min = condition_min_amount_msat / 1000 + u64::from(condition_min_amount_msat % 1000 != 0);
+    let max = condition_max_amount_msat / 1000;
+    if user_amount_sat < min || user_amount_sat > max {
+        return Err(LnUrlPayError::AmountOutOfRange { min, max }.into());
     }
 
+    // The comment length is counted in characters, as per LUD-12
     match comment {
         None => Ok(()),
-        Some(msg) => match msg.len() <= condition_max_comment_len as usize {
+        Some(msg) => match msg.chars().count() <= condition_max_comment_len as usize {
             true => Ok(()),
-            false => Err(anyhow!(
-                "Comment is longer than the maximum allowed comment length"
-            )),
+            false => Err(LnUrlPayError::CommentTooLong {
+                max: condition_max_comment_len,
+            }
+            .into()),
         },
     }
 }
@@ -125,10 +129,42 @@ fn validate_invoice(
 pub(crate) mod model {
     use crate::input_parser::*;
     use crate::lnurl::pay::{Aes256CbcDec, Aes256CbcEnc};
+    use crate::Payment;
 
     use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
     use anyhow::{anyhow, Result};
     use serde::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// Errors returned when the user input doesn't meet the conditions of the LNURL-pay request
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum LnUrlPayError {
+        /// The comment is longer than [LnUrlPayRequestData::comment_allowed] characters. A `max`
+        /// of 0 means the endpoint doesn't accept comments.
+        CommentTooLong { max: u16 },
+        /// The amount is outside of the range accepted by the LNURL-pay endpoint, see
+        /// [LnUrlPayRequestData::min_sendable] and [LnUrlPayRequestData::max_sendable]. The
+        /// limits are in sats, rounded to the whole sats that fall within the endpoint limits.
+        AmountOutOfRange { min: u64, max: u64 },
+    }
+
+    impl fmt::Display for LnUrlPayError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                LnUrlPayError::CommentTooLong { max } => write!(
+                    f,
+                    "Comment is longer than the maximum allowed comment length of {max}"
+                ),
+                LnUrlPayError::AmountOutOfRange { min, max } => write!(
+                    f,
+                    "Amount is outside of the range allowed by the LNURL-pay endpoint: \
+                     {min} to {max} sat"
+                ),
+            }
+        }
+    }
+
+    impl std::error::Error for LnUrlPayError {}
 
     pub(crate) enum ValidatedCallbackResponse {
         EndpointSuccess { data: CallbackResponse },
@@ -139,7 +175,7 @@ pub(crate) mod model {
     ///
     /// * `EndpointSuccess` indicates the payment is complete. The endpoint may return a `SuccessActionProcessed`,
     /// in which case, the wallet has to present it to the user as described in
-    /// <https://github.com/lnurl/luds/blob/luds/09.md>
+    /// <https://github.com/lnurl/luds/blob/luds/09.md>. The `payment` is the settled payment record.
     ///
     /// * `EndpointError` indicates a generic issue the LNURL endpoint encountered, including a freetext
     /// field with the reason.
@@ -147,6 +183,7 @@ pub(crate) mod model {
     pub enum LnUrlPayResult {
         EndpointSuccess {
             data: Option<SuccessActionProcessed>,
+            payment: Payment,
         },
         EndpointError {
             data: LnUrlErrorData,
@@ -163,7 +200,7 @@ pub(crate) mod model {
     /// Payload of the AES success action, as received from the LNURL endpoint
     ///
     /// See [AesSuccessActionDataDecrypted] for a similar wrapper containing the decrypted payload
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Clone)]
     pub struct AesSuccessActionData {
         /// Contents description, up to 144 characters
         pub description: String,
@@ -185,6 +222,29 @@ pub(crate) mod model {
         pub plaintext: String,
     }
 
+    /// Outcome of decrypting an [AesSuccessActionData] payload
+    ///
+    /// Serialized untagged, so decrypted payloads keep the format they were stored with before
+    /// decryption failures were represented.
+    #[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
+    #[serde(untagged)]
+    pub enum AesDecryptedOrError {
+        /// See [AesSuccessActionDataDecrypted]
+        Decrypted {
+            #[serde(flatten)]
+            data: AesSuccessActionDataDecrypted,
+        },
+
+        /// The payload couldn't be decrypted, for example because the key was wrong. The original
+        /// payload is kept so its description can still be shown.
+        ErrorStatus {
+            reason: String,
+            description: String,
+            ciphertext: String,
+            iv: String,
+        },
+    }
+
     #[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
     pub struct MessageSuccessActionData {
         pub message: String,
@@ -203,8 +263,8 @@ pub(crate) mod model {
     pub enum SuccessActionProcessed {
         /// See [SuccessAction::Aes] for received payload
         ///
-        /// See [AesSuccessActionDataDecrypted] for decrypted payload
-        Aes { data: AesSuccessActionDataDecrypted },
+        /// See [AesDecryptedOrError] for the decrypted payload, or the reason decryption failed
+        Aes { data: AesDecryptedOrError },
 
         /// See [SuccessAction::Message]
         Message { data: MessageSuccessActionData },
@@ -289,6 +349,21 @@ pub(crate) mod model {
         }
     }
 
+    impl From<(AesSuccessActionData, &[u8; 32])> for AesDecryptedOrError {
+        fn from(value: (AesSuccessActionData, &[u8; 32])) -> Self {
+            let (data, key) = value;
+            match AesSuccessActionDataDecrypted::try_from((data.clone(), key)) {
+                Ok(decrypted) => AesDecryptedOrError::Decrypted { data: decrypted },
+                Err(err) => AesDecryptedOrError::ErrorStatus {
+                    reason: err.to_string(),
+                    description: data.description,
+                    ciphertext: data.ciphertext,
+                    iv: data.iv,
+                },
+            }
+        }
+    }
+
     impl MessageSuccessActionData {
         pub fn validate(&self) -> Result<()> {
             match self.message.len() <= 144 {
@@ -337,8 +412,10 @@ mod tests {
     use crate::lnurl::pay::*;
     use crate::{breez_services::tests::get_dummy_node_state, lnurl::pay::model::*};
 
+    use crate::PaymentDetails;
     use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
     use anyhow::{anyhow, Result};
+    use bitcoin::hashes::hex::ToHex;
     use mockito::Mock;
     use rand::Rng;
 
@@ -538,12 +615,62 @@ mod tests {
 
     #[test]
     fn test_lnurl_pay_validate_input() -> Result<()> {
-        assert!(validate_user_input(100, &None, 0, 100, 0).is_ok());
-        assert!(validate_user_input(100, &Some("test".into()), 0, 100, 5).is_ok());
+        assert!(validate_user_input(100, &None, 0, 100_000, 0).is_ok());
+        assert!(validate_user_input(100, &Some("test".into()), 0, 100_000, 5).is_ok());
+
+        assert!(validate_user_input(5, &None, 10_000, 100_000, 5).is_err());
+        assert!(validate_user_input(200, &None, 10_000, 100_000, 5).is_err());
+        assert!(validate_user_input(100, &Some("test".into()), 10_000, 100_000, 0).is_err());
 
-        assert!(validate_user_input(5, &None, 10, 100, 5).is_err());
-        assert!(validate_user_input(200, &None, 10, 100, 5).is_err());
-        assert!(validate_user_input(100, &Some("test".into()), 10, 100, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lnurl_pay_validate_amount_range() {
+        let amount_error = |amount_sat, min_msat, max_msat| {
+            validate_user_input(amount_sat, &None, min_msat, max_msat, 0)
+                .err()
+                .and_then(|e| e.downcast_ref::<LnUrlPayError>().cloned())
+        };
+        let out_of_range = |min, max| Some(LnUrlPayError::AmountOutOfRange { min, max });
+
+        // Limits in whole sats
+        assert_eq!(amount_error(9, 10_000, 100_000), out_of_range(10, 100));
+        assert_eq!(amount_error(10, 10_000, 100_000), None);
+        assert_eq!(amount_error(100, 10_000, 100_000), None);
+        assert_eq!(amount_error(101, 10_000, 100_000), out_of_range(10, 100));
+
+        // Limits with msat fractions only allow the whole sats within them
+        assert_eq!(amount_error(10, 10_001, 100_999), out_of_range(11, 100));
+        assert_eq!(amount_error(11, 10_001, 100_999), None);
+        assert_eq!(amount_error(100, 10_001, 100_999), None);
+        assert_eq!(amount_error(101, 10_001, 100_999), out_of_range(11, 100));
+
+        // A range without a whole sat in it rejects every amount
+        assert_eq!(amount_error(1, 1_500, 1_800), out_of_range(2, 1));
+        assert_eq!(amount_error(2, 1_500, 1_800), out_of_range(2, 1));
+    }
+
+    #[test]
+    fn test_lnurl_pay_validate_comment_length() -> Result<()> {
+        let too_long = |max| Some(LnUrlPayError::CommentTooLong { max });
+        let comment_error = |comment: &str, max| {
+            validate_user_input(100, &Some(comment.into()), 0, 100_000, max)
+                .err()
+                .and_then(|e| e.downcast_ref::<LnUrlPayError>().cloned())
+        };
+
+        assert_eq!(comment_error("1234", 5), None);
+        assert_eq!(comment_error("12345", 5), None);
+        assert_eq!(comment_error("123456", 5), too_long(5));
+
+        // Without comments allowed, only an empty comment passes
+        assert_eq!(comment_error("", 0), None);
+        assert_eq!(comment_error("1", 0), too_long(0));
+
+        // Characters are counted, not bytes
+        assert_eq!(comment_error("ñññññ", 5), None);
+        assert_eq!(comment_error("ññññññ", 5), too_long(5));
 
         Ok(())
     }
@@ -727,6 +854,23 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_lnurl_pay_amount_out_of_range() -> Result<()> {
+        let mock_breez_services = crate::breez_services::tests::breez_services().await?;
+
+        // Amounts out of range are rejected before the callback is called (no mock needed)
+        let err = mock_breez_services
+            .lnurl_pay(101, None, get_test_pay_req_data(10, 100, 0))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<LnUrlPayError>(),
+            Some(&LnUrlPayError::AmountOutOfRange { min: 10, max: 100 })
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_lnurl_pay_no_success_action() -> Result<()> {
         let pay_req = get_test_pay_req_data(0, 100, 0);
@@ -745,8 +889,21 @@ mod tests {
             .lnurl_pay(user_amount_sat, None, pay_req)
             .await?
         {
-            LnUrlPayResult::EndpointSuccess { data: None } => Ok(()),
-            LnUrlPayResult::EndpointSuccess { data: Some(_) } => {
+            LnUrlPayResult::EndpointSuccess {
+                data: None,
+                payment,
+            } => {
+                assert_eq!(payment.amount_msat, user_amount_sat * 1000);
+                match payment.details {
+                    PaymentDetails::Ln { data } => {
+                        assert_eq!(data.payment_hash, inv.payment_hash().to_hex());
+                        assert_eq!(data.lnurl_metadata, Some(temp_desc));
+                    }
+                    _ => return Err(anyhow!("Unexpected payment details")),
+                }
+                Ok(())
+            }
+            LnUrlPayResult::EndpointSuccess { data: Some(_), .. } => {
                 Err(anyhow!("Unexpected success action"))
             }
             _ => Err(anyhow!("Unexpected success action type")),
@@ -791,11 +948,12 @@ mod tests {
             .lnurl_pay(user_amount_sat, None, pay_req)
             .await?
         {
-            LnUrlPayResult::EndpointSuccess { data: None } => Err(anyhow!(
+            LnUrlPayResult::EndpointSuccess { data: None, .. } => Err(anyhow!(
                 "Expected success action in callback, but none provided"
             )),
             LnUrlPayResult::EndpointSuccess {
                 data: Some(SuccessActionProcessed::Message { data: msg }),
+                ..
             } => match msg.message {
                 s if s == "test msg" => Ok(()),
                 _ => Err(anyhow!("Unexpected success action message content")),
@@ -877,6 +1035,7 @@ mod tests {
         {
             LnUrlPayResult::EndpointSuccess {
                 data: Some(SuccessActionProcessed::Url { data: url }),
+                ..
             } => {
                 if url.url == "https://localhost/test-url" && url.description == "test description"
                 {
@@ -885,7 +1044,7 @@ mod tests {
                     Err(anyhow!("Unexpected success action content"))
                 }
             }
-            LnUrlPayResult::EndpointSuccess { data: None } => Err(anyhow!(
+            LnUrlPayResult::EndpointSuccess { data: None, .. } => Err(anyhow!(
                 "Expected success action in callback, but none provided"
             )),
             _ => Err(anyhow!("Unexpected success action type")),
@@ -902,7 +1061,9 @@ mod tests {
             plaintext: plaintext.clone(),
         };
         let sa = SuccessActionProcessed::Aes {
-            data: sa_data.clone(),
+            data: AesDecryptedOrError::Decrypted {
+                data: sa_data.clone(),
+            },
         };
 
         // Generate preimage
@@ -943,19 +1104,113 @@ mod tests {
         {
             LnUrlPayResult::EndpointSuccess {
                 data: Some(received_sa),
+                ..
             } => match received_sa == sa {
                 true => Ok(()),
                 false => Err(anyhow!(
                     "Decrypted payload and description doesn't match expected success action"
                 )),
             },
-            LnUrlPayResult::EndpointSuccess { data: None } => Err(anyhow!(
+            LnUrlPayResult::EndpointSuccess { data: None, .. } => Err(anyhow!(
                 "Expected success action in callback, but none provided"
             )),
             _ => Err(anyhow!("Unexpected success action type")),
         }
     }
 
+    #[tokio::test]
+    async fn test_lnurl_pay_aes_success_action_bad_key() -> Result<()> {
+        let sa_data = AesSuccessActionDataDecrypted {
+            description: "test description in AES payload".to_string(),
+            plaintext: "Hello, test plaintext".to_string(),
+        };
+        let preimage = sha256::Hash::hash(&rand_vec_u8(10));
+
+        let pay_req = get_test_pay_req_data(0, 100, 0);
+        let temp_desc = pay_req.metadata_str.clone();
+        let inv = rand_invoice_with_description_hash_and_preimage(temp_desc, preimage)?;
+
+        let user_amount_sat = inv.amount_milli_satoshis().unwrap() / 1000;
+        let bolt11 = inv.to_string();
+        // The payload is encrypted with a key that doesn't match the preimage
+        let _m = mock_lnurl_pay_callback_endpoint_aes_success_action(
+            &pay_req,
+            user_amount_sat,
+            None,
+            bolt11.clone(),
+            sa_data.clone(),
+            rand::thread_rng().gen::<[u8; 16]>(),
+            rand::thread_rng().gen::<[u8; 32]>(),
+        )?;
+
+        let mock_node_api = MockNodeAPI::new(get_dummy_node_state());
+        let model_payment = mock_node_api
+            .add_dummy_payment_for(bolt11, Some(preimage))
+            .await?;
+        let mock_breez_services = crate::breez_services::tests::breez_services_with(
+            Some(Arc::new(mock_node_api)),
+            vec![model_payment],
+        )
+        .await?;
+
+        match mock_breez_services
+            .lnurl_pay(user_amount_sat, None, pay_req)
+            .await?
+        {
+            LnUrlPayResult::EndpointSuccess {
+                data:
+                    Some(SuccessActionProcessed::Aes {
+                        data:
+                            AesDecryptedOrError::ErrorStatus {
+                                description,
+                                ciphertext,
+                                iv,
+                                ..
+                            },
+                    }),
+                ..
+            } => {
+                assert_eq!(description, sa_data.description);
+                assert!(!ciphertext.is_empty());
+                assert_eq!(iv.len(), 24);
+                Ok(())
+            }
+            res => Err(anyhow!("Expected an AES decryption error, got {res:?}")),
+        }
+    }
+
+    #[test]
+    fn test_aes_decrypted_or_error_serialization() -> Result<()> {
+        // Decrypted payloads keep the format used before decryption errors were represented
+        let stored = r#"{"Aes":{"data":{"description":"desc","plaintext":"text"}}}"#;
+        let sa: SuccessActionProcessed = serde_json::from_str(stored)?;
+        assert_eq!(
+            sa,
+            SuccessActionProcessed::Aes {
+                data: AesDecryptedOrError::Decrypted {
+                    data: AesSuccessActionDataDecrypted {
+                        description: "desc".to_string(),
+                        plaintext: "text".to_string(),
+                    }
+                }
+            }
+        );
+        assert_eq!(serde_json::to_string(&sa)?, stored);
+
+        let failed = SuccessActionProcessed::Aes {
+            data: AesDecryptedOrError::ErrorStatus {
+                reason: "bad key".to_string(),
+                description: "desc".to_string(),
+                ciphertext: "ct".to_string(),
+                iv: "iv".to_string(),
+            },
+        };
+        let roundtrip: SuccessActionProcessed =
+            serde_json::from_str(&serde_json::to_string(&failed)?)?;
+        assert_eq!(roundtrip, failed);
+        Ok(())
+    }
+
     #[test]
     fn test_lnurl_pay_build_pay_callback_url() -> Result<()> {
         let pay_req = get_test_pay_req_data(0, 100, 0);
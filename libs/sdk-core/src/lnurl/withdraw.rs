@@ -1,9 +1,47 @@
+use std::fmt;
 use std::str::FromStr;
 
 use crate::{lnurl::*, LnUrlCallbackStatus};
 use crate::{LNInvoice, LnUrlWithdrawRequestData};
 use anyhow::{anyhow, Result};
 
+/// Errors returned when the user input doesn't meet the conditions of the LNURL-withdraw request
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LnUrlWithdrawError {
+    /// The amount is outside of the range accepted by the LNURL-withdraw endpoint, see
+    /// [LnUrlWithdrawRequestData::min_withdrawable] and [LnUrlWithdrawRequestData::max_withdrawable]
+    AmountOutOfRange { min_msat: u64, max_msat: u64 },
+}
+
+impl fmt::Display for LnUrlWithdrawError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LnUrlWithdrawError::AmountOutOfRange { min_msat, max_msat } => write!(
+                f,
+                "Amount is outside of the range allowed by the LNURL-withdraw endpoint: \
+                 {min_msat} to {max_msat} msat"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LnUrlWithdrawError {}
+
+/// Checks that `amount_msat` is within the limits of the LNURL-withdraw endpoint
+pub(crate) fn validate_withdraw_amount(
+    req_data: &LnUrlWithdrawRequestData,
+    amount_msat: u64,
+) -> Result<()> {
+    if amount_msat < req_data.min_withdrawable || amount_msat > req_data.max_withdrawable {
+        return Err(LnUrlWithdrawError::AmountOutOfRange {
+            min_msat: req_data.min_withdrawable,
+            max_msat: req_data.max_withdrawable,
+        }
+        .into());
+    }
+    Ok(())
+}
+
 /// Validates invoice and performs the second and last step of LNURL-withdraw, as per
 /// <https://github.com/lnurl/luds/blob/luds/03.md>
 ///
@@ -16,24 +54,16 @@ pub(crate) async fn validate_lnurl_withdraw(
     req_data: LnUrlWithdrawRequestData,
     invoice: LNInvoice,
 ) -> Result<LnUrlCallbackStatus> {
-    match invoice
+    let amount_msat = invoice
         .amount_msat
         .ok_or("Expected invoice amount, but found none")
-        .map_err(|e| anyhow!(e))?
-    {
-        n if n < req_data.min_withdrawable => Err(anyhow!(
-            "Amount is smaller than the minimum allowed by the LNURL-withdraw endpoint"
-        )),
-        n if n > req_data.max_withdrawable => Err(anyhow!(
-            "Amount is bigger than the maximum allowed by the LNURL-withdraw endpoint"
-        )),
-        _ => {
-            let callback_url = build_withdraw_callback_url(&req_data, &invoice)?;
-            let callback_resp_text = reqwest::get(&callback_url).await?.text().await?;
-
-            serde_json::from_str::<LnUrlCallbackStatus>(&callback_resp_text).map_err(|e| anyhow!(e))
-        }
-    }
+        .map_err(|e| anyhow!(e))?;
+    validate_withdraw_amount(&req_data, amount_msat)?;
+
+    let callback_url = build_withdraw_callback_url(&req_data, &invoice)?;
+    let callback_resp_text = reqwest::get(&callback_url).await?.text().await?;
+
+    serde_json::from_str::<LnUrlCallbackStatus>(&callback_resp_text).map_err(|e| anyhow!(e))
 }
 
 fn build_withdraw_callback_url(
@@ -54,6 +84,7 @@ fn build_withdraw_callback_url(
 mod tests {
     use anyhow::Result;
 
+    use crate::breez_services::tests::breez_services;
     use crate::input_parser::LnUrlWithdrawRequestData;
     use crate::lnurl::withdraw::*;
     use crate::test_utils::rand_string;
@@ -125,6 +156,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_lnurl_withdraw_validate_amount_range() {
+        let withdraw_req = get_test_withdraw_req_data(10, 100);
+        let out_of_range = Some(LnUrlWithdrawError::AmountOutOfRange {
+            min_msat: 10_000,
+            max_msat: 100_000,
+        });
+        let validation_error = |amount_msat| {
+            validate_withdraw_amount(&withdraw_req, amount_msat)
+                .err()
+                .and_then(|e| e.downcast_ref::<LnUrlWithdrawError>().cloned())
+        };
+
+        assert_eq!(validation_error(9_999), out_of_range);
+        assert_eq!(validation_error(10_000), None);
+        assert_eq!(validation_error(100_000), None);
+        assert_eq!(validation_error(100_001), out_of_range);
+    }
+
+    #[tokio::test]
+    async fn test_lnurl_withdraw_amount_out_of_range() -> Result<()> {
+        let breez_services = breez_services().await?;
+
+        // Amounts out of range are rejected before an invoice is created
+        let err = breez_services
+            .lnurl_withdraw(get_test_withdraw_req_data(0, 100), 101, None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<LnUrlWithdrawError>(),
+            Some(&LnUrlWithdrawError::AmountOutOfRange {
+                min_msat: 0,
+                max_msat: 100_000,
+            })
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_lnurl_withdraw_endpoint_failure() -> Result<()> {
         let invoice_str = "lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz";
@@ -7,6 +7,7 @@ use crate::models::LspAPI;
 use anyhow::Result;
 use prost::Message;
 use serde::{Deserialize, Serialize};
+use std::cmp::max;
 use tonic::Request;
 
 /// Details of supported LSP
@@ -29,6 +30,28 @@ pub struct LspInformation {
     pub channel_minimum_fee_msat: i64,
 }
 
+impl LspInformation {
+    /// The fee, in millisatoshis, the LSP charges for opening a channel to receive `amount_msats`
+    pub(crate) fn channel_fees_msat(&self, amount_msats: u64) -> u64 {
+        let channel_fees_msat_calculated =
+            amount_msats * self.channel_fee_permyriad as u64 / 10_000 / 1_000_000;
+        max(
+            channel_fees_msat_calculated,
+            self.channel_minimum_fee_msat as u64,
+        )
+    }
+
+    /// Whether a channel opened by the LSP can receive `amount_msat`: the amount has to fit in
+    /// the advertised [LspInformation::channel_capacity], in sats, and cover the opening fee
+    pub(crate) fn can_open_channel_for(&self, amount_msat: u64) -> bool {
+        let capacity_msat = u64::try_from(self.channel_capacity)
+            .unwrap_or_default()
+            .saturating_mul(1000);
+        amount_msat <= capacity_msat
+            && amount_msat >= self.channel_fees_msat(amount_msat).saturating_add(1000)
+    }
+}
+
 fn convert_to_lsp_info(lsp_id: String, lsp_info: grpc::LspInformation) -> LspInformation {
     LspInformation {
         id: lsp_id,
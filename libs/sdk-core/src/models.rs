@@ -1,12 +1,14 @@
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::{anyhow, Result};
 use bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey};
+use gl_client::pb::CloseChannelResponse;
 use gl_client::pb::Peer;
 use gl_client::pb::WithdrawResponse;
-use gl_client::pb::{CloseChannelResponse, Invoice};
 use lightning_invoice::RawInvoice;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use strum_macros::Display;
 use strum_macros::EnumString;
 use tokio::sync::mpsc;
@@ -14,6 +16,7 @@ use tonic::Streaming;
 
 use crate::fiat::{FiatCurrency, Rate};
 use crate::grpc::{PaymentInformation, RegisterPaymentReply};
+use crate::invoice::LNInvoice;
 use crate::lnurl::pay::model::SuccessActionProcessed;
 use crate::lsp::LspInformation;
 use crate::models::Network::*;
@@ -30,29 +33,41 @@ pub enum PaymentType {
 /// Trait covering functions affecting the LN node
 #[tonic::async_trait]
 pub trait NodeAPI: Send + Sync {
+    /// Creates an invoice, without an amount when `amount_sats` is `None`, and returns its bolt11.
+    /// Without `expiry_secs` the node default expiry is used.
     async fn create_invoice(
         &self,
-        amount_sats: u64,
+        amount_sats: Option<u64>,
         description: String,
         preimage: Option<Vec<u8>>,
-    ) -> Result<Invoice>;
+        expiry_secs: Option<u32>,
+    ) -> Result<String>;
+    /// Whether the node has an invoice for `payment_hash`, paid or not
+    async fn has_invoice(&self, payment_hash: Vec<u8>) -> Result<bool>;
     async fn pull_changed(&self, since_timestamp: i64) -> Result<SyncResponse>;
-    /// As per the `pb::PayRequest` docs, `amount_sats` is only needed when the invoice doesn't specify an amount
+    /// As per the `pb::PayRequest` docs, `amount_sats` is only needed when the invoice doesn't specify an amount.
+    /// Routes are retried for up to `timeout_sec`.
     async fn send_payment(
         &self,
         bolt11: String,
         amount_sats: Option<u64>,
+        max_fee_sat: Option<u64>,
+        timeout_sec: u32,
     ) -> Result<crate::models::PaymentResponse>;
     async fn send_spontaneous_payment(
         &self,
         node_id: String,
         amount_sats: u64,
+        timeout_sec: u32,
+        extra_tlvs: Vec<TlvEntry>,
     ) -> Result<crate::models::PaymentResponse>;
     async fn start(&self) -> Result<()>;
+    /// Sends the on-chain funds to `to_address`, spending only `utxos` if not empty
     async fn sweep(
         &self,
         to_address: String,
-        fee_rate_sats_per_byte: u64,
+        fee_rate: SatPerVbyte,
+        utxos: Vec<UnspentTransactionOutput>,
     ) -> Result<WithdrawResponse>;
     async fn start_signer(&self, shutdown: mpsc::Receiver<()>);
     async fn list_peers(&self) -> Result<Vec<Peer>>;
@@ -61,7 +76,8 @@ pub trait NodeAPI: Send + Sync {
     async fn close_peer_channels(&self, node_id: String) -> Result<CloseChannelResponse>;
     async fn stream_incoming_payments(&self) -> Result<Streaming<gl_client::pb::IncomingPayment>>;
     async fn stream_log_messages(&self) -> Result<Streaming<gl_client::pb::LogEntry>>;
-    async fn execute_command(&self, command: String) -> Result<String>;
+    /// With `dry_run`, commands changing the node state only describe what they would do
+    async fn execute_command(&self, command: String, dry_run: bool) -> Result<String>;
 
     /// Gets the private key at the path specified
     fn derive_bip32_key(&self, path: Vec<ChildNumber>) -> Result<ExtendedPrivKey>;
@@ -87,6 +103,11 @@ pub trait FiatAPI: Send + Sync {
 
     /// Get the live rates from the server.
     async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>>;
+
+    /// Like [FiatAPI::fetch_fiat_rates], bypassing any cache.
+    async fn refresh_fiat_rates(&self) -> Result<Vec<Rate>> {
+        self.fetch_fiat_rates().await
+    }
 }
 
 /// Summary of an ongoing swap
@@ -120,6 +141,53 @@ pub struct LogEntry {
     pub level: String,
 }
 
+/// Whether [LogEntry::new] masks secrets, as set by [Config::redact_logs]
+static REDACT_LOGS: AtomicBool = AtomicBool::new(false);
+
+/// Length of the hex encoding of the 32 byte secrets handled by the SDK: preimages, private keys
+/// and device keys
+const SECRET_HEX_LEN: usize = 64;
+
+pub(crate) fn set_redact_logs(enabled: bool) {
+    REDACT_LOGS.store(enabled, Ordering::Relaxed);
+}
+
+impl LogEntry {
+    /// Creates a log entry, masking the secrets in `line` if [Config::redact_logs] is set
+    pub fn new(line: String, level: String) -> Self {
+        let line = if REDACT_LOGS.load(Ordering::Relaxed) {
+            redact_secrets(&line)
+        } else {
+            line
+        };
+        LogEntry { line, level }
+    }
+}
+
+/// Masks the hex strings of `line` that have the length of a secret.
+///
+/// Payment hashes and txids have the same shape as secrets, so they are masked as well. Public
+/// keys, which are longer, are kept.
+fn redact_secrets(line: &str) -> String {
+    let mut redacted = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_hexdigit()) {
+        redacted.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest
+            .find(|c: char| !c.is_ascii_hexdigit())
+            .unwrap_or(rest.len());
+        if end == SECRET_HEX_LEN {
+            redacted.push_str("<redacted>");
+        } else {
+            redacted.push_str(&rest[..end]);
+        }
+        rest = &rest[end..];
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
 /// Configuration for the Breez Services
 ///
 /// Use [Config::production] or [Config::staging] for default configs of the different supported
@@ -134,6 +202,50 @@ pub struct Config {
     pub default_lsp_id: Option<String>,
     pub api_key: Option<String>,
     pub maxfee_percent: f64,
+    /// Connect to the selected LSP when the SDK starts. If disabled, the LSP is only connected by
+    /// the next [crate::BreezServices::sync] or an explicit [crate::BreezServices::connect_lsp].
+    pub auto_connect_lsp: bool,
+    /// How long, in seconds, fetched fiat rates are cached before being fetched again
+    pub fiat_rate_cache_ttl_sec: u64,
+    /// Maximum number of chain service requests running at the same time, extra requests are queued
+    pub max_concurrent_chain_requests: u32,
+    /// Minimum number of confirmations for a UTXO to count towards the spendable on-chain balance
+    /// and to be selected as an input when sweeping. Set to 0 to treat unconfirmed funds as spendable.
+    pub onchain_min_confirmations: u32,
+    /// Fiat currency preselected for the user, its rate is fetched on start so it's ready on first use
+    pub default_fiat_currency: Option<String>,
+    /// Number of recent events kept to be replayed to late subscribers of the events stream
+    pub event_buffer_size: u32,
+    /// Whether [crate::BreezServices::receive_payment_split] may split an amount above the maximum
+    /// of a single payment over several invoices
+    pub allow_receive_split: bool,
+    /// Whether invoice expiry checks use the device time corrected by the offset found in
+    /// [crate::BreezServices::check_clock_skew]
+    pub correct_clock_skew: bool,
+    /// Whether hex encoded secrets, such as preimages and private keys, are masked in the
+    /// streamed [LogEntry] lines
+    pub redact_logs: bool,
+    /// Maximum delay between attempts to reopen the node streams after a connectivity loss
+    pub max_reconnect_backoff_sec: u32,
+    /// How to handle a swap whose confirmed deposit exceeds its maximum allowed deposit
+    pub overpaid_swap_handling: OverpaidSwapHandling,
+    /// Optional token sent to the mempool.space instance on each request, for self-hosted
+    /// instances that require one. Setting it requires an https `mempoolspace_url`.
+    pub mempoolspace_api_token: Option<String>,
+    /// Age, in seconds past their expiry, after which completed swaps are pruned on
+    /// sync, see [crate::BreezServices::prune_terminal_swaps]. Swaps are kept forever if not set.
+    pub swap_retention_sec: Option<u64>,
+    /// The API served at `mempoolspace_url`, see [ChainBackend]
+    pub chain_backend: ChainBackend,
+    /// Number of workers converting the payment history pulled from the node in parallel, which
+    /// speeds up the first sync of nodes with a long history
+    pub sync_workers: u32,
+    /// Whether the swaps that expired while the SDK was offline are marked as expired on startup,
+    /// see [crate::BreezEvent::SwapsReconciled]
+    pub reconcile_expired_swaps_on_start: bool,
+    /// Key used to encrypt the local databases at rest, which requires the `sqlcipher` feature.
+    /// Existing unencrypted databases are encrypted with it on the next start.
+    pub db_encryption_key: Option<Vec<u8>>,
 }
 
 impl Config {
@@ -147,6 +259,23 @@ impl Config {
             default_lsp_id: Some(String::from("03cea51f-b654-4fb0-8e82-eca137f236a0")),
             api_key: None,
             maxfee_percent: 0.5,
+            auto_connect_lsp: true,
+            fiat_rate_cache_ttl_sec: 60,
+            max_concurrent_chain_requests: 5,
+            onchain_min_confirmations: 1,
+            default_fiat_currency: Some(String::from("USD")),
+            event_buffer_size: 20,
+            allow_receive_split: false,
+            correct_clock_skew: false,
+            redact_logs: false,
+            max_reconnect_backoff_sec: 60,
+            overpaid_swap_handling: OverpaidSwapHandling::Refund,
+            mempoolspace_api_token: None,
+            swap_retention_sec: None,
+            chain_backend: ChainBackend::MempoolSpace,
+            sync_workers: 4,
+            reconcile_expired_swaps_on_start: true,
+            db_encryption_key: None,
         }
     }
 
@@ -161,6 +290,38 @@ impl Config {
             default_lsp_id: Some(String::from("ea51d025-042d-456c-8325-63e430797481")),
             api_key: None,
             maxfee_percent: 0.5,
+            auto_connect_lsp: true,
+            fiat_rate_cache_ttl_sec: 60,
+            max_concurrent_chain_requests: 5,
+            onchain_min_confirmations: 1,
+            default_fiat_currency: Some(String::from("USD")),
+            event_buffer_size: 20,
+            allow_receive_split: false,
+            correct_clock_skew: false,
+            redact_logs: false,
+            max_reconnect_backoff_sec: 60,
+            overpaid_swap_handling: OverpaidSwapHandling::Refund,
+            mempoolspace_api_token: None,
+            swap_retention_sec: None,
+            chain_backend: ChainBackend::MempoolSpace,
+            sync_workers: 4,
+            reconcile_expired_swaps_on_start: true,
+            db_encryption_key: None,
+        }
+    }
+
+    /// Replaces the network specific defaults with the ones of `network`.
+    ///
+    /// The default LSPs only serve mainnet, so there is no default LSP on the other networks.
+    pub fn for_network(self, network: Network) -> Self {
+        if network == Bitcoin {
+            return self;
+        }
+        Config {
+            network,
+            mempoolspace_url: network.default_mempoolspace_url().to_string(),
+            default_lsp_id: None,
+            ..self
         }
     }
 }
@@ -182,7 +343,7 @@ pub struct GreenlightCredentials {
 }
 
 /// The different supported bitcoin networks
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Network {
     /// Mainnet
     Bitcoin,
@@ -191,6 +352,19 @@ pub enum Network {
     Regtest,
 }
 
+impl Network {
+    /// The mempool.space instance of the network. There is no public regtest instance, so a
+    /// local mempool backend is assumed.
+    fn default_mempoolspace_url(&self) -> &'static str {
+        match self {
+            Bitcoin => "https://mempool.space",
+            Testnet => "https://mempool.space/testnet",
+            Signet => "https://mempool.space/signet",
+            Regtest => "http://localhost:8999",
+        }
+    }
+}
+
 impl From<bitcoin::network::constants::Network> for Network {
     fn from(network: bitcoin::network::constants::Network) -> Self {
         match network {
@@ -240,6 +414,44 @@ impl TryFrom<i32> for FeeratePreset {
     }
 }
 
+/// The highest on-chain fee rate accepted, in sat/vbyte. Anything above is assumed to be a mistake.
+pub(crate) const MAX_SAT_PER_VBYTE: u32 = 5_000;
+
+/// A validated on-chain fee rate, in sat/vbyte
+///
+/// All on-chain fee rates taken by the SDK are expressed in sat/vbyte and converted to this type
+/// before use, so zero or absurdly high rates are rejected early.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SatPerVbyte(u32);
+
+impl SatPerVbyte {
+    pub(crate) fn new(sat_per_vbyte: u32) -> Result<Self> {
+        if sat_per_vbyte == 0 {
+            return Err(anyhow!("Fee rate must be greater than zero"));
+        }
+        if sat_per_vbyte > MAX_SAT_PER_VBYTE {
+            return Err(anyhow!(
+                "Fee rate of {sat_per_vbyte} sat/vbyte exceeds the maximum of {MAX_SAT_PER_VBYTE} sat/vbyte"
+            ));
+        }
+        Ok(Self(sat_per_vbyte))
+    }
+
+    pub(crate) fn sat_per_vbyte(&self) -> u32 {
+        self.0
+    }
+
+    /// The fee rate in sat per 1000 vbytes
+    pub(crate) fn sat_per_kvbyte(&self) -> u64 {
+        self.0 as u64 * 1000
+    }
+
+    /// The fee paid by a transaction of the given virtual size
+    pub(crate) fn fee_for_vsize(&self, vsize: u64) -> u64 {
+        self.0 as u64 * vsize
+    }
+}
+
 /// The node state of a Greenlight LN node running in the cloud
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct NodeState {
@@ -258,6 +470,176 @@ pub struct NodeState {
     pub inbound_liquidity_msats: u64,
 }
 
+/// A peer of the node, see [crate::BreezServices::list_peers]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PeerInfo {
+    /// The peer public key, hex encoded
+    pub id: String,
+    /// The addresses the peer is known by, as `host:port`
+    pub addresses: Vec<String>,
+    pub connected: bool,
+    /// Number of channels with the peer, in any state
+    pub channels_count: u32,
+}
+
+/// Estimated cost of receiving a given amount, see [crate::BreezServices::receive_cost_estimate]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ReceiveCost {
+    /// The fee the LSP charges for opening a new channel, zero if the inbound liquidity suffices
+    pub lsp_fee_msat: u64,
+    /// The amount that will end up in the wallet after the LSP fee is deducted
+    pub net_received_msat: u64,
+}
+
+/// The invoice created by [crate::BreezServices::receive_payment], with the fee the LSP takes
+/// when it has to open a channel to deliver the payment
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ReceivePaymentResponse {
+    pub ln_invoice: LNInvoice,
+    /// The fee deducted from the received amount for opening a channel, `None` when the inbound
+    /// liquidity suffices and no channel is opened
+    pub opening_fee_msat: Option<u64>,
+    /// The LSP fee parameters [ReceivePaymentResponse::opening_fee_msat] was computed from
+    pub opening_fee_params: Option<OpeningFeeParams>,
+}
+
+/// The value of a `{key}` placeholder of a description template, see
+/// [crate::BreezServices::receive_payment_with_template]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct TemplateValue {
+    pub key: String,
+    pub value: String,
+}
+
+/// How the LSP computes the fee for opening a channel, from [LspInformation]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct OpeningFeeParams {
+    /// The fee charged for small amounts, see [LspInformation::channel_minimum_fee_msat]
+    pub min_msat: u64,
+    /// The fee proportional to the amount, see [LspInformation::channel_fee_permyriad]
+    pub channel_fee_permyriad: u64,
+}
+
+impl From<&LspInformation> for OpeningFeeParams {
+    fn from(lsp_info: &LspInformation) -> Self {
+        OpeningFeeParams {
+            min_msat: lsp_info.channel_minimum_fee_msat as u64,
+            channel_fee_permyriad: lsp_info.channel_fee_permyriad as u64,
+        }
+    }
+}
+
+/// The amounts that can be received over lightning, see [crate::BreezServices::receive_payment_limits]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ReceiveLimits {
+    /// The smallest amount that can be received. Without inbound liquidity, it has to cover the
+    /// fee of the LSP for opening a channel.
+    pub min_receivable_msat: u64,
+    pub max_receivable_msat: u64,
+    /// Amounts up to this one fit in the current inbound liquidity and are received without fee
+    pub max_receivable_without_fee_msat: u64,
+    /// The fee the LSP charges when receiving [ReceiveLimits::max_receivable_msat]
+    pub max_receivable_fee_msat: u64,
+}
+
+/// What an amount entered by the user is meant for, see [crate::BreezServices::validate_amount]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmountContext {
+    /// Paying a lightning invoice or a keysend payment
+    Send,
+    /// Creating a lightning invoice
+    Receive,
+    /// Depositing on-chain funds to the current swap address
+    SwapIn,
+}
+
+/// A limit broken by an amount, see [crate::BreezServices::validate_amount]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AmountViolation {
+    /// The amount is below the minimum allowed in this context
+    BelowMinimum { min_msat: u64 },
+    /// The amount is above the maximum allowed in this context
+    AboveMaximum { max_msat: u64 },
+    /// The balance doesn't cover the amount
+    InsufficientBalance { max_payable_msat: u64 },
+}
+
+/// Snapshot of the SDK state meant to be attached to support requests, see
+/// [crate::BreezServices::diagnostics]. It never includes secrets such as keys, preimages or the
+/// API key.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Diagnostics {
+    pub sdk_version: String,
+    pub network: Network,
+    pub node_id: Option<String>,
+    pub block_height: Option<u32>,
+    pub lsp_id: Option<String>,
+    pub channels_balance_msat: u64,
+    pub onchain_balance_msat: u64,
+    pub connected_peers: u32,
+    pub swaps: SwapDiagnostics,
+    pub last_errors: Vec<String>,
+    pub background_tasks_running: bool,
+}
+
+/// Number of swaps in each stage of their lifecycle, as part of the [Diagnostics]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct SwapDiagnostics {
+    pub total: u32,
+    pub unused: u32,
+    pub in_progress: u32,
+    pub redeemable: u32,
+    pub refundable: u32,
+    pub expired: u32,
+}
+
+/// Whether the SDK is connected to the node, see [crate::BreezServices::connection_status]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConnectionStatus {
+    /// The streams of node events are open
+    Connected,
+    /// The streams of node events are being opened
+    Connecting,
+    /// The SDK isn't started, or waits to retry opening the streams of node events
+    Disconnected,
+}
+
+/// An external service the SDK depends on, see [crate::BreezServices::connectivity_check]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ServiceType {
+    /// The Greenlight node
+    Greenlight,
+    /// The LSP, served by the Breez server
+    Lsp,
+    /// The chain service, Mempool.space by default
+    Mempool,
+    /// The fiat rates provider
+    Fiat,
+    /// The signer of the MoonPay urls used by [crate::BreezServices::buy_bitcoin]
+    MoonPay,
+}
+
+/// Whether a [ServiceType] could be reached, as reported by
+/// [crate::BreezServices::connectivity_check]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ServiceStatus {
+    pub service: ServiceType,
+    pub reachable: bool,
+    /// Time until the service replied, failed, or the check timed out
+    pub latency_ms: u64,
+    /// Why the service is unreachable, if it is
+    pub error: Option<String>,
+}
+
+/// Returned by [crate::BreezServices::check_clock_skew] when the device clock is off by more than
+/// [crate::invoice::EXPIRY_CLOCK_SKEW_SECS]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ClockSkewWarning {
+    /// Seconds to add to the device time to get the network time. Positive when the device clock
+    /// is behind.
+    pub offset_secs: i64,
+}
+
 /// Internal response to a [NodeAPI::pull_changed] call
 pub struct SyncResponse {
     pub node_state: NodeState,
@@ -266,7 +648,11 @@ pub struct SyncResponse {
 }
 
 /// Represents a payment, including its [PaymentType] and [PaymentDetails].
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+///
+/// Besides the msat amounts, the serialized payment includes them in sat, rounded down, and in
+/// btc, as a decimal string. These are derived from the msat amounts and ignored when
+/// deserializing.
+#[derive(PartialEq, Eq, Debug, Clone, Deserialize)]
 pub struct Payment {
     pub id: String,
     pub payment_type: PaymentType,
@@ -278,6 +664,46 @@ pub struct Payment {
     pub details: PaymentDetails,
 }
 
+impl Serialize for Payment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Payment", 12)?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("payment_type", &self.payment_type)?;
+        state.serialize_field("payment_time", &self.payment_time)?;
+        state.serialize_field("amount_msat", &self.amount_msat)?;
+        state.serialize_field("amount_sat", &(self.amount_msat / 1000))?;
+        state.serialize_field("amount_btc", &msat_to_btc_string(self.amount_msat))?;
+        state.serialize_field("fee_msat", &self.fee_msat)?;
+        state.serialize_field("fee_sat", &(self.fee_msat / 1000))?;
+        state.serialize_field("fee_btc", &msat_to_btc_string(self.fee_msat))?;
+        state.serialize_field("pending", &self.pending)?;
+        state.serialize_field("description", &self.description)?;
+        state.serialize_field("details", &self.details)?;
+        state.end()
+    }
+}
+
+/// Formats an msat amount in btc, keeping the msat precision
+fn msat_to_btc_string(amount_msat: u64) -> String {
+    const MSAT_PER_BTC: u64 = 100_000_000_000;
+    format!(
+        "{}.{:011}",
+        amount_msat / MSAT_PER_BTC,
+        amount_msat % MSAT_PER_BTC
+    )
+}
+
+/// A custom TLV record sent or received along a spontaneous payment, for example the `7629169`
+/// record of podcasting apps. Only odd types starting at [CUSTOM_TLV_MIN_TYPE] can be sent.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+pub struct TlvEntry {
+    pub type_num: u64,
+    pub value: Vec<u8>,
+}
+
+/// The first TLV type of the range reserved for custom records
+pub const CUSTOM_TLV_MIN_TYPE: u64 = 1 << 16;
+
 /// Represents a payment response.
 #[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentResponse {
@@ -321,6 +747,26 @@ pub struct LnPaymentDetails {
 
     /// Only set for [PaymentType::Sent] payments where the receiver endpoint returned LNURL metadata
     pub lnurl_metadata: Option<String>,
+
+    /// The hops of the route the payment took, for [PaymentType::Sent] payments whose route the
+    /// node reports. Empty otherwise.
+    #[serde(default)]
+    pub route: Vec<PaymentRouteHop>,
+
+    /// The custom records attached by the sender of a received spontaneous payment, for example a
+    /// message sent along a podcast payment. Empty otherwise.
+    #[serde(default)]
+    pub custom_tlvs: Vec<TlvEntry>,
+}
+
+/// A hop of the route taken by a sent payment, see [LnPaymentDetails::route]
+#[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
+pub struct PaymentRouteHop {
+    /// The node this hop forwards the payment to
+    pub node_id: String,
+    pub short_channel_id: String,
+    /// The fee charged by the node forwarding the payment over this hop
+    pub fee_msat: u64,
 }
 
 /// Represents the funds that were on the user side of the channel at the time it was closed.
@@ -331,6 +777,49 @@ pub struct ClosedChannelPaymentDetails {
     pub funding_txid: String,
 }
 
+/// The outcome of sweeping the on-chain funds, see [crate::BreezServices::sweep]
+#[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
+pub struct SweepResult {
+    pub txid: String,
+    /// The amount sent to the destination address
+    pub amount_sat: u64,
+    /// The fee paid by the sweep transaction, its inputs minus its outputs. It's only estimated from
+    /// the transaction size and the requested feerate if an input is missing from [NodeState::utxos].
+    pub fee_sat: u64,
+    pub destination: String,
+}
+
+/// The estimated outcome of sweeping the on-chain funds, see [crate::BreezServices::prepare_sweep]
+#[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
+pub struct SweepPreview {
+    /// The estimated virtual size of the sweep transaction
+    pub tx_vsize: u64,
+    /// The fee paid by the sweep transaction, based on its estimated size and the feerate
+    pub fee_sat: u64,
+    /// The amount that would be sent to the destination address
+    pub amount_sat: u64,
+}
+
+/// The estimated outcome of refunding a swap, see [crate::BreezServices::prepare_refund]
+#[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
+pub struct RefundPreview {
+    /// The fee paid by the refund transaction, based on its estimated size and the feerate
+    pub fee_sat: u64,
+    /// The amount that would be sent to the destination address, across all confirmed UTXOs
+    pub refund_amount_sat: u64,
+}
+
+/// A recorded LNURL-auth attempt, see [crate::BreezServices::list_lnurl_auth_logins]
+#[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
+pub struct LnUrlAuthLogin {
+    pub domain: String,
+    pub k1: String,
+    /// Epoch time, in seconds
+    pub timestamp: i64,
+    /// Whether the LNURL-auth endpoint accepted the login
+    pub success: bool,
+}
+
 /// Lightning channel
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Channel {
@@ -342,6 +831,35 @@ pub struct Channel {
     pub closed_at: Option<u64>,
 }
 
+/// A channel of the node with its balances, see [crate::BreezServices::list_channels]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct ChannelInfo {
+    pub short_channel_id: String,
+    pub funding_txid: String,
+    pub state: ChannelState,
+    /// Only known for the channels the node currently reports
+    pub capacity_msat: Option<u64>,
+    /// The amount we can send over the channel, excluding the channel reserve
+    pub local_balance_msat: u64,
+    /// The amount we can receive over the channel
+    pub remote_balance_msat: u64,
+    pub closed_at: Option<u64>,
+}
+
+impl From<Channel> for ChannelInfo {
+    fn from(c: Channel) -> Self {
+        ChannelInfo {
+            short_channel_id: c.short_channel_id,
+            funding_txid: c.funding_txid,
+            state: c.state,
+            capacity_msat: None,
+            local_balance_msat: c.spendable_msat,
+            remote_balance_msat: c.receivable_msat,
+            closed_at: c.closed_at,
+        }
+    }
+}
+
 /// State of a Lightning channel
 #[derive(Clone, PartialEq, Eq, Debug, EnumString, Display, Deserialize, Serialize)]
 pub enum ChannelState {
@@ -351,6 +869,30 @@ pub enum ChannelState {
     Closed,
 }
 
+/// The kind of chain service used to monitor the chain
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum ChainBackend {
+    /// A mempool.space instance, e.g. `https://mempool.space`
+    #[default]
+    MempoolSpace,
+    /// An Esplora instance, e.g. `https://blockstream.info/api`
+    Esplora,
+}
+
+/// How to handle a swap whose confirmed deposit exceeds its [SwapInfo::max_allowed_deposit]
+///
+/// Redeeming a swap spends its whole deposit, so the deposit can't be split into a redeemed and a
+/// refunded part.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum OverpaidSwapHandling {
+    /// Don't redeem the swap, so that the whole deposit can be refunded once the swap expires
+    #[default]
+    Refund,
+    /// Try to redeem the whole deposit anyway, in case the swapper accepts it. If it doesn't, the
+    /// deposit can still be refunded once the swap expires.
+    Redeem,
+}
+
 /// The status of a swap
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum SwapStatus {
@@ -408,6 +950,12 @@ pub struct SwapInfo {
     pub min_allowed_deposit: i64,
     pub max_allowed_deposit: i64,
     pub last_redeem_error: Option<String>,
+    /// The block height at which the swap expires: [SwapInfo::lock_height] blocks after the
+    /// deposit confirmed or, while the address is unused, after it was created
+    pub expiry_block: u32,
+    /// The estimated unix timestamp of [SwapInfo::expiry_block], after which an unused address
+    /// is only checked for late deposits about once a day
+    pub expires_at: i64,
 }
 
 impl SwapInfo {
@@ -431,12 +979,35 @@ impl SwapInfo {
             && self.status != SwapStatus::Expired
     }
 
+    /// Whether more than the maximum allowed deposit was confirmed, see [OverpaidSwapHandling]
+    pub(crate) fn overpaid(&self) -> bool {
+        self.max_allowed_deposit > 0 && self.confirmed_sats as i64 > self.max_allowed_deposit
+    }
+
     pub(crate) fn refundable(&self) -> bool {
         self.confirmed_sats > self.paid_sats && self.status == SwapStatus::Expired
     }
 
+    /// Whether the swap is refreshed on every block. A deposit that is still unconfirmed when the
+    /// swap expires stays monitored until it confirms and becomes refundable.
     pub(crate) fn monitored(&self) -> bool {
-        self.unused() || self.in_progress() || self.refundable()
+        self.unused()
+            || self.in_progress()
+            || self.refundable()
+            || (self.status == SwapStatus::Expired && self.unconfirmed_sats > 0)
+    }
+
+    /// Whether the swap expired with no funds on its address, e.g. it was never used or it was
+    /// refunded. It is still refreshed from time to time, as funds sent to it later have to be
+    /// refunded.
+    pub(crate) fn dormant(&self) -> bool {
+        self.status == SwapStatus::Expired && self.confirmed_sats == 0 && self.unconfirmed_sats == 0
+    }
+
+    /// Whether the swap reached an end state, i.e. it was redeemed or expired with nothing left to
+    /// refund, and no deposit is pending
+    pub(crate) fn terminal(&self) -> bool {
+        !self.monitored() && self.unconfirmed_sats == 0
     }
 }
 
@@ -463,6 +1034,23 @@ pub struct UnspentTransactionOutput {
     pub reserved: bool,
     #[serde(default)]
     pub reserved_to_block: u32,
+    #[serde(default)]
+    pub confirmed: bool,
+}
+
+impl UnspentTransactionOutput {
+    /// The outpoint of this UTXO, formatted as `txid:vout`
+    pub fn outpoint(&self) -> String {
+        format!("{}:{}", hex::encode(&self.txid), self.outnum)
+    }
+
+    /// Whether this UTXO counts towards the spendable on-chain balance.
+    ///
+    /// The node only reports whether a UTXO is confirmed, so any `min_confirmations` of 1 or more
+    /// requires the UTXO to be confirmed.
+    pub(crate) fn is_spendable(&self, min_confirmations: u32) -> bool {
+        !self.reserved && (min_confirmations == 0 || self.confirmed)
+    }
 }
 
 //// Contains the result of the entire LNURL interaction, as reported by the LNURL endpoint.
@@ -511,8 +1099,93 @@ mod tests {
     use rand::random;
 
     use crate::grpc::PaymentInformation;
+    use crate::models::{
+        redact_secrets, LnPaymentDetails, Payment, PaymentDetails, PaymentType, SatPerVbyte,
+        UnspentTransactionOutput, MAX_SAT_PER_VBYTE,
+    };
     use crate::test_utils::rand_vec_u8;
 
+    #[test]
+    fn test_utxo_min_confirmations() {
+        let utxo = |confirmed| UnspentTransactionOutput {
+            txid: vec![1],
+            outnum: 0,
+            amount_millisatoshi: 1000,
+            address: "address".to_string(),
+            reserved: false,
+            reserved_to_block: 0,
+            confirmed,
+        };
+        let unconfirmed = utxo(false);
+        let confirmed = utxo(true);
+
+        assert!(unconfirmed.is_spendable(0));
+        assert!(!unconfirmed.is_spendable(1));
+        assert!(confirmed.is_spendable(0));
+        assert!(confirmed.is_spendable(1));
+
+        let reserved = UnspentTransactionOutput {
+            reserved: true,
+            ..utxo(true)
+        };
+        assert!(!reserved.is_spendable(0));
+    }
+
+    #[test]
+    fn test_sat_per_vbyte() -> Result<(), Box<dyn std::error::Error>> {
+        let fee_rate = SatPerVbyte::new(12)?;
+        assert_eq!(fee_rate.sat_per_vbyte(), 12);
+        assert_eq!(fee_rate.sat_per_kvbyte(), 12_000);
+        assert_eq!(fee_rate.fee_for_vsize(141), 1692);
+
+        assert!(SatPerVbyte::new(1).is_ok());
+        assert!(SatPerVbyte::new(MAX_SAT_PER_VBYTE).is_ok());
+        assert!(SatPerVbyte::new(0).is_err());
+        assert!(SatPerVbyte::new(MAX_SAT_PER_VBYTE + 1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_payment_serialized_amounts() -> Result<(), Box<dyn std::error::Error>> {
+        let payment = Payment {
+            id: "id".to_string(),
+            payment_type: PaymentType::Sent,
+            payment_time: 1_700_000_000,
+            amount_msat: 123_456_789_012,
+            fee_msat: 2_500,
+            pending: false,
+            description: None,
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    payment_hash: "hash".to_string(),
+                    label: "".to_string(),
+                    destination_pubkey: "pubkey".to_string(),
+                    payment_preimage: "".to_string(),
+                    keysend: false,
+                    bolt11: "".to_string(),
+                    lnurl_success_action: None,
+                    lnurl_metadata: None,
+                    ln_address: None,
+                    route: vec![],
+                    custom_tlvs: vec![],
+                },
+            },
+        };
+
+        let serialized = serde_json::to_value(&payment)?;
+        assert_eq!(serialized["amount_msat"], 123_456_789_012u64);
+        assert_eq!(serialized["amount_sat"], 123_456_789u64);
+        assert_eq!(serialized["amount_btc"], "1.23456789012");
+        assert_eq!(serialized["fee_msat"], 2_500);
+        assert_eq!(serialized["fee_sat"], 2);
+        assert_eq!(serialized["fee_btc"], "0.00000002500");
+
+        // The derived amounts don't get in the way of deserializing
+        assert_eq!(serde_json::from_value::<Payment>(serialized)?, payment);
+        Ok(())
+    }
+
     #[test]
     fn test_payment_information_ser_de() -> Result<(), Box<dyn std::error::Error>> {
         let dummy_payment_info = PaymentInformation {
@@ -533,4 +1206,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_redact_secrets() {
+        let preimage = hex::encode(rand_vec_u8(32));
+        let node_id = format!("02{}", hex::encode(rand_vec_u8(32)));
+        let line = format!("paid to {node_id}, preimage: {preimage}");
+        assert_eq!(
+            redact_secrets(&line),
+            format!("paid to {node_id}, preimage: <redacted>")
+        );
+
+        let line = format!("preimage=0x{preimage}\nblock 767640");
+        assert_eq!(redact_secrets(&line), "preimage=0x<redacted>\nblock 767640");
+    }
 }
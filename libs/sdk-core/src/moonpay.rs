@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use anyhow::Result;
 use reqwest::Url;
 
 use crate::breez_services::BreezServer;
+use crate::buy::OnRampProvider;
 use crate::grpc::SignUrlRequest;
 use crate::SwapInfo;
 
@@ -10,6 +13,7 @@ pub(crate) struct MoonPayConfig {
     pub base_url: String,
     pub api_key: String,
     pub currency_code: String,
+    pub base_currency_code: String,
     pub color_code: String,
     pub redirect_url: String,
     pub enabled_payment_methods: String,
@@ -20,6 +24,7 @@ pub(crate) fn moonpay_config() -> MoonPayConfig {
         base_url: String::from("https://buy.moonpay.io"),
         api_key: String::from("pk_live_Mx5g6bpD6Etd7T0bupthv7smoTNn2Vr"),
         currency_code: String::from("btc"),
+        base_currency_code: String::from("usd"),
         color_code: String::from("#055DEB"),
         redirect_url: String::from("https://buy.moonpay.io/transaction_receipt?addFunds=true"),
         enabled_payment_methods: String::from(
@@ -28,13 +33,21 @@ pub(crate) fn moonpay_config() -> MoonPayConfig {
     }
 }
 
-async fn create_moonpay_url(wallet_address: &str, max_amount: &str) -> Result<Url> {
+async fn create_moonpay_url(
+    wallet_address: &str,
+    max_amount: &str,
+    base_currency_code: Option<&str>,
+) -> Result<Url> {
     let config = moonpay_config();
+    let base_currency_code = base_currency_code
+        .map(str::to_lowercase)
+        .unwrap_or(config.base_currency_code);
     let url = Url::parse_with_params(
         &config.base_url,
         &[
             ("apiKey", &config.api_key),
             ("currencyCode", &config.currency_code),
+            ("baseCurrencyCode", &base_currency_code),
             ("colorCode", &config.color_code),
             ("redirectURL", &config.redirect_url),
             ("enabledPaymentMethods", &config.enabled_payment_methods),
@@ -47,16 +60,28 @@ async fn create_moonpay_url(wallet_address: &str, max_amount: &str) -> Result<Ur
 
 #[tonic::async_trait]
 pub(crate) trait MoonPayApi: Send + Sync {
-    async fn buy_bitcoin_url(&self, swap_info: &SwapInfo) -> Result<String>;
+    async fn buy_bitcoin_url(
+        &self,
+        swap_info: &SwapInfo,
+        base_currency_code: Option<String>,
+    ) -> Result<String>;
+
+    /// Connects to the service signing the urls, without signing any
+    async fn check_signer(&self) -> Result<()>;
 }
 
 #[tonic::async_trait]
 impl MoonPayApi for BreezServer {
-    async fn buy_bitcoin_url(&self, swap_info: &SwapInfo) -> Result<String> {
+    async fn buy_bitcoin_url(
+        &self,
+        swap_info: &SwapInfo,
+        base_currency_code: Option<String>,
+    ) -> Result<String> {
         let config = moonpay_config();
         let url = create_moonpay_url(
             swap_info.bitcoin_address.as_str(),
             format!("{:.8}", swap_info.max_allowed_deposit as f64 / 100000000.0).as_str(),
+            base_currency_code.as_deref(),
         )
         .await?;
         let mut signer = self.get_signer_client().await?.clone();
@@ -70,6 +95,39 @@ impl MoonPayApi for BreezServer {
             .full_url;
         Ok(signed_url)
     }
+
+    async fn check_signer(&self) -> Result<()> {
+        self.get_signer_client().await?;
+        Ok(())
+    }
+}
+
+/// The MoonPay [OnRampProvider], relying on a [MoonPayApi] to sign its urls
+pub(crate) struct MoonPayProvider {
+    moonpay_api: Arc<dyn MoonPayApi>,
+}
+
+impl MoonPayProvider {
+    pub(crate) fn new(moonpay_api: Arc<dyn MoonPayApi>) -> Self {
+        Self { moonpay_api }
+    }
+}
+
+#[tonic::async_trait]
+impl OnRampProvider for MoonPayProvider {
+    async fn generate_url(
+        &self,
+        swap_info: &SwapInfo,
+        base_currency_code: Option<String>,
+    ) -> Result<String> {
+        self.moonpay_api
+            .buy_bitcoin_url(swap_info, base_currency_code)
+            .await
+    }
+
+    async fn check_reachable(&self) -> Result<()> {
+        self.moonpay_api.check_signer().await
+    }
 }
 
 #[cfg(test)]
@@ -84,13 +142,17 @@ pub(crate) mod tests {
         let max_amount = "a max amount";
         let config = moonpay_config();
 
-        let url = create_moonpay_url(wallet_address, max_amount).await?;
+        let url = create_moonpay_url(wallet_address, max_amount, None).await?;
 
         let query_pairs = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
         assert_eq!(url.host_str(), Some("buy.moonpay.io"));
         assert_eq!(url.path(), "/");
         assert_eq!(query_pairs.get("apiKey"), Some(&config.api_key));
         assert_eq!(query_pairs.get("currencyCode"), Some(&config.currency_code));
+        assert_eq!(
+            query_pairs.get("baseCurrencyCode"),
+            Some(&config.base_currency_code)
+        );
         assert_eq!(query_pairs.get("colorCode"), Some(&config.color_code));
         assert_eq!(query_pairs.get("redirectURL"), Some(&config.redirect_url));
         assert_eq!(
@@ -107,4 +169,16 @@ pub(crate) mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_sign_moonpay_url_with_base_currency() -> Result<(), Box<dyn std::error::Error>> {
+        let url = create_moonpay_url("a wallet address", "a max amount", Some("EUR")).await?;
+
+        let query_pairs = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+        assert_eq!(
+            query_pairs.get("baseCurrencyCode"),
+            Some(&String::from("eur"))
+        );
+        Ok(())
+    }
 }
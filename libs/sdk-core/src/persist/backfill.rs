@@ -0,0 +1,213 @@
+use anyhow::Result;
+use rusqlite::{named_params, OptionalExtension, Transaction};
+
+use super::db::SqliteStorage;
+
+/// Fills columns added by a migration for the rows that existed before it.
+///
+/// Rows are processed in `rowid` order and in batches, each committed together with the progress
+/// so far. An interrupted backfill resumes after the last committed batch on the next run, and a
+/// completed one never runs again.
+pub(crate) trait Backfill {
+    /// Unique name under which the progress is recorded
+    fn name(&self) -> &'static str;
+
+    /// The table whose rows are backfilled, e.g. `sync.payments`
+    fn table(&self) -> &'static str;
+
+    /// Fills the derived columns of the row with the given `rowid`, from the row itself or from
+    /// an external lookup
+    fn backfill_row(&self, tx: &Transaction, rowid: i64) -> Result<()>;
+}
+
+/// Progress of a running [Backfill], as reported after every batch
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BackfillProgress {
+    pub name: &'static str,
+    /// Rows backfilled so far, including those of previous interrupted runs
+    pub processed: u64,
+    /// Rows to backfill in total
+    pub total: u64,
+}
+
+/// The backfills to run once the migrations are applied, see [SqliteStorage::init]
+pub(crate) fn current_backfills() -> Vec<Box<dyn Backfill>> {
+    vec![]
+}
+
+const BACKFILL_BATCH_SIZE: u32 = 500;
+
+impl SqliteStorage {
+    /// Runs `backfill` to completion, calling `on_progress` after every batch
+    pub(crate) fn run_backfill(
+        &self,
+        backfill: &dyn Backfill,
+        batch_size: u32,
+        on_progress: &dyn Fn(BackfillProgress),
+    ) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let progress: Option<(i64, bool)> = con
+            .query_row(
+                "SELECT last_rowid, completed FROM backfills WHERE name = ?1",
+                [backfill.name()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let (mut last_rowid, completed) = progress.unwrap_or((0, false));
+        if completed {
+            return Ok(());
+        }
+
+        let count_rows = |query: String| -> Result<u64> {
+            Ok(con.query_row(&query, [last_rowid], |row| row.get(0))?)
+        };
+        let mut processed = count_rows(format!(
+            "SELECT COUNT(*) FROM {} WHERE rowid <= ?1",
+            backfill.table()
+        ))?;
+        let total = processed
+            + count_rows(format!(
+                "SELECT COUNT(*) FROM {} WHERE rowid > ?1",
+                backfill.table()
+            ))?;
+
+        loop {
+            let tx = con.transaction()?;
+            let rowids: Vec<i64> = tx
+                .prepare(&format!(
+                    "SELECT rowid FROM {} WHERE rowid > ?1 ORDER BY rowid LIMIT ?2",
+                    backfill.table()
+                ))?
+                .query_map((last_rowid, batch_size), |row| row.get(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+            for rowid in rowids.iter() {
+                backfill.backfill_row(&tx, *rowid)?;
+            }
+            if let Some(rowid) = rowids.last() {
+                last_rowid = *rowid;
+            }
+
+            let completed = rowids.len() < batch_size as usize;
+            tx.execute(
+                "INSERT OR REPLACE INTO backfills (name, last_rowid, completed)
+                 VALUES (:name, :last_rowid, :completed)",
+                named_params! {
+                    ":name": backfill.name(),
+                    ":last_rowid": last_rowid,
+                    ":completed": completed,
+                },
+            )?;
+            tx.commit()?;
+
+            processed += rowids.len() as u64;
+            on_progress(BackfillProgress {
+                name: backfill.name(),
+                processed,
+                total,
+            });
+            if completed {
+                return Ok(());
+            }
+        }
+    }
+
+    pub(crate) fn run_backfills(&self) -> Result<()> {
+        for backfill in current_backfills() {
+            self.run_backfill(backfill.as_ref(), BACKFILL_BATCH_SIZE, &|progress| {
+                info!(
+                    "backfill {}: {}/{} rows",
+                    progress.name, progress.processed, progress.total
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use anyhow::{anyhow, Result};
+    use rusqlite::Transaction;
+
+    use super::{Backfill, BackfillProgress};
+    use crate::persist::db::SqliteStorage;
+    use crate::persist::test_utils;
+
+    /// Fills `doubled` from `value`, failing once on `fail_at` to simulate an interruption
+    struct DoubleValue {
+        fail_at: Mutex<Option<i64>>,
+        processed: Mutex<Vec<i64>>,
+    }
+
+    impl Backfill for DoubleValue {
+        fn name(&self) -> &'static str {
+            "double_value"
+        }
+
+        fn table(&self) -> &'static str {
+            "seeded"
+        }
+
+        fn backfill_row(&self, tx: &Transaction, rowid: i64) -> Result<()> {
+            let mut fail_at = self.fail_at.lock().unwrap();
+            if *fail_at == Some(rowid) {
+                *fail_at = None;
+                return Err(anyhow!("Interrupted"));
+            }
+            tx.execute(
+                "UPDATE seeded SET doubled = value * 2 WHERE rowid = ?1",
+                [rowid],
+            )?;
+            self.processed.lock().unwrap().push(rowid);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_backfill() -> Result<()> {
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        let con = storage.get_connection()?;
+        con.execute(
+            "CREATE TABLE seeded (value INTEGER NOT NULL, doubled INTEGER)",
+            [],
+        )?;
+        for value in 1..=25 {
+            con.execute("INSERT INTO seeded (value) VALUES (?1)", [value])?;
+        }
+
+        let backfill = DoubleValue {
+            fail_at: Mutex::new(Some(17)),
+            processed: Mutex::new(vec![]),
+        };
+        let reports = Mutex::new(vec![]);
+        let on_progress = |p: BackfillProgress| reports.lock().unwrap().push(p.processed);
+
+        // The batch containing row 17 is rolled back, the previous ones are kept
+        assert!(storage.run_backfill(&backfill, 10, &on_progress).is_err());
+        assert_eq!(*reports.lock().unwrap(), vec![10]);
+
+        // Resuming starts from the interrupted batch
+        backfill.processed.lock().unwrap().clear();
+        storage.run_backfill(&backfill, 10, &on_progress)?;
+        assert_eq!(*reports.lock().unwrap(), vec![10, 20, 25]);
+        assert_eq!(
+            *backfill.processed.lock().unwrap(),
+            (11..=25).collect::<Vec<_>>()
+        );
+
+        let not_backfilled: u32 = con.query_row(
+            "SELECT COUNT(*) FROM seeded WHERE doubled IS NULL OR doubled != value * 2",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(not_backfilled, 0);
+
+        // A completed backfill doesn't run again
+        storage.run_backfill(&backfill, 10, &on_progress)?;
+        assert_eq!(reports.lock().unwrap().len(), 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use std::fs;
+use std::path::Path;
+
+use super::db::{remove_sidecar_files, SqliteStorage};
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// The version of the [BackupData] format created by [SqliteStorage::backup]
+const BACKUP_VERSION: u32 = 1;
+
+/// The leading fields of every backup format version, read before the rest of the backup
+#[derive(Deserialize)]
+struct BackupHeader {
+    version: u32,
+}
+
+/// A backup in the format of [BACKUP_VERSION]: a JSON object with the format `version` and the
+/// files of both databases, base64 encoded
+#[derive(Serialize, Deserialize)]
+struct BackupData {
+    version: u32,
+    main_db: String,
+    sync_db: String,
+}
+
+impl SqliteStorage {
+    /// Creates a consistent snapshot of both databases with `VACUUM INTO`, which can be written
+    /// while other connections keep using the databases.
+    pub fn backup(&self) -> Result<Vec<u8>> {
+        let snapshot_dir = tempfile::tempdir()?;
+        let main_db_file = snapshot_dir.path().join("storage.sql");
+        let sync_db_file = snapshot_dir.path().join("sync_storage.sql");
+
+        let con = self.get_connection()?;
+        con.execute(
+            "VACUUM main INTO ?",
+            [main_db_file.to_string_lossy().to_string()],
+        )?;
+        con.execute(
+            "VACUUM sync INTO ?",
+            [sync_db_file.to_string_lossy().to_string()],
+        )?;
+
+        let data = BackupData {
+            version: BACKUP_VERSION,
+            main_db: base64::encode(fs::read(main_db_file)?),
+            sync_db: base64::encode(fs::read(sync_db_file)?),
+        };
+        Ok(serde_json::to_vec(&data)?)
+    }
+
+    /// Replaces both databases with a snapshot created by [SqliteStorage::backup].
+    ///
+    /// The snapshot is migrated to the current schema before it replaces the database files, so
+    /// a failed restore leaves the current databases untouched. A snapshot of another node than
+    /// the one of the current databases is rejected, as is one created by a newer SDK in a format
+    /// version this one doesn't know.
+    pub fn restore(&self, data: Vec<u8>) -> Result<()> {
+        let header: BackupHeader =
+            serde_json::from_slice(&data).map_err(|_| anyhow!("Invalid backup data"))?;
+        if header.version > BACKUP_VERSION {
+            return Err(anyhow!(
+                "Unsupported backup version {}, this SDK restores backups up to version {BACKUP_VERSION}",
+                header.version
+            ));
+        }
+        let data: BackupData =
+            serde_json::from_slice(&data).map_err(|_| anyhow!("Invalid backup data"))?;
+
+        // Stage the snapshot next to the databases, so that moving it in place is a rename
+        let working_dir = Path::new(&self.main_db_file)
+            .parent()
+            .ok_or_else(|| anyhow!("Invalid database path"))?;
+        let staging_dir = tempfile::tempdir_in(working_dir)?;
+        let staged_main_db = staging_dir.path().join("storage.sql");
+        let staged_sync_db = staging_dir.path().join("sync_storage.sql");
+        fs::write(&staged_main_db, base64::decode(data.main_db)?)?;
+        fs::write(&staged_sync_db, base64::decode(data.sync_db)?)?;
+
+        let staged = SqliteStorage::new(staging_dir.path().to_string_lossy().to_string())
+            .with_encryption_key(self.encryption_key.clone());
+        staged.init()?;
+
+        let current_node_id = self.get_node_state()?.map(|state| state.id);
+        let backup_node_id = staged.get_node_state()?.map(|state| state.id);
+        if let (Some(current), Some(backup)) = (current_node_id, backup_node_id) {
+            if current != backup {
+                return Err(anyhow!(
+                    "The backup belongs to node {backup}, not to the current node {current}"
+                ));
+            }
+        }
+
+        // Both snapshots are staged, so only renames are left. The current databases are moved
+        // aside first, so that they can be put back if one of the renames fails.
+        remove_sidecar_files(&staged_main_db.to_string_lossy())?;
+        remove_sidecar_files(&staged_sync_db.to_string_lossy())?;
+        let previous_dir = tempfile::tempdir_in(working_dir)?;
+        let replacements = [
+            (Path::new(&self.main_db_file), staged_main_db.as_path()),
+            (Path::new(&self.sync_db_file), staged_sync_db.as_path()),
+        ];
+        let mut done = Vec::new();
+        for (index, (db_file, staged_db_file)) in replacements.iter().enumerate() {
+            let previous = previous_dir.path().join(index.to_string());
+            if let Err(err) = replace_db_file(db_file, staged_db_file, &previous) {
+                for (db_file, previous) in done.into_iter().rev() {
+                    if let Err(rollback_err) = restore_db_file(db_file, &previous) {
+                        error!(
+                            "Failed to put back {db_file:?} after a failed restore: {rollback_err}"
+                        );
+                    }
+                }
+                return Err(err);
+            }
+            done.push((*db_file, previous));
+        }
+        Ok(())
+    }
+}
+
+/// Moves `db_file` with its journal and write-ahead log to `previous`, then moves `staged` in its
+/// place. On failure, `db_file` is left as it was.
+fn replace_db_file(db_file: &Path, staged: &Path, previous: &Path) -> Result<()> {
+    fs::create_dir(previous)?;
+    move_db_file(db_file, previous)?;
+    if let Err(err) = fs::rename(staged, db_file) {
+        restore_db_file(db_file, previous)?;
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+/// Moves back the `db_file` set aside by [replace_db_file]
+fn restore_db_file(db_file: &Path, previous: &Path) -> Result<()> {
+    let dir = db_file
+        .parent()
+        .ok_or_else(|| anyhow!("Invalid database path"))?;
+    remove_sidecar_files(&db_file.to_string_lossy())?;
+    move_db_file(&previous.join(file_name(db_file)?), dir)
+}
+
+/// Moves `db_file` with its sidecar files, if they exist, to the directory `to`
+fn move_db_file(db_file: &Path, to: &Path) -> Result<()> {
+    let name = file_name(db_file)?;
+    for suffix in ["", "-journal", "-wal", "-shm"] {
+        let from = db_file.with_file_name(format!("{name}{suffix}"));
+        match fs::rename(&from, to.join(format!("{name}{suffix}"))) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err.into()),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn file_name(path: &Path) -> Result<String> {
+    Ok(path
+        .file_name()
+        .ok_or_else(|| anyhow!("Invalid database path"))?
+        .to_string_lossy()
+        .to_string())
+}
+
+#[test]
+fn test_backup_restore() -> Result<()> {
+    use crate::breez_services::tests::get_dummy_node_state;
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init()?;
+    storage.set_node_state(&get_dummy_node_state())?;
+    storage.update_setting("key".to_string(), "value".to_string())?;
+    let backup = storage.backup()?;
+
+    storage.delete_setting("key".to_string())?;
+    // A stale write-ahead log of the replaced database isn't applied to the restored one
+    let stale_wal = format!("{}-wal", storage.main_db_file);
+    fs::write(&stale_wal, b"stale")?;
+    storage.restore(backup.clone())?;
+    assert!(!Path::new(&stale_wal).exists());
+    assert_eq!(
+        storage.get_setting("key".to_string())?,
+        Some("value".to_string())
+    );
+
+    // On a new device, the backup of the same node replaces the empty databases
+    let new_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    new_storage.init()?;
+    new_storage.restore(backup.clone())?;
+    assert_eq!(
+        new_storage.get_setting("key".to_string())?,
+        Some("value".to_string())
+    );
+
+    let other_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    other_storage.init()?;
+    other_storage.set_node_state(&crate::NodeState {
+        id: "other".to_string(),
+        ..get_dummy_node_state()
+    })?;
+    assert!(other_storage.restore(backup).is_err());
+    assert!(other_storage.restore(b"not a backup".to_vec()).is_err());
+    assert_eq!(other_storage.get_node_state()?.unwrap().id, "other");
+    Ok(())
+}
+
+#[test]
+fn test_backup_version() -> Result<()> {
+    use crate::breez_services::tests::get_dummy_node_state;
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init()?;
+    storage.set_node_state(&get_dummy_node_state())?;
+    storage.update_setting("key".to_string(), "value".to_string())?;
+    let backup = storage.backup()?;
+
+    // The backup starts with the version of its format and round trips
+    let header: BackupHeader = serde_json::from_slice(&backup)?;
+    assert_eq!(header.version, BACKUP_VERSION);
+    storage.delete_setting("key".to_string())?;
+    storage.restore(backup.clone())?;
+    assert_eq!(
+        storage.get_setting("key".to_string())?,
+        Some("value".to_string())
+    );
+
+    // A backup of a newer format is rejected, even if the rest of it is readable
+    let mut newer: serde_json::Value = serde_json::from_slice(&backup)?;
+    newer["version"] = (BACKUP_VERSION + 1).into();
+    storage.delete_setting("key".to_string())?;
+    let err = storage.restore(serde_json::to_vec(&newer)?).unwrap_err();
+    assert!(err.to_string().contains("Unsupported backup version"));
+    assert_eq!(storage.get_setting("key".to_string())?, None);
+
+    // A backup without a version isn't a backup of any known format
+    let mut unversioned: serde_json::Value = serde_json::from_slice(&backup)?;
+    unversioned.as_object_mut().unwrap().remove("version");
+    assert!(storage.restore(serde_json::to_vec(&unversioned)?).is_err());
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::chain::RecommendedFees;
 use crate::models::NodeState;
 
 use super::db::SqliteStorage;
@@ -41,6 +42,20 @@ impl SqliteStorage {
             None => None,
         })
     }
+
+    pub fn set_recommended_fees(&self, fees: &RecommendedFees) -> Result<()> {
+        let serialized_fees = serde_json::to_string(fees)?;
+        self.update_cached_item("recommended_fees".to_string(), serialized_fees)?;
+        Ok(())
+    }
+
+    pub fn get_recommended_fees(&self) -> Result<Option<RecommendedFees>> {
+        let fees_str = self.get_cached_item("recommended_fees".to_string())?;
+        Ok(match fees_str {
+            Some(str) => serde_json::from_str(str.as_str())?,
+            None => None,
+        })
+    }
 }
 
 #[test]
@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use rusqlite::{
     types::{FromSql, FromSqlError, ToSqlOutput},
     Connection, ToSql,
@@ -9,8 +9,9 @@ use rusqlite_migration::{Migrations, M};
 use super::migrations::current_migrations;
 
 pub struct SqliteStorage {
-    main_db_file: String,
-    sync_db_file: String,
+    pub(super) main_db_file: String,
+    pub(super) sync_db_file: String,
+    pub(super) encryption_key: Option<Vec<u8>>,
 }
 
 impl SqliteStorage {
@@ -21,24 +22,134 @@ impl SqliteStorage {
         SqliteStorage {
             main_db_file,
             sync_db_file,
+            encryption_key: None,
         }
     }
 
+    /// Encrypts both databases with `encryption_key`, see [crate::Config::db_encryption_key]
+    pub fn with_encryption_key(mut self, encryption_key: Option<Vec<u8>>) -> SqliteStorage {
+        self.encryption_key = encryption_key;
+        self
+    }
+
     pub fn init(&self) -> Result<()> {
+        if let Some(key) = &self.encryption_key {
+            encrypt_if_plaintext(&self.main_db_file, key)?;
+            encrypt_if_plaintext(&self.sync_db_file, key)?;
+        }
         let migrations = Migrations::new(current_migrations().into_iter().map(M::up).collect());
         let mut conn = self.get_connection()?;
-        migrations
-            .to_latest(&mut conn)
-            .map_err(anyhow::Error::msg)?;
-        Ok(())
+        migrations.to_latest(&mut conn)?;
+        self.run_backfills()
     }
 
     pub(crate) fn get_connection(&self) -> Result<Connection> {
-        let con = Connection::open(self.main_db_file.clone()).map_err(anyhow::Error::msg)?;
+        let con = Connection::open(self.main_db_file.clone())?;
+        // The key has to be applied first, the attached database then uses the same key
+        if let Some(key) = &self.encryption_key {
+            apply_encryption_key(&con, key)?;
+        }
         let sql = "ATTACH DATABASE ? AS sync;";
         con.execute(sql, [self.sync_db_file.clone()])?;
         Ok(con)
     }
+
+    /// Rebuilds both databases to reclaim the space left by deleted rows, then truncates the
+    /// write-ahead log.
+    ///
+    /// `VACUUM` needs exclusive access and takes time on large databases, so this should only
+    /// be called while the SDK is idle.
+    pub fn compact(&self) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute_batch("VACUUM main; VACUUM sync;")?;
+        con.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlcipher")]
+fn apply_encryption_key(con: &Connection, key: &[u8]) -> Result<()> {
+    // Passed as a raw key, so SQLCipher doesn't derive it from a passphrase
+    con.execute_batch(&format!("PRAGMA key = \"x'{}'\";", hex::encode(key)))?;
+    // SQLCipher only reads the database on first access, fail here if the key is wrong
+    con.query_row("SELECT count(*) FROM sqlite_master", [], |_| Ok(()))
+        .map_err(|_| anyhow!("Failed to open the database with the given encryption key"))
+}
+
+#[cfg(not(feature = "sqlcipher"))]
+fn apply_encryption_key(_con: &Connection, _key: &[u8]) -> Result<()> {
+    Err(anyhow!(
+        "Database encryption requires the SDK to be built with the sqlcipher feature"
+    ))
+}
+
+/// Encrypts the database at `path` with `key` if it exists unencrypted, so that setting
+/// [crate::Config::db_encryption_key] on an existing install keeps its data.
+///
+/// The database is exported to an encrypted copy with `sqlcipher_export`, which then replaces it.
+#[cfg(feature = "sqlcipher")]
+fn encrypt_if_plaintext(path: &str, key: &[u8]) -> Result<()> {
+    if !is_plaintext_db(path)? {
+        return Ok(());
+    }
+
+    let encrypted_path = format!("{path}.encrypted");
+    remove_db_files(&encrypted_path)?;
+    {
+        let con = Connection::open(path)?;
+        con.execute(
+            &format!(
+                "ATTACH DATABASE ? AS encrypted KEY \"x'{}'\";",
+                hex::encode(key)
+            ),
+            [&encrypted_path],
+        )?;
+        con.query_row("SELECT sqlcipher_export('encrypted')", [], |_| Ok(()))?;
+        con.execute_batch("DETACH DATABASE encrypted;")?;
+    }
+    std::fs::rename(&encrypted_path, path)?;
+    // The journal or write-ahead log of the unencrypted database doesn't apply to the new file
+    remove_sidecar_files(path)
+}
+
+#[cfg(not(feature = "sqlcipher"))]
+fn encrypt_if_plaintext(_path: &str, _key: &[u8]) -> Result<()> {
+    Ok(())
+}
+
+/// Whether the file at `path` is an unencrypted SQLite database, i.e. starts with its header
+#[cfg(feature = "sqlcipher")]
+fn is_plaintext_db(path: &str) -> Result<bool> {
+    use std::io::Read;
+
+    let mut file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err.into()),
+    };
+    let mut header = [0; 16];
+    Ok(file.read_exact(&mut header).is_ok() && &header == b"SQLite format 3\0")
+}
+
+/// Removes the rollback journal and write-ahead log files SQLite may keep next to `db_file`
+pub(super) fn remove_sidecar_files(db_file: &str) -> Result<()> {
+    for suffix in ["-journal", "-wal", "-shm"] {
+        match std::fs::remove_file(format!("{db_file}{suffix}")) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err.into()),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Removes the database at `db_file`, if any, along with its sidecar files
+#[cfg(feature = "sqlcipher")]
+fn remove_db_files(db_file: &str) -> Result<()> {
+    match std::fs::remove_file(db_file) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err.into()),
+        _ => {}
+    }
+    remove_sidecar_files(db_file)
 }
 
 pub(crate) struct StringArray(pub Vec<String>);
@@ -57,3 +168,98 @@ impl ToSql for StringArray {
         Ok(ToSqlOutput::from(res?))
     }
 }
+
+#[test]
+fn test_compact() -> Result<()> {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init()?;
+    for i in 0..100 {
+        storage.update_setting(format!("key{i}"), "value".repeat(100))?;
+    }
+    for i in 0..90 {
+        storage.delete_setting(format!("key{i}"))?;
+    }
+
+    storage.compact()?;
+    let settings = storage.list_settings()?;
+    assert_eq!(settings.len(), 10);
+    assert_eq!(
+        storage.get_setting("key99".to_string())?,
+        Some("value".repeat(100))
+    );
+
+    // The compacted database is still usable
+    storage.update_setting("key0".to_string(), "value".to_string())?;
+    assert_eq!(storage.list_settings()?.len(), 11);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "sqlcipher")]
+fn test_encrypted_storage() -> Result<()> {
+    use crate::persist::test_utils;
+
+    let working_dir = test_utils::create_test_sql_dir();
+    let storage = SqliteStorage::new(working_dir.clone()).with_encryption_key(Some(vec![1; 32]));
+    storage.init()?;
+    storage.update_setting("key".to_string(), "value".to_string())?;
+
+    // Neither without a key nor with another key
+    assert!(SqliteStorage::new(working_dir.clone()).init().is_err());
+    assert!(SqliteStorage::new(working_dir.clone())
+        .with_encryption_key(Some(vec![2; 32]))
+        .init()
+        .is_err());
+
+    let storage = SqliteStorage::new(working_dir).with_encryption_key(Some(vec![1; 32]));
+    storage.init()?;
+    assert_eq!(
+        storage.get_setting("key".to_string())?,
+        Some("value".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "sqlcipher")]
+fn test_encrypt_existing_storage() -> Result<()> {
+    use crate::persist::test_utils;
+
+    let working_dir = test_utils::create_test_sql_dir();
+    let storage = SqliteStorage::new(working_dir.clone());
+    storage.init()?;
+    storage.update_setting("key".to_string(), "value".to_string())?;
+    assert!(is_plaintext_db(&storage.main_db_file)?);
+
+    // Enabling the encryption keeps the data of the unencrypted databases
+    let storage = SqliteStorage::new(working_dir.clone()).with_encryption_key(Some(vec![1; 32]));
+    storage.init()?;
+    assert!(!is_plaintext_db(&storage.main_db_file)?);
+    assert!(!is_plaintext_db(&storage.sync_db_file)?);
+    assert_eq!(
+        storage.get_setting("key".to_string())?,
+        Some("value".to_string())
+    );
+    assert!(SqliteStorage::new(working_dir.clone()).init().is_err());
+
+    // Once encrypted, they are opened as is
+    let storage = SqliteStorage::new(working_dir).with_encryption_key(Some(vec![1; 32]));
+    storage.init()?;
+    assert_eq!(
+        storage.get_setting("key".to_string())?,
+        Some("value".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "sqlcipher"))]
+fn test_encryption_requires_sqlcipher() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir())
+        .with_encryption_key(Some(vec![1; 32]));
+    assert!(storage.init().is_err());
+}
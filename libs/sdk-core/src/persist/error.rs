@@ -0,0 +1,134 @@
+use std::fmt;
+
+use rusqlite::ErrorCode;
+
+/// A failure of the persistent storage, classified so that apps can react to it, e.g. by retrying
+/// an operation that failed with [StorageError::Busy]
+#[derive(Debug)]
+pub enum StorageError {
+    /// A uniqueness, foreign key or other constraint was violated
+    ConstraintViolation(rusqlite::Error),
+    /// The database is locked by another connection, the operation can be retried
+    Busy(rusqlite::Error),
+    /// The database file is corrupt or isn't a database
+    Corrupt(rusqlite::Error),
+    /// Any other storage failure
+    Other(rusqlite::Error),
+}
+
+impl From<rusqlite::Error> for StorageError {
+    fn from(err: rusqlite::Error) -> Self {
+        let code = match &err {
+            rusqlite::Error::SqliteFailure(failure, _) => Some(failure.code),
+            _ => None,
+        };
+        match code {
+            Some(ErrorCode::ConstraintViolation) => StorageError::ConstraintViolation(err),
+            Some(ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked) => StorageError::Busy(err),
+            Some(ErrorCode::DatabaseCorrupt | ErrorCode::NotADatabase) => {
+                StorageError::Corrupt(err)
+            }
+            _ => StorageError::Other(err),
+        }
+    }
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::ConstraintViolation(err) => {
+                write!(f, "Storage constraint violation: {err}")
+            }
+            StorageError::Busy(err) => write!(f, "Storage is busy: {err}"),
+            StorageError::Corrupt(err) => write!(f, "Storage is corrupt: {err}"),
+            StorageError::Other(err) => write!(f, "Storage error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StorageError::ConstraintViolation(err)
+            | StorageError::Busy(err)
+            | StorageError::Corrupt(err)
+            | StorageError::Other(err) => Some(err),
+        }
+    }
+}
+
+/// Replaces a raw [rusqlite::Error] with the matching [StorageError], leaving other errors as
+/// they are. Used where errors leave the SDK, so that apps can tell storage failures apart.
+///
+/// A SQLite failure found deeper in the chain of `err`, e.g. under a context, is mapped as well
+/// and keeps the message of `err`.
+pub fn map_storage_error(err: anyhow::Error) -> anyhow::Error {
+    let err = match err.downcast::<rusqlite::Error>() {
+        Ok(err) => return StorageError::from(err).into(),
+        Err(err) => err,
+    };
+    let failure = err
+        .chain()
+        .find_map(|cause| match cause.downcast_ref::<rusqlite::Error>() {
+            Some(rusqlite::Error::SqliteFailure(failure, msg)) => {
+                Some(rusqlite::Error::SqliteFailure(*failure, msg.clone()))
+            }
+            _ => None,
+        });
+    match failure {
+        Some(failure) => anyhow::Error::from(StorageError::from(failure)).context(err.to_string()),
+        None => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{anyhow, Context, Result};
+    use rusqlite::TransactionBehavior;
+
+    use super::{map_storage_error, StorageError};
+    use crate::persist::db::SqliteStorage;
+    use crate::persist::test_utils;
+
+    fn storage_error(res: Result<usize>) -> StorageError {
+        map_storage_error(res.unwrap_err())
+            .downcast::<StorageError>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_map_storage_error() -> Result<()> {
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        let con = storage.get_connection()?;
+        con.execute("CREATE TABLE keyed (id INTEGER PRIMARY KEY)", [])?;
+        con.execute("INSERT INTO keyed (id) VALUES (1)", [])?;
+
+        let insert =
+            || -> Result<usize> { Ok(con.execute("INSERT INTO keyed (id) VALUES (1)", [])?) };
+        let err = storage_error(insert());
+        assert!(matches!(err, StorageError::ConstraintViolation(_)));
+        // The underlying error is kept as the source
+        let source = std::error::Error::source(&err).unwrap();
+        assert!(source.to_string().contains("UNIQUE constraint failed"));
+
+        // A write while another connection holds the write lock
+        let mut locking = storage.get_connection()?;
+        let _lock = locking.transaction_with_behavior(TransactionBehavior::Exclusive)?;
+        let err = storage_error(insert());
+        assert!(matches!(err, StorageError::Busy(_)));
+
+        // A failure under a context is mapped too, keeping the context
+        let err = map_storage_error(insert().context("Failed to insert").unwrap_err());
+        assert!(matches!(
+            err.downcast_ref::<StorageError>(),
+            Some(StorageError::Busy(_))
+        ));
+        assert_eq!(err.to_string(), "Failed to insert");
+
+        // Errors not raised by the storage are left as they are
+        let err = map_storage_error(anyhow!("Not a storage error"));
+        assert!(err.downcast_ref::<StorageError>().is_none());
+        Ok(())
+    }
+}
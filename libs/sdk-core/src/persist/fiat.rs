@@ -0,0 +1,189 @@
+use crate::fiat::Rate;
+
+use super::db::SqliteStorage;
+use anyhow::Result;
+use rusqlite::OptionalExtension;
+
+/// Snapshots are kept at most once per hour per currency
+const SNAPSHOT_INTERVAL_SEC: i64 = 60 * 60;
+/// Snapshots older than this are thinned out to one per day per currency
+const HOURLY_RETENTION_SEC: i64 = 30 * 24 * 60 * 60;
+const DAY_SEC: i64 = 24 * 60 * 60;
+
+impl SqliteStorage {
+    /// Stores a snapshot of the given rates, taken at `timestamp`.
+    ///
+    /// The snapshot replaces any other taken in the same hour, and the snapshots older than 30 days
+    /// are down-sampled to the first one of each day, so the history stays bounded.
+    pub fn insert_fiat_rates(&self, rates: &[Rate], timestamp: i64) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        let slot = timestamp - timestamp.rem_euclid(SNAPSHOT_INTERVAL_SEC);
+        {
+            let mut prep_statement = tx.prepare(
+                "
+                 DELETE FROM fiat_rate_history
+                 WHERE coin = ?1 AND timestamp >= ?2 AND timestamp < ?3
+                ",
+            )?;
+            for rate in rates {
+                prep_statement.execute((&rate.coin, slot, slot + SNAPSHOT_INTERVAL_SEC))?;
+            }
+            let mut prep_statement = tx.prepare(
+                "INSERT OR REPLACE INTO fiat_rate_history (coin, value, timestamp) VALUES (?1,?2,?3)",
+            )?;
+            for rate in rates {
+                prep_statement.execute((&rate.coin, rate.value, timestamp))?;
+            }
+        }
+        tx.execute(
+            "
+             DELETE FROM fiat_rate_history
+             WHERE timestamp < ?1
+             AND EXISTS (
+              SELECT 1 FROM fiat_rate_history AS earlier
+              WHERE earlier.coin = fiat_rate_history.coin
+              AND earlier.timestamp / ?2 = fiat_rate_history.timestamp / ?2
+              AND earlier.timestamp < fiat_rate_history.timestamp
+             )
+            ",
+            (timestamp - HOURLY_RETENTION_SEC, DAY_SEC),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// The time of the most recent rates snapshot, if any
+    pub fn last_fiat_rates_timestamp(&self) -> Result<Option<i64>> {
+        Ok(self.get_connection()?.query_row(
+            "SELECT MAX(timestamp) FROM fiat_rate_history",
+            [],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Looks up the snapshot of the `coin` rate that is closest in time to `timestamp`
+    pub fn get_fiat_rate_at(&self, coin: &str, timestamp: i64) -> Result<Option<Rate>> {
+        // Both lookups are bounded range scans on the (coin, timestamp) primary key
+        let con = self.get_connection()?;
+        let query_closest = |sql: &str| -> Result<Option<(i64, Rate)>> {
+            Ok(con
+                .query_row(sql, (coin, timestamp), |row| {
+                    Ok((
+                        row.get(2)?,
+                        Rate {
+                            coin: row.get(0)?,
+                            value: row.get(1)?,
+                        },
+                    ))
+                })
+                .optional()?)
+        };
+        let before = query_closest(
+            "
+             SELECT coin, value, timestamp FROM fiat_rate_history
+             WHERE coin = ?1 AND timestamp <= ?2
+             ORDER BY timestamp DESC
+             LIMIT 1
+            ",
+        )?;
+        let after = query_closest(
+            "
+             SELECT coin, value, timestamp FROM fiat_rate_history
+             WHERE coin = ?1 AND timestamp >= ?2
+             ORDER BY timestamp ASC
+             LIMIT 1
+            ",
+        )?;
+
+        Ok(match (before, after) {
+            (Some((t_before, before)), Some((t_after, after))) => {
+                if timestamp - t_before <= t_after - timestamp {
+                    Some(before)
+                } else {
+                    Some(after)
+                }
+            }
+            (before, after) => before.or(after).map(|(_, rate)| rate),
+        })
+    }
+}
+
+#[test]
+fn test_fiat_rate_history() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert_eq!(storage.last_fiat_rates_timestamp().unwrap(), None);
+    assert_eq!(storage.get_fiat_rate_at("USD", 1000).unwrap(), None);
+
+    let hour = SNAPSHOT_INTERVAL_SEC;
+    for (timestamp, value) in [(hour, 20_000.0), (2 * hour, 25_000.0), (3 * hour, 30_000.0)] {
+        let rates = [
+            Rate {
+                coin: "USD".to_string(),
+                value,
+            },
+            Rate {
+                coin: "EUR".to_string(),
+                value: value / 2.0,
+            },
+        ];
+        storage.insert_fiat_rates(&rates, timestamp).unwrap();
+    }
+    assert_eq!(storage.last_fiat_rates_timestamp().unwrap(), Some(3 * hour));
+
+    let rate = storage
+        .get_fiat_rate_at("USD", 2 * hour - 100)
+        .unwrap()
+        .unwrap();
+    assert_eq!(rate.value, 25_000.0);
+    let rate = storage.get_fiat_rate_at("EUR", 0).unwrap().unwrap();
+    assert_eq!(rate.value, 10_000.0);
+    let rate = storage.get_fiat_rate_at("USD", 10 * hour).unwrap().unwrap();
+    assert_eq!(rate.value, 30_000.0);
+    assert_eq!(storage.get_fiat_rate_at("GBP", hour).unwrap(), None);
+}
+
+#[test]
+fn test_fiat_rate_history_down_sampling() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    let usd = |value| {
+        [Rate {
+            coin: "USD".to_string(),
+            value,
+        }]
+    };
+    let count = || -> i64 {
+        storage
+            .get_connection()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM fiat_rate_history", [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+    };
+
+    // A day of snapshots every 10 minutes keeps one per hour, the latest of each
+    for i in 0..144 {
+        storage.insert_fiat_rates(&usd(i as f64), i * 600).unwrap();
+    }
+    assert_eq!(count(), 24);
+    let rate = storage.get_fiat_rate_at("USD", 0).unwrap().unwrap();
+    assert_eq!(rate.value, 5.0);
+
+    // Once they are older than the hourly retention, only the first of the day is kept
+    storage
+        .insert_fiat_rates(&usd(1000.0), HOURLY_RETENTION_SEC + DAY_SEC)
+        .unwrap();
+    assert_eq!(count(), 2);
+    let rate = storage
+        .get_fiat_rate_at("USD", DAY_SEC / 2)
+        .unwrap()
+        .unwrap();
+    assert_eq!(rate.value, 5.0);
+}
@@ -0,0 +1,70 @@
+use crate::models::LnUrlAuthLogin;
+
+use super::db::SqliteStorage;
+use anyhow::Result;
+
+impl SqliteStorage {
+    /// Records an LNURL-auth attempt
+    pub fn insert_lnurl_auth_login(&self, login: &LnUrlAuthLogin) -> Result<()> {
+        self.get_connection()?.execute(
+            "
+             INSERT INTO lnurl_auth_history (
+               domain,
+               k1,
+               timestamp,
+               success
+             )
+             VALUES (?1,?2,?3,?4)
+            ",
+            (&login.domain, &login.k1, login.timestamp, login.success),
+        )?;
+        Ok(())
+    }
+
+    /// Lists the recorded LNURL-auth attempts, most recent first
+    pub fn list_lnurl_auth_logins(&self) -> Result<Vec<LnUrlAuthLogin>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT domain, k1, timestamp, success FROM lnurl_auth_history ORDER BY timestamp DESC, id DESC",
+        )?;
+        let logins = stmt
+            .query_map([], |row| {
+                Ok(LnUrlAuthLogin {
+                    domain: row.get(0)?,
+                    k1: row.get(1)?,
+                    timestamp: row.get(2)?,
+                    success: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(logins)
+    }
+}
+
+#[test]
+fn test_lnurl_auth_logins() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert!(storage.list_lnurl_auth_logins().unwrap().is_empty());
+
+    let first = LnUrlAuthLogin {
+        domain: "example.com".to_string(),
+        k1: "k1".to_string(),
+        timestamp: 1000,
+        success: true,
+    };
+    let second = LnUrlAuthLogin {
+        domain: "example.org".to_string(),
+        k1: "k2".to_string(),
+        timestamp: 2000,
+        success: false,
+    };
+    storage.insert_lnurl_auth_login(&first).unwrap();
+    storage.insert_lnurl_auth_login(&second).unwrap();
+    assert_eq!(
+        storage.list_lnurl_auth_logins().unwrap(),
+        vec![second, first]
+    );
+}
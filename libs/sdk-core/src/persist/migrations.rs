@@ -362,5 +362,58 @@ pub(crate) fn current_migrations() -> Vec<&'static str> {
          
          DROP TABLE old_payments_external_info;
         ",
+       "
+       CREATE TABLE IF NOT EXISTS settled_incoming_payments (
+        payment_hash TEXT NOT NULL PRIMARY KEY,
+        settled_at INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS fiat_rate_history (
+        coin TEXT NOT NULL,
+        value REAL NOT NULL,
+        timestamp INTEGER NOT NULL,
+        PRIMARY KEY (coin, timestamp)
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS sweeps (
+        txid TEXT NOT NULL PRIMARY KEY,
+        amount_sat INTEGER NOT NULL,
+        fee_sat INTEGER NOT NULL,
+        destination TEXT NOT NULL,
+        created_at INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS lnurl_auth_history (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        domain TEXT NOT NULL,
+        k1 TEXT NOT NULL,
+        timestamp INTEGER NOT NULL,
+        success INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "
+       ALTER TABLE swaps_info ADD COLUMN expiry_block INTEGER NOT NULL DEFAULT 0;
+       ALTER TABLE swaps_info ADD COLUMN expires_at INTEGER NOT NULL DEFAULT 0;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS backfills (
+        name TEXT NOT NULL PRIMARY KEY,
+        last_rowid INTEGER NOT NULL,
+        completed INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE INDEX IF NOT EXISTS payments_payment_time ON payments(payment_time);
+       CREATE INDEX IF NOT EXISTS payments_payment_type ON payments(payment_type);
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS sync.payments_custom_tlvs (
+        payment_id TEXT NOT NULL PRIMARY KEY,
+        custom_tlvs TEXT NOT NULL
+       ) STRICT;
+       ",
     ]
 }
@@ -1,9 +1,15 @@
+pub(crate) mod backfill;
+pub(crate) mod backup;
 pub(crate) mod cache;
 pub(crate) mod channels;
 pub(crate) mod db;
+pub(crate) mod error;
+pub(crate) mod fiat;
+pub(crate) mod lnurl_auth;
 pub(crate) mod migrations;
 pub(crate) mod settings;
 pub(crate) mod swap;
+pub(crate) mod sweep;
 pub(crate) mod transactions;
 
 #[cfg(test)]
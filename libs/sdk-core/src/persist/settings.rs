@@ -1,6 +1,10 @@
 use super::db::SqliteStorage;
+use crate::chain::FeeTier;
 use anyhow::{anyhow, Result};
 
+/// Prefix of the keys of the app settings, keeping them apart from the ones of the SDK
+const APP_SETTING_PREFIX: &str = "app.";
+
 #[allow(dead_code)]
 pub struct SettingItem {
     key: String,
@@ -58,6 +62,36 @@ impl SqliteStorage {
         self.get_setting("lsp".to_string())
             .map_err(|err| anyhow!(err))
     }
+
+    pub fn set_preferred_fee_tier(&self, tier: &FeeTier) -> Result<()> {
+        self.update_setting(
+            "preferred_fee_tier".to_string(),
+            serde_json::to_string(tier)?,
+        )
+    }
+
+    pub fn get_preferred_fee_tier(&self) -> Result<Option<FeeTier>> {
+        Ok(match self.get_setting("preferred_fee_tier".to_string())? {
+            Some(tier) => Some(serde_json::from_str(&tier)?),
+            None => None,
+        })
+    }
+
+    pub fn set_preferred_fiat_currency(&self, id: String) -> Result<()> {
+        self.update_setting("preferred_fiat_currency".to_string(), id)
+    }
+
+    pub fn get_preferred_fiat_currency(&self) -> Result<Option<String>> {
+        self.get_setting("preferred_fiat_currency".to_string())
+    }
+
+    pub fn set_app_setting(&self, key: String, value: String) -> Result<()> {
+        self.update_setting(format!("{APP_SETTING_PREFIX}{key}"), value)
+    }
+
+    pub fn get_app_setting(&self, key: String) -> Result<Option<String>> {
+        self.get_setting(format!("{APP_SETTING_PREFIX}{key}"))
+    }
 }
 
 #[test]
@@ -89,3 +123,21 @@ fn test_settings() {
     assert_eq!(settings[1].key, "key2");
     assert_eq!(settings[1].value, "val3");
 }
+
+#[test]
+fn test_preferred_fee_tier() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert_eq!(storage.get_preferred_fee_tier().unwrap(), None);
+
+    let tier = FeeTier::Custom { sat_per_vbyte: 12 };
+    storage.set_preferred_fee_tier(&tier).unwrap();
+    assert_eq!(storage.get_preferred_fee_tier().unwrap(), Some(tier));
+    storage.set_preferred_fee_tier(&FeeTier::Economy).unwrap();
+    assert_eq!(
+        storage.get_preferred_fee_tier().unwrap(),
+        Some(FeeTier::Economy)
+    );
+}
@@ -49,8 +49,10 @@ impl SqliteStorage {
           unconfirmed_sats, 
           unconfirmed_tx_ids, 
           confirmed_sats,
-          confirmed_tx_ids     
-        ) VALUES (:bitcoin_address, :status, :bolt11, :paid_sats, :unconfirmed_sats, :unconfirmed_tx_ids, :confirmed_sats, :confirmed_tx_ids)",         
+          confirmed_tx_ids,
+          expiry_block,
+          expires_at
+        ) VALUES (:bitcoin_address, :status, :bolt11, :paid_sats, :unconfirmed_sats, :unconfirmed_tx_ids, :confirmed_sats, :confirmed_tx_ids, :expiry_block, :expires_at)",
             named_params! {
                ":bitcoin_address": swap_info.bitcoin_address,
                ":status": swap_info.status as i32,
@@ -59,7 +61,9 @@ impl SqliteStorage {
                ":unconfirmed_sats": swap_info.unconfirmed_sats,
                ":unconfirmed_tx_ids": StringArray(swap_info.unconfirmed_tx_ids),
                ":confirmed_sats": swap_info.confirmed_sats,
-               ":confirmed_tx_ids": StringArray(swap_info.confirmed_tx_ids)               
+               ":confirmed_tx_ids": StringArray(swap_info.confirmed_tx_ids),
+               ":expiry_block": swap_info.expiry_block,
+               ":expires_at": swap_info.expires_at
             },
         )?;
         tx.commit()?;
@@ -98,6 +102,24 @@ impl SqliteStorage {
         Ok(())
     }
 
+    pub(crate) fn update_swap_expiry(
+        &self,
+        bitcoin_address: String,
+        expiry_block: u32,
+        expires_at: i64,
+    ) -> Result<()> {
+        self.get_connection()?.execute(
+            "UPDATE swaps_info SET expiry_block=:expiry_block, expires_at=:expires_at where bitcoin_address=:bitcoin_address",
+            named_params! {
+             ":expiry_block": expiry_block,
+             ":expires_at": expires_at,
+             ":bitcoin_address": bitcoin_address,
+            },
+        )?;
+
+        Ok(())
+    }
+
     pub(crate) fn update_swap_bolt11(&self, bitcoin_address: String, bolt11: String) -> Result<()> {
         self.get_connection()?.execute(
             "UPDATE swaps_info SET bolt11=:bolt11 where bitcoin_address=:bitcoin_address",
@@ -126,6 +148,27 @@ impl SqliteStorage {
         Ok(())
     }
 
+    pub(crate) fn delete_swaps(&self, bitcoin_addresses: &[String]) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        for bitcoin_address in bitcoin_addresses {
+            tx.execute(
+                "DELETE FROM swaps_info WHERE bitcoin_address = ?1",
+                [bitcoin_address],
+            )?;
+            tx.execute(
+                "DELETE FROM sync.swap_refunds WHERE bitcoin_address = ?1",
+                [bitcoin_address],
+            )?;
+            tx.execute(
+                "DELETE FROM sync.swaps WHERE bitcoin_address = ?1",
+                [bitcoin_address],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn update_swap_chain_info(
         &self,
@@ -173,7 +216,9 @@ impl SqliteStorage {
              (SELECT json_group_array(refund_tx_id) FROM sync.swap_refunds as swap_refunds where bitcoin_address = swaps.bitcoin_address) as refund_tx_ids,
              unconfirmed_tx_ids as unconfirmed_tx_ids,
              confirmed_tx_ids as confirmed_tx_ids,
-             last_redeem_error as last_redeem_error               
+             last_redeem_error as last_redeem_error,
+             expiry_block as expiry_block,
+             expires_at as expires_at
             FROM sync.swaps as swaps
              LEFT JOIN swaps_info ON swaps.bitcoin_address = swaps_info.bitcoin_address
              LEFT JOIN sync.swap_refunds as swap_refunds ON swaps.bitcoin_address = swap_refunds.bitcoin_address
@@ -272,6 +317,12 @@ impl SqliteStorage {
             min_allowed_deposit: row.get("min_allowed_deposit")?,
             max_allowed_deposit: row.get("max_allowed_deposit")?,
             last_redeem_error: row.get("last_redeem_error")?,
+            expiry_block: row
+                .get::<&str, Option<u32>>("expiry_block")?
+                .unwrap_or_default(),
+            expires_at: row
+                .get::<&str, Option<i64>>("expires_at")?
+                .unwrap_or_default(),
         })
     }
 }
@@ -311,6 +362,8 @@ fn test_swaps() -> Result<(), Box<dyn std::error::Error>> {
         min_allowed_deposit: 0,
         max_allowed_deposit: 100,
         last_redeem_error: None,
+        expiry_block: 800_000,
+        expires_at: 1_700_000_000,
     };
     storage.insert_swap(tested_swap_info.clone())?;
     let item_value = storage.get_swap_info_by_address("1".to_string())?.unwrap();
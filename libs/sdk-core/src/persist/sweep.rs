@@ -0,0 +1,74 @@
+use crate::models::SweepResult;
+
+use super::db::SqliteStorage;
+use anyhow::Result;
+
+impl SqliteStorage {
+    /// Records a broadcast sweep transaction, created at `created_at`
+    pub fn insert_sweep(&self, sweep: &SweepResult, created_at: i64) -> Result<()> {
+        self.get_connection()?.execute(
+            "
+             INSERT OR REPLACE INTO sweeps (
+               txid,
+               amount_sat,
+               fee_sat,
+               destination,
+               created_at
+             )
+             VALUES (?1,?2,?3,?4,?5)
+            ",
+            (
+                &sweep.txid,
+                sweep.amount_sat,
+                sweep.fee_sat,
+                &sweep.destination,
+                created_at,
+            ),
+        )?;
+        Ok(())
+    }
+
+    /// Lists the recorded sweeps, most recent first
+    pub fn list_sweeps(&self) -> Result<Vec<SweepResult>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT txid, amount_sat, fee_sat, destination FROM sweeps ORDER BY created_at DESC",
+        )?;
+        let sweeps = stmt
+            .query_map([], |row| {
+                Ok(SweepResult {
+                    txid: row.get(0)?,
+                    amount_sat: row.get(1)?,
+                    fee_sat: row.get(2)?,
+                    destination: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(sweeps)
+    }
+}
+
+#[test]
+fn test_sweeps() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert!(storage.list_sweeps().unwrap().is_empty());
+
+    let first = SweepResult {
+        txid: "txid1".to_string(),
+        amount_sat: 10_000,
+        fee_sat: 200,
+        destination: "address1".to_string(),
+    };
+    let second = SweepResult {
+        txid: "txid2".to_string(),
+        amount_sat: 20_000,
+        fee_sat: 300,
+        destination: "address2".to_string(),
+    };
+    storage.insert_sweep(&first, 1000).unwrap();
+    storage.insert_sweep(&second, 2000).unwrap();
+    assert_eq!(storage.list_sweeps().unwrap(), vec![second, first]);
+}
@@ -2,7 +2,7 @@ use super::db::SqliteStorage;
 use crate::lnurl::pay::model::SuccessActionProcessed;
 use crate::models::*;
 use anyhow::{anyhow, Result};
-use rusqlite::types::{FromSql, FromSqlError, ToSql, ToSqlOutput};
+use rusqlite::types::{FromSql, FromSqlError, ToSql, ToSqlOutput, Type};
 use rusqlite::OptionalExtension;
 use rusqlite::Row;
 use std::str::FromStr;
@@ -13,9 +13,15 @@ impl SqliteStorage {
     /// Note that, if a payment has details of type [LnPaymentDetails] which contain a [SuccessActionProcessed],
     /// then the [LnPaymentDetails] will NOT be persisted. In that case, the [SuccessActionProcessed]
     /// can be inserted separately via [SqliteStorage::insert_lnurl_payment_external_info].
+    ///
+    /// The custom TLV records of received payments are also kept separately, since the node
+    /// doesn't report them once the payment is pulled again on sync.
+    ///
+    /// All the payments are written in a single transaction.
     pub fn insert_payments(&self, transactions: &[Payment]) -> Result<()> {
-        let con = self.get_connection()?;
-        let mut prep_statement = con.prepare(
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        let mut prep_statement = tx.prepare(
             "
          INSERT OR REPLACE INTO payments (
            id,
@@ -42,7 +48,17 @@ impl SqliteStorage {
                 &ln_tx.description,
                 &ln_tx.details,
             ))?;
+            if let PaymentDetails::Ln { data } = &ln_tx.details {
+                if !data.custom_tlvs.is_empty() {
+                    tx.execute(
+                        "INSERT OR REPLACE INTO sync.payments_custom_tlvs (payment_id, custom_tlvs) VALUES (?1,?2)",
+                        (&ln_tx.id, serde_json::to_string(&data.custom_tlvs)?),
+                    )?;
+                }
+            }
         }
+        drop(prep_statement);
+        tx.commit()?;
         Ok(())
     }
 
@@ -77,6 +93,28 @@ impl SqliteStorage {
         Ok(())
     }
 
+    /// Records the settlement of an incoming payment.
+    ///
+    /// Returns false if a settlement was already recorded for this payment hash.
+    pub fn insert_incoming_settlement(&self, payment_hash: &str, settled_at: i64) -> Result<bool> {
+        let inserted = self.get_connection()?.execute(
+            "INSERT OR IGNORE INTO settled_incoming_payments (payment_hash, settled_at) VALUES (?1,?2)",
+            (payment_hash, settled_at),
+        )?;
+        Ok(inserted == 1)
+    }
+
+    /// Deletes the payments and channels, which are pulled from the node again on the next sync.
+    /// Data that can't be pulled from the node, like the LNURL metadata of payments, is kept.
+    pub(crate) fn delete_node_derived_data(&self) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        tx.execute("DELETE FROM payments", [])?;
+        tx.execute("DELETE FROM channels", [])?;
+        tx.commit()?;
+        Ok(())
+    }
+
     pub fn last_payment_timestamp(&self) -> Result<i64> {
         self.get_connection()?
             .query_row("SELECT max(payment_time) FROM payments", [], |row| {
@@ -88,13 +126,29 @@ impl SqliteStorage {
     /// Constructs [Payment] by joining data in the `payment` and `payments_external_info` tables
     ///
     /// This queries all payments. To query a single payment, see [Self::get_payment_by_hash]
+    ///
+    /// The `description_contains` filter is matched case-insensitively against the payment description.
     pub fn list_payments(
         &self,
         type_filter: PaymentTypeFilter,
         from_timestamp: Option<i64>,
         to_timestamp: Option<i64>,
+        min_amount_msat: Option<u64>,
+        max_amount_msat: Option<u64>,
+        description_contains: Option<String>,
     ) -> Result<Vec<Payment>> {
-        let where_clause = filter_to_where_clause(type_filter, from_timestamp, to_timestamp);
+        let where_clause = filter_to_where_clause(
+            type_filter,
+            from_timestamp,
+            to_timestamp,
+            min_amount_msat,
+            max_amount_msat,
+            description_contains.is_some(),
+        );
+        let params: Vec<String> = description_contains
+            .into_iter()
+            .map(|d| format!("%{}%", escape_like_pattern(&d.to_lowercase())))
+            .collect();
         let con = self.get_connection()?;
         let mut stmt = con.prepare(
             format!(
@@ -110,11 +164,15 @@ impl SqliteStorage {
              p.details,
              e.lnurl_success_action,
              e.lnurl_metadata,
-             e.ln_address
+             e.ln_address,
+             t.custom_tlvs
             FROM payments p
             LEFT JOIN sync.payments_external_info e
             ON
              p.id = e.payment_id
+            LEFT JOIN sync.payments_custom_tlvs t
+            ON
+             p.id = t.payment_id
             {where_clause} ORDER BY payment_time DESC
           "
             )
@@ -122,7 +180,9 @@ impl SqliteStorage {
         )?;
 
         let vec: Vec<Payment> = stmt
-            .query_map([], |row| self.sql_row_to_payment(row))?
+            .query_map(rusqlite::params_from_iter(params), |row| {
+                self.sql_row_to_payment(row)
+            })?
             .map(|i| i.unwrap())
             .collect();
 
@@ -131,6 +191,7 @@ impl SqliteStorage {
 
     /// This queries a single payment by hash.
     ///
+    /// The payment id is its hash and the primary key of the table, so this is an indexed lookup.
     /// To query all payments, see [Self::list_payments]
     pub(crate) fn get_payment_by_hash(&self, hash: &String) -> Result<Option<Payment>> {
         self.get_connection()?
@@ -147,11 +208,15 @@ impl SqliteStorage {
                  p.details,
                  e.lnurl_success_action,
                  e.lnurl_metadata,
-                 e.ln_address
+                 e.ln_address,
+                 t.custom_tlvs
                 FROM payments p
                 LEFT JOIN sync.payments_external_info e
                 ON
                  p.id = e.payment_id
+                LEFT JOIN sync.payments_custom_tlvs t
+                ON
+                 p.id = t.payment_id
                 WHERE
                  id = ?1",
                 [hash],
@@ -178,6 +243,12 @@ impl SqliteStorage {
             data.lnurl_success_action = row.get(8)?;
             data.lnurl_metadata = row.get(9)?;
             data.ln_address = row.get(10)?;
+            let custom_tlvs: Option<String> = row.get(11)?;
+            if let Some(custom_tlvs) = custom_tlvs {
+                data.custom_tlvs = serde_json::from_str(&custom_tlvs).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(11, Type::Text, Box::new(e))
+                })?;
+            }
         }
 
         Ok(payment)
@@ -188,6 +259,9 @@ fn filter_to_where_clause(
     type_filter: PaymentTypeFilter,
     from_timestamp: Option<i64>,
     to_timestamp: Option<i64>,
+    min_amount_msat: Option<u64>,
+    max_amount_msat: Option<u64>,
+    filter_description: bool,
 ) -> String {
     let mut where_clause: Vec<String> = Vec::new();
 
@@ -197,6 +271,15 @@ fn filter_to_where_clause(
     if let Some(t) = to_timestamp {
         where_clause.push(format!("payment_time <= {t}"));
     };
+    if let Some(a) = min_amount_msat {
+        where_clause.push(format!("amount_msat >= {a}"));
+    };
+    if let Some(a) = max_amount_msat {
+        where_clause.push(format!("amount_msat <= {a}"));
+    };
+    if filter_description {
+        where_clause.push("lower(description) LIKE ?1 ESCAPE '\\'".to_string());
+    };
 
     match type_filter {
         PaymentTypeFilter::Sent => {
@@ -220,6 +303,13 @@ fn filter_to_where_clause(
     where_clause_str
 }
 
+/// Escapes the LIKE wildcards so the text is matched literally
+fn escape_like_pattern(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 impl FromSql for PaymentDetails {
     fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
         serde_json::from_str(value.as_str()?).map_err(|_| FromSqlError::InvalidType)
@@ -284,6 +374,8 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
                     lnurl_success_action: Some(sa.clone()),
                     lnurl_metadata: Some(lnurl_metadata.to_string()),
                     ln_address: Some(test_ln_address.to_string()),
+                    route: vec![],
+                    custom_tlvs: vec![],
                 },
             },
         },
@@ -306,6 +398,8 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
                     lnurl_success_action: None,
                     lnurl_metadata: None,
                     ln_address: None,
+                    route: vec![],
+                    custom_tlvs: vec![],
                 },
             },
         },
@@ -321,12 +415,14 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
     )?;
 
     // retrieve all
-    let retrieve_txs = storage.list_payments(PaymentTypeFilter::All, None, None)?;
+    let retrieve_txs =
+        storage.list_payments(PaymentTypeFilter::All, None, None, None, None, None)?;
     assert_eq!(retrieve_txs.len(), 2);
     assert_eq!(retrieve_txs, txs);
 
     //test only sent
-    let retrieve_txs = storage.list_payments(PaymentTypeFilter::Sent, None, None)?;
+    let retrieve_txs =
+        storage.list_payments(PaymentTypeFilter::Sent, None, None, None, None, None)?;
     assert_eq!(retrieve_txs.len(), 1);
     assert_eq!(retrieve_txs[0], txs[0]);
     assert!(
@@ -337,17 +433,197 @@ fn test_ln_transactions() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     //test only received
-    let retrieve_txs = storage.list_payments(PaymentTypeFilter::Received, None, None)?;
+    let retrieve_txs =
+        storage.list_payments(PaymentTypeFilter::Received, None, None, None, None, None)?;
+    assert_eq!(retrieve_txs.len(), 1);
+    assert_eq!(retrieve_txs[0], txs[1]);
+
+    //test amount range
+    let retrieve_txs = storage.list_payments(
+        PaymentTypeFilter::All,
+        None,
+        None,
+        Some(100),
+        Some(100),
+        None,
+    )?;
+    assert_eq!(retrieve_txs.len(), 2);
+    let retrieve_txs =
+        storage.list_payments(PaymentTypeFilter::All, None, None, Some(101), None, None)?;
+    assert_eq!(retrieve_txs.len(), 0);
+    let retrieve_txs =
+        storage.list_payments(PaymentTypeFilter::All, None, None, None, Some(99), None)?;
+    assert_eq!(retrieve_txs.len(), 0);
+
+    //test description search
+    let retrieve_txs = storage.list_payments(
+        PaymentTypeFilter::All,
+        None,
+        None,
+        None,
+        None,
+        Some("DES".to_string()),
+    )?;
     assert_eq!(retrieve_txs.len(), 1);
     assert_eq!(retrieve_txs[0], txs[1]);
+    let retrieve_txs = storage.list_payments(
+        PaymentTypeFilter::All,
+        None,
+        None,
+        None,
+        None,
+        Some("d%".to_string()),
+    )?;
+    assert_eq!(retrieve_txs.len(), 0);
 
     let max_ts = storage.last_payment_timestamp()?;
     assert_eq!(max_ts, 1001);
 
     storage.insert_payments(&txs)?;
-    let retrieve_txs = storage.list_payments(PaymentTypeFilter::All, None, None)?;
+    let retrieve_txs =
+        storage.list_payments(PaymentTypeFilter::All, None, None, None, None, None)?;
     assert_eq!(retrieve_txs.len(), 2);
     assert_eq!(retrieve_txs, txs);
 
     Ok(())
 }
+
+#[test]
+fn test_payments_indexes_migration() -> Result<(), Box<dyn std::error::Error>> {
+    use crate::models::{LnPaymentDetails, Payment, PaymentDetails};
+    use crate::persist::migrations::current_migrations;
+    use crate::persist::test_utils;
+    use rusqlite_migration::{Migrations, M};
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+
+    // Bring the database to the schema it had before the indexes were added
+    let mut migrations = current_migrations();
+    let index_position = migrations
+        .iter()
+        .position(|m| m.contains("payments_payment_time"))
+        .unwrap();
+    let index_migration = migrations[index_position];
+    migrations.truncate(index_position);
+    Migrations::new(migrations.into_iter().map(M::up).collect())
+        .to_latest(&mut storage.get_connection()?)?;
+
+    let payment = Payment {
+        id: "123".to_string(),
+        payment_type: PaymentType::Sent,
+        payment_time: 1001,
+        amount_msat: 100,
+        fee_msat: 20,
+        pending: false,
+        description: Some("desc".to_string()),
+        details: PaymentDetails::Ln {
+            data: LnPaymentDetails {
+                payment_hash: "123".to_string(),
+                label: "label".to_string(),
+                destination_pubkey: "pubkey".to_string(),
+                payment_preimage: "payment_preimage".to_string(),
+                keysend: false,
+                bolt11: "bolt11".to_string(),
+                lnurl_success_action: None,
+                lnurl_metadata: None,
+                ln_address: None,
+                route: vec![],
+                custom_tlvs: vec![],
+            },
+        },
+    };
+    storage.insert_payments(&[payment.clone()])?;
+
+    // Upgrading keeps the existing payments, and running it again is a no-op
+    storage.init()?;
+    storage.init()?;
+    storage.get_connection()?.execute_batch(index_migration)?;
+    let retrieve_txs =
+        storage.list_payments(PaymentTypeFilter::All, None, None, None, None, None)?;
+    assert_eq!(retrieve_txs, vec![payment]);
+
+    let query_plan = |where_clause: String| -> Result<String> {
+        let con = storage.get_connection()?;
+        let mut stmt = con.prepare(&format!(
+            "EXPLAIN QUERY PLAN SELECT p.id FROM payments p {where_clause} ORDER BY payment_time DESC"
+        ))?;
+        let details: Vec<String> = stmt
+            .query_map([], |row| row.get(3))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(details.join("\n"))
+    };
+
+    let plan = query_plan(filter_to_where_clause(
+        PaymentTypeFilter::All,
+        Some(1000),
+        Some(2000),
+        None,
+        None,
+        false,
+    ))?;
+    assert!(plan.contains("payments_payment_time"), "{plan}");
+
+    let plan = query_plan(filter_to_where_clause(
+        PaymentTypeFilter::Received,
+        None,
+        None,
+        None,
+        None,
+        false,
+    ))?;
+    assert!(plan.contains("payments_payment_type"), "{plan}");
+
+    Ok(())
+}
+
+#[test]
+fn test_custom_tlvs_kept_on_sync() -> Result<(), Box<dyn std::error::Error>> {
+    use crate::models::{LnPaymentDetails, Payment, PaymentDetails, TlvEntry};
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init()?;
+
+    let custom_tlvs = vec![TlvEntry {
+        type_num: 7629169,
+        value: b"{\"podcast\":\"Episode 1\"}".to_vec(),
+    }];
+    let mut payment = Payment {
+        id: "123".to_string(),
+        payment_type: PaymentType::Received,
+        payment_time: 1001,
+        amount_msat: 100_000,
+        fee_msat: 0,
+        pending: false,
+        description: None,
+        details: PaymentDetails::Ln {
+            data: LnPaymentDetails {
+                payment_hash: "123".to_string(),
+                label: "keysend-123".to_string(),
+                destination_pubkey: "".to_string(),
+                payment_preimage: "payment_preimage".to_string(),
+                keysend: true,
+                bolt11: "".to_string(),
+                lnurl_success_action: None,
+                lnurl_metadata: None,
+                ln_address: None,
+                route: vec![],
+                custom_tlvs: custom_tlvs.clone(),
+            },
+        },
+    };
+    storage.insert_payments(&[payment.clone()])?;
+
+    // The payment as pulled from the node on the next sync, without the records
+    if let PaymentDetails::Ln { ref mut data } = payment.details {
+        data.custom_tlvs = vec![];
+    }
+    storage.insert_payments(&[payment])?;
+
+    let stored = storage.get_payment_by_hash(&"123".to_string())?.unwrap();
+    assert!(matches!(
+        stored.details,
+        PaymentDetails::Ln { data } if data.custom_tlvs == custom_tlvs
+    ));
+    Ok(())
+}
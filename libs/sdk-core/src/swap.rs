@@ -1,8 +1,10 @@
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::binding::parse_invoice;
-use crate::chain::{ChainService, MempoolSpace, OnchainTx};
+use crate::chain::{ChainService, OnchainTx};
 use crate::grpc::{AddFundInitRequest, GetSwapPaymentRequest};
 use anyhow::{anyhow, Result};
 use bitcoin::blockdata::constants::WITNESS_SCALE_FACTOR;
@@ -22,7 +24,14 @@ use rand::Rng;
 use ripemd::{Digest, Ripemd160};
 
 use crate::breez_services::{BreezEvent, BreezServer, PaymentReceiver, Receiver};
-use crate::models::{Swap, SwapInfo, SwapStatus, SwapperAPI};
+use crate::models::{
+    OverpaidSwapHandling, RefundPreview, SatPerVbyte, Swap, SwapInfo, SwapStatus, SwapperAPI,
+};
+
+/// The average time between two blocks, used to estimate when a block height is reached
+const AVERAGE_BLOCK_TIME_SECS: i64 = 600;
+/// Dormant swaps are refreshed about once a day, see [SwapInfo::dormant]
+const DORMANT_SWAPS_REFRESH_INTERVAL_BLOCKS: u32 = 144;
 
 #[derive(Clone)]
 struct Utxo {
@@ -114,6 +123,9 @@ pub(crate) struct BTCReceiveSwap {
     persister: Arc<crate::persist::db::SqliteStorage>,
     chain_service: Arc<dyn ChainService>,
     payment_receiver: Arc<dyn Receiver>,
+    overpaid_swap_handling: OverpaidSwapHandling,
+    /// The tip at which the dormant swaps were last refreshed
+    dormant_swaps_refreshed_at: AtomicU32,
 }
 
 impl BTCReceiveSwap {
@@ -121,8 +133,9 @@ impl BTCReceiveSwap {
         network: bitcoin::Network,
         swapper_api: Arc<dyn SwapperAPI>,
         persister: Arc<crate::persist::db::SqliteStorage>,
-        chain_service: Arc<MempoolSpace>,
+        chain_service: Arc<dyn ChainService>,
         payment_receiver: Arc<PaymentReceiver>,
+        overpaid_swap_handling: OverpaidSwapHandling,
     ) -> Self {
         Self {
             network,
@@ -130,6 +143,8 @@ impl BTCReceiveSwap {
             persister,
             chain_service,
             payment_receiver,
+            overpaid_swap_handling,
+            dormant_swaps_refreshed_at: AtomicU32::new(0),
         }
     }
 
@@ -138,11 +153,30 @@ impl BTCReceiveSwap {
     /// * Refresh lighting status of swap addresses, e.g lookup for corresponding lightning payment
     /// * Redeem funds related to swap addresses, e.g when on chain funds are discovered use the SwapperAPI to
     ///   request payment by passing bolt11 invoice.
-    pub(crate) async fn on_event(&self, e: BreezEvent) -> Result<()> {
+    ///
+    /// Returns the events to emit as a result, a [BreezEvent::SwapUpdated] for each swap whose
+    /// confirmed balance changed, followed by a [BreezEvent::SwapOverpaid] if the new balance
+    /// exceeds the maximum allowed deposit.
+    pub(crate) async fn on_event(&self, e: BreezEvent) -> Result<Vec<BreezEvent>> {
         match e {
             BreezEvent::NewBlock { block: tip } => {
                 debug!("got chain event {:?}", e);
-                _ = self.execute_pending_swaps(tip).await;
+                if let Ok(updated_swaps) = self.execute_pending_swaps(tip).await {
+                    let mut events = vec![];
+                    for details in updated_swaps {
+                        let overpaid = details.overpaid();
+                        events.push(BreezEvent::SwapUpdated {
+                            details: details.clone(),
+                        });
+                        if overpaid {
+                            events.push(BreezEvent::SwapOverpaid {
+                                details,
+                                handling: self.overpaid_swap_handling,
+                            });
+                        }
+                    }
+                    return Ok(events);
+                }
             }
 
             // When invoice is paid we lookup for a swap that matches the same hash.
@@ -164,7 +198,7 @@ impl BTCReceiveSwap {
             _ => {} // skip events were are not interested in
         }
 
-        Ok(())
+        Ok(vec![])
     }
 
     /// Create a [SwapInfo] that represents the details of an on-going swap.
@@ -216,6 +250,10 @@ impl BTCReceiveSwap {
             return Err(anyhow!("wrong address"));
         }
 
+        // Until it is used, the address expires lock_height blocks from now
+        let tip = self.chain_service.current_tip().await?;
+        let expiry_block = tip + swap_reply.lock_height as u32;
+
         let swap_info = SwapInfo {
             bitcoin_address: swap_reply.bitcoin_address,
             created_at: 0,
@@ -237,6 +275,8 @@ impl BTCReceiveSwap {
             min_allowed_deposit: swap_reply.min_allowed_deposit,
             max_allowed_deposit: swap_reply.max_allowed_deposit,
             last_redeem_error: None,
+            expiry_block,
+            expires_at: estimate_block_timestamp(expiry_block, tip)?,
         };
 
         // persist the address
@@ -255,6 +295,15 @@ impl BTCReceiveSwap {
             .collect())
     }
 
+    /// The swap deposits are currently made to, without creating a new one: the one in progress,
+    /// or else the unused one.
+    pub(crate) async fn current_swap(&self) -> Result<Option<SwapInfo>> {
+        if let Some(swap_info) = self.list_in_progress().await?.into_iter().next() {
+            return Ok(Some(swap_info));
+        }
+        Ok(self.list_unused()?.into_iter().next())
+    }
+
     pub(crate) async fn list_in_progress(&self) -> Result<Vec<SwapInfo>> {
         Ok(self
             .persister
@@ -273,6 +322,15 @@ impl BTCReceiveSwap {
             .collect())
     }
 
+    fn list_dormant(&self) -> Result<Vec<SwapInfo>> {
+        Ok(self
+            .persister
+            .list_swaps_with_status(SwapStatus::Expired)?
+            .into_iter()
+            .filter(SwapInfo::dormant)
+            .collect())
+    }
+
     pub(crate) fn list_refundables(&self) -> Result<Vec<SwapInfo>> {
         Ok(self
             .persister
@@ -297,12 +355,66 @@ impl BTCReceiveSwap {
         self.persister.get_swap_info_by_address(address)
     }
 
-    pub(crate) async fn execute_pending_swaps(&self, tip: u32) -> Result<()> {
+    /// Deletes the redeemed swaps that expired before `expired_before`, returning how many were
+    /// deleted. Swaps with refund transactions are kept, as they explain those transactions in the
+    /// on-chain history. Swaps that were never paid are kept too, as their keys are needed to
+    /// refund any funds sent to their address later.
+    pub(crate) fn prune_terminal_swaps(&self, expired_before: i64) -> Result<u32> {
+        let prunable: Vec<String> = self
+            .persister
+            .list_swaps()?
+            .into_iter()
+            .filter(|s| {
+                s.terminal()
+                    && s.paid_sats > 0
+                    && s.refund_tx_ids.is_empty()
+                    && s.expires_at < expired_before
+            })
+            .map(|s| s.bitcoin_address)
+            .collect();
+        self.persister.delete_swaps(&prunable)?;
+        Ok(prunable.len() as u32)
+    }
+
+    /// Marks as expired the swaps that expired while the SDK was offline, i.e. whose stored expiry
+    /// block is at or below `tip`.
+    ///
+    /// These swaps are refreshed from the chain first, with [Self::refresh_swap_on_chain_status],
+    /// as a deposit confirmed while offline moves the expiry to `lock_height` blocks after its
+    /// confirmation, and an unconfirmed deposit doesn't expire yet. A swap that can't be refreshed
+    /// is left as is.
+    ///
+    /// Returns the swaps that were marked as expired.
+    pub(crate) async fn reconcile_expired_swaps(&self, tip: u32) -> Result<Vec<SwapInfo>> {
+        let mut expired = vec![];
+        for s in self.persister.list_swaps_with_status(SwapStatus::Initial)? {
+            if s.expiry_block == 0 || tip < s.expiry_block {
+                continue;
+            }
+            match self
+                .refresh_swap_on_chain_status(s.bitcoin_address.clone(), tip)
+                .await
+            {
+                Ok(refreshed) if refreshed.status == SwapStatus::Expired => expired.push(refreshed),
+                Ok(_) => {}
+                Err(err) => error!(
+                    "failed to refresh swap status for address {} {}",
+                    s.bitcoin_address, err
+                ),
+            }
+        }
+        Ok(expired)
+    }
+
+    /// Refreshes and redeems the monitored swaps, returning those whose confirmed balance changed
+    pub(crate) async fn execute_pending_swaps(&self, tip: u32) -> Result<Vec<SwapInfo>> {
         // first refresh all swaps we monitor
-        _ = self.refresh_monitored_swaps(tip).await?;
+        let updated_swaps = self.refresh_monitored_swaps(tip).await?;
 
-        // redeem swaps
-        let redeemable_swaps = self.list_redeemables()?;
+        // redeem swaps, overpaid ones are left to be refunded unless configured otherwise
+        let redeemable_swaps = self.list_redeemables()?.into_iter().filter(|s| {
+            !s.overpaid() || self.overpaid_swap_handling == OverpaidSwapHandling::Redeem
+        });
         for s in redeemable_swaps {
             let redeem_res = self.redeem_swap(s.bitcoin_address.clone()).await;
 
@@ -325,25 +437,45 @@ impl BTCReceiveSwap {
             }
         }
 
-        Ok(())
+        Ok(updated_swaps)
     }
 
+    /// Refreshes the on-chain status of the monitored swaps, returning those whose confirmed
+    /// balance changed.
+    ///
+    /// The transactions are queried again on every block, so a deposit that is reorged out of the
+    /// chain lowers the confirmed balance back. The dormant swaps are only refreshed every
+    /// [DORMANT_SWAPS_REFRESH_INTERVAL_BLOCKS], and on the first block after startup.
     async fn refresh_monitored_swaps(&self, tip: u32) -> Result<Vec<SwapInfo>> {
-        let to_check = self.list_monitored()?;
+        let mut to_check = self.list_monitored()?;
+        let refreshed_at = self.dormant_swaps_refreshed_at.load(Ordering::Relaxed);
+        if refreshed_at == 0 || tip >= refreshed_at + DORMANT_SWAPS_REFRESH_INTERVAL_BLOCKS {
+            to_check.extend(self.list_dormant()?);
+            self.dormant_swaps_refreshed_at
+                .store(tip, Ordering::Relaxed);
+        }
+        let mut updated_swaps = vec![];
         for s in to_check {
             let address = s.bitcoin_address.clone();
-            let result = self
+            match self
                 .refresh_swap_on_chain_status(address.clone(), tip)
-                .await;
-            if result.is_err() {
-                error!(
+                .await
+            {
+                Ok(refreshed) if refreshed.confirmed_sats != s.confirmed_sats => {
+                    info!(
+                        "swap {} confirmed sats changed from {} to {}",
+                        address, s.confirmed_sats, refreshed.confirmed_sats
+                    );
+                    updated_swaps.push(refreshed);
+                }
+                Ok(_) => {}
+                Err(err) => error!(
                     "failed to refresh swap status for address {} {}",
-                    address.clone(),
-                    result.err().unwrap()
-                )
+                    address, err
+                ),
             }
         }
-        self.list_monitored()
+        Ok(updated_swaps)
     }
 
     /// refreshes the on-chain status of the swap. This method updates the following information
@@ -369,6 +501,7 @@ impl BTCReceiveSwap {
             .chain_service
             .address_transactions(bitcoin_address.clone())
             .await?;
+        let unused = txs.is_empty();
         let confirmed_txs: Vec<OnchainTx> = txs
             .clone()
             .into_iter()
@@ -384,10 +517,24 @@ impl BTCReceiveSwap {
             }
         });
 
+        // Once funds are confirmed, the swap expires lock_height blocks after the confirmation.
+        // Swaps created before the expiry was tracked start counting from the current tip.
+        let expiry_block = if !confirmed_txs.is_empty() {
+            confirmed_block + swap_info.lock_height as u32
+        } else if swap_info.expiry_block == 0 {
+            current_tip + swap_info.lock_height as u32
+        } else {
+            swap_info.expiry_block
+        };
+        self.persister.update_swap_expiry(
+            bitcoin_address.clone(),
+            expiry_block,
+            estimate_block_timestamp(expiry_block, current_tip)?,
+        )?;
+
+        // An expired swap stays expired, funds sent to it later can only be refunded
         let mut swap_status = swap_info.status.clone();
-        if !confirmed_txs.is_empty()
-            && current_tip - confirmed_block >= swap_info.lock_height as u32
-        {
+        if (!confirmed_txs.is_empty() || unused) && current_tip >= expiry_block {
             swap_status = SwapStatus::Expired
         }
 
@@ -439,7 +586,8 @@ impl BTCReceiveSwap {
                     String::from("Bitcoin Transfer"),
                     Some(swap_info.preimage),
                 )
-                .await?;
+                .await?
+                .ln_invoice;
             self.persister
                 .update_swap_bolt11(bitcoin_address.clone(), invoice.bolt11)?;
             swap_info = self
@@ -469,18 +617,9 @@ impl BTCReceiveSwap {
         &self,
         swap_address: String,
         to_address: String,
-        sat_per_vbyte: u32,
+        fee_rate: SatPerVbyte,
     ) -> Result<String> {
-        let swap_info = self
-            .persister
-            .get_swap_info_by_address(swap_address.clone())?
-            .ok_or_else(|| anyhow!(format!("swap address {swap_address} was not found")))?;
-
-        let transactions = self
-            .chain_service
-            .address_transactions(swap_address.clone())
-            .await?;
-        let utxos = get_utxos(swap_address, transactions)?;
+        let (swap_info, utxos) = self.refundable_utxos(swap_address).await?;
 
         let script = create_submarine_swap_script(
             swap_info.payment_hash,
@@ -494,7 +633,7 @@ impl BTCReceiveSwap {
             to_address,
             swap_info.lock_height as u32,
             &script,
-            sat_per_vbyte,
+            fee_rate.sat_per_vbyte(),
         )?;
         info!("broadcasting refund tx {:?}", hex::encode(&refund_tx));
         let txid = self.chain_service.broadcast_transaction(refund_tx).await?;
@@ -512,6 +651,71 @@ impl BTCReceiveSwap {
 
         Ok(txid)
     }
+
+    /// Estimates the refund of a swap without signing or broadcasting it, see [Self::refund_swap]
+    pub(crate) async fn prepare_refund(
+        &self,
+        swap_address: String,
+        to_address: String,
+        fee_rate: SatPerVbyte,
+    ) -> Result<RefundPreview> {
+        let (swap_info, utxos) = self.refundable_utxos(swap_address).await?;
+        let refund_tx = build_refund_tx(
+            &utxos,
+            to_address,
+            swap_info.lock_height as u32,
+            fee_rate.sat_per_vbyte(),
+        )?;
+        let refund_amount_sat = refund_tx.output[0].value;
+        Ok(RefundPreview {
+            fee_sat: utxos.confirmed_sats() as u64 - refund_amount_sat,
+            refund_amount_sat,
+        })
+    }
+
+    /// The amount a refund of `swap_address` would currently return, after the fee. Zero when the
+    /// swap can't be refunded, or when its deposit doesn't cover the fee.
+    pub(crate) async fn refundable_amount(
+        &self,
+        swap_address: String,
+        fee_rate: SatPerVbyte,
+    ) -> Result<u64> {
+        let (swap_info, utxos) = self.refundable_utxos(swap_address.clone()).await?;
+        if !swap_info.refundable() || utxos.confirmed.is_empty() {
+            return Ok(0);
+        }
+
+        // The destination isn't known yet. The output of the swap address is at least as large as
+        // those of the common address types, so the fee isn't underestimated.
+        let refund_tx = build_refund_tx(
+            &utxos,
+            swap_address,
+            swap_info.lock_height as u32,
+            fee_rate.sat_per_vbyte(),
+        );
+        // Building the refund only fails here when the deposit doesn't cover the fee
+        Ok(refund_tx.map_or(0, |tx| tx.output[0].value))
+    }
+
+    async fn refundable_utxos(&self, swap_address: String) -> Result<(SwapInfo, AddressUtxos)> {
+        let swap_info = self
+            .persister
+            .get_swap_info_by_address(swap_address.clone())?
+            .ok_or_else(|| anyhow!(format!("swap address {swap_address} was not found")))?;
+
+        let transactions = self
+            .chain_service
+            .address_transactions(swap_address.clone())
+            .await?;
+        let utxos = get_utxos(swap_address, transactions)?;
+        Ok((swap_info, utxos))
+    }
+}
+
+/// Estimates the unix timestamp at which `block` is mined, given the current `tip`
+fn estimate_block_timestamp(block: u32, tip: u32) -> Result<i64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    Ok(now + (block as i64 - tip as i64) * AVERAGE_BLOCK_TIME_SECS)
 }
 
 struct SwapKeys {
@@ -608,6 +812,48 @@ fn create_refund_tx(
     input_script: &Script,
     sat_per_vbyte: u32,
 ) -> Result<Vec<u8>> {
+    let mut tx = build_refund_tx(&utxos, to_address, lock_delay, sat_per_vbyte)?;
+
+    let scpt = Secp256k1::signing_only();
+
+    // go over all inputs and sign them
+    let mut signed_inputs: Vec<TxIn> = Vec::new();
+    for (index, input) in tx.input.iter().enumerate() {
+        let mut signer = SighashCache::new(&tx);
+        let sig = signer.segwit_signature_hash(
+            index,
+            input_script,
+            utxos.confirmed[index].value as u64,
+            EcdsaSighashType::All,
+        )?;
+        let msg = Message::from_slice(&sig[..])?;
+        let secret_key = SecretKey::from_slice(private_key.as_slice())?;
+        let sig = scpt.sign_ecdsa(&msg, &secret_key);
+
+        let mut sigvec = sig.serialize_der().to_vec();
+        sigvec.push(EcdsaSighashType::All as u8);
+
+        let witness: Vec<Vec<u8>> = vec![sigvec, vec![], input_script.serialize()];
+
+        let mut signed_input = input.clone();
+        let w = Witness::from_vec(witness);
+        signed_input.witness = w;
+        signed_inputs.push(signed_input);
+    }
+    tx.input = signed_inputs;
+
+    //tx.output[0].value = confirmed_amount;
+    Ok(encode::serialize(&tx))
+}
+
+/// Builds the unsigned refund transaction, spending all the confirmed `utxos` to `to_address`
+/// minus the fee for the given `sat_per_vbyte`
+fn build_refund_tx(
+    utxos: &AddressUtxos,
+    to_address: String,
+    lock_delay: u32,
+    sat_per_vbyte: u32,
+) -> Result<Transaction> {
     if utxos.confirmed.is_empty() {
         return Err(anyhow!("must have at least one input"));
     }
@@ -664,41 +910,13 @@ fn create_refund_tx(
         return Err(anyhow!("insufficient funds to pay fees"));
     }
     tx.output[0].value = confirmed_amount - fees;
-
-    let scpt = Secp256k1::signing_only();
-
-    // go over all inputs and sign them
-    let mut signed_inputs: Vec<TxIn> = Vec::new();
-    for (index, input) in tx.input.iter().enumerate() {
-        let mut signer = SighashCache::new(&tx);
-        let sig = signer.segwit_signature_hash(
-            index,
-            input_script,
-            utxos.confirmed[index].value as u64,
-            EcdsaSighashType::All,
-        )?;
-        let msg = Message::from_slice(&sig[..])?;
-        let secret_key = SecretKey::from_slice(private_key.as_slice())?;
-        let sig = scpt.sign_ecdsa(&msg, &secret_key);
-
-        let mut sigvec = sig.serialize_der().to_vec();
-        sigvec.push(EcdsaSighashType::All as u8);
-
-        let witness: Vec<Vec<u8>> = vec![sigvec, vec![], input_script.serialize()];
-
-        let mut signed_input = input.clone();
-        let w = Witness::from_vec(witness);
-        signed_input.witness = w;
-        signed_inputs.push(signed_input);
-    }
-    tx.input = signed_inputs;
-
-    //tx.output[0].value = confirmed_amount;
-    Ok(encode::serialize(&tx))
+    Ok(tx)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::AtomicU32;
+    use std::time::{SystemTime, UNIX_EPOCH};
     use std::{sync::Arc, vec};
 
     use bitcoin::hashes::{hex::FromHex, sha256};
@@ -720,7 +938,10 @@ mod tests {
         BreezEvent,
     };
 
-    use super::{create_refund_tx, create_submarine_swap_script, get_utxos};
+    use super::{
+        build_refund_tx, create_refund_tx, create_submarine_swap_script, get_utxos,
+        DORMANT_SWAPS_REFRESH_INTERVAL_BLOCKS,
+    };
 
     #[test]
     fn test_build_swap_script() {
@@ -789,6 +1010,62 @@ mod tests {
         assert_eq!(utxos.unconfirmed.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_refundable_amount() {
+        let chain_service = Arc::new(MockChainService::default());
+        let (mut swapper, _) = create_swapper(chain_service.clone());
+        let swap_info = swapper.create_swap_address().await.unwrap();
+        let address = swap_info.bitcoin_address.clone();
+        let fee_rate = SatPerVbyte::new(10).unwrap();
+        assert_eq!(
+            swapper
+                .refundable_amount(address.clone(), fee_rate)
+                .await
+                .unwrap(),
+            0
+        );
+
+        // Funds that can still be redeemed aren't refundable
+        swapper.chain_service = chain_service_with_confirmed_txs(address.clone());
+        swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 1,
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            swapper
+                .refundable_amount(address.clone(), fee_rate)
+                .await
+                .unwrap(),
+            0
+        );
+
+        swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 145,
+            })
+            .await
+            .unwrap();
+        let amount = swapper
+            .refundable_amount(address.clone(), fee_rate)
+            .await
+            .unwrap();
+        assert!(amount > 0 && amount < 50000);
+        let cheaper = swapper
+            .refundable_amount(address.clone(), SatPerVbyte::new(1).unwrap())
+            .await
+            .unwrap();
+        assert!(cheaper > amount);
+
+        // The deposit doesn't cover the fee
+        let expensive = SatPerVbyte::new(MAX_SAT_PER_VBYTE).unwrap();
+        assert_eq!(
+            swapper.refundable_amount(address, expensive).await.unwrap(),
+            0
+        );
+    }
+
     // 1. User has sent funds to swap address
     // 2. Swap didn't complete before timeout
     // Swap should move to Expired status and returned in the refundable list.
@@ -829,7 +1106,7 @@ mod tests {
             .refund_swap(
                 swap.bitcoin_address,
                 String::from("34RQERthXaruAXtW6q1bvrGTeUbqi2Sm1i"),
-                1,
+                SatPerVbyte::new(1).unwrap(),
             )
             .await
             .unwrap();
@@ -847,6 +1124,333 @@ mod tests {
         assert_eq!(refundables[0].clone().refund_tx_ids[0], address);
     }
 
+    // 1. User sent funds to swap address and they confirmed
+    // 2. The deposit is reorged out of the chain
+    // Swap confirmed balance goes back down and each change is reported.
+    #[tokio::test]
+    async fn test_reorged_deposit() {
+        let chain_service = Arc::new(MockChainService::default());
+        let (mut swapper, _) = create_swapper(chain_service.clone());
+        let swap_info = swapper.create_swap_address().await.unwrap();
+        let address = swap_info.bitcoin_address.clone();
+        let confirmed_sats = |events: Vec<BreezEvent>| -> Vec<u32> {
+            events
+                .into_iter()
+                .filter_map(|e| match e {
+                    BreezEvent::SwapUpdated { details } => Some(details.confirmed_sats),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        swapper.chain_service = chain_service_with_confirmed_txs(address.clone());
+        let events = swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 1,
+            })
+            .await
+            .unwrap();
+        assert_eq!(confirmed_sats(events), vec![50000]);
+
+        // The deposit vanished from the chain at the next block
+        swapper.chain_service = chain_service.clone();
+        let events = swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 2,
+            })
+            .await
+            .unwrap();
+        assert_eq!(confirmed_sats(events), vec![0]);
+        let swap = swapper.get_swap_info(address).unwrap().unwrap();
+        assert_eq!(swap.confirmed_sats, 0);
+        assert!(swap.confirmed_tx_ids.is_empty());
+        assert_eq!(swapper.list_redeemables().unwrap().len(), 0);
+
+        // Nothing changed since
+        let events = swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 3,
+            })
+            .await
+            .unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_overpaid_swap() {
+        let chain_service = Arc::new(MockChainService::default());
+        let (mut swapper, persister) = create_swapper(chain_service.clone());
+        let swap_info = swapper.create_swap_address().await.unwrap();
+        let address = swap_info.bitcoin_address.clone();
+        // The 50000 sats deposit exceeds the maximum
+        persister
+            .get_connection()
+            .unwrap()
+            .execute(
+                "UPDATE sync.swaps SET max_allowed_deposit = 20000 WHERE bitcoin_address = ?1",
+                [&address],
+            )
+            .unwrap();
+        swapper.chain_service = chain_service_with_confirmed_txs(address.clone());
+
+        // By default the swap isn't redeemed, so that the deposit can be refunded
+        let events = swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 1,
+            })
+            .await
+            .unwrap();
+        let overpaid: Vec<_> = events
+            .into_iter()
+            .filter_map(|e| match e {
+                BreezEvent::SwapOverpaid { details, handling } => {
+                    Some((details.confirmed_sats, handling))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(overpaid, vec![(50000, OverpaidSwapHandling::Refund)]);
+        let swap = swapper.get_swap_info(address.clone()).unwrap().unwrap();
+        assert!(swap.bolt11.is_none());
+
+        // Redeeming is attempted when configured, without reporting the same overpayment again
+        swapper.overpaid_swap_handling = OverpaidSwapHandling::Redeem;
+        let events = swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 2,
+            })
+            .await
+            .unwrap();
+        assert!(events.is_empty());
+        let swap = swapper.get_swap_info(address).unwrap().unwrap();
+        assert!(swap.bolt11.is_some());
+    }
+
+    // Redeemed swaps are pruned once old enough, unpaid, refunded or monitored ones are kept
+    #[tokio::test]
+    async fn test_prune_terminal_swaps() {
+        let chain_service = Arc::new(MockChainService::default());
+        let (swapper, persister) = create_swapper(chain_service);
+        let mut addresses = vec![];
+        for _ in 0..5 {
+            let address = swapper.create_swap_address().await.unwrap().bitcoin_address;
+            persister
+                .update_swap_expiry(address.clone(), 0, 1_000)
+                .unwrap();
+            // an unused swap would be handed out again, expire it to get a new address
+            persister
+                .update_swap_chain_info(address.clone(), 0, vec![], 0, vec![], SwapStatus::Expired)
+                .unwrap();
+            addresses.push(address);
+        }
+        let (redeemed, expired, refunded, recent, in_progress) = (
+            &addresses[0],
+            &addresses[1],
+            &addresses[2],
+            &addresses[3],
+            &addresses[4],
+        );
+
+        for address in [redeemed, recent] {
+            persister
+                .update_swap_chain_info(
+                    address.clone(),
+                    0,
+                    vec![],
+                    50000,
+                    vec![],
+                    SwapStatus::Initial,
+                )
+                .unwrap();
+            persister
+                .update_swap_paid_amount(address.clone(), 50000)
+                .unwrap();
+        }
+        for address in [expired, refunded] {
+            persister
+                .update_swap_chain_info(address.clone(), 0, vec![], 0, vec![], SwapStatus::Expired)
+                .unwrap();
+        }
+        persister
+            .insert_swap_refund_tx_ids(refunded.clone(), "refund".to_string())
+            .unwrap();
+        persister
+            .update_swap_expiry(recent.clone(), 0, 3_000)
+            .unwrap();
+        persister
+            .update_swap_chain_info(
+                in_progress.clone(),
+                0,
+                vec![],
+                50000,
+                vec![],
+                SwapStatus::Initial,
+            )
+            .unwrap();
+
+        assert_eq!(swapper.prune_terminal_swaps(2_000).unwrap(), 1);
+        assert!(swapper.get_swap_info(redeemed.clone()).unwrap().is_none());
+        for address in [expired, refunded, recent, in_progress] {
+            assert!(swapper.get_swap_info(address.clone()).unwrap().is_some());
+        }
+
+        // Unpaid and non terminal swaps are never pruned, however old
+        assert_eq!(swapper.prune_terminal_swaps(i64::MAX).unwrap(), 1);
+        assert!(swapper.get_swap_info(recent.clone()).unwrap().is_none());
+        for address in [expired, refunded, in_progress] {
+            assert!(swapper.get_swap_info(address.clone()).unwrap().is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_expired_swaps() {
+        let chain_service = Arc::new(MockChainService::default());
+        let (mut swapper, persister) = create_swapper(chain_service);
+        let mut addresses = vec![];
+        for _ in 0..5 {
+            let address = swapper.create_swap_address().await.unwrap().bitcoin_address;
+            // an unused swap would be handed out again, expire it to get a new address
+            persister
+                .update_swap_chain_info(address.clone(), 0, vec![], 0, vec![], SwapStatus::Expired)
+                .unwrap();
+            addresses.push(address);
+        }
+        let (expired, unconfirmed, funded, pending, untracked) = (
+            &addresses[0],
+            &addresses[1],
+            &addresses[2],
+            &addresses[3],
+            &addresses[4],
+        );
+        for (address, expiry_block) in [
+            (expired, 100),
+            (unconfirmed, 100),
+            (funded, 100),
+            (pending, 200),
+            (untracked, 0),
+        ] {
+            persister
+                .update_swap_expiry(address.clone(), expiry_block, 0)
+                .unwrap();
+            persister
+                .update_swap_chain_info(address.clone(), 0, vec![], 0, vec![], SwapStatus::Initial)
+                .unwrap();
+        }
+
+        // Deposits were sent to two of the expiring addresses while offline, one confirmed
+        let mut chain_service = MockChainService::default();
+        chain_service.address_to_transactions.clear();
+        let unconfirmed_txs = confirmed_txs(unconfirmed).replace(
+            r#""status":{"confirmed":true,"block_height":767637,"block_hash":"000000000000000000077769f3b2e6a28b9ed688f0d773f9ff2d73c622a2cfac","block_time":1671174562}"#,
+            r#""status":{"confirmed":false}"#,
+        );
+        assert!(!unconfirmed_txs.contains("block_height"));
+        for (address, txs) in [
+            (unconfirmed, unconfirmed_txs),
+            (funded, confirmed_txs(funded)),
+        ] {
+            chain_service
+                .address_to_transactions
+                .insert(address.clone(), serde_json::from_str(&txs).unwrap());
+        }
+        swapper.chain_service = Arc::new(chain_service);
+
+        let reconciled = swapper.reconcile_expired_swaps(150).await.unwrap();
+        assert_eq!(
+            reconciled
+                .into_iter()
+                .map(|s| s.bitcoin_address)
+                .collect::<Vec<_>>(),
+            vec![expired.clone()]
+        );
+        for (address, status) in [
+            (expired, SwapStatus::Expired),
+            (unconfirmed, SwapStatus::Initial),
+            (funded, SwapStatus::Initial),
+            (pending, SwapStatus::Initial),
+            (untracked, SwapStatus::Initial),
+        ] {
+            let swap = swapper.get_swap_info(address.clone()).unwrap().unwrap();
+            assert_eq!(swap.status, status);
+        }
+
+        // The confirmed deposit expires lock_height blocks after its confirmation
+        let swap = swapper.get_swap_info(funded.clone()).unwrap().unwrap();
+        assert_eq!(swap.confirmed_sats, 50_000);
+        assert_eq!(swap.expiry_block, 767637 + swap.lock_height as u32);
+
+        // Reconciling again has nothing left to do
+        assert!(swapper
+            .reconcile_expired_swaps(150)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    // 1. User never sent funds to swap address
+    // 2. The address expires lock_height blocks after it was created
+    // Swap is no longer monitored every block, but funds sent later are still found and refundable.
+    #[tokio::test]
+    async fn test_unused_swap_expiry() {
+        let chain_service = Arc::new(MockChainService::default());
+        let (mut swapper, _) = create_swapper(chain_service.clone());
+        let swap_info = swapper.create_swap_address().await.unwrap();
+        let address = swap_info.bitcoin_address.clone();
+        assert_eq!(swap_info.expiry_block, chain_service.tip + 144);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        assert!((swap_info.expires_at - (now + 144 * 600)).abs() < 60);
+
+        swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 143,
+            })
+            .await
+            .unwrap();
+        let swap = swapper.get_swap_info(address.clone()).unwrap().unwrap();
+        assert_eq!(swap.status, SwapStatus::Initial);
+        assert_eq!(swapper.list_monitored().unwrap().len(), 1);
+
+        swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 144,
+            })
+            .await
+            .unwrap();
+        let swap = swapper.get_swap_info(address.clone()).unwrap().unwrap();
+        assert_eq!(swap.status, SwapStatus::Expired);
+        assert_eq!(swap.expiry_block, swap_info.expiry_block);
+        assert_eq!(swapper.list_monitored().unwrap().len(), 0);
+
+        // A new address is handed out instead of the expired one
+        let new_swap_info = swapper.create_swap_address().await.unwrap();
+        assert_ne!(new_swap_info.bitcoin_address, address);
+
+        // Funds sent to the expired address are found by the next refresh of the dormant swaps
+        swapper.chain_service = chain_service_with_confirmed_txs(address.clone());
+        swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 144 + DORMANT_SWAPS_REFRESH_INTERVAL_BLOCKS - 2,
+            })
+            .await
+            .unwrap();
+        assert!(swapper.list_refundables().unwrap().is_empty());
+        let events = swapper
+            .on_event(BreezEvent::NewBlock {
+                block: chain_service.tip + 143 + DORMANT_SWAPS_REFRESH_INTERVAL_BLOCKS,
+            })
+            .await
+            .unwrap();
+        assert!(events.iter().any(|e| matches!(e,
+            BreezEvent::SwapUpdated { details } if details.bitcoin_address == address)));
+        let refundables = swapper.list_refundables().unwrap();
+        assert_eq!(refundables.len(), 1);
+        assert_eq!(refundables[0].bitcoin_address, address);
+        assert_eq!(refundables[0].confirmed_sats, 50_000);
+    }
+
     // 1. User sent funds to swap address
     // 2. Funds are redeemed in lightning transaction
     // Swap paid amount is updated and no longer redeemable.
@@ -877,6 +1481,8 @@ mod tests {
                     lnurl_success_action: None,
                     lnurl_metadata: None,
                     ln_address: None,
+                    route: vec![],
+                    custom_tlvs: vec![],
                 },
             },
         };
@@ -1077,6 +1683,52 @@ mod tests {
         assert_eq!(hex::encode(refund_tx), "0200000000010130037fa97f58d7f685ce861f7862112d8377364c4898f1d63213ff949ffeb31a00000000002001000001204e00000000000016001465c96c830168b8f0b584294d3b9716bb8584c2d80347304402203285efcf44640551a56c53bde677988964ef1b4d11182d5d6634096042c320120220227b625f7827993aca5b9d2f4690c5e5fae44d8d42fdd5f3778ba21df8ba7c7b010064a9148a486ff2e31d6158bf39e2608864d63fefd09d5b876321024d4b6cd1361032ca9bd2aeb9d900aa4d45d9ead80ac9423374c451a7254d076667022001b27521031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f68ac80af0a00");
     }
 
+    #[test]
+    fn test_build_refund_tx_aggregates_utxos() {
+        let utxo = |txid: &str, value, block_height| Utxo {
+            out: OutPoint {
+                txid: Txid::from_hex(txid).unwrap(),
+                vout: 0,
+            },
+            value,
+            block_height: Some(block_height),
+        };
+        let utxos = AddressUtxos {
+            confirmed: vec![
+                utxo(
+                    "1ab3fe9f94ff1332d6f198484c3677832d1162781f86ce85f6d7587fa97f0330",
+                    20000,
+                    700000,
+                ),
+                utxo(
+                    "ec901bcab07df7d475d98fff2933dcb56d57bbdaa029c4142aed93462b6928fe",
+                    30000,
+                    700010,
+                ),
+            ],
+            unconfirmed: vec![],
+        };
+        let to_address = String::from("bc1qvhykeqcpdzu0pdvy99xnh9ckhwzcfskct6h6l2");
+
+        let free_tx = build_refund_tx(&utxos, to_address.clone(), 288, 0).unwrap();
+        assert_eq!(free_tx.input.len(), 2);
+        assert_eq!(free_tx.output[0].value, 50000);
+        assert_eq!(free_tx.lock_time.0, 700298);
+
+        let tx = build_refund_tx(&utxos, to_address.clone(), 288, 10).unwrap();
+        let fee_sat = 50000 - tx.output[0].value;
+        assert!(fee_sat > 0);
+        // Each additional input increases the fee
+        let single_input = AddressUtxos {
+            confirmed: vec![utxos.confirmed[0].clone()],
+            unconfirmed: vec![],
+        };
+        let single_tx = build_refund_tx(&single_input, to_address.clone(), 288, 10).unwrap();
+        assert!(20000 - single_tx.output[0].value < fee_sat);
+
+        assert!(build_refund_tx(&utxos, to_address, 288, 1000).is_err());
+    }
+
     fn create_swapper(
         chain_service: Arc<dyn ChainService>,
     ) -> (BTCReceiveSwap, Arc<SqliteStorage>) {
@@ -1095,17 +1747,19 @@ mod tests {
             persister: persister.clone(),
             chain_service: chain_service.clone(),
             payment_receiver: Arc::new(MockReceiver::default()),
+            overpaid_swap_handling: OverpaidSwapHandling::default(),
+            dormant_swaps_refreshed_at: AtomicU32::new(0),
         };
         (swapper, persister)
     }
 
     fn chain_service_with_confirmed_txs(address: String) -> Arc<dyn ChainService> {
+        chain_service_with_transactions(address.clone(), confirmed_txs(&address))
+    }
+
+    fn confirmed_txs(address: &str) -> String {
         let confirmed_txs_raw = r#"[{"txid":"ec901bcab07df7d475d98fff2933dcb56d57bbdaa029c4142aed93462b6928fe","version":1,"locktime":767636,"vin":[{"txid":"d4344fc9e7f66b3a1a50d1d76836a157629ba0c6ede093e94f1c809d334c9146","vout":0,"prevout":{"scriptpubkey":"0014cab22290b7adc75f861de820baa97d319c1110a6","scriptpubkey_asm":"OP_0 OP_PUSHBYTES_20 cab22290b7adc75f861de820baa97d319c1110a6","scriptpubkey_type":"v0_p2wpkh","scriptpubkey_address":"bc1qe2ez9y9h4hr4lpsaaqst42taxxwpzy9xlzqt8k","value":209639471},"scriptsig":"","scriptsig_asm":"","witness":["304402202e914c35b75da798f0898c7cfe6ead207aaee41219afd77124fd56971f05d9030220123ce5d124f4635171b7622995dae35e00373a5fbf8117bfdca5e5080ad6554101","02122fa6d20413bb5da5c7e3fb42228be5436b1bd84e29b294bfc200db5eac460e"],"is_coinbase":false,"sequence":4294967293}],"vout":[{"scriptpubkey":"0014b34b7da80e662d1db3fcfbe34b7f4cacc4fac34d","scriptpubkey_asm":"OP_0 OP_PUSHBYTES_20 b34b7da80e662d1db3fcfbe34b7f4cacc4fac34d","scriptpubkey_type":"v0_p2wpkh","scriptpubkey_address":"bc1qkd9hm2qwvck3mvlul035kl6v4nz04s6dmryeq5","value":50000},{"scriptpubkey":"0014f0e2a057d0e60411ac3d7218e29bf9489a59df18","scriptpubkey_asm":"OP_0 OP_PUSHBYTES_20 f0e2a057d0e60411ac3d7218e29bf9489a59df18","scriptpubkey_type":"v0_p2wpkh","scriptpubkey_address":"bc1q7r32q47suczprtpawgvw9xlefzd9nhccyatxvu","value":12140465}],"size":222,"weight":561,"fee":1753,"status":{"confirmed":true,"block_height":767637,"block_hash":"000000000000000000077769f3b2e6a28b9ed688f0d773f9ff2d73c622a2cfac","block_time":1671174562}}]"#;
-        let confirmed_txs = confirmed_txs_raw.replace(
-            "bc1qkd9hm2qwvck3mvlul035kl6v4nz04s6dmryeq5",
-            address.as_str(),
-        );
-        chain_service_with_transactions(address, confirmed_txs)
+        confirmed_txs_raw.replace("bc1qkd9hm2qwvck3mvlul035kl6v4nz04s6dmryeq5", address)
     }
 
     fn chain_service_after_spent(address: String) -> Arc<dyn ChainService> {
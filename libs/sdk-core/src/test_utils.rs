@@ -1,21 +1,22 @@
 use anyhow::{anyhow, Result};
+use bitcoin::consensus::serialize;
 use bitcoin::hashes::hex::ToHex;
 use bitcoin::hashes::{sha256, Hash};
 use bitcoin::secp256k1::ecdsa::RecoverableSignature;
 use bitcoin::secp256k1::{KeyPair, Message};
 use bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey};
 use bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey};
+use bitcoin::{Address, OutPoint, PackedLockTime, Transaction, TxIn, TxOut};
 use gl_client::pb::amount::Unit;
-use gl_client::pb::{
-    Amount, CloseChannelResponse, CloseChannelType, Invoice, Peer, WithdrawResponse,
-};
+use gl_client::pb::{Amount, CloseChannelResponse, CloseChannelType, Peer, WithdrawResponse};
 use lightning::ln::PaymentSecret;
 use lightning_invoice::{Currency, InvoiceBuilder, RawInvoice};
 use rand::distributions::{Alphanumeric, DistString, Standard};
 use rand::rngs::OsRng;
 use rand::{random, Rng};
 use std::collections::HashMap;
-use std::time::{Duration, SystemTime};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::{mpsc, Mutex};
 use tonic::Streaming;
 
@@ -24,7 +25,10 @@ use crate::chain::{ChainService, OnchainTx, RecommendedFees};
 use crate::fiat::{FiatCurrency, Rate};
 use crate::grpc::{PaymentInformation, RegisterPaymentReply};
 use crate::lsp::LspInformation;
-use crate::models::{FiatAPI, LspAPI, NodeAPI, NodeState, Payment, Swap, SwapperAPI, SyncResponse};
+use crate::models::{
+    FiatAPI, LspAPI, NodeAPI, NodeState, Payment, PaymentDetails, PaymentRouteHop, SatPerVbyte,
+    Swap, SwapperAPI, SyncResponse, TlvEntry, UnspentTransactionOutput,
+};
 use crate::moonpay::MoonPayApi;
 use crate::swap::create_submarine_swap_script;
 use crate::SwapInfo;
@@ -74,6 +78,8 @@ pub struct MockChainService {
     pub tip: u32,
     pub recommended_fees: RecommendedFees,
     pub address_to_transactions: HashMap<String, Vec<OnchainTx>>,
+    /// Seconds by which the reported server time is ahead of the device clock
+    pub server_time_offset: i64,
 }
 
 impl Default for MockChainService {
@@ -99,6 +105,7 @@ impl Default for MockChainService {
                 "bc1qkd9hm2qwvck3mvlul035kl6v4nz04s6dmryeq5".to_string(),
                 txs,
             )]),
+            server_time_offset: 0,
         }
     }
 }
@@ -125,6 +132,20 @@ impl ChainService for MockChainService {
         rand::thread_rng().fill(&mut array);
         Ok(hex::encode(array))
     }
+
+    async fn server_time(&self) -> Result<i64> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        Ok(now + self.server_time_offset)
+    }
+
+    async fn transaction_confirmations(&self, txid: String) -> Result<u32> {
+        self.address_to_transactions
+            .values()
+            .flatten()
+            .find(|tx| tx.txid == txid)
+            .map(|tx| tx.status.confirmations(self.tip))
+            .ok_or_else(|| anyhow!("Transaction {txid} not found"))
+    }
 }
 
 impl TryFrom<Payment> for crate::models::PaymentResponse {
@@ -166,11 +187,18 @@ impl Receiver for MockReceiver {
         _amount_sats: u64,
         _description: String,
         _preimage: Option<Vec<u8>>,
-    ) -> Result<crate::LNInvoice> {
-        Ok(parse_invoice(&self.bolt11)?)
+    ) -> Result<crate::ReceivePaymentResponse> {
+        Ok(crate::ReceivePaymentResponse {
+            ln_invoice: parse_invoice(&self.bolt11)?,
+            opening_fee_msat: None,
+            opening_fee_params: None,
+        })
     }
 }
 
+/// Fee paid by the transactions that [MockNodeAPI] builds when sweeping the given UTXOs
+pub const MOCK_SWEEP_FEE_SAT: u64 = 333;
+
 pub struct MockNodeAPI {
     /// Simulated repository of confirmed new outgoing payments.
     ///
@@ -178,6 +206,12 @@ pub struct MockNodeAPI {
     /// [NodeAPI::pull_changed], which is called in [BreezServices::sync], always retrieves the newly
     /// added test payments
     cloud_payments: Mutex<Vec<gl_client::pb::Payment>>,
+    /// Routes reported for the outgoing payments, by payment hash
+    payment_routes: Mutex<HashMap<String, Vec<PaymentRouteHop>>>,
+    /// Payment hashes of the invoices created with [NodeAPI::create_invoice]
+    invoice_hashes: Mutex<Vec<Vec<u8>>>,
+    /// Expiries requested for the invoices created with [NodeAPI::create_invoice]
+    pub(crate) invoice_expiries: Mutex<Vec<Option<u32>>>,
     node_state: NodeState,
 }
 
@@ -185,38 +219,47 @@ pub struct MockNodeAPI {
 impl NodeAPI for MockNodeAPI {
     async fn create_invoice(
         &self,
-        amount_sats: u64,
+        amount_sats: Option<u64>,
         description: String,
         preimage: Option<Vec<u8>>,
-    ) -> Result<Invoice> {
-        let invoice = create_invoice(description.clone(), amount_sats * 1000, vec![], preimage);
-        Ok(Invoice {
-            label: "".to_string(),
+        expiry_secs: Option<u32>,
+    ) -> Result<String> {
+        let invoice = create_invoice(
             description,
-            amount: Some(Amount {
-                unit: Some(Unit::Satoshi(amount_sats)),
-            }),
-            received: None,
-            status: 0,
-            payment_time: 0,
-            expiry_time: invoice.expiry as u32,
-            bolt11: invoice.bolt11,
-            payment_hash: hex::decode(invoice.payment_hash).unwrap(),
-            payment_preimage: vec![],
-        })
+            amount_sats.unwrap_or_default() * 1000,
+            vec![],
+            preimage,
+        );
+        let payment_hash = hex::decode(invoice.payment_hash).unwrap();
+        self.invoice_hashes.lock().await.push(payment_hash);
+        self.invoice_expiries.lock().await.push(expiry_secs);
+        Ok(invoice.bolt11)
+    }
+
+    async fn has_invoice(&self, payment_hash: Vec<u8>) -> Result<bool> {
+        Ok(self.invoice_hashes.lock().await.contains(&payment_hash))
     }
 
     async fn pull_changed(&self, _since_timestamp: i64) -> Result<SyncResponse> {
+        let routes = self.payment_routes.lock().await;
+        let mut payments: Vec<Payment> = self
+            .cloud_payments
+            .lock()
+            .await
+            .iter()
+            .cloned()
+            .flat_map(TryInto::try_into)
+            .collect();
+        for payment in payments.iter_mut() {
+            if let PaymentDetails::Ln { data } = &mut payment.details {
+                if let Some(route) = routes.get(&data.payment_hash) {
+                    data.route = route.clone();
+                }
+            }
+        }
         Ok(SyncResponse {
             node_state: self.node_state.clone(),
-            payments: self
-                .cloud_payments
-                .lock()
-                .await
-                .iter()
-                .cloned()
-                .flat_map(TryInto::try_into)
-                .collect(),
+            payments,
             channels: Vec::new(),
         })
     }
@@ -225,6 +268,8 @@ impl NodeAPI for MockNodeAPI {
         &self,
         bolt11: String,
         _amount_sats: Option<u64>,
+        _max_fee_sat: Option<u64>,
+        _timeout_sec: u32,
     ) -> Result<PaymentResponse> {
         let payment = self.add_dummy_payment_for(bolt11, None).await?;
         payment.try_into()
@@ -234,6 +279,8 @@ impl NodeAPI for MockNodeAPI {
         &self,
         _node_id: String,
         _amount_sats: u64,
+        _timeout_sec: u32,
+        _extra_tlvs: Vec<TlvEntry>,
     ) -> Result<PaymentResponse> {
         let payment = self.add_dummy_payment_rand().await?;
         payment.try_into()
@@ -245,12 +292,41 @@ impl NodeAPI for MockNodeAPI {
 
     async fn sweep(
         &self,
-        _to_address: String,
-        _fee_rate_sats_per_byte: u64,
+        to_address: String,
+        _fee_rate: SatPerVbyte,
+        utxos: Vec<UnspentTransactionOutput>,
     ) -> Result<WithdrawResponse> {
+        let (input, value) = match utxos.is_empty() {
+            true => (vec![TxIn::default()], 50_000),
+            false => (
+                utxos
+                    .iter()
+                    .map(|u| {
+                        Ok(TxIn {
+                            previous_output: OutPoint::from_str(&u.outpoint())?,
+                            ..Default::default()
+                        })
+                    })
+                    .collect::<Result<Vec<TxIn>>>()?,
+                utxos
+                    .iter()
+                    .map(|u| u.amount_millisatoshi / 1000)
+                    .sum::<u64>()
+                    - MOCK_SWEEP_FEE_SAT,
+            ),
+        };
+        let tx = Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input,
+            output: vec![TxOut {
+                value,
+                script_pubkey: Address::from_str(&to_address)?.script_pubkey(),
+            }],
+        };
         Ok(WithdrawResponse {
-            tx: rand_vec_u8(32),
-            txid: rand_vec_u8(32),
+            tx: serialize(&tx),
+            txid: tx.txid().as_inner().to_vec(),
         })
     }
 
@@ -283,7 +359,7 @@ impl NodeAPI for MockNodeAPI {
         Err(anyhow!("Not implemented"))
     }
 
-    async fn execute_command(&self, _command: String) -> Result<String> {
+    async fn execute_command(&self, _command: String, _dry_run: bool) -> Result<String> {
         Err(anyhow!("Not implemented"))
     }
 
@@ -296,9 +372,21 @@ impl MockNodeAPI {
     pub fn new(node_state: NodeState) -> Self {
         Self {
             cloud_payments: Mutex::new(vec![]),
+            payment_routes: Mutex::new(HashMap::new()),
+            invoice_hashes: Mutex::new(vec![]),
+            invoice_expiries: Mutex::new(vec![]),
             node_state,
         }
     }
+    /// Sets the route reported for the outgoing payment with the given hash
+    pub(crate) async fn set_payment_route(
+        &self,
+        payment_hash: String,
+        route: Vec<PaymentRouteHop>,
+    ) {
+        self.payment_routes.lock().await.insert(payment_hash, route);
+    }
+
     /// Creates a (simulated) payment for the specified BOLT11 and adds it to a test-specific
     /// global state.
     ///
@@ -458,13 +546,22 @@ impl FiatAPI for MockBreezServer {
 
 #[tonic::async_trait]
 impl MoonPayApi for MockBreezServer {
-    async fn buy_bitcoin_url(&self, swap_info: &SwapInfo) -> Result<String> {
+    async fn buy_bitcoin_url(
+        &self,
+        swap_info: &SwapInfo,
+        base_currency_code: Option<String>,
+    ) -> Result<String> {
         Ok(format!(
-            "https://mock.moonpay?wa={}&ma={}",
+            "https://mock.moonpay?wa={}&ma={}&bc={}",
             swap_info.bitcoin_address.as_str(),
             format!("{:.8}", swap_info.max_allowed_deposit as f64 / 100000000.0).as_str(),
+            base_currency_code.unwrap_or_default(),
         ))
     }
+
+    async fn check_signer(&self) -> Result<()> {
+        Ok(())
+    }
 }
 
 pub(crate) fn rand_invoice_with_description_hash(
@@ -534,19 +631,38 @@ pub fn create_invoice(
     amount_msat: u64,
     hints: Vec<RouteHint>,
     invoice_preimage: Option<Vec<u8>>,
+) -> LNInvoice {
+    create_invoice_for_currency(
+        Currency::Bitcoin,
+        description,
+        amount_msat,
+        hints,
+        invoice_preimage,
+    )
+}
+
+pub fn create_invoice_for_currency(
+    currency: Currency,
+    description: String,
+    amount_msat: u64,
+    hints: Vec<RouteHint>,
+    invoice_preimage: Option<Vec<u8>>,
 ) -> LNInvoice {
     let preimage = invoice_preimage.map_or(rand::thread_rng().gen::<[u8; 32]>().to_vec(), |p| p);
     let hashed = Message::from_hashed_data::<sha256::Hash>(&preimage[..]);
     let hash = hashed.as_ref();
 
-    let mut invoice_builder = InvoiceBuilder::new(Currency::Bitcoin)
+    let mut invoice_builder = InvoiceBuilder::new(currency)
         .description(description)
         .payment_hash(sha256::Hash::hash(hash))
         .timestamp(SystemTime::now())
-        .amount_milli_satoshis(amount_msat)
         .expiry_time(Duration::new(3600, 0))
         .payment_secret(PaymentSecret(rand::thread_rng().gen::<[u8; 32]>()))
         .min_final_cltv_expiry_delta(32);
+    // A zero amount creates an invoice without an amount
+    if amount_msat > 0 {
+        invoice_builder = invoice_builder.amount_milli_satoshis(amount_msat);
+    }
 
     for hint in hints {
         invoice_builder = invoice_builder.private_route(hint.to_ldk_hint().unwrap());
@@ -23,6 +23,23 @@ typedef struct wire_Config {
   struct wire_uint_8_list *default_lsp_id;
   struct wire_uint_8_list *api_key;
   double maxfee_percent;
+  bool auto_connect_lsp;
+  uint64_t fiat_rate_cache_ttl_sec;
+  uint32_t max_concurrent_chain_requests;
+  uint32_t onchain_min_confirmations;
+  struct wire_uint_8_list *default_fiat_currency;
+  uint32_t event_buffer_size;
+  bool allow_receive_split;
+  bool correct_clock_skew;
+  bool redact_logs;
+  uint32_t max_reconnect_backoff_sec;
+  int32_t overpaid_swap_handling;
+  struct wire_uint_8_list *mempoolspace_api_token;
+  uint64_t *swap_retention_sec;
+  int32_t chain_backend;
+  uint32_t sync_workers;
+  bool reconcile_expired_swaps_on_start;
+  struct wire_uint_8_list *db_encryption_key;
 } wire_Config;
 
 typedef struct wire_GreenlightCredentials {
@@ -30,6 +47,31 @@ typedef struct wire_GreenlightCredentials {
   struct wire_uint_8_list *device_cert;
 } wire_GreenlightCredentials;
 
+typedef struct wire_TlvEntry {
+  uint64_t type_num;
+  struct wire_uint_8_list *value;
+} wire_TlvEntry;
+
+typedef struct wire_list_tlv_entry {
+  struct wire_TlvEntry *ptr;
+  int32_t len;
+} wire_list_tlv_entry;
+
+typedef struct wire_TemplateValue {
+  struct wire_uint_8_list *key;
+  struct wire_uint_8_list *value;
+} wire_TemplateValue;
+
+typedef struct wire_list_template_value {
+  struct wire_TemplateValue *ptr;
+  int32_t len;
+} wire_list_template_value;
+
+typedef struct wire_StringList {
+  struct wire_uint_8_list **ptr;
+  int32_t len;
+} wire_StringList;
+
 typedef struct wire_LnUrlPayRequestData {
   struct wire_uint_8_list *callback;
   uint64_t min_sendable;
@@ -55,6 +97,44 @@ typedef struct wire_LnUrlAuthRequestData {
   struct wire_uint_8_list *url;
 } wire_LnUrlAuthRequestData;
 
+typedef struct wire_FeeTier_Fastest {
+
+} wire_FeeTier_Fastest;
+
+typedef struct wire_FeeTier_HalfHour {
+
+} wire_FeeTier_HalfHour;
+
+typedef struct wire_FeeTier_Hour {
+
+} wire_FeeTier_Hour;
+
+typedef struct wire_FeeTier_Economy {
+
+} wire_FeeTier_Economy;
+
+typedef struct wire_FeeTier_Minimum {
+
+} wire_FeeTier_Minimum;
+
+typedef struct wire_FeeTier_Custom {
+  uint64_t sat_per_vbyte;
+} wire_FeeTier_Custom;
+
+typedef union FeeTierKind {
+  struct wire_FeeTier_Fastest *Fastest;
+  struct wire_FeeTier_HalfHour *HalfHour;
+  struct wire_FeeTier_Hour *Hour;
+  struct wire_FeeTier_Economy *Economy;
+  struct wire_FeeTier_Minimum *Minimum;
+  struct wire_FeeTier_Custom *Custom;
+} FeeTierKind;
+
+typedef struct wire_FeeTier {
+  int32_t tag;
+  union FeeTierKind *kind;
+} wire_FeeTier;
+
 typedef struct DartCObject *WireSyncReturn;
 
 void store_dart_post_cobject(DartPostCObjectFnType ptr);
@@ -88,28 +168,71 @@ void wire_init_services(int64_t port_,
 
 void wire_start_node(int64_t port_);
 
-void wire_breez_events_stream(int64_t port_);
+void wire_breez_events_stream(int64_t port_, bool replay);
 
 void wire_breez_log_stream(int64_t port_);
 
 void wire_stop_node(int64_t port_);
 
-void wire_send_payment(int64_t port_, struct wire_uint_8_list *bolt11, uint64_t *amount_sats);
+void wire_send_payment(int64_t port_,
+                       struct wire_uint_8_list *bolt11,
+                       uint64_t *amount_sats,
+                       uint64_t *max_fee_sat,
+                       uint32_t *timeout_sec);
 
 void wire_send_spontaneous_payment(int64_t port_,
                                    struct wire_uint_8_list *node_id,
-                                   uint64_t amount_sats);
+                                   uint64_t amount_sats,
+                                   uint32_t *timeout_sec,
+                                   struct wire_list_tlv_entry *extra_tlvs);
 
 void wire_receive_payment(int64_t port_,
                           uint64_t amount_sats,
-                          struct wire_uint_8_list *description);
+                          struct wire_uint_8_list *description,
+                          uint32_t *expiry_secs,
+                          struct wire_uint_8_list *preimage,
+                          bool *include_lsp_hint,
+                          struct wire_uint_8_list *fallback_address);
+
+void wire_receive_payment_with_template(int64_t port_,
+                                        uint64_t amount_sats,
+                                        struct wire_uint_8_list *description_template,
+                                        struct wire_list_template_value *values);
+
+void wire_receive_payment_with_description_hash(int64_t port_,
+                                                uint64_t amount_sats,
+                                                struct wire_uint_8_list *description,
+                                                struct wire_uint_8_list *description_hash);
+
+void wire_receive_payment_split(int64_t port_,
+                                uint64_t amount_sats,
+                                struct wire_uint_8_list *description);
+
+void wire_receive_donation(int64_t port_, struct wire_uint_8_list *description);
+
+void wire_validate_amount(int64_t port_, uint64_t amount_msat, int32_t context);
+
+void wire_receive_cost_estimate(int64_t port_, uint64_t amount_msat);
+
+void wire_receive_payment_limits(int64_t port_);
+
+void wire_list_peers(int64_t port_);
+
+void wire_list_channels(int64_t port_);
 
 void wire_node_info(int64_t port_);
 
+void wire_connection_status(int64_t port_);
+
+void wire_payment_by_hash(int64_t port_, struct wire_uint_8_list *payment_hash);
+
 void wire_list_payments(int64_t port_,
                         int32_t filter,
                         int64_t *from_timestamp,
-                        int64_t *to_timestamp);
+                        int64_t *to_timestamp,
+                        uint64_t *min_amount_msat,
+                        uint64_t *max_amount_msat,
+                        struct wire_uint_8_list *description_contains);
 
 void wire_list_lsps(int64_t port_);
 
@@ -117,17 +240,37 @@ void wire_connect_lsp(int64_t port_, struct wire_uint_8_list *lsp_id);
 
 void wire_fetch_lsp_info(int64_t port_, struct wire_uint_8_list *id);
 
+void wire_lsp_liquidity_available(int64_t port_, uint64_t amount_msat);
+
 void wire_lsp_id(int64_t port_);
 
 void wire_fetch_fiat_rates(int64_t port_);
 
+void wire_fiat_rate(int64_t port_, struct wire_uint_8_list *coin);
+
+void wire_fiat_rate_at(int64_t port_, struct wire_uint_8_list *coin, int64_t timestamp);
+
+void wire_preferred_fiat_currency(int64_t port_);
+
+void wire_select_fiat_currency(int64_t port_, struct wire_uint_8_list *id);
+
 void wire_list_fiat_currencies(int64_t port_);
 
+void wire_list_fiat_currency_symbols(int64_t port_, struct wire_uint_8_list *locale);
+
 void wire_close_lsp_channels(int64_t port_);
 
 void wire_sweep(int64_t port_,
                 struct wire_uint_8_list *to_address,
-                uint64_t fee_rate_sats_per_byte);
+                uint32_t sat_per_vbyte,
+                struct wire_StringList *utxos);
+
+void wire_prepare_sweep(int64_t port_,
+                        struct wire_uint_8_list *to_address,
+                        uint32_t sat_per_vbyte,
+                        struct wire_StringList *utxos);
+
+void wire_list_sweeps(int64_t port_);
 
 void wire_receive_onchain(int64_t port_);
 
@@ -140,12 +283,41 @@ void wire_refund(int64_t port_,
                  struct wire_uint_8_list *to_address,
                  uint32_t sat_per_vbyte);
 
-void wire_execute_command(int64_t port_, struct wire_uint_8_list *command);
+void wire_prepare_refund(int64_t port_,
+                         struct wire_uint_8_list *swap_address,
+                         struct wire_uint_8_list *to_address,
+                         uint32_t sat_per_vbyte);
+
+void wire_refundable_amount(int64_t port_,
+                            struct wire_uint_8_list *swap_address,
+                            uint32_t sat_per_vbyte);
+
+void wire_prune_terminal_swaps(int64_t port_, uint64_t older_than_sec);
+
+void wire_execute_command(int64_t port_, struct wire_uint_8_list *command, bool dry_run);
 
 void wire_sync_node(int64_t port_);
 
+void wire_wait_for_block_height(int64_t port_, uint32_t height, uint64_t timeout_sec);
+
+void wire_full_resync(int64_t port_);
+
+void wire_optimize_storage(int64_t port_);
+
+void wire_backup(int64_t port_);
+
+void wire_restore(int64_t port_, struct wire_uint_8_list *data);
+
+void wire_set_setting(int64_t port_, struct wire_uint_8_list *key, struct wire_uint_8_list *value);
+
+void wire_get_setting(int64_t port_, struct wire_uint_8_list *key);
+
 void wire_parse_invoice(int64_t port_, struct wire_uint_8_list *invoice);
 
+void wire_parse_amount(int64_t port_, struct wire_uint_8_list *input, int32_t default_unit);
+
+void wire_extract_lnurl_pay_metadata(int64_t port_, struct wire_LnUrlPayRequestData *req_data);
+
 void wire_parse(int64_t port_, struct wire_uint_8_list *s);
 
 void wire_lnurl_pay(int64_t port_,
@@ -158,16 +330,42 @@ void wire_lnurl_withdraw(int64_t port_,
                          uint64_t amount_sats,
                          struct wire_uint_8_list *description);
 
+void wire_lnurl_withdraw_max(int64_t port_,
+                             struct wire_LnUrlWithdrawRequestData *req_data,
+                             struct wire_uint_8_list *description);
+
 void wire_lnurl_auth(int64_t port_, struct wire_LnUrlAuthRequestData *req_data);
 
+void wire_list_lnurl_auth_logins(int64_t port_);
+
 void wire_mnemonic_to_seed(int64_t port_, struct wire_uint_8_list *phrase);
 
 void wire_recommended_fees(int64_t port_);
 
-void wire_default_config(int64_t port_, int32_t config_type);
+void wire_preferred_fee_tier(int64_t port_);
+
+void wire_set_preferred_fee_tier(int64_t port_, struct wire_FeeTier *tier);
+
+void wire_resolve_fee_rate(int64_t port_);
+
+void wire_buy_bitcoin(int64_t port_, int32_t provider, struct wire_uint_8_list *base_currency_code);
+
+void wire_diagnostics(int64_t port_);
+
+void wire_connectivity_check(int64_t port_);
+
+void wire_check_clock_skew(int64_t port_);
+
+void wire_default_config(int64_t port_, int32_t config_type, int32_t network);
+
+struct wire_StringList *new_StringList_0(int32_t len);
+
+bool *new_box_autoadd_bool_0(bool value);
 
 struct wire_Config *new_box_autoadd_config_0(void);
 
+struct wire_FeeTier *new_box_autoadd_fee_tier_0(void);
+
 struct wire_GreenlightCredentials *new_box_autoadd_greenlight_credentials_0(void);
 
 int64_t *new_box_autoadd_i64_0(int64_t value);
@@ -178,10 +376,18 @@ struct wire_LnUrlPayRequestData *new_box_autoadd_ln_url_pay_request_data_0(void)
 
 struct wire_LnUrlWithdrawRequestData *new_box_autoadd_ln_url_withdraw_request_data_0(void);
 
+uint32_t *new_box_autoadd_u32_0(uint32_t value);
+
 uint64_t *new_box_autoadd_u64_0(uint64_t value);
 
+struct wire_list_template_value *new_list_template_value_0(int32_t len);
+
+struct wire_list_tlv_entry *new_list_tlv_entry_0(int32_t len);
+
 struct wire_uint_8_list *new_uint_8_list_0(int32_t len);
 
+union FeeTierKind *inflate_FeeTier_Custom(void);
+
 void free_WireSyncReturn(WireSyncReturn ptr);
 
 static int64_t dummy_method_to_enforce_bundling(void) {
@@ -197,38 +403,85 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_send_payment);
     dummy_var ^= ((int64_t) (void*) wire_send_spontaneous_payment);
     dummy_var ^= ((int64_t) (void*) wire_receive_payment);
+    dummy_var ^= ((int64_t) (void*) wire_receive_payment_with_template);
+    dummy_var ^= ((int64_t) (void*) wire_receive_payment_with_description_hash);
+    dummy_var ^= ((int64_t) (void*) wire_receive_payment_split);
+    dummy_var ^= ((int64_t) (void*) wire_receive_donation);
+    dummy_var ^= ((int64_t) (void*) wire_validate_amount);
+    dummy_var ^= ((int64_t) (void*) wire_receive_cost_estimate);
+    dummy_var ^= ((int64_t) (void*) wire_receive_payment_limits);
+    dummy_var ^= ((int64_t) (void*) wire_list_peers);
+    dummy_var ^= ((int64_t) (void*) wire_list_channels);
     dummy_var ^= ((int64_t) (void*) wire_node_info);
+    dummy_var ^= ((int64_t) (void*) wire_connection_status);
+    dummy_var ^= ((int64_t) (void*) wire_payment_by_hash);
     dummy_var ^= ((int64_t) (void*) wire_list_payments);
     dummy_var ^= ((int64_t) (void*) wire_list_lsps);
     dummy_var ^= ((int64_t) (void*) wire_connect_lsp);
     dummy_var ^= ((int64_t) (void*) wire_fetch_lsp_info);
+    dummy_var ^= ((int64_t) (void*) wire_lsp_liquidity_available);
     dummy_var ^= ((int64_t) (void*) wire_lsp_id);
     dummy_var ^= ((int64_t) (void*) wire_fetch_fiat_rates);
+    dummy_var ^= ((int64_t) (void*) wire_fiat_rate);
+    dummy_var ^= ((int64_t) (void*) wire_fiat_rate_at);
+    dummy_var ^= ((int64_t) (void*) wire_preferred_fiat_currency);
+    dummy_var ^= ((int64_t) (void*) wire_select_fiat_currency);
     dummy_var ^= ((int64_t) (void*) wire_list_fiat_currencies);
+    dummy_var ^= ((int64_t) (void*) wire_list_fiat_currency_symbols);
     dummy_var ^= ((int64_t) (void*) wire_close_lsp_channels);
     dummy_var ^= ((int64_t) (void*) wire_sweep);
+    dummy_var ^= ((int64_t) (void*) wire_prepare_sweep);
+    dummy_var ^= ((int64_t) (void*) wire_list_sweeps);
     dummy_var ^= ((int64_t) (void*) wire_receive_onchain);
     dummy_var ^= ((int64_t) (void*) wire_in_progress_swap);
     dummy_var ^= ((int64_t) (void*) wire_list_refundables);
     dummy_var ^= ((int64_t) (void*) wire_refund);
+    dummy_var ^= ((int64_t) (void*) wire_prepare_refund);
+    dummy_var ^= ((int64_t) (void*) wire_refundable_amount);
+    dummy_var ^= ((int64_t) (void*) wire_prune_terminal_swaps);
     dummy_var ^= ((int64_t) (void*) wire_execute_command);
     dummy_var ^= ((int64_t) (void*) wire_sync_node);
+    dummy_var ^= ((int64_t) (void*) wire_wait_for_block_height);
+    dummy_var ^= ((int64_t) (void*) wire_full_resync);
+    dummy_var ^= ((int64_t) (void*) wire_optimize_storage);
+    dummy_var ^= ((int64_t) (void*) wire_backup);
+    dummy_var ^= ((int64_t) (void*) wire_restore);
+    dummy_var ^= ((int64_t) (void*) wire_set_setting);
+    dummy_var ^= ((int64_t) (void*) wire_get_setting);
     dummy_var ^= ((int64_t) (void*) wire_parse_invoice);
+    dummy_var ^= ((int64_t) (void*) wire_parse_amount);
+    dummy_var ^= ((int64_t) (void*) wire_extract_lnurl_pay_metadata);
     dummy_var ^= ((int64_t) (void*) wire_parse);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_pay);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_withdraw);
+    dummy_var ^= ((int64_t) (void*) wire_lnurl_withdraw_max);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_auth);
+    dummy_var ^= ((int64_t) (void*) wire_list_lnurl_auth_logins);
     dummy_var ^= ((int64_t) (void*) wire_mnemonic_to_seed);
     dummy_var ^= ((int64_t) (void*) wire_recommended_fees);
+    dummy_var ^= ((int64_t) (void*) wire_preferred_fee_tier);
+    dummy_var ^= ((int64_t) (void*) wire_set_preferred_fee_tier);
+    dummy_var ^= ((int64_t) (void*) wire_resolve_fee_rate);
+    dummy_var ^= ((int64_t) (void*) wire_buy_bitcoin);
+    dummy_var ^= ((int64_t) (void*) wire_diagnostics);
+    dummy_var ^= ((int64_t) (void*) wire_connectivity_check);
+    dummy_var ^= ((int64_t) (void*) wire_check_clock_skew);
     dummy_var ^= ((int64_t) (void*) wire_default_config);
+    dummy_var ^= ((int64_t) (void*) new_StringList_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_bool_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_fee_tier_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_greenlight_credentials_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_i64_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_ln_url_auth_request_data_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_ln_url_pay_request_data_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_ln_url_withdraw_request_data_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_u32_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_u64_0);
+    dummy_var ^= ((int64_t) (void*) new_list_template_value_0);
+    dummy_var ^= ((int64_t) (void*) new_list_tlv_entry_0);
     dummy_var ^= ((int64_t) (void*) new_uint_8_list_0);
+    dummy_var ^= ((int64_t) (void*) inflate_FeeTier_Custom);
     dummy_var ^= ((int64_t) (void*) free_WireSyncReturn);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     dummy_var ^= ((int64_t) (void*) get_dart_object);
@@ -35,7 +35,7 @@ class BreezBridge {
 
   void initialize() {
     /// Listen to BreezEvent's(new block, invoice paid, synced)
-    _lnToolkit.breezEventsStream().listen((event) async {
+    _lnToolkit.breezEventsStream(replay: false).listen((event) async {
       _log.v("Received breez event: $event");
       if (event is BreezEvent_InvoicePaid) {
         _invoicePaidStream.add(event.details);
@@ -170,7 +170,7 @@ class BreezBridge {
   ///
   /// * `amountSats` - The amount to receive in satoshis
   /// * `description` - The bolt11 payment request description
-  Future<LNInvoice> receivePayment({
+  Future<ReceivePaymentResponse> receivePayment({
     required int amountSats,
     required String description,
   }) async =>
@@ -179,6 +179,24 @@ class BreezBridge {
         description: description,
       );
 
+  /// Creates an bolt11 payment request whose description is rendered from a template.
+  ///
+  /// # Arguments
+  ///
+  /// * `amountSats` - The amount to receive in satoshis
+  /// * `descriptionTemplate` - The description, with `{key}` placeholders such as `"Order #{id}"`
+  /// * `values` - The value of each placeholder
+  Future<ReceivePaymentResponse> receivePaymentWithTemplate({
+    required int amountSats,
+    required String descriptionTemplate,
+    required Map<String, String> values,
+  }) async =>
+      await _lnToolkit.receivePaymentWithTemplate(
+        amountSats: amountSats,
+        descriptionTemplate: descriptionTemplate,
+        values: values.entries.map((e) => TemplateValue(key: e.key, value: e.value)).toList(),
+      );
+
   /// get the node state from the persistent storage
   Future<NodeState?> getNodeState() async {
     final nodeState = await _lnToolkit.nodeInfo();
@@ -186,8 +204,8 @@ class BreezBridge {
     return nodeState;
   }
 
-  Future<Config> defaultConfig(EnvironmentType envType) {
-    return _lnToolkit.defaultConfig(configType: envType);
+  Future<Config> defaultConfig(EnvironmentType envType, Network network) {
+    return _lnToolkit.defaultConfig(configType: envType, network: network);
   }
 
   /// list payments (incoming/outgoing payments) from the persistent storage
@@ -234,15 +252,16 @@ class BreezBridge {
   Future closeLspChannels() async => await _lnToolkit.closeLspChannels();
 
   /// Withdraw on-chain funds in the wallet to an external btc address
-  Future sweep({
+  Future<SweepResult> sweep({
     required String toAddress,
-    required int feeRateSatsPerByte,
+    required int satPerVbyte,
   }) async {
-    await _lnToolkit.sweep(
+    final result = await _lnToolkit.sweep(
       toAddress: toAddress,
-      feeRateSatsPerByte: feeRateSatsPerByte,
+      satPerVbyte: satPerVbyte,
     );
     await listPayments();
+    return result;
   }
 
   /// Onchain receive swap API
@@ -263,7 +282,8 @@ class BreezBridge {
         satPerVbyte: satPerVbyte,
       );
 
-  Future<String> executeCommand({required String command}) => _lnToolkit.executeCommand(command: command);
+  Future<String> executeCommand({required String command, bool dryRun = false}) =>
+      _lnToolkit.executeCommand(command: command, dryRun: dryRun);
 
   Future<LNInvoice> parseInvoice(String invoice) async => await _lnToolkit.parseInvoice(invoice: invoice);
 
@@ -373,6 +393,23 @@ extension SDKConfig on Config {
     String? defaultLspId,
     String? apiKey,
     double? maxfeePercent,
+    bool? autoConnectLsp,
+    int? fiatRateCacheTtlSec,
+    int? maxConcurrentChainRequests,
+    int? onchainMinConfirmations,
+    String? defaultFiatCurrency,
+    int? eventBufferSize,
+    bool? allowReceiveSplit,
+    bool? correctClockSkew,
+    bool? redactLogs,
+    int? maxReconnectBackoffSec,
+    OverpaidSwapHandling? overpaidSwapHandling,
+    String? mempoolspaceApiToken,
+    int? swapRetentionSec,
+    ChainBackend? chainBackend,
+    int? syncWorkers,
+    bool? reconcileExpiredSwapsOnStart,
+    Uint8List? dbEncryptionKey,
   }) {
     return Config(
       breezserver: breezserver ?? this.breezserver,
@@ -383,6 +420,23 @@ extension SDKConfig on Config {
       defaultLspId: defaultLspId ?? this.defaultLspId,
       apiKey: apiKey ?? this.apiKey,
       maxfeePercent: maxfeePercent ?? this.maxfeePercent,
+      autoConnectLsp: autoConnectLsp ?? this.autoConnectLsp,
+      fiatRateCacheTtlSec: fiatRateCacheTtlSec ?? this.fiatRateCacheTtlSec,
+      maxConcurrentChainRequests: maxConcurrentChainRequests ?? this.maxConcurrentChainRequests,
+      onchainMinConfirmations: onchainMinConfirmations ?? this.onchainMinConfirmations,
+      defaultFiatCurrency: defaultFiatCurrency ?? this.defaultFiatCurrency,
+      eventBufferSize: eventBufferSize ?? this.eventBufferSize,
+      allowReceiveSplit: allowReceiveSplit ?? this.allowReceiveSplit,
+      correctClockSkew: correctClockSkew ?? this.correctClockSkew,
+      redactLogs: redactLogs ?? this.redactLogs,
+      maxReconnectBackoffSec: maxReconnectBackoffSec ?? this.maxReconnectBackoffSec,
+      overpaidSwapHandling: overpaidSwapHandling ?? this.overpaidSwapHandling,
+      mempoolspaceApiToken: mempoolspaceApiToken ?? this.mempoolspaceApiToken,
+      swapRetentionSec: swapRetentionSec ?? this.swapRetentionSec,
+      chainBackend: chainBackend ?? this.chainBackend,
+      syncWorkers: syncWorkers ?? this.syncWorkers,
+      reconcileExpiredSwapsOnStart: reconcileExpiredSwapsOnStart ?? this.reconcileExpiredSwapsOnStart,
+      dbEncryptionKey: dbEncryptionKey ?? this.dbEncryptionKey,
     );
   }
 }
@@ -67,7 +67,10 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kStartNodeConstMeta;
 
-  Stream<BreezEvent> breezEventsStream({dynamic hint});
+  /// Registers the stream of [BreezEvent]s.
+  ///
+  /// With `replay`, the most recent events are sent first, see [Config::event_buffer_size].
+  Stream<BreezEvent> breezEventsStream({required bool replay, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kBreezEventsStreamConstMeta;
 
@@ -75,34 +78,120 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kBreezLogStreamConstMeta;
 
-  /// Cleanup node resources and stop the signer.
+  /// Cleanup node resources and stop the signer, see [BreezServices::stop].
+  ///
+  /// The instance is released even if it wasn't started, so [init_services] can be called again.
   Future<void> stopNode({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kStopNodeConstMeta;
 
   /// See [BreezServices::send_payment]
-  Future<Payment> sendPayment({required String bolt11, int? amountSats, dynamic hint});
+  Future<Payment> sendPayment(
+      {required String bolt11, int? amountSats, int? maxFeeSat, int? timeoutSec, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSendPaymentConstMeta;
 
   /// See [BreezServices::send_spontaneous_payment]
-  Future<Payment> sendSpontaneousPayment({required String nodeId, required int amountSats, dynamic hint});
+  Future<Payment> sendSpontaneousPayment(
+      {required String nodeId,
+      required int amountSats,
+      int? timeoutSec,
+      List<TlvEntry>? extraTlvs,
+      dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSendSpontaneousPaymentConstMeta;
 
   /// See [BreezServices::receive_payment]
-  Future<LNInvoice> receivePayment({required int amountSats, required String description, dynamic hint});
+  Future<ReceivePaymentResponse> receivePayment(
+      {required int amountSats,
+      required String description,
+      int? expirySecs,
+      Uint8List? preimage,
+      bool? includeLspHint,
+      String? fallbackAddress,
+      dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kReceivePaymentConstMeta;
 
+  /// See [BreezServices::receive_payment_with_template]
+  Future<ReceivePaymentResponse> receivePaymentWithTemplate(
+      {required int amountSats,
+      required String descriptionTemplate,
+      required List<TemplateValue> values,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentWithTemplateConstMeta;
+
+  /// See [BreezServices::receive_payment_with_description_hash]
+  Future<ReceivePaymentResponse> receivePaymentWithDescriptionHash(
+      {required int amountSats,
+      required String description,
+      required Uint8List descriptionHash,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentWithDescriptionHashConstMeta;
+
+  /// See [BreezServices::receive_payment_split]
+  Future<List<LNInvoice>> receivePaymentSplit(
+      {required int amountSats, required String description, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentSplitConstMeta;
+
+  /// See [BreezServices::receive_donation]
+  Future<LNInvoice> receiveDonation({required String description, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceiveDonationConstMeta;
+
+  /// See [BreezServices::validate_amount]
+  Future<List<AmountViolation>> validateAmount(
+      {required int amountMsat, required AmountContext context, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kValidateAmountConstMeta;
+
+  /// See [BreezServices::receive_cost_estimate]
+  Future<ReceiveCost> receiveCostEstimate({required int amountMsat, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceiveCostEstimateConstMeta;
+
+  /// See [BreezServices::receive_payment_limits]
+  Future<ReceiveLimits> receivePaymentLimits({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentLimitsConstMeta;
+
+  /// See [BreezServices::list_peers]
+  Future<List<PeerInfo>> listPeers({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListPeersConstMeta;
+
+  /// See [BreezServices::list_channels]
+  Future<List<ChannelInfo>> listChannels({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListChannelsConstMeta;
+
   /// See [BreezServices::node_info]
   Future<NodeState?> nodeInfo({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kNodeInfoConstMeta;
 
+  /// See [BreezServices::connection_status]
+  Future<ConnectionStatus> connectionStatus({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kConnectionStatusConstMeta;
+
+  /// See [BreezServices::payment_by_hash]
+  Future<Payment?> paymentByHash({required String paymentHash, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPaymentByHashConstMeta;
+
   /// See [BreezServices::list_payments]
   Future<List<Payment>> listPayments(
-      {required PaymentTypeFilter filter, int? fromTimestamp, int? toTimestamp, dynamic hint});
+      {required PaymentTypeFilter filter,
+      int? fromTimestamp,
+      int? toTimestamp,
+      int? minAmountMsat,
+      int? maxAmountMsat,
+      String? descriptionContains,
+      dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kListPaymentsConstMeta;
 
@@ -121,6 +210,11 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kFetchLspInfoConstMeta;
 
+  /// See [BreezServices::lsp_liquidity_available]
+  Future<bool> lspLiquidityAvailable({required int amountMsat, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLspLiquidityAvailableConstMeta;
+
   /// See [BreezServices::lsp_id]
   Future<String?> lspId({dynamic hint});
 
@@ -131,21 +225,58 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kFetchFiatRatesConstMeta;
 
+  /// See [BreezServices::fiat_rate]
+  Future<Rate?> fiatRate({required String coin, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFiatRateConstMeta;
+
+  /// See [BreezServices::fiat_rate_at]
+  Future<Rate?> fiatRateAt({required String coin, required int timestamp, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFiatRateAtConstMeta;
+
+  /// See [BreezServices::preferred_fiat_currency]
+  Future<String?> preferredFiatCurrency({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPreferredFiatCurrencyConstMeta;
+
+  /// See [BreezServices::select_fiat_currency]
+  Future<Rate> selectFiatCurrency({required String id, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSelectFiatCurrencyConstMeta;
+
   /// See [BreezServices::list_fiat_currencies]
   Future<List<FiatCurrency>> listFiatCurrencies({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kListFiatCurrenciesConstMeta;
 
+  /// See [BreezServices::list_fiat_currency_symbols]
+  Future<List<CurrencySymbolEntry>> listFiatCurrencySymbols({required String locale, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListFiatCurrencySymbolsConstMeta;
+
   /// See [BreezServices::close_lsp_channels]
   Future<void> closeLspChannels({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kCloseLspChannelsConstMeta;
 
   /// See [BreezServices::sweep]
-  Future<void> sweep({required String toAddress, required int feeRateSatsPerByte, dynamic hint});
+  Future<SweepResult> sweep(
+      {required String toAddress, required int satPerVbyte, List<String>? utxos, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSweepConstMeta;
 
+  /// See [BreezServices::prepare_sweep]
+  Future<SweepPreview> prepareSweep(
+      {required String toAddress, required int satPerVbyte, List<String>? utxos, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPrepareSweepConstMeta;
+
+  /// See [BreezServices::list_sweeps]
+  Future<List<SweepResult>> listSweeps({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListSweepsConstMeta;
+
   /// See [BreezServices::receive_onchain]
   Future<SwapInfo> receiveOnchain({dynamic hint});
 
@@ -167,8 +298,24 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kRefundConstMeta;
 
+  /// See [BreezServices::prepare_refund]
+  Future<RefundPreview> prepareRefund(
+      {required String swapAddress, required String toAddress, required int satPerVbyte, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPrepareRefundConstMeta;
+
+  /// See [BreezServices::refundable_amount]
+  Future<int> refundableAmount({required String swapAddress, required int satPerVbyte, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRefundableAmountConstMeta;
+
+  /// See [BreezServices::prune_terminal_swaps]
+  Future<int> pruneTerminalSwaps({required int olderThanSec, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPruneTerminalSwapsConstMeta;
+
   /// See [BreezServices::execute_dev_command]
-  Future<String> executeCommand({required String command, dynamic hint});
+  Future<String> executeCommand({required String command, required bool dryRun, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kExecuteCommandConstMeta;
 
@@ -176,10 +323,54 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kSyncNodeConstMeta;
 
+  /// See [BreezServices::wait_for_block_height]
+  Future<int> waitForBlockHeight({required int height, required int timeoutSec, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kWaitForBlockHeightConstMeta;
+
+  /// See [BreezServices::full_resync]
+  Future<void> fullResync({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFullResyncConstMeta;
+
+  /// See [BreezServices::optimize_storage]
+  Future<void> optimizeStorage({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kOptimizeStorageConstMeta;
+
+  /// See [BreezServices::backup]
+  Future<Uint8List> backup({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBackupConstMeta;
+
+  /// See [BreezServices::restore]
+  Future<void> restore({required Uint8List data, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRestoreConstMeta;
+
+  /// See [BreezServices::set_setting]
+  Future<void> setSetting({required String key, required String value, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetSettingConstMeta;
+
+  /// See [BreezServices::get_setting]
+  Future<String?> getSetting({required String key, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGetSettingConstMeta;
+
   Future<LNInvoice> parseInvoice({required String invoice, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kParseInvoiceConstMeta;
 
+  Future<int> parseAmount({required String input, required AmountUnit defaultUnit, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kParseAmountConstMeta;
+
+  /// See [LnUrlPayRequestData::extract_metadata]
+  Future<ParsedMetadata> extractLnurlPayMetadata({required LnUrlPayRequestData reqData, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExtractLnurlPayMetadataConstMeta;
+
   Future<InputType> parse({required String s, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kParseConstMeta;
@@ -199,11 +390,22 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kLnurlWithdrawConstMeta;
 
+  /// See [BreezServices::lnurl_withdraw_max]
+  Future<LnUrlCallbackStatus> lnurlWithdrawMax(
+      {required LnUrlWithdrawRequestData reqData, String? description, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLnurlWithdrawMaxConstMeta;
+
   /// See [BreezServices::lnurl_auth]
   Future<LnUrlCallbackStatus> lnurlAuth({required LnUrlAuthRequestData reqData, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kLnurlAuthConstMeta;
 
+  /// See [BreezServices::list_lnurl_auth_logins]
+  Future<List<LnUrlAuthLogin>> listLnurlAuthLogins({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListLnurlAuthLoginsConstMeta;
+
   /// See [breez_services::mnemonic_to_seed]
   Future<Uint8List> mnemonicToSeed({required String phrase, dynamic hint});
 
@@ -214,12 +416,64 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kRecommendedFeesConstMeta;
 
+  /// See [BreezServices::preferred_fee_tier]
+  Future<FeeTier> preferredFeeTier({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPreferredFeeTierConstMeta;
+
+  /// See [BreezServices::set_preferred_fee_tier]
+  Future<void> setPreferredFeeTier({required FeeTier tier, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetPreferredFeeTierConstMeta;
+
+  /// See [BreezServices::resolve_fee_rate]
+  Future<int> resolveFeeRate({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kResolveFeeRateConstMeta;
+
+  /// See [BreezServices::buy_bitcoin]
+  Future<String> buyBitcoin({required BuyBitcoinProvider provider, String? baseCurrencyCode, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBuyBitcoinConstMeta;
+
+  /// See [BreezServices::diagnostics]
+  Future<Diagnostics> diagnostics({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDiagnosticsConstMeta;
+
+  /// See [BreezServices::connectivity_check]
+  Future<List<ServiceStatus>> connectivityCheck({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kConnectivityCheckConstMeta;
+
+  /// See [BreezServices::check_clock_skew]
+  Future<ClockSkewWarning?> checkClockSkew({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCheckClockSkewConstMeta;
+
   /// See [BreezServices::default_config]
-  Future<Config> defaultConfig({required EnvironmentType configType, dynamic hint});
+  Future<Config> defaultConfig({required EnvironmentType configType, required Network network, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kDefaultConfigConstMeta;
 }
 
+@freezed
+class AesDecryptedOrError with _$AesDecryptedOrError {
+  /// See [AesSuccessActionDataDecrypted]
+  const factory AesDecryptedOrError.decrypted({
+    required AesSuccessActionDataDecrypted data,
+  }) = AesDecryptedOrError_Decrypted;
+
+  /// The payload couldn't be decrypted, for example because the key was wrong. The original
+  /// payload is kept so its description can still be shown.
+  const factory AesDecryptedOrError.errorStatus({
+    required String reason,
+    required String description,
+    required String ciphertext,
+    required String iv,
+  }) = AesDecryptedOrError_ErrorStatus;
+}
+
 /// Wrapper for the decrypted [AesSuccessActionData] payload
 class AesSuccessActionDataDecrypted {
   /// Contents description, up to 144 characters
@@ -234,6 +488,43 @@ class AesSuccessActionDataDecrypted {
   });
 }
 
+/// What an amount entered by the user is meant for, see [crate::BreezServices::validate_amount]
+enum AmountContext {
+  /// Paying a lightning invoice or a keysend payment
+  Send,
+
+  /// Creating a lightning invoice
+  Receive,
+
+  /// Depositing on-chain funds to the current swap address
+  SwapIn,
+}
+
+/// The unit of an amount entered by the user, see [parse_amount]
+enum AmountUnit {
+  Btc,
+  Sat,
+  Msat,
+}
+
+@freezed
+class AmountViolation with _$AmountViolation {
+  /// The amount is below the minimum allowed in this context
+  const factory AmountViolation.belowMinimum({
+    required int minMsat,
+  }) = AmountViolation_BelowMinimum;
+
+  /// The amount is above the maximum allowed in this context
+  const factory AmountViolation.aboveMaximum({
+    required int maxMsat,
+  }) = AmountViolation_AboveMaximum;
+
+  /// The balance doesn't cover the amount
+  const factory AmountViolation.insufficientBalance({
+    required int maxPayableMsat,
+  }) = AmountViolation_InsufficientBalance;
+}
+
 /// Wrapped in a [BitcoinAddress], this is the result of [parse] when given a plain or BIP-21 BTC address.
 class BitcoinAddressData {
   final String address;
@@ -275,6 +566,98 @@ class BreezEvent with _$BreezEvent {
   const factory BreezEvent.paymentFailed({
     required PaymentFailedData details,
   }) = BreezEvent_PaymentFailed;
+
+  /// Indicates that an already settled invoice was reported as paid again. The duplicate
+  /// settlement is ignored and not counted towards the received amount.
+  const factory BreezEvent.duplicateIncomingPayment({
+    required InvoicePaidDetails details,
+  }) = BreezEvent_DuplicateIncomingPayment;
+
+  /// Indicates that the SDK connected to the selected LSP on startup
+  const factory BreezEvent.lspConnected({
+    required String lspId,
+  }) = BreezEvent_LspConnected;
+
+  /// Indicates that an outgoing payment passed validation and is being dispatched to the node.
+  /// It is emitted before the node attempts any route, and is followed by either a
+  /// [BreezEvent::PaymentSucceed] or a [BreezEvent::PaymentFailed].
+  const factory BreezEvent.paymentPending({
+    required PaymentPendingData details,
+  }) = BreezEvent_PaymentPending;
+
+  /// Indicates that the confirmed balance of a swap address changed, either because a deposit
+  /// confirmed or because a confirmed deposit was reorged out of the chain
+  const factory BreezEvent.swapUpdated({
+    required SwapInfo details,
+  }) = BreezEvent_SwapUpdated;
+
+  /// Indicates that the streams of node events were lost, or that they were reopened and the
+  /// local state sync-ed after a connectivity loss
+  const factory BreezEvent.connectionStatusChanged({
+    required bool connected,
+  }) = BreezEvent_ConnectionStatusChanged;
+
+  /// Indicates that more than the maximum allowed deposit was confirmed on a swap address, and
+  /// how the deposit is handled
+  const factory BreezEvent.swapOverpaid({
+    required SwapInfo details,
+    required OverpaidSwapHandling handling,
+  }) = BreezEvent_SwapOverpaid;
+
+  /// Indicates that swaps which expired while the SDK was offline were marked as expired on
+  /// startup, see [Config::reconcile_expired_swaps_on_start]
+  const factory BreezEvent.swapsReconciled({
+    required List<SwapInfo> expired,
+  }) = BreezEvent_SwapsReconciled;
+
+  /// Indicates how many of the `total` payments pulled by [BreezServices::sync] are stored so
+  /// far. It is first emitted with `processed` 0 once the node state and channels are updated,
+  /// and the sync ends with a [BreezEvent::Synced]. These events aren't replayed.
+  const factory BreezEvent.syncProgress({
+    required int processed,
+    required int total,
+  }) = BreezEvent_SyncProgress;
+}
+
+/// Different providers will demand different behaviours when the user is trying to buy bitcoin.
+enum BuyBitcoinProvider {
+  MoonPay,
+}
+
+/// The kind of chain service used to monitor the chain
+enum ChainBackend {
+  /// A mempool.space instance, e.g. `https://mempool.space`
+  MempoolSpace,
+
+  /// An Esplora instance, e.g. `https://blockstream.info/api`
+  Esplora,
+}
+
+/// A channel of the node with its balances, see [crate::BreezServices::list_channels]
+class ChannelInfo {
+  final String shortChannelId;
+  final String fundingTxid;
+  final ChannelState state;
+
+  /// Only known for the channels the node currently reports
+  final int? capacityMsat;
+
+  /// The amount we can send over the channel, excluding the channel reserve
+  final int localBalanceMsat;
+
+  /// The amount we can receive over the channel
+  final int remoteBalanceMsat;
+  final int? closedAt;
+
+  const ChannelInfo({
+    required this.shortChannelId,
+    required this.fundingTxid,
+    required this.state,
+    this.capacityMsat,
+    required this.localBalanceMsat,
+    required this.remoteBalanceMsat,
+    this.closedAt,
+  });
 }
 
 /// State of a Lightning channel
@@ -285,6 +668,18 @@ enum ChannelState {
   Closed,
 }
 
+/// Returned by [crate::BreezServices::check_clock_skew] when the device clock is off by more than
+/// [crate::invoice::EXPIRY_CLOCK_SKEW_SECS]
+class ClockSkewWarning {
+  /// Seconds to add to the device time to get the network time. Positive when the device clock
+  /// is behind.
+  final int offsetSecs;
+
+  const ClockSkewWarning({
+    required this.offsetSecs,
+  });
+}
+
 /// Represents the funds that were on the user side of the channel at the time it was closed.
 class ClosedChannelPaymentDetails {
   final String shortChannelId;
@@ -312,6 +707,67 @@ class Config {
   final String? apiKey;
   final double maxfeePercent;
 
+  /// Connect to the selected LSP when the SDK starts. If disabled, the LSP is only connected by
+  /// the next [crate::BreezServices::sync] or an explicit [crate::BreezServices::connect_lsp].
+  final bool autoConnectLsp;
+
+  /// How long, in seconds, fetched fiat rates are cached before being fetched again
+  final int fiatRateCacheTtlSec;
+
+  /// Maximum number of chain service requests running at the same time, extra requests are queued
+  final int maxConcurrentChainRequests;
+
+  /// Minimum number of confirmations for a UTXO to count towards the spendable on-chain balance
+  /// and to be selected as an input when sweeping. Set to 0 to treat unconfirmed funds as spendable.
+  final int onchainMinConfirmations;
+
+  /// Fiat currency preselected for the user, its rate is fetched on start so it's ready on first use
+  final String? defaultFiatCurrency;
+
+  /// Number of recent events kept to be replayed to late subscribers of the events stream
+  final int eventBufferSize;
+
+  /// Whether [crate::BreezServices::receive_payment_split] may split an amount above the maximum
+  /// of a single payment over several invoices
+  final bool allowReceiveSplit;
+
+  /// Whether invoice expiry checks use the device time corrected by the offset found in
+  /// [crate::BreezServices::check_clock_skew]
+  final bool correctClockSkew;
+
+  /// Whether hex encoded secrets, such as preimages and private keys, are masked in the
+  /// streamed [LogEntry] lines
+  final bool redactLogs;
+
+  /// Maximum delay between attempts to reopen the node streams after a connectivity loss
+  final int maxReconnectBackoffSec;
+
+  /// How to handle a swap whose confirmed deposit exceeds its maximum allowed deposit
+  final OverpaidSwapHandling overpaidSwapHandling;
+
+  /// Optional token sent to the mempool.space instance on each request, for self-hosted
+  /// instances that require one. Setting it requires an https `mempoolspace_url`.
+  final String? mempoolspaceApiToken;
+
+  /// Age, in seconds past their expiry, after which completed swaps are pruned on
+  /// sync, see [crate::BreezServices::prune_terminal_swaps]. Swaps are kept forever if not set.
+  final int? swapRetentionSec;
+
+  /// The API served at `mempoolspace_url`, see [ChainBackend]
+  final ChainBackend chainBackend;
+
+  /// Number of workers converting the payment history pulled from the node in parallel, which
+  /// speeds up the first sync of nodes with a long history
+  final int syncWorkers;
+
+  /// Whether the swaps that expired while the SDK was offline are marked as expired on startup,
+  /// see [crate::BreezEvent::SwapsReconciled]
+  final bool reconcileExpiredSwapsOnStart;
+
+  /// Key used to encrypt the local databases at rest, which requires the `sqlcipher` feature.
+  /// Existing unencrypted databases are encrypted with it on the next start.
+  final Uint8List? dbEncryptionKey;
+
   const Config({
     required this.breezserver,
     required this.mempoolspaceUrl,
@@ -321,9 +777,38 @@ class Config {
     this.defaultLspId,
     this.apiKey,
     required this.maxfeePercent,
+    required this.autoConnectLsp,
+    required this.fiatRateCacheTtlSec,
+    required this.maxConcurrentChainRequests,
+    required this.onchainMinConfirmations,
+    this.defaultFiatCurrency,
+    required this.eventBufferSize,
+    required this.allowReceiveSplit,
+    required this.correctClockSkew,
+    required this.redactLogs,
+    required this.maxReconnectBackoffSec,
+    required this.overpaidSwapHandling,
+    this.mempoolspaceApiToken,
+    this.swapRetentionSec,
+    required this.chainBackend,
+    required this.syncWorkers,
+    required this.reconcileExpiredSwapsOnStart,
+    this.dbEncryptionKey,
   });
 }
 
+/// Whether the SDK is connected to the node, see [crate::BreezServices::connection_status]
+enum ConnectionStatus {
+  /// The streams of node events are open
+  Connected,
+
+  /// The streams of node events are being opened
+  Connecting,
+
+  /// The SDK isn't started, or waits to retry opening the streams of node events
+  Disconnected,
+}
+
 /// Details about a supported currency in the fiat rate feed
 class CurrencyInfo {
   final String name;
@@ -345,12 +830,70 @@ class CurrencyInfo {
   });
 }
 
+/// Lightweight representation of a fiat currency, meant for currency pickers
+class CurrencySymbolEntry {
+  final String id;
+  final String? symbol;
+
+  /// The name of the currency, localized when a translation is available
+  final String name;
+
+  const CurrencySymbolEntry({
+    required this.id,
+    this.symbol,
+    required this.name,
+  });
+}
+
+/// Snapshot of the SDK state meant to be attached to support requests, see
+/// [crate::BreezServices::diagnostics]. It never includes secrets such as keys, preimages or the
+/// API key.
+class Diagnostics {
+  final String sdkVersion;
+  final Network network;
+  final String? nodeId;
+  final int? blockHeight;
+  final String? lspId;
+  final int channelsBalanceMsat;
+  final int onchainBalanceMsat;
+  final int connectedPeers;
+  final SwapDiagnostics swaps;
+  final List<String> lastErrors;
+  final bool backgroundTasksRunning;
+
+  const Diagnostics({
+    required this.sdkVersion,
+    required this.network,
+    this.nodeId,
+    this.blockHeight,
+    this.lspId,
+    required this.channelsBalanceMsat,
+    required this.onchainBalanceMsat,
+    required this.connectedPeers,
+    required this.swaps,
+    required this.lastErrors,
+    required this.backgroundTasksRunning,
+  });
+}
+
 /// Indicates the different kinds of supported environments for [crate::BreezServices].
 enum EnvironmentType {
   Production,
   Staging,
 }
 
+@freezed
+class FeeTier with _$FeeTier {
+  const factory FeeTier.fastest() = FeeTier_Fastest;
+  const factory FeeTier.halfHour() = FeeTier_HalfHour;
+  const factory FeeTier.hour() = FeeTier_Hour;
+  const factory FeeTier.economy() = FeeTier_Economy;
+  const factory FeeTier.minimum() = FeeTier_Minimum;
+  const factory FeeTier.custom({
+    required int satPerVbyte,
+  }) = FeeTier_Custom;
+}
+
 /// Wrapper around the [CurrencyInfo] of a fiat currency
 class FiatCurrency {
   final String id;
@@ -382,9 +925,6 @@ class InputType with _$InputType {
   const factory InputType.bitcoinAddress({
     required BitcoinAddressData address,
   }) = InputType_BitcoinAddress;
-
-  /// Also covers URIs like `bitcoin:...&lightning=bolt11`. In this case, it returns the BOLT11
-  /// and discards all other data.
   const factory InputType.bolt11({
     required LNInvoice invoice,
   }) = InputType_Bolt11;
@@ -430,6 +970,38 @@ class InputType with _$InputType {
   const factory InputType.lnUrlError({
     required LnUrlErrorData data,
   }) = InputType_LnUrlError;
+
+  /// # Supported standards
+  ///
+  /// - LUD-01 LNURL bech32 encoding
+  /// - LUD-02 `channelRequest` spec
+  const factory InputType.lnUrlChannel({
+    required LnUrlChannelRequestData data,
+  }) = InputType_LnUrlChannel;
+
+  /// A BIP21 URI with a `lightning=bolt11` param, like `bitcoin:...?lightning=bolt11`.
+  ///
+  /// Both payment options are returned, so the caller can prefer the LN invoice and fall back
+  /// to the on-chain address.
+  const factory InputType.unified({
+    required BitcoinAddressData address,
+    required LNInvoice invoice,
+  }) = InputType_Unified;
+
+  /// # Supported standards
+  ///
+  /// - BOLT12 offers, which can be recognized but not paid yet
+  const factory InputType.bolt12Offer({
+    required LNOffer offer,
+  }) = InputType_Bolt12Offer;
+
+  /// A node connection string, like `pubkey@host:port`, with which a peer connection can be
+  /// opened. The port defaults to 9735 if the string doesn't specify one.
+  const factory InputType.nodeConnection({
+    required String nodeId,
+    required String host,
+    required int port,
+  }) = InputType_NodeConnection;
 }
 
 /// Details of an invoice that has been paid, included as payload in an emitted [BreezEvent]
@@ -456,6 +1028,9 @@ class LNInvoice {
   final List<RouteHint> routingHints;
   final Uint8List paymentSecret;
 
+  /// The network the invoice was created for, as encoded in its human readable part
+  final Network network;
+
   const LNInvoice({
     required this.bolt11,
     required this.payeePubkey,
@@ -467,6 +1042,26 @@ class LNInvoice {
     required this.expiry,
     required this.routingHints,
     required this.paymentSecret,
+    required this.network,
+  });
+}
+
+/// Wrapper for a BOLT12 offer
+class LNOffer {
+  final String bolt12;
+  final String signingPubkey;
+  final String description;
+
+  /// The amount to pay, in bitcoin or in a fiat currency. Offers without an amount accept any.
+  final OfferAmount? amount;
+  final String? issuer;
+
+  const LNOffer({
+    required this.bolt12,
+    required this.signingPubkey,
+    required this.description,
+    this.amount,
+    this.issuer,
   });
 }
 
@@ -489,6 +1084,14 @@ class LnPaymentDetails {
   /// Only set for [PaymentType::Sent] payments where the receiver endpoint returned LNURL metadata
   final String? lnurlMetadata;
 
+  /// The hops of the route the payment took, for [PaymentType::Sent] payments whose route the
+  /// node reports. Empty otherwise.
+  final List<PaymentRouteHop> route;
+
+  /// The custom records attached by the sender of a received spontaneous payment, for example a
+  /// message sent along a podcast payment. Empty otherwise.
+  final List<TlvEntry> customTlvs;
+
   const LnPaymentDetails({
     required this.paymentHash,
     required this.label,
@@ -499,6 +1102,27 @@ class LnPaymentDetails {
     this.lnurlSuccessAction,
     this.lnAddress,
     this.lnurlMetadata,
+    required this.route,
+    required this.customTlvs,
+  });
+}
+
+/// A recorded LNURL-auth attempt, see [crate::BreezServices::list_lnurl_auth_logins]
+class LnUrlAuthLogin {
+  final String domain;
+  final String k1;
+
+  /// Epoch time, in seconds
+  final int timestamp;
+
+  /// Whether the LNURL-auth endpoint accepted the login
+  final bool success;
+
+  const LnUrlAuthLogin({
+    required this.domain,
+    required this.k1,
+    required this.timestamp,
+    required this.success,
   });
 }
 
@@ -541,6 +1165,24 @@ class LnUrlCallbackStatus with _$LnUrlCallbackStatus {
   }) = LnUrlCallbackStatus_ErrorStatus;
 }
 
+/// Wrapped in a [LnUrlChannel], this is the result of [parse] when given a LNURL-channel endpoint.
+///
+/// It represents the endpoint's parameters for the LNURL workflow.
+///
+/// See https://github.com/lnurl/luds/blob/luds/02.md
+class LnUrlChannelRequestData {
+  /// The node URI of the service, in the `node_id@host:port` format
+  final String uri;
+  final String callback;
+  final String k1;
+
+  const LnUrlChannelRequestData({
+    required this.uri,
+    required this.callback,
+    required this.k1,
+  });
+}
+
 /// Wrapped in a [LnUrlError], this represents a LNURL-endpoint error.
 class LnUrlErrorData {
   final String reason;
@@ -597,6 +1239,7 @@ class LnUrlPayRequestData {
 class LnUrlPayResult with _$LnUrlPayResult {
   const factory LnUrlPayResult.endpointSuccess({
     SuccessActionProcessed? data,
+    required Payment payment,
   }) = LnUrlPayResult_EndpointSuccess;
   const factory LnUrlPayResult.endpointError({
     required LnUrlErrorData data,
@@ -746,7 +1389,67 @@ class NodeState {
   });
 }
 
+@freezed
+class OfferAmount with _$OfferAmount {
+  const factory OfferAmount.bitcoin({
+    required int amountMsat,
+  }) = OfferAmount_Bitcoin;
+
+  /// An amount in the smallest unit of an ISO 4217 currency, for example cents for USD
+  const factory OfferAmount.currency({
+    required String iso4217Code,
+    required int fractionalAmount,
+  }) = OfferAmount_Currency;
+}
+
+/// How the LSP computes the fee for opening a channel, from [LspInformation]
+class OpeningFeeParams {
+  /// The fee charged for small amounts, see [LspInformation::channel_minimum_fee_msat]
+  final int minMsat;
+
+  /// The fee proportional to the amount, see [LspInformation::channel_fee_permyriad]
+  final int channelFeePermyriad;
+
+  const OpeningFeeParams({
+    required this.minMsat,
+    required this.channelFeePermyriad,
+  });
+}
+
+/// How to handle a swap whose confirmed deposit exceeds its [SwapInfo::max_allowed_deposit]
+///
+/// Redeeming a swap spends its whole deposit, so the deposit can't be split into a redeemed and a
+/// refunded part.
+enum OverpaidSwapHandling {
+  /// Don't redeem the swap, so that the whole deposit can be refunded once the swap expires
+  Refund,
+
+  /// Try to redeem the whole deposit anyway, in case the swapper accepts it. If it doesn't, the
+  /// deposit can still be refunded once the swap expires.
+  Redeem,
+}
+
+/// The [LnUrlPayRequestData] metadata items shown to the user, see [LnUrlPayRequestData::extract_metadata]
+class ParsedMetadata {
+  /// The mandatory short description
+  final String description;
+  final String? longDescription;
+
+  /// The PNG or JPEG thumbnail
+  final Uint8List? image;
+
+  const ParsedMetadata({
+    required this.description,
+    this.longDescription,
+    this.image,
+  });
+}
+
 /// Represents a payment, including its [PaymentType] and [PaymentDetails].
+///
+/// Besides the msat amounts, the serialized payment includes them in sat, rounded down, and in
+/// btc, as a decimal string. These are derived from the msat amounts and ignored when
+/// deserializing.
 class Payment {
   final String id;
   final PaymentType paymentType;
@@ -791,22 +1494,79 @@ class PaymentFailedData {
   });
 }
 
-/// Different types of supported payments
-enum PaymentType {
-  Sent,
-  Received,
-  ClosedChannel,
-}
+/// Details of an outgoing payment being dispatched, included as payload in an emitted [BreezEvent]
+class PaymentPendingData {
+  final String nodeId;
 
-/// Different types of supported filters which can be applied when retrieving the transaction list
-enum PaymentTypeFilter {
-  Sent,
-  Received,
-  All,
-}
+  /// The invoice being paid, absent for spontaneous payments
+  final String? bolt11;
 
-/// Denominator in an exchange rate
-class Rate {
+  /// The hash of the invoice being paid, to match the pending payment with the
+  /// [BreezEvent::PaymentSucceed] or [BreezEvent::PaymentFailed] that follows. Absent for
+  /// spontaneous payments, whose preimage is only picked by the node.
+  final String? paymentHash;
+  final int amountMsat;
+
+  const PaymentPendingData({
+    required this.nodeId,
+    this.bolt11,
+    this.paymentHash,
+    required this.amountMsat,
+  });
+}
+
+/// A hop of the route taken by a sent payment, see [LnPaymentDetails::route]
+class PaymentRouteHop {
+  /// The node this hop forwards the payment to
+  final String nodeId;
+  final String shortChannelId;
+
+  /// The fee charged by the node forwarding the payment over this hop
+  final int feeMsat;
+
+  const PaymentRouteHop({
+    required this.nodeId,
+    required this.shortChannelId,
+    required this.feeMsat,
+  });
+}
+
+/// Different types of supported payments
+enum PaymentType {
+  Sent,
+  Received,
+  ClosedChannel,
+}
+
+/// Different types of supported filters which can be applied when retrieving the transaction list
+enum PaymentTypeFilter {
+  Sent,
+  Received,
+  All,
+}
+
+/// A peer of the node, see [crate::BreezServices::list_peers]
+class PeerInfo {
+  /// The peer public key, hex encoded
+  final String id;
+
+  /// The addresses the peer is known by, as `host:port`
+  final List<String> addresses;
+  final bool connected;
+
+  /// Number of channels with the peer, in any state
+  final int channelsCount;
+
+  const PeerInfo({
+    required this.id,
+    required this.addresses,
+    required this.connected,
+    required this.channelsCount,
+  });
+}
+
+/// Denominator in an exchange rate
+class Rate {
   final String coin;
   final double value;
 
@@ -816,6 +1576,60 @@ class Rate {
   });
 }
 
+/// Estimated cost of receiving a given amount, see [crate::BreezServices::receive_cost_estimate]
+class ReceiveCost {
+  /// The fee the LSP charges for opening a new channel, zero if the inbound liquidity suffices
+  final int lspFeeMsat;
+
+  /// The amount that will end up in the wallet after the LSP fee is deducted
+  final int netReceivedMsat;
+
+  const ReceiveCost({
+    required this.lspFeeMsat,
+    required this.netReceivedMsat,
+  });
+}
+
+/// The amounts that can be received over lightning, see [crate::BreezServices::receive_payment_limits]
+class ReceiveLimits {
+  /// The smallest amount that can be received. Without inbound liquidity, it has to cover the
+  /// fee of the LSP for opening a channel.
+  final int minReceivableMsat;
+  final int maxReceivableMsat;
+
+  /// Amounts up to this one fit in the current inbound liquidity and are received without fee
+  final int maxReceivableWithoutFeeMsat;
+
+  /// The fee the LSP charges when receiving [ReceiveLimits::max_receivable_msat]
+  final int maxReceivableFeeMsat;
+
+  const ReceiveLimits({
+    required this.minReceivableMsat,
+    required this.maxReceivableMsat,
+    required this.maxReceivableWithoutFeeMsat,
+    required this.maxReceivableFeeMsat,
+  });
+}
+
+/// The invoice created by [crate::BreezServices::receive_payment], with the fee the LSP takes
+/// when it has to open a channel to deliver the payment
+class ReceivePaymentResponse {
+  final LNInvoice lnInvoice;
+
+  /// The fee deducted from the received amount for opening a channel, `None` when the inbound
+  /// liquidity suffices and no channel is opened
+  final int? openingFeeMsat;
+
+  /// The LSP fee parameters [ReceivePaymentResponse::opening_fee_msat] was computed from
+  final OpeningFeeParams? openingFeeParams;
+
+  const ReceivePaymentResponse({
+    required this.lnInvoice,
+    this.openingFeeMsat,
+    this.openingFeeParams,
+  });
+}
+
 /// Wrapper containing the result of the recommended fees query, in sat/vByte, based on mempool.space data
 class RecommendedFees {
   final int fastestFee;
@@ -833,6 +1647,20 @@ class RecommendedFees {
   });
 }
 
+/// The estimated outcome of refunding a swap, see [crate::BreezServices::prepare_refund]
+class RefundPreview {
+  /// The fee paid by the refund transaction, based on its estimated size and the feerate
+  final int feeSat;
+
+  /// The amount that would be sent to the destination address, across all confirmed UTXOs
+  final int refundAmountSat;
+
+  const RefundPreview({
+    required this.feeSat,
+    required this.refundAmountSat,
+  });
+}
+
 /// A route hint for a LN payment
 class RouteHint {
   final List<RouteHintHop> hops;
@@ -874,13 +1702,51 @@ class RouteHintHop {
   });
 }
 
+/// Whether a [ServiceType] could be reached, as reported by
+/// [crate::BreezServices::connectivity_check]
+class ServiceStatus {
+  final ServiceType service;
+  final bool reachable;
+
+  /// Time until the service replied, failed, or the check timed out
+  final int latencyMs;
+
+  /// Why the service is unreachable, if it is
+  final String? error;
+
+  const ServiceStatus({
+    required this.service,
+    required this.reachable,
+    required this.latencyMs,
+    this.error,
+  });
+}
+
+/// An external service the SDK depends on, see [crate::BreezServices::connectivity_check]
+enum ServiceType {
+  /// The Greenlight node
+  Greenlight,
+
+  /// The LSP, served by the Breez server
+  Lsp,
+
+  /// The chain service, Mempool.space by default
+  Mempool,
+
+  /// The fiat rates provider
+  Fiat,
+
+  /// The signer of the MoonPay urls used by [crate::BreezServices::buy_bitcoin]
+  MoonPay,
+}
+
 @freezed
 class SuccessActionProcessed with _$SuccessActionProcessed {
   /// See [SuccessAction::Aes] for received payload
   ///
-  /// See [AesSuccessActionDataDecrypted] for decrypted payload
+  /// See [AesDecryptedOrError] for the decrypted payload, or the reason decryption failed
   const factory SuccessActionProcessed.aes({
-    required AesSuccessActionDataDecrypted data,
+    required AesDecryptedOrError data,
   }) = SuccessActionProcessed_Aes;
 
   /// See [SuccessAction::Message]
@@ -894,6 +1760,25 @@ class SuccessActionProcessed with _$SuccessActionProcessed {
   }) = SuccessActionProcessed_Url;
 }
 
+/// Number of swaps in each stage of their lifecycle, as part of the [Diagnostics]
+class SwapDiagnostics {
+  final int total;
+  final int unused;
+  final int inProgress;
+  final int redeemable;
+  final int refundable;
+  final int expired;
+
+  const SwapDiagnostics({
+    required this.total,
+    required this.unused,
+    required this.inProgress,
+    required this.redeemable,
+    required this.refundable,
+    required this.expired,
+  });
+}
+
 /// Represents the details of an on-going swap.
 ///
 /// Once this SwapInfo is created it will be monitored on-chain and its state is
@@ -922,6 +1807,14 @@ class SwapInfo {
   final int maxAllowedDeposit;
   final String? lastRedeemError;
 
+  /// The block height at which the swap expires: [SwapInfo::lock_height] blocks after the
+  /// deposit confirmed or, while the address is unused, after it was created
+  final int expiryBlock;
+
+  /// The estimated unix timestamp of [SwapInfo::expiry_block], after which an unused address
+  /// is only checked for late deposits about once a day
+  final int expiresAt;
+
   const SwapInfo({
     required this.bitcoinAddress,
     required this.createdAt,
@@ -943,6 +1836,8 @@ class SwapInfo {
     required this.minAllowedDeposit,
     required this.maxAllowedDeposit,
     this.lastRedeemError,
+    required this.expiryBlock,
+    required this.expiresAt,
   });
 }
 
@@ -959,6 +1854,44 @@ enum SwapStatus {
   Expired,
 }
 
+/// The estimated outcome of sweeping the on-chain funds, see [crate::BreezServices::prepare_sweep]
+class SweepPreview {
+  /// The estimated virtual size of the sweep transaction
+  final int txVsize;
+
+  /// The fee paid by the sweep transaction, based on its estimated size and the feerate
+  final int feeSat;
+
+  /// The amount that would be sent to the destination address
+  final int amountSat;
+
+  const SweepPreview({
+    required this.txVsize,
+    required this.feeSat,
+    required this.amountSat,
+  });
+}
+
+/// The outcome of sweeping the on-chain funds, see [crate::BreezServices::sweep]
+class SweepResult {
+  final String txid;
+
+  /// The amount sent to the destination address
+  final int amountSat;
+
+  /// The fee paid by the sweep transaction, its inputs minus its outputs. It's only estimated from
+  /// the transaction size and the requested feerate if an input is missing from [NodeState::utxos].
+  final int feeSat;
+  final String destination;
+
+  const SweepResult({
+    required this.txid,
+    required this.amountSat,
+    required this.feeSat,
+    required this.destination,
+  });
+}
+
 /// Settings for the symbol representation of a currency
 class Symbol {
   final String? grapheme;
@@ -974,6 +1907,30 @@ class Symbol {
   });
 }
 
+/// The value of a `{key}` placeholder of a description template, see
+/// [crate::BreezServices::receive_payment_with_template]
+class TemplateValue {
+  final String key;
+  final String value;
+
+  const TemplateValue({
+    required this.key,
+    required this.value,
+  });
+}
+
+/// A custom TLV record sent or received along a spontaneous payment, for example the `7629169`
+/// record of podcasting apps. Only odd types starting at [CUSTOM_TLV_MIN_TYPE] can be sent.
+class TlvEntry {
+  final int typeNum;
+  final Uint8List value;
+
+  const TlvEntry({
+    required this.typeNum,
+    required this.value,
+  });
+}
+
 /// UTXO known to the LN node
 class UnspentTransactionOutput {
   final Uint8List txid;
@@ -982,6 +1939,7 @@ class UnspentTransactionOutput {
   final String address;
   final bool reserved;
   final int reservedToBlock;
+  final bool confirmed;
 
   const UnspentTransactionOutput({
     required this.txid,
@@ -990,6 +1948,7 @@ class UnspentTransactionOutput {
     required this.address,
     required this.reserved,
     required this.reservedToBlock,
+    required this.confirmed,
   });
 }
 
@@ -1104,19 +2063,20 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
-  Stream<BreezEvent> breezEventsStream({dynamic hint}) {
+  Stream<BreezEvent> breezEventsStream({required bool replay, dynamic hint}) {
+    var arg0 = api2wire_bool(replay);
     return _platform.executeStream(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_breez_events_stream(port_),
+      callFfi: (port_) => _platform.inner.wire_breez_events_stream(port_, arg0),
       parseSuccessData: _wire2api_breez_event,
       constMeta: kBreezEventsStreamConstMeta,
-      argValues: [],
+      argValues: [replay],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kBreezEventsStreamConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "breez_events_stream",
-        argNames: [],
+        argNames: ["replay"],
       );
 
   Stream<LogEntry> breezLogStream({dynamic hint}) {
@@ -1149,57 +2109,244 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
-  Future<Payment> sendPayment({required String bolt11, int? amountSats, dynamic hint}) {
+  Future<Payment> sendPayment(
+      {required String bolt11, int? amountSats, int? maxFeeSat, int? timeoutSec, dynamic hint}) {
     var arg0 = _platform.api2wire_String(bolt11);
     var arg1 = _platform.api2wire_opt_box_autoadd_u64(amountSats);
+    var arg2 = _platform.api2wire_opt_box_autoadd_u64(maxFeeSat);
+    var arg3 = _platform.api2wire_opt_box_autoadd_u32(timeoutSec);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_send_payment(port_, arg0, arg1),
+      callFfi: (port_) => _platform.inner.wire_send_payment(port_, arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_payment,
       constMeta: kSendPaymentConstMeta,
-      argValues: [bolt11, amountSats],
+      argValues: [bolt11, amountSats, maxFeeSat, timeoutSec],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kSendPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "send_payment",
-        argNames: ["bolt11", "amountSats"],
+        argNames: ["bolt11", "amountSats", "maxFeeSat", "timeoutSec"],
       );
 
-  Future<Payment> sendSpontaneousPayment({required String nodeId, required int amountSats, dynamic hint}) {
+  Future<Payment> sendSpontaneousPayment(
+      {required String nodeId,
+      required int amountSats,
+      int? timeoutSec,
+      List<TlvEntry>? extraTlvs,
+      dynamic hint}) {
     var arg0 = _platform.api2wire_String(nodeId);
     var arg1 = _platform.api2wire_u64(amountSats);
+    var arg2 = _platform.api2wire_opt_box_autoadd_u32(timeoutSec);
+    var arg3 = _platform.api2wire_opt_list_tlv_entry(extraTlvs);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_send_spontaneous_payment(port_, arg0, arg1),
+      callFfi: (port_) => _platform.inner.wire_send_spontaneous_payment(port_, arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_payment,
       constMeta: kSendSpontaneousPaymentConstMeta,
-      argValues: [nodeId, amountSats],
+      argValues: [nodeId, amountSats, timeoutSec, extraTlvs],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kSendSpontaneousPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "send_spontaneous_payment",
-        argNames: ["nodeId", "amountSats"],
+        argNames: ["nodeId", "amountSats", "timeoutSec", "extraTlvs"],
       );
 
-  Future<LNInvoice> receivePayment({required int amountSats, required String description, dynamic hint}) {
+  Future<ReceivePaymentResponse> receivePayment(
+      {required int amountSats,
+      required String description,
+      int? expirySecs,
+      Uint8List? preimage,
+      bool? includeLspHint,
+      String? fallbackAddress,
+      dynamic hint}) {
     var arg0 = _platform.api2wire_u64(amountSats);
     var arg1 = _platform.api2wire_String(description);
+    var arg2 = _platform.api2wire_opt_box_autoadd_u32(expirySecs);
+    var arg3 = _platform.api2wire_opt_uint_8_list(preimage);
+    var arg4 = _platform.api2wire_opt_box_autoadd_bool(includeLspHint);
+    var arg5 = _platform.api2wire_opt_String(fallbackAddress);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_receive_payment(port_, arg0, arg1),
-      parseSuccessData: _wire2api_ln_invoice,
+      callFfi: (port_) => _platform.inner.wire_receive_payment(port_, arg0, arg1, arg2, arg3, arg4, arg5),
+      parseSuccessData: _wire2api_receive_payment_response,
       constMeta: kReceivePaymentConstMeta,
-      argValues: [amountSats, description],
+      argValues: [amountSats, description, expirySecs, preimage, includeLspHint, fallbackAddress],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kReceivePaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "receive_payment",
+        argNames: [
+          "amountSats",
+          "description",
+          "expirySecs",
+          "preimage",
+          "includeLspHint",
+          "fallbackAddress",
+        ],
+      );
+
+  Future<ReceivePaymentResponse> receivePaymentWithTemplate(
+      {required int amountSats,
+      required String descriptionTemplate,
+      required List<TemplateValue> values,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(amountSats);
+    var arg1 = _platform.api2wire_String(descriptionTemplate);
+    var arg2 = _platform.api2wire_list_template_value(values);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_payment_with_template(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_receive_payment_response,
+      constMeta: kReceivePaymentWithTemplateConstMeta,
+      argValues: [amountSats, descriptionTemplate, values],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentWithTemplateConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_payment_with_template",
+        argNames: ["amountSats", "descriptionTemplate", "values"],
+      );
+
+  Future<ReceivePaymentResponse> receivePaymentWithDescriptionHash(
+      {required int amountSats,
+      required String description,
+      required Uint8List descriptionHash,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(amountSats);
+    var arg1 = _platform.api2wire_String(description);
+    var arg2 = _platform.api2wire_uint_8_list(descriptionHash);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_payment_with_description_hash(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_receive_payment_response,
+      constMeta: kReceivePaymentWithDescriptionHashConstMeta,
+      argValues: [amountSats, description, descriptionHash],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentWithDescriptionHashConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_payment_with_description_hash",
+        argNames: ["amountSats", "description", "descriptionHash"],
+      );
+
+  Future<List<LNInvoice>> receivePaymentSplit(
+      {required int amountSats, required String description, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(amountSats);
+    var arg1 = _platform.api2wire_String(description);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_payment_split(port_, arg0, arg1),
+      parseSuccessData: _wire2api_list_ln_invoice,
+      constMeta: kReceivePaymentSplitConstMeta,
+      argValues: [amountSats, description],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentSplitConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_payment_split",
         argNames: ["amountSats", "description"],
       );
 
+  Future<LNInvoice> receiveDonation({required String description, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(description);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_donation(port_, arg0),
+      parseSuccessData: _wire2api_ln_invoice,
+      constMeta: kReceiveDonationConstMeta,
+      argValues: [description],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceiveDonationConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_donation",
+        argNames: ["description"],
+      );
+
+  Future<List<AmountViolation>> validateAmount(
+      {required int amountMsat, required AmountContext context, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(amountMsat);
+    var arg1 = api2wire_amount_context(context);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_validate_amount(port_, arg0, arg1),
+      parseSuccessData: _wire2api_list_amount_violation,
+      constMeta: kValidateAmountConstMeta,
+      argValues: [amountMsat, context],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kValidateAmountConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "validate_amount",
+        argNames: ["amountMsat", "context"],
+      );
+
+  Future<ReceiveCost> receiveCostEstimate({required int amountMsat, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(amountMsat);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_cost_estimate(port_, arg0),
+      parseSuccessData: _wire2api_receive_cost,
+      constMeta: kReceiveCostEstimateConstMeta,
+      argValues: [amountMsat],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceiveCostEstimateConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_cost_estimate",
+        argNames: ["amountMsat"],
+      );
+
+  Future<ReceiveLimits> receivePaymentLimits({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_payment_limits(port_),
+      parseSuccessData: _wire2api_receive_limits,
+      constMeta: kReceivePaymentLimitsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentLimitsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_payment_limits",
+        argNames: [],
+      );
+
+  Future<List<PeerInfo>> listPeers({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_peers(port_),
+      parseSuccessData: _wire2api_list_peer_info,
+      constMeta: kListPeersConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListPeersConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_peers",
+        argNames: [],
+      );
+
+  Future<List<ChannelInfo>> listChannels({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_channels(port_),
+      parseSuccessData: _wire2api_list_channel_info,
+      constMeta: kListChannelsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListChannelsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_channels",
+        argNames: [],
+      );
+
   Future<NodeState?> nodeInfo({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_node_info(port_),
@@ -1215,23 +2362,70 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<ConnectionStatus> connectionStatus({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_connection_status(port_),
+      parseSuccessData: _wire2api_connection_status,
+      constMeta: kConnectionStatusConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kConnectionStatusConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "connection_status",
+        argNames: [],
+      );
+
+  Future<Payment?> paymentByHash({required String paymentHash, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(paymentHash);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_payment_by_hash(port_, arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_payment,
+      constMeta: kPaymentByHashConstMeta,
+      argValues: [paymentHash],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPaymentByHashConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "payment_by_hash",
+        argNames: ["paymentHash"],
+      );
+
   Future<List<Payment>> listPayments(
-      {required PaymentTypeFilter filter, int? fromTimestamp, int? toTimestamp, dynamic hint}) {
+      {required PaymentTypeFilter filter,
+      int? fromTimestamp,
+      int? toTimestamp,
+      int? minAmountMsat,
+      int? maxAmountMsat,
+      String? descriptionContains,
+      dynamic hint}) {
     var arg0 = api2wire_payment_type_filter(filter);
     var arg1 = _platform.api2wire_opt_box_autoadd_i64(fromTimestamp);
     var arg2 = _platform.api2wire_opt_box_autoadd_i64(toTimestamp);
+    var arg3 = _platform.api2wire_opt_box_autoadd_u64(minAmountMsat);
+    var arg4 = _platform.api2wire_opt_box_autoadd_u64(maxAmountMsat);
+    var arg5 = _platform.api2wire_opt_String(descriptionContains);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_list_payments(port_, arg0, arg1, arg2),
+      callFfi: (port_) => _platform.inner.wire_list_payments(port_, arg0, arg1, arg2, arg3, arg4, arg5),
       parseSuccessData: _wire2api_list_payment,
       constMeta: kListPaymentsConstMeta,
-      argValues: [filter, fromTimestamp, toTimestamp],
+      argValues: [filter, fromTimestamp, toTimestamp, minAmountMsat, maxAmountMsat, descriptionContains],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kListPaymentsConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "list_payments",
-        argNames: ["filter", "fromTimestamp", "toTimestamp"],
+        argNames: [
+          "filter",
+          "fromTimestamp",
+          "toTimestamp",
+          "minAmountMsat",
+          "maxAmountMsat",
+          "descriptionContains",
+        ],
       );
 
   Future<List<LspInformation>> listLsps({dynamic hint}) {
@@ -1281,6 +2475,22 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["id"],
       );
 
+  Future<bool> lspLiquidityAvailable({required int amountMsat, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(amountMsat);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_lsp_liquidity_available(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      constMeta: kLspLiquidityAvailableConstMeta,
+      argValues: [amountMsat],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kLspLiquidityAvailableConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lsp_liquidity_available",
+        argNames: ["amountMsat"],
+      );
+
   Future<String?> lspId({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_lsp_id(port_),
@@ -1311,71 +2521,188 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
-  Future<List<FiatCurrency>> listFiatCurrencies({dynamic hint}) {
+  Future<Rate?> fiatRate({required String coin, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(coin);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_list_fiat_currencies(port_),
-      parseSuccessData: _wire2api_list_fiat_currency,
-      constMeta: kListFiatCurrenciesConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner.wire_fiat_rate(port_, arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_rate,
+      constMeta: kFiatRateConstMeta,
+      argValues: [coin],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kListFiatCurrenciesConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "list_fiat_currencies",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kFiatRateConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "fiat_rate",
+        argNames: ["coin"],
       );
 
-  Future<void> closeLspChannels({dynamic hint}) {
+  Future<Rate?> fiatRateAt({required String coin, required int timestamp, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(coin);
+    var arg1 = _platform.api2wire_i64(timestamp);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_close_lsp_channels(port_),
-      parseSuccessData: _wire2api_unit,
-      constMeta: kCloseLspChannelsConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner.wire_fiat_rate_at(port_, arg0, arg1),
+      parseSuccessData: _wire2api_opt_box_autoadd_rate,
+      constMeta: kFiatRateAtConstMeta,
+      argValues: [coin, timestamp],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kCloseLspChannelsConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "close_lsp_channels",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kFiatRateAtConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "fiat_rate_at",
+        argNames: ["coin", "timestamp"],
       );
 
-  Future<void> sweep({required String toAddress, required int feeRateSatsPerByte, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(toAddress);
-    var arg1 = _platform.api2wire_u64(feeRateSatsPerByte);
+  Future<String?> preferredFiatCurrency({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_sweep(port_, arg0, arg1),
-      parseSuccessData: _wire2api_unit,
-      constMeta: kSweepConstMeta,
-      argValues: [toAddress, feeRateSatsPerByte],
+      callFfi: (port_) => _platform.inner.wire_preferred_fiat_currency(port_),
+      parseSuccessData: _wire2api_opt_String,
+      constMeta: kPreferredFiatCurrencyConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kSweepConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "sweep",
-        argNames: ["toAddress", "feeRateSatsPerByte"],
+  FlutterRustBridgeTaskConstMeta get kPreferredFiatCurrencyConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "preferred_fiat_currency",
+        argNames: [],
       );
 
-  Future<SwapInfo> receiveOnchain({dynamic hint}) {
+  Future<Rate> selectFiatCurrency({required String id, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(id);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_receive_onchain(port_),
-      parseSuccessData: _wire2api_swap_info,
-      constMeta: kReceiveOnchainConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner.wire_select_fiat_currency(port_, arg0),
+      parseSuccessData: _wire2api_rate,
+      constMeta: kSelectFiatCurrencyConstMeta,
+      argValues: [id],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kReceiveOnchainConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "receive_onchain",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kSelectFiatCurrencyConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "select_fiat_currency",
+        argNames: ["id"],
       );
 
-  Future<SwapInfo?> inProgressSwap({dynamic hint}) {
+  Future<List<FiatCurrency>> listFiatCurrencies({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_in_progress_swap(port_),
+      callFfi: (port_) => _platform.inner.wire_list_fiat_currencies(port_),
+      parseSuccessData: _wire2api_list_fiat_currency,
+      constMeta: kListFiatCurrenciesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListFiatCurrenciesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_fiat_currencies",
+        argNames: [],
+      );
+
+  Future<List<CurrencySymbolEntry>> listFiatCurrencySymbols({required String locale, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(locale);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_fiat_currency_symbols(port_, arg0),
+      parseSuccessData: _wire2api_list_currency_symbol_entry,
+      constMeta: kListFiatCurrencySymbolsConstMeta,
+      argValues: [locale],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListFiatCurrencySymbolsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_fiat_currency_symbols",
+        argNames: ["locale"],
+      );
+
+  Future<void> closeLspChannels({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_close_lsp_channels(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kCloseLspChannelsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCloseLspChannelsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "close_lsp_channels",
+        argNames: [],
+      );
+
+  Future<SweepResult> sweep(
+      {required String toAddress, required int satPerVbyte, List<String>? utxos, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(toAddress);
+    var arg1 = api2wire_u32(satPerVbyte);
+    var arg2 = _platform.api2wire_opt_StringList(utxos);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_sweep(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_sweep_result,
+      constMeta: kSweepConstMeta,
+      argValues: [toAddress, satPerVbyte, utxos],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSweepConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "sweep",
+        argNames: ["toAddress", "satPerVbyte", "utxos"],
+      );
+
+  Future<SweepPreview> prepareSweep(
+      {required String toAddress, required int satPerVbyte, List<String>? utxos, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(toAddress);
+    var arg1 = api2wire_u32(satPerVbyte);
+    var arg2 = _platform.api2wire_opt_StringList(utxos);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prepare_sweep(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_sweep_preview,
+      constMeta: kPrepareSweepConstMeta,
+      argValues: [toAddress, satPerVbyte, utxos],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPrepareSweepConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prepare_sweep",
+        argNames: ["toAddress", "satPerVbyte", "utxos"],
+      );
+
+  Future<List<SweepResult>> listSweeps({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_sweeps(port_),
+      parseSuccessData: _wire2api_list_sweep_result,
+      constMeta: kListSweepsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListSweepsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_sweeps",
+        argNames: [],
+      );
+
+  Future<SwapInfo> receiveOnchain({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_onchain(port_),
+      parseSuccessData: _wire2api_swap_info,
+      constMeta: kReceiveOnchainConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceiveOnchainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_onchain",
+        argNames: [],
+      );
+
+  Future<SwapInfo?> inProgressSwap({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_in_progress_swap(port_),
       parseSuccessData: _wire2api_opt_box_autoadd_swap_info,
       constMeta: kInProgressSwapConstMeta,
       argValues: [],
@@ -1422,20 +2749,73 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["swapAddress", "toAddress", "satPerVbyte"],
       );
 
-  Future<String> executeCommand({required String command, dynamic hint}) {
+  Future<RefundPreview> prepareRefund(
+      {required String swapAddress, required String toAddress, required int satPerVbyte, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(swapAddress);
+    var arg1 = _platform.api2wire_String(toAddress);
+    var arg2 = api2wire_u32(satPerVbyte);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prepare_refund(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_refund_preview,
+      constMeta: kPrepareRefundConstMeta,
+      argValues: [swapAddress, toAddress, satPerVbyte],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPrepareRefundConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prepare_refund",
+        argNames: ["swapAddress", "toAddress", "satPerVbyte"],
+      );
+
+  Future<int> refundableAmount({required String swapAddress, required int satPerVbyte, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(swapAddress);
+    var arg1 = api2wire_u32(satPerVbyte);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_refundable_amount(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u64,
+      constMeta: kRefundableAmountConstMeta,
+      argValues: [swapAddress, satPerVbyte],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRefundableAmountConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "refundable_amount",
+        argNames: ["swapAddress", "satPerVbyte"],
+      );
+
+  Future<int> pruneTerminalSwaps({required int olderThanSec, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(olderThanSec);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prune_terminal_swaps(port_, arg0),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kPruneTerminalSwapsConstMeta,
+      argValues: [olderThanSec],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPruneTerminalSwapsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prune_terminal_swaps",
+        argNames: ["olderThanSec"],
+      );
+
+  Future<String> executeCommand({required String command, required bool dryRun, dynamic hint}) {
     var arg0 = _platform.api2wire_String(command);
+    var arg1 = api2wire_bool(dryRun);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_execute_command(port_, arg0),
+      callFfi: (port_) => _platform.inner.wire_execute_command(port_, arg0, arg1),
       parseSuccessData: _wire2api_String,
       constMeta: kExecuteCommandConstMeta,
-      argValues: [command],
+      argValues: [command, dryRun],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kExecuteCommandConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "execute_command",
-        argNames: ["command"],
+        argNames: ["command", "dryRun"],
       );
 
   Future<void> syncNode({dynamic hint}) {
@@ -1453,6 +2833,117 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<int> waitForBlockHeight({required int height, required int timeoutSec, dynamic hint}) {
+    var arg0 = api2wire_u32(height);
+    var arg1 = _platform.api2wire_u64(timeoutSec);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_wait_for_block_height(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kWaitForBlockHeightConstMeta,
+      argValues: [height, timeoutSec],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kWaitForBlockHeightConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "wait_for_block_height",
+        argNames: ["height", "timeoutSec"],
+      );
+
+  Future<void> fullResync({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_full_resync(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kFullResyncConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFullResyncConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "full_resync",
+        argNames: [],
+      );
+
+  Future<void> optimizeStorage({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_optimize_storage(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kOptimizeStorageConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kOptimizeStorageConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "optimize_storage",
+        argNames: [],
+      );
+
+  Future<Uint8List> backup({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_backup(port_),
+      parseSuccessData: _wire2api_uint_8_list,
+      constMeta: kBackupConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kBackupConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "backup",
+        argNames: [],
+      );
+
+  Future<void> restore({required Uint8List data, dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(data);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_restore(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kRestoreConstMeta,
+      argValues: [data],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRestoreConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "restore",
+        argNames: ["data"],
+      );
+
+  Future<void> setSetting({required String key, required String value, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(key);
+    var arg1 = _platform.api2wire_String(value);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_setting(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetSettingConstMeta,
+      argValues: [key, value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetSettingConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_setting",
+        argNames: ["key", "value"],
+      );
+
+  Future<String?> getSetting({required String key, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(key);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_get_setting(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      constMeta: kGetSettingConstMeta,
+      argValues: [key],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetSettingConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_setting",
+        argNames: ["key"],
+      );
+
   Future<LNInvoice> parseInvoice({required String invoice, dynamic hint}) {
     var arg0 = _platform.api2wire_String(invoice);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -1469,6 +2960,40 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["invoice"],
       );
 
+  Future<int> parseAmount({required String input, required AmountUnit defaultUnit, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(input);
+    var arg1 = api2wire_amount_unit(defaultUnit);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_parse_amount(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u64,
+      constMeta: kParseAmountConstMeta,
+      argValues: [input, defaultUnit],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kParseAmountConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "parse_amount",
+        argNames: ["input", "defaultUnit"],
+      );
+
+  Future<ParsedMetadata> extractLnurlPayMetadata({required LnUrlPayRequestData reqData, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_ln_url_pay_request_data(reqData);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_extract_lnurl_pay_metadata(port_, arg0),
+      parseSuccessData: _wire2api_parsed_metadata,
+      constMeta: kExtractLnurlPayMetadataConstMeta,
+      argValues: [reqData],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExtractLnurlPayMetadataConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "extract_lnurl_pay_metadata",
+        argNames: ["reqData"],
+      );
+
   Future<InputType> parse({required String s, dynamic hint}) {
     var arg0 = _platform.api2wire_String(s);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -1526,6 +3051,24 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["reqData", "amountSats", "description"],
       );
 
+  Future<LnUrlCallbackStatus> lnurlWithdrawMax(
+      {required LnUrlWithdrawRequestData reqData, String? description, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_ln_url_withdraw_request_data(reqData);
+    var arg1 = _platform.api2wire_opt_String(description);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_lnurl_withdraw_max(port_, arg0, arg1),
+      parseSuccessData: _wire2api_ln_url_callback_status,
+      constMeta: kLnurlWithdrawMaxConstMeta,
+      argValues: [reqData, description],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kLnurlWithdrawMaxConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lnurl_withdraw_max",
+        argNames: ["reqData", "description"],
+      );
+
   Future<LnUrlCallbackStatus> lnurlAuth({required LnUrlAuthRequestData reqData, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_ln_url_auth_request_data(reqData);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -1542,6 +3085,21 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["reqData"],
       );
 
+  Future<List<LnUrlAuthLogin>> listLnurlAuthLogins({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_lnurl_auth_logins(port_),
+      parseSuccessData: _wire2api_list_ln_url_auth_login,
+      constMeta: kListLnurlAuthLoginsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListLnurlAuthLoginsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_lnurl_auth_logins",
+        argNames: [],
+      );
+
   Future<Uint8List> mnemonicToSeed({required String phrase, dynamic hint}) {
     var arg0 = _platform.api2wire_String(phrase);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -1573,59 +3131,210 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
-  Future<Config> defaultConfig({required EnvironmentType configType, dynamic hint}) {
-    var arg0 = api2wire_environment_type(configType);
+  Future<FeeTier> preferredFeeTier({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_default_config(port_, arg0),
-      parseSuccessData: _wire2api_config,
-      constMeta: kDefaultConfigConstMeta,
-      argValues: [configType],
+      callFfi: (port_) => _platform.inner.wire_preferred_fee_tier(port_),
+      parseSuccessData: _wire2api_fee_tier,
+      constMeta: kPreferredFeeTierConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kDefaultConfigConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "default_config",
-        argNames: ["configType"],
+  FlutterRustBridgeTaskConstMeta get kPreferredFeeTierConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "preferred_fee_tier",
+        argNames: [],
       );
 
-  void dispose() {
-    _platform.dispose();
+  Future<void> setPreferredFeeTier({required FeeTier tier, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_fee_tier(tier);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_preferred_fee_tier(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetPreferredFeeTierConstMeta,
+      argValues: [tier],
+      hint: hint,
+    ));
   }
-// Section: wire2api
 
-  String _wire2api_String(dynamic raw) {
-    return raw as String;
-  }
+  FlutterRustBridgeTaskConstMeta get kSetPreferredFeeTierConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_preferred_fee_tier",
+        argNames: ["tier"],
+      );
 
-  List<String> _wire2api_StringList(dynamic raw) {
-    return (raw as List<dynamic>).cast<String>();
+  Future<int> resolveFeeRate({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_resolve_fee_rate(port_),
+      parseSuccessData: _wire2api_u64,
+      constMeta: kResolveFeeRateConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
   }
 
-  AesSuccessActionDataDecrypted _wire2api_aes_success_action_data_decrypted(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return AesSuccessActionDataDecrypted(
-      description: _wire2api_String(arr[0]),
-      plaintext: _wire2api_String(arr[1]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta get kResolveFeeRateConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "resolve_fee_rate",
+        argNames: [],
+      );
 
-  BitcoinAddressData _wire2api_bitcoin_address_data(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return BitcoinAddressData(
-      address: _wire2api_String(arr[0]),
-      network: _wire2api_network(arr[1]),
-      amountSat: _wire2api_opt_box_autoadd_u64(arr[2]),
-      label: _wire2api_opt_String(arr[3]),
-      message: _wire2api_opt_String(arr[4]),
-    );
+  Future<String> buyBitcoin({required BuyBitcoinProvider provider, String? baseCurrencyCode, dynamic hint}) {
+    var arg0 = api2wire_buy_bitcoin_provider(provider);
+    var arg1 = _platform.api2wire_opt_String(baseCurrencyCode);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_buy_bitcoin(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kBuyBitcoinConstMeta,
+      argValues: [provider, baseCurrencyCode],
+      hint: hint,
+    ));
   }
 
-  bool _wire2api_bool(dynamic raw) {
-    return raw as bool;
-  }
+  FlutterRustBridgeTaskConstMeta get kBuyBitcoinConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "buy_bitcoin",
+        argNames: ["provider", "baseCurrencyCode"],
+      );
+
+  Future<Diagnostics> diagnostics({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_diagnostics(port_),
+      parseSuccessData: _wire2api_diagnostics,
+      constMeta: kDiagnosticsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDiagnosticsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "diagnostics",
+        argNames: [],
+      );
+
+  Future<List<ServiceStatus>> connectivityCheck({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_connectivity_check(port_),
+      parseSuccessData: _wire2api_list_service_status,
+      constMeta: kConnectivityCheckConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kConnectivityCheckConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "connectivity_check",
+        argNames: [],
+      );
+
+  Future<ClockSkewWarning?> checkClockSkew({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_check_clock_skew(port_),
+      parseSuccessData: _wire2api_opt_box_autoadd_clock_skew_warning,
+      constMeta: kCheckClockSkewConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCheckClockSkewConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "check_clock_skew",
+        argNames: [],
+      );
+
+  Future<Config> defaultConfig(
+      {required EnvironmentType configType, required Network network, dynamic hint}) {
+    var arg0 = api2wire_environment_type(configType);
+    var arg1 = api2wire_network(network);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_default_config(port_, arg0, arg1),
+      parseSuccessData: _wire2api_config,
+      constMeta: kDefaultConfigConstMeta,
+      argValues: [configType, network],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDefaultConfigConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "default_config",
+        argNames: ["configType", "network"],
+      );
+
+  void dispose() {
+    _platform.dispose();
+  }
+// Section: wire2api
+
+  String _wire2api_String(dynamic raw) {
+    return raw as String;
+  }
+
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
+
+  AesDecryptedOrError _wire2api_aes_decrypted_or_error(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return AesDecryptedOrError_Decrypted(
+          data: _wire2api_box_autoadd_aes_success_action_data_decrypted(raw[1]),
+        );
+      case 1:
+        return AesDecryptedOrError_ErrorStatus(
+          reason: _wire2api_String(raw[1]),
+          description: _wire2api_String(raw[2]),
+          ciphertext: _wire2api_String(raw[3]),
+          iv: _wire2api_String(raw[4]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  AesSuccessActionDataDecrypted _wire2api_aes_success_action_data_decrypted(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AesSuccessActionDataDecrypted(
+      description: _wire2api_String(arr[0]),
+      plaintext: _wire2api_String(arr[1]),
+    );
+  }
+
+  AmountViolation _wire2api_amount_violation(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return AmountViolation_BelowMinimum(
+          minMsat: _wire2api_u64(raw[1]),
+        );
+      case 1:
+        return AmountViolation_AboveMaximum(
+          maxMsat: _wire2api_u64(raw[1]),
+        );
+      case 2:
+        return AmountViolation_InsufficientBalance(
+          maxPayableMsat: _wire2api_u64(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  BitcoinAddressData _wire2api_bitcoin_address_data(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BitcoinAddressData(
+      address: _wire2api_String(arr[0]),
+      network: _wire2api_network(arr[1]),
+      amountSat: _wire2api_opt_box_autoadd_u64(arr[2]),
+      label: _wire2api_opt_String(arr[3]),
+      message: _wire2api_opt_String(arr[4]),
+    );
+  }
+
+  bool _wire2api_bool(dynamic raw) {
+    return raw as bool;
+  }
+
+  AesDecryptedOrError _wire2api_box_autoadd_aes_decrypted_or_error(dynamic raw) {
+    return _wire2api_aes_decrypted_or_error(raw);
+  }
 
   AesSuccessActionDataDecrypted _wire2api_box_autoadd_aes_success_action_data_decrypted(dynamic raw) {
     return _wire2api_aes_success_action_data_decrypted(raw);
@@ -1639,6 +3348,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw as bool;
   }
 
+  ClockSkewWarning _wire2api_box_autoadd_clock_skew_warning(dynamic raw) {
+    return _wire2api_clock_skew_warning(raw);
+  }
+
   ClosedChannelPaymentDetails _wire2api_box_autoadd_closed_channel_payment_details(dynamic raw) {
     return _wire2api_closed_channel_payment_details(raw);
   }
@@ -1651,6 +3364,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_ln_invoice(raw);
   }
 
+  LNOffer _wire2api_box_autoadd_ln_offer(dynamic raw) {
+    return _wire2api_ln_offer(raw);
+  }
+
   LnPaymentDetails _wire2api_box_autoadd_ln_payment_details(dynamic raw) {
     return _wire2api_ln_payment_details(raw);
   }
@@ -1659,6 +3376,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_ln_url_auth_request_data(raw);
   }
 
+  LnUrlChannelRequestData _wire2api_box_autoadd_ln_url_channel_request_data(dynamic raw) {
+    return _wire2api_ln_url_channel_request_data(raw);
+  }
+
   LnUrlErrorData _wire2api_box_autoadd_ln_url_error_data(dynamic raw) {
     return _wire2api_ln_url_error_data(raw);
   }
@@ -1683,6 +3404,14 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_node_state(raw);
   }
 
+  OfferAmount _wire2api_box_autoadd_offer_amount(dynamic raw) {
+    return _wire2api_offer_amount(raw);
+  }
+
+  OpeningFeeParams _wire2api_box_autoadd_opening_fee_params(dynamic raw) {
+    return _wire2api_opening_fee_params(raw);
+  }
+
   Payment _wire2api_box_autoadd_payment(dynamic raw) {
     return _wire2api_payment(raw);
   }
@@ -1691,6 +3420,14 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_payment_failed_data(raw);
   }
 
+  PaymentPendingData _wire2api_box_autoadd_payment_pending_data(dynamic raw) {
+    return _wire2api_payment_pending_data(raw);
+  }
+
+  Rate _wire2api_box_autoadd_rate(dynamic raw) {
+    return _wire2api_rate(raw);
+  }
+
   SuccessActionProcessed _wire2api_box_autoadd_success_action_processed(dynamic raw) {
     return _wire2api_success_action_processed(raw);
   }
@@ -1735,15 +3472,75 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         return BreezEvent_PaymentFailed(
           details: _wire2api_box_autoadd_payment_failed_data(raw[1]),
         );
+      case 5:
+        return BreezEvent_DuplicateIncomingPayment(
+          details: _wire2api_box_autoadd_invoice_paid_details(raw[1]),
+        );
+      case 6:
+        return BreezEvent_LspConnected(
+          lspId: _wire2api_String(raw[1]),
+        );
+      case 7:
+        return BreezEvent_PaymentPending(
+          details: _wire2api_box_autoadd_payment_pending_data(raw[1]),
+        );
+      case 8:
+        return BreezEvent_SwapUpdated(
+          details: _wire2api_box_autoadd_swap_info(raw[1]),
+        );
+      case 9:
+        return BreezEvent_ConnectionStatusChanged(
+          connected: _wire2api_bool(raw[1]),
+        );
+      case 10:
+        return BreezEvent_SwapOverpaid(
+          details: _wire2api_box_autoadd_swap_info(raw[1]),
+          handling: _wire2api_overpaid_swap_handling(raw[2]),
+        );
+      case 11:
+        return BreezEvent_SwapsReconciled(
+          expired: _wire2api_list_swap_info(raw[1]),
+        );
+      case 12:
+        return BreezEvent_SyncProgress(
+          processed: _wire2api_u32(raw[1]),
+          total: _wire2api_u32(raw[2]),
+        );
       default:
         throw Exception("unreachable");
     }
   }
 
+  ChainBackend _wire2api_chain_backend(dynamic raw) {
+    return ChainBackend.values[raw as int];
+  }
+
+  ChannelInfo _wire2api_channel_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ChannelInfo(
+      shortChannelId: _wire2api_String(arr[0]),
+      fundingTxid: _wire2api_String(arr[1]),
+      state: _wire2api_channel_state(arr[2]),
+      capacityMsat: _wire2api_opt_box_autoadd_u64(arr[3]),
+      localBalanceMsat: _wire2api_u64(arr[4]),
+      remoteBalanceMsat: _wire2api_u64(arr[5]),
+      closedAt: _wire2api_opt_box_autoadd_u64(arr[6]),
+    );
+  }
+
   ChannelState _wire2api_channel_state(dynamic raw) {
     return ChannelState.values[raw as int];
   }
 
+  ClockSkewWarning _wire2api_clock_skew_warning(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ClockSkewWarning(
+      offsetSecs: _wire2api_i64(arr[0]),
+    );
+  }
+
   ClosedChannelPaymentDetails _wire2api_closed_channel_payment_details(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
@@ -1756,7 +3553,7 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
   Config _wire2api_config(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    if (arr.length != 25) throw Exception('unexpected arr length: expect 25 but see ${arr.length}');
     return Config(
       breezserver: _wire2api_String(arr[0]),
       mempoolspaceUrl: _wire2api_String(arr[1]),
@@ -1766,9 +3563,30 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       defaultLspId: _wire2api_opt_String(arr[5]),
       apiKey: _wire2api_opt_String(arr[6]),
       maxfeePercent: _wire2api_f64(arr[7]),
+      autoConnectLsp: _wire2api_bool(arr[8]),
+      fiatRateCacheTtlSec: _wire2api_u64(arr[9]),
+      maxConcurrentChainRequests: _wire2api_u32(arr[10]),
+      onchainMinConfirmations: _wire2api_u32(arr[11]),
+      defaultFiatCurrency: _wire2api_opt_String(arr[12]),
+      eventBufferSize: _wire2api_u32(arr[13]),
+      allowReceiveSplit: _wire2api_bool(arr[14]),
+      correctClockSkew: _wire2api_bool(arr[15]),
+      redactLogs: _wire2api_bool(arr[16]),
+      maxReconnectBackoffSec: _wire2api_u32(arr[17]),
+      overpaidSwapHandling: _wire2api_overpaid_swap_handling(arr[18]),
+      mempoolspaceApiToken: _wire2api_opt_String(arr[19]),
+      swapRetentionSec: _wire2api_opt_box_autoadd_u64(arr[20]),
+      chainBackend: _wire2api_chain_backend(arr[21]),
+      syncWorkers: _wire2api_u32(arr[22]),
+      reconcileExpiredSwapsOnStart: _wire2api_bool(arr[23]),
+      dbEncryptionKey: _wire2api_opt_uint_8_list(arr[24]),
     );
   }
 
+  ConnectionStatus _wire2api_connection_status(dynamic raw) {
+    return ConnectionStatus.values[raw as int];
+  }
+
   CurrencyInfo _wire2api_currency_info(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
@@ -1783,10 +3601,59 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  CurrencySymbolEntry _wire2api_currency_symbol_entry(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CurrencySymbolEntry(
+      id: _wire2api_String(arr[0]),
+      symbol: _wire2api_opt_String(arr[1]),
+      name: _wire2api_String(arr[2]),
+    );
+  }
+
+  Diagnostics _wire2api_diagnostics(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return Diagnostics(
+      sdkVersion: _wire2api_String(arr[0]),
+      network: _wire2api_network(arr[1]),
+      nodeId: _wire2api_opt_String(arr[2]),
+      blockHeight: _wire2api_opt_box_autoadd_u32(arr[3]),
+      lspId: _wire2api_opt_String(arr[4]),
+      channelsBalanceMsat: _wire2api_u64(arr[5]),
+      onchainBalanceMsat: _wire2api_u64(arr[6]),
+      connectedPeers: _wire2api_u32(arr[7]),
+      swaps: _wire2api_swap_diagnostics(arr[8]),
+      lastErrors: _wire2api_StringList(arr[9]),
+      backgroundTasksRunning: _wire2api_bool(arr[10]),
+    );
+  }
+
   double _wire2api_f64(dynamic raw) {
     return raw as double;
   }
 
+  FeeTier _wire2api_fee_tier(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return FeeTier_Fastest();
+      case 1:
+        return FeeTier_HalfHour();
+      case 2:
+        return FeeTier_Hour();
+      case 3:
+        return FeeTier_Economy();
+      case 4:
+        return FeeTier_Minimum();
+      case 5:
+        return FeeTier_Custom(
+          satPerVbyte: _wire2api_u64(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   FiatCurrency _wire2api_fiat_currency(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -1847,6 +3714,25 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         return InputType_LnUrlError(
           data: _wire2api_box_autoadd_ln_url_error_data(raw[1]),
         );
+      case 8:
+        return InputType_LnUrlChannel(
+          data: _wire2api_box_autoadd_ln_url_channel_request_data(raw[1]),
+        );
+      case 9:
+        return InputType_Unified(
+          address: _wire2api_box_autoadd_bitcoin_address_data(raw[1]),
+          invoice: _wire2api_box_autoadd_ln_invoice(raw[2]),
+        );
+      case 10:
+        return InputType_Bolt12Offer(
+          offer: _wire2api_box_autoadd_ln_offer(raw[1]),
+        );
+      case 11:
+        return InputType_NodeConnection(
+          nodeId: _wire2api_String(raw[1]),
+          host: _wire2api_String(raw[2]),
+          port: _wire2api_u16(raw[3]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -1861,10 +3747,30 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  List<AmountViolation> _wire2api_list_amount_violation(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_amount_violation).toList();
+  }
+
+  List<ChannelInfo> _wire2api_list_channel_info(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_channel_info).toList();
+  }
+
+  List<CurrencySymbolEntry> _wire2api_list_currency_symbol_entry(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_currency_symbol_entry).toList();
+  }
+
   List<FiatCurrency> _wire2api_list_fiat_currency(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_fiat_currency).toList();
   }
 
+  List<LNInvoice> _wire2api_list_ln_invoice(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_ln_invoice).toList();
+  }
+
+  List<LnUrlAuthLogin> _wire2api_list_ln_url_auth_login(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_ln_url_auth_login).toList();
+  }
+
   List<LocaleOverrides> _wire2api_list_locale_overrides(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_locale_overrides).toList();
   }
@@ -1881,6 +3787,14 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return (raw as List<dynamic>).map(_wire2api_payment).toList();
   }
 
+  List<PaymentRouteHop> _wire2api_list_payment_route_hop(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_payment_route_hop).toList();
+  }
+
+  List<PeerInfo> _wire2api_list_peer_info(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_peer_info).toList();
+  }
+
   List<Rate> _wire2api_list_rate(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_rate).toList();
   }
@@ -1893,17 +3807,29 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return (raw as List<dynamic>).map(_wire2api_route_hint_hop).toList();
   }
 
+  List<ServiceStatus> _wire2api_list_service_status(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_service_status).toList();
+  }
+
   List<SwapInfo> _wire2api_list_swap_info(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_swap_info).toList();
   }
 
+  List<SweepResult> _wire2api_list_sweep_result(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_sweep_result).toList();
+  }
+
+  List<TlvEntry> _wire2api_list_tlv_entry(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_tlv_entry).toList();
+  }
+
   List<UnspentTransactionOutput> _wire2api_list_unspent_transaction_output(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_unspent_transaction_output).toList();
   }
 
   LNInvoice _wire2api_ln_invoice(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
     return LNInvoice(
       bolt11: _wire2api_String(arr[0]),
       payeePubkey: _wire2api_String(arr[1]),
@@ -1915,12 +3841,25 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       expiry: _wire2api_u64(arr[7]),
       routingHints: _wire2api_list_route_hint(arr[8]),
       paymentSecret: _wire2api_uint_8_list(arr[9]),
+      network: _wire2api_network(arr[10]),
+    );
+  }
+
+  LNOffer _wire2api_ln_offer(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LNOffer(
+      bolt12: _wire2api_String(arr[0]),
+      signingPubkey: _wire2api_String(arr[1]),
+      description: _wire2api_String(arr[2]),
+      amount: _wire2api_opt_box_autoadd_offer_amount(arr[3]),
+      issuer: _wire2api_opt_String(arr[4]),
     );
   }
 
   LnPaymentDetails _wire2api_ln_payment_details(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
     return LnPaymentDetails(
       paymentHash: _wire2api_String(arr[0]),
       label: _wire2api_String(arr[1]),
@@ -1931,6 +3870,19 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       lnurlSuccessAction: _wire2api_opt_box_autoadd_success_action_processed(arr[6]),
       lnAddress: _wire2api_opt_String(arr[7]),
       lnurlMetadata: _wire2api_opt_String(arr[8]),
+      route: _wire2api_list_payment_route_hop(arr[9]),
+      customTlvs: _wire2api_list_tlv_entry(arr[10]),
+    );
+  }
+
+  LnUrlAuthLogin _wire2api_ln_url_auth_login(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LnUrlAuthLogin(
+      domain: _wire2api_String(arr[0]),
+      k1: _wire2api_String(arr[1]),
+      timestamp: _wire2api_i64(arr[2]),
+      success: _wire2api_bool(arr[3]),
     );
   }
 
@@ -1958,6 +3910,16 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     }
   }
 
+  LnUrlChannelRequestData _wire2api_ln_url_channel_request_data(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LnUrlChannelRequestData(
+      uri: _wire2api_String(arr[0]),
+      callback: _wire2api_String(arr[1]),
+      k1: _wire2api_String(arr[2]),
+    );
+  }
+
   LnUrlErrorData _wire2api_ln_url_error_data(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -1985,6 +3947,7 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       case 0:
         return LnUrlPayResult_EndpointSuccess(
           data: _wire2api_opt_box_autoadd_success_action_processed(raw[1]),
+          payment: _wire2api_box_autoadd_payment(raw[2]),
         );
       case 1:
         return LnUrlPayResult_EndpointError(
@@ -2087,6 +4050,31 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  OfferAmount _wire2api_offer_amount(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return OfferAmount_Bitcoin(
+          amountMsat: _wire2api_u64(raw[1]),
+        );
+      case 1:
+        return OfferAmount_Currency(
+          iso4217Code: _wire2api_String(raw[1]),
+          fractionalAmount: _wire2api_u64(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  OpeningFeeParams _wire2api_opening_fee_params(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OpeningFeeParams(
+      minMsat: _wire2api_u64(arr[0]),
+      channelFeePermyriad: _wire2api_u64(arr[1]),
+    );
+  }
+
   String? _wire2api_opt_String(dynamic raw) {
     return raw == null ? null : _wire2api_String(raw);
   }
@@ -2095,6 +4083,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw == null ? null : _wire2api_box_autoadd_bool(raw);
   }
 
+  ClockSkewWarning? _wire2api_opt_box_autoadd_clock_skew_warning(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_clock_skew_warning(raw);
+  }
+
   LNInvoice? _wire2api_opt_box_autoadd_ln_invoice(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_ln_invoice(raw);
   }
@@ -2107,6 +4099,22 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw == null ? null : _wire2api_box_autoadd_node_state(raw);
   }
 
+  OfferAmount? _wire2api_opt_box_autoadd_offer_amount(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_offer_amount(raw);
+  }
+
+  OpeningFeeParams? _wire2api_opt_box_autoadd_opening_fee_params(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_opening_fee_params(raw);
+  }
+
+  Payment? _wire2api_opt_box_autoadd_payment(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_payment(raw);
+  }
+
+  Rate? _wire2api_opt_box_autoadd_rate(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_rate(raw);
+  }
+
   SuccessActionProcessed? _wire2api_opt_box_autoadd_success_action_processed(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_success_action_processed(raw);
   }
@@ -2135,6 +4143,24 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw == null ? null : _wire2api_list_localized_name(raw);
   }
 
+  Uint8List? _wire2api_opt_uint_8_list(dynamic raw) {
+    return raw == null ? null : _wire2api_uint_8_list(raw);
+  }
+
+  OverpaidSwapHandling _wire2api_overpaid_swap_handling(dynamic raw) {
+    return OverpaidSwapHandling.values[raw as int];
+  }
+
+  ParsedMetadata _wire2api_parsed_metadata(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ParsedMetadata(
+      description: _wire2api_String(arr[0]),
+      longDescription: _wire2api_opt_String(arr[1]),
+      image: _wire2api_opt_uint_8_list(arr[2]),
+    );
+  }
+
   Payment _wire2api_payment(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
@@ -2175,19 +4201,81 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
-  PaymentType _wire2api_payment_type(dynamic raw) {
-    return PaymentType.values[raw as int];
+  PaymentPendingData _wire2api_payment_pending_data(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PaymentPendingData(
+      nodeId: _wire2api_String(arr[0]),
+      bolt11: _wire2api_opt_String(arr[1]),
+      paymentHash: _wire2api_opt_String(arr[2]),
+      amountMsat: _wire2api_u64(arr[3]),
+    );
   }
 
-  Rate _wire2api_rate(dynamic raw) {
+  PaymentRouteHop _wire2api_payment_route_hop(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PaymentRouteHop(
+      nodeId: _wire2api_String(arr[0]),
+      shortChannelId: _wire2api_String(arr[1]),
+      feeMsat: _wire2api_u64(arr[2]),
+    );
+  }
+
+  PaymentType _wire2api_payment_type(dynamic raw) {
+    return PaymentType.values[raw as int];
+  }
+
+  PeerInfo _wire2api_peer_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PeerInfo(
+      id: _wire2api_String(arr[0]),
+      addresses: _wire2api_StringList(arr[1]),
+      connected: _wire2api_bool(arr[2]),
+      channelsCount: _wire2api_u32(arr[3]),
+    );
+  }
+
+  Rate _wire2api_rate(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
     return Rate(
       coin: _wire2api_String(arr[0]),
       value: _wire2api_f64(arr[1]),
     );
   }
 
+  ReceiveCost _wire2api_receive_cost(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReceiveCost(
+      lspFeeMsat: _wire2api_u64(arr[0]),
+      netReceivedMsat: _wire2api_u64(arr[1]),
+    );
+  }
+
+  ReceiveLimits _wire2api_receive_limits(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReceiveLimits(
+      minReceivableMsat: _wire2api_u64(arr[0]),
+      maxReceivableMsat: _wire2api_u64(arr[1]),
+      maxReceivableWithoutFeeMsat: _wire2api_u64(arr[2]),
+      maxReceivableFeeMsat: _wire2api_u64(arr[3]),
+    );
+  }
+
+  ReceivePaymentResponse _wire2api_receive_payment_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReceivePaymentResponse(
+      lnInvoice: _wire2api_ln_invoice(arr[0]),
+      openingFeeMsat: _wire2api_opt_box_autoadd_u64(arr[1]),
+      openingFeeParams: _wire2api_opt_box_autoadd_opening_fee_params(arr[2]),
+    );
+  }
+
   RecommendedFees _wire2api_recommended_fees(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
@@ -2200,6 +4288,15 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  RefundPreview _wire2api_refund_preview(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RefundPreview(
+      feeSat: _wire2api_u64(arr[0]),
+      refundAmountSat: _wire2api_u64(arr[1]),
+    );
+  }
+
   RouteHint _wire2api_route_hint(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -2222,11 +4319,26 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  ServiceStatus _wire2api_service_status(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ServiceStatus(
+      service: _wire2api_service_type(arr[0]),
+      reachable: _wire2api_bool(arr[1]),
+      latencyMs: _wire2api_u64(arr[2]),
+      error: _wire2api_opt_String(arr[3]),
+    );
+  }
+
+  ServiceType _wire2api_service_type(dynamic raw) {
+    return ServiceType.values[raw as int];
+  }
+
   SuccessActionProcessed _wire2api_success_action_processed(dynamic raw) {
     switch (raw[0]) {
       case 0:
         return SuccessActionProcessed_Aes(
-          data: _wire2api_box_autoadd_aes_success_action_data_decrypted(raw[1]),
+          data: _wire2api_box_autoadd_aes_decrypted_or_error(raw[1]),
         );
       case 1:
         return SuccessActionProcessed_Message(
@@ -2241,9 +4353,22 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     }
   }
 
+  SwapDiagnostics _wire2api_swap_diagnostics(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SwapDiagnostics(
+      total: _wire2api_u32(arr[0]),
+      unused: _wire2api_u32(arr[1]),
+      inProgress: _wire2api_u32(arr[2]),
+      redeemable: _wire2api_u32(arr[3]),
+      refundable: _wire2api_u32(arr[4]),
+      expired: _wire2api_u32(arr[5]),
+    );
+  }
+
   SwapInfo _wire2api_swap_info(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 20) throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    if (arr.length != 22) throw Exception('unexpected arr length: expect 22 but see ${arr.length}');
     return SwapInfo(
       bitcoinAddress: _wire2api_String(arr[0]),
       createdAt: _wire2api_i64(arr[1]),
@@ -2265,6 +4390,8 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       minAllowedDeposit: _wire2api_i64(arr[17]),
       maxAllowedDeposit: _wire2api_i64(arr[18]),
       lastRedeemError: _wire2api_opt_String(arr[19]),
+      expiryBlock: _wire2api_u32(arr[20]),
+      expiresAt: _wire2api_i64(arr[21]),
     );
   }
 
@@ -2272,6 +4399,27 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return SwapStatus.values[raw as int];
   }
 
+  SweepPreview _wire2api_sweep_preview(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SweepPreview(
+      txVsize: _wire2api_u64(arr[0]),
+      feeSat: _wire2api_u64(arr[1]),
+      amountSat: _wire2api_u64(arr[2]),
+    );
+  }
+
+  SweepResult _wire2api_sweep_result(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SweepResult(
+      txid: _wire2api_String(arr[0]),
+      amountSat: _wire2api_u64(arr[1]),
+      feeSat: _wire2api_u64(arr[2]),
+      destination: _wire2api_String(arr[3]),
+    );
+  }
+
   Symbol _wire2api_symbol(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
@@ -2283,6 +4431,15 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  TlvEntry _wire2api_tlv_entry(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TlvEntry(
+      typeNum: _wire2api_u64(arr[0]),
+      value: _wire2api_uint_8_list(arr[1]),
+    );
+  }
+
   int _wire2api_u16(dynamic raw) {
     return raw as int;
   }
@@ -2309,7 +4466,7 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
   UnspentTransactionOutput _wire2api_unspent_transaction_output(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
     return UnspentTransactionOutput(
       txid: _wire2api_uint_8_list(arr[0]),
       outnum: _wire2api_u32(arr[1]),
@@ -2317,6 +4474,7 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       address: _wire2api_String(arr[3]),
       reserved: _wire2api_bool(arr[4]),
       reservedToBlock: _wire2api_u32(arr[5]),
+      confirmed: _wire2api_bool(arr[6]),
     );
   }
 
@@ -2332,6 +4490,31 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
 // Section: api2wire
 
+@protected
+int api2wire_amount_context(AmountContext raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_amount_unit(AmountUnit raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+bool api2wire_bool(bool raw) {
+  return raw;
+}
+
+@protected
+int api2wire_buy_bitcoin_provider(BuyBitcoinProvider raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_chain_backend(ChainBackend raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_environment_type(EnvironmentType raw) {
   return api2wire_i32(raw.index);
@@ -2352,6 +4535,11 @@ int api2wire_network(Network raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_overpaid_swap_handling(OverpaidSwapHandling raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_payment_type_filter(PaymentTypeFilter raw) {
   return api2wire_i32(raw.index);
@@ -2384,6 +4572,20 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return api2wire_uint_8_list(utf8.encoder.convert(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_StringList(List<String> raw) {
+    final ans = inner.new_StringList_0(raw.length);
+    for (var i = 0; i < raw.length; i++) {
+      ans.ref.ptr[i] = api2wire_String(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Bool> api2wire_box_autoadd_bool(bool raw) {
+    return inner.new_box_autoadd_bool_0(api2wire_bool(raw));
+  }
+
   @protected
   ffi.Pointer<wire_Config> api2wire_box_autoadd_config(Config raw) {
     final ptr = inner.new_box_autoadd_config_0();
@@ -2391,6 +4593,13 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_FeeTier> api2wire_box_autoadd_fee_tier(FeeTier raw) {
+    final ptr = inner.new_box_autoadd_fee_tier_0();
+    _api_fill_to_wire_fee_tier(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_GreenlightCredentials> api2wire_box_autoadd_greenlight_credentials(
       GreenlightCredentials raw) {
@@ -2428,6 +4637,11 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_box_autoadd_u32(int raw) {
+    return inner.new_box_autoadd_u32_0(api2wire_u32(raw));
+  }
+
   @protected
   ffi.Pointer<ffi.Uint64> api2wire_box_autoadd_u64(int raw) {
     return inner.new_box_autoadd_u64_0(api2wire_u64(raw));
@@ -2438,11 +4652,39 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return raw;
   }
 
+  @protected
+  ffi.Pointer<wire_list_template_value> api2wire_list_template_value(List<TemplateValue> raw) {
+    final ans = inner.new_list_template_value_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_template_value(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_tlv_entry> api2wire_list_tlv_entry(List<TlvEntry> raw) {
+    final ans = inner.new_list_tlv_entry_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_tlv_entry(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_uint_8_list> api2wire_opt_String(String? raw) {
     return raw == null ? ffi.nullptr : api2wire_String(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_opt_StringList(List<String>? raw) {
+    return raw == null ? ffi.nullptr : api2wire_StringList(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Bool> api2wire_opt_box_autoadd_bool(bool? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_bool(raw);
+  }
+
   @protected
   ffi.Pointer<wire_GreenlightCredentials> api2wire_opt_box_autoadd_greenlight_credentials(
       GreenlightCredentials? raw) {
@@ -2454,11 +4696,26 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_i64(raw);
   }
 
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Uint64> api2wire_opt_box_autoadd_u64(int? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_u64(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_list_tlv_entry> api2wire_opt_list_tlv_entry(List<TlvEntry>? raw) {
+    return raw == null ? ffi.nullptr : api2wire_list_tlv_entry(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_opt_uint_8_list(Uint8List? raw) {
+    return raw == null ? ffi.nullptr : api2wire_uint_8_list(raw);
+  }
+
   @protected
   int api2wire_u64(int raw) {
     return raw;
@@ -2478,6 +4735,10 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_config(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_fee_tier(FeeTier apiObj, ffi.Pointer<wire_FeeTier> wireObj) {
+    _api_fill_to_wire_fee_tier(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_greenlight_credentials(
       GreenlightCredentials apiObj, ffi.Pointer<wire_GreenlightCredentials> wireObj) {
     _api_fill_to_wire_greenlight_credentials(apiObj, wireObj.ref);
@@ -2507,6 +4768,53 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.default_lsp_id = api2wire_opt_String(apiObj.defaultLspId);
     wireObj.api_key = api2wire_opt_String(apiObj.apiKey);
     wireObj.maxfee_percent = api2wire_f64(apiObj.maxfeePercent);
+    wireObj.auto_connect_lsp = api2wire_bool(apiObj.autoConnectLsp);
+    wireObj.fiat_rate_cache_ttl_sec = api2wire_u64(apiObj.fiatRateCacheTtlSec);
+    wireObj.max_concurrent_chain_requests = api2wire_u32(apiObj.maxConcurrentChainRequests);
+    wireObj.onchain_min_confirmations = api2wire_u32(apiObj.onchainMinConfirmations);
+    wireObj.default_fiat_currency = api2wire_opt_String(apiObj.defaultFiatCurrency);
+    wireObj.event_buffer_size = api2wire_u32(apiObj.eventBufferSize);
+    wireObj.allow_receive_split = api2wire_bool(apiObj.allowReceiveSplit);
+    wireObj.correct_clock_skew = api2wire_bool(apiObj.correctClockSkew);
+    wireObj.redact_logs = api2wire_bool(apiObj.redactLogs);
+    wireObj.max_reconnect_backoff_sec = api2wire_u32(apiObj.maxReconnectBackoffSec);
+    wireObj.overpaid_swap_handling = api2wire_overpaid_swap_handling(apiObj.overpaidSwapHandling);
+    wireObj.mempoolspace_api_token = api2wire_opt_String(apiObj.mempoolspaceApiToken);
+    wireObj.swap_retention_sec = api2wire_opt_box_autoadd_u64(apiObj.swapRetentionSec);
+    wireObj.chain_backend = api2wire_chain_backend(apiObj.chainBackend);
+    wireObj.sync_workers = api2wire_u32(apiObj.syncWorkers);
+    wireObj.reconcile_expired_swaps_on_start = api2wire_bool(apiObj.reconcileExpiredSwapsOnStart);
+    wireObj.db_encryption_key = api2wire_opt_uint_8_list(apiObj.dbEncryptionKey);
+  }
+
+  void _api_fill_to_wire_fee_tier(FeeTier apiObj, wire_FeeTier wireObj) {
+    if (apiObj is FeeTier_Fastest) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is FeeTier_HalfHour) {
+      wireObj.tag = 1;
+      return;
+    }
+    if (apiObj is FeeTier_Hour) {
+      wireObj.tag = 2;
+      return;
+    }
+    if (apiObj is FeeTier_Economy) {
+      wireObj.tag = 3;
+      return;
+    }
+    if (apiObj is FeeTier_Minimum) {
+      wireObj.tag = 4;
+      return;
+    }
+    if (apiObj is FeeTier_Custom) {
+      var pre_sat_per_vbyte = api2wire_u64(apiObj.satPerVbyte);
+      wireObj.tag = 5;
+      wireObj.kind = inner.inflate_FeeTier_Custom();
+      wireObj.kind.ref.Custom.ref.sat_per_vbyte = pre_sat_per_vbyte;
+      return;
+    }
   }
 
   void _api_fill_to_wire_greenlight_credentials(
@@ -2547,6 +4855,16 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
       GreenlightCredentials? apiObj, ffi.Pointer<wire_GreenlightCredentials> wireObj) {
     if (apiObj != null) _api_fill_to_wire_box_autoadd_greenlight_credentials(apiObj, wireObj);
   }
+
+  void _api_fill_to_wire_template_value(TemplateValue apiObj, wire_TemplateValue wireObj) {
+    wireObj.key = api2wire_String(apiObj.key);
+    wireObj.value = api2wire_String(apiObj.value);
+  }
+
+  void _api_fill_to_wire_tlv_entry(TlvEntry apiObj, wire_TlvEntry wireObj) {
+    wireObj.type_num = api2wire_u64(apiObj.typeNum);
+    wireObj.value = api2wire_uint_8_list(apiObj.value);
+  }
 }
 
 // ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
@@ -2728,15 +5046,17 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
 
   void wire_breez_events_stream(
     int port_,
+    bool replay,
   ) {
     return _wire_breez_events_stream(
       port_,
+      replay,
     );
   }
 
   late final _wire_breez_events_streamPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_breez_events_stream');
-  late final _wire_breez_events_stream = _wire_breez_events_streamPtr.asFunction<void Function(int)>();
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Bool)>>('wire_breez_events_stream');
+  late final _wire_breez_events_stream = _wire_breez_events_streamPtr.asFunction<void Function(int, bool)>();
 
   void wire_breez_log_stream(
     int port_,
@@ -2765,56 +5085,227 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
     int port_,
     ffi.Pointer<wire_uint_8_list> bolt11,
     ffi.Pointer<ffi.Uint64> amount_sats,
+    ffi.Pointer<ffi.Uint64> max_fee_sat,
+    ffi.Pointer<ffi.Uint32> timeout_sec,
   ) {
     return _wire_send_payment(
       port_,
       bolt11,
       amount_sats,
+      max_fee_sat,
+      timeout_sec,
     );
   }
 
   late final _wire_send_paymentPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<ffi.Uint64>)>>('wire_send_payment');
-  late final _wire_send_payment = _wire_send_paymentPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<ffi.Uint64>)>();
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<ffi.Uint64>,
+              ffi.Pointer<ffi.Uint64>, ffi.Pointer<ffi.Uint32>)>>('wire_send_payment');
+  late final _wire_send_payment = _wire_send_paymentPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<ffi.Uint64>, ffi.Pointer<ffi.Uint64>,
+          ffi.Pointer<ffi.Uint32>)>();
 
   void wire_send_spontaneous_payment(
     int port_,
     ffi.Pointer<wire_uint_8_list> node_id,
     int amount_sats,
+    ffi.Pointer<ffi.Uint32> timeout_sec,
+    ffi.Pointer<wire_list_tlv_entry> extra_tlvs,
   ) {
     return _wire_send_spontaneous_payment(
       port_,
       node_id,
       amount_sats,
+      timeout_sec,
+      extra_tlvs,
     );
   }
 
-  late final _wire_send_spontaneous_paymentPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint64)>>(
-          'wire_send_spontaneous_payment');
-  late final _wire_send_spontaneous_payment =
-      _wire_send_spontaneous_paymentPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+  late final _wire_send_spontaneous_paymentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint64, ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_list_tlv_entry>)>>('wire_send_spontaneous_payment');
+  late final _wire_send_spontaneous_payment = _wire_send_spontaneous_paymentPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>, int, ffi.Pointer<ffi.Uint32>,
+          ffi.Pointer<wire_list_tlv_entry>)>();
 
   void wire_receive_payment(
     int port_,
     int amount_sats,
     ffi.Pointer<wire_uint_8_list> description,
+    ffi.Pointer<ffi.Uint32> expiry_secs,
+    ffi.Pointer<wire_uint_8_list> preimage,
+    ffi.Pointer<ffi.Bool> include_lsp_hint,
+    ffi.Pointer<wire_uint_8_list> fallback_address,
   ) {
     return _wire_receive_payment(
       port_,
       amount_sats,
       description,
+      expiry_secs,
+      preimage,
+      include_lsp_hint,
+      fallback_address,
+    );
+  }
+
+  late final _wire_receive_paymentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Uint64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<ffi.Bool>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_receive_payment');
+  late final _wire_receive_payment = _wire_receive_paymentPtr.asFunction<
+      void Function(int, int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<ffi.Uint32>,
+          ffi.Pointer<wire_uint_8_list>, ffi.Pointer<ffi.Bool>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_receive_payment_with_template(
+    int port_,
+    int amount_sats,
+    ffi.Pointer<wire_uint_8_list> description_template,
+    ffi.Pointer<wire_list_template_value> values,
+  ) {
+    return _wire_receive_payment_with_template(
+      port_,
+      amount_sats,
+      description_template,
+      values,
+    );
+  }
+
+  late final _wire_receive_payment_with_templatePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Uint64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_list_template_value>)>>('wire_receive_payment_with_template');
+  late final _wire_receive_payment_with_template = _wire_receive_payment_with_templatePtr.asFunction<
+      void Function(int, int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_list_template_value>)>();
+
+  void wire_receive_payment_with_description_hash(
+    int port_,
+    int amount_sats,
+    ffi.Pointer<wire_uint_8_list> description,
+    ffi.Pointer<wire_uint_8_list> description_hash,
+  ) {
+    return _wire_receive_payment_with_description_hash(
+      port_,
+      amount_sats,
+      description,
+      description_hash,
     );
   }
 
-  late final _wire_receive_paymentPtr =
+  late final _wire_receive_payment_with_description_hashPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Uint64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_receive_payment_with_description_hash');
+  late final _wire_receive_payment_with_description_hash = _wire_receive_payment_with_description_hashPtr
+      .asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_receive_payment_split(
+    int port_,
+    int amount_sats,
+    ffi.Pointer<wire_uint_8_list> description,
+  ) {
+    return _wire_receive_payment_split(
+      port_,
+      amount_sats,
+      description,
+    );
+  }
+
+  late final _wire_receive_payment_splitPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64, ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_receive_payment');
-  late final _wire_receive_payment =
-      _wire_receive_paymentPtr.asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>)>();
+          'wire_receive_payment_split');
+  late final _wire_receive_payment_split =
+      _wire_receive_payment_splitPtr.asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_receive_donation(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> description,
+  ) {
+    return _wire_receive_donation(
+      port_,
+      description,
+    );
+  }
+
+  late final _wire_receive_donationPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_receive_donation');
+  late final _wire_receive_donation =
+      _wire_receive_donationPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_validate_amount(
+    int port_,
+    int amount_msat,
+    int context,
+  ) {
+    return _wire_validate_amount(
+      port_,
+      amount_msat,
+      context,
+    );
+  }
+
+  late final _wire_validate_amountPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64, ffi.Int32)>>(
+          'wire_validate_amount');
+  late final _wire_validate_amount = _wire_validate_amountPtr.asFunction<void Function(int, int, int)>();
+
+  void wire_receive_cost_estimate(
+    int port_,
+    int amount_msat,
+  ) {
+    return _wire_receive_cost_estimate(
+      port_,
+      amount_msat,
+    );
+  }
+
+  late final _wire_receive_cost_estimatePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_receive_cost_estimate');
+  late final _wire_receive_cost_estimate =
+      _wire_receive_cost_estimatePtr.asFunction<void Function(int, int)>();
+
+  void wire_receive_payment_limits(
+    int port_,
+  ) {
+    return _wire_receive_payment_limits(
+      port_,
+    );
+  }
+
+  late final _wire_receive_payment_limitsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_receive_payment_limits');
+  late final _wire_receive_payment_limits = _wire_receive_payment_limitsPtr.asFunction<void Function(int)>();
+
+  void wire_list_peers(
+    int port_,
+  ) {
+    return _wire_list_peers(
+      port_,
+    );
+  }
+
+  late final _wire_list_peersPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_peers');
+  late final _wire_list_peers = _wire_list_peersPtr.asFunction<void Function(int)>();
+
+  void wire_list_channels(
+    int port_,
+  ) {
+    return _wire_list_channels(
+      port_,
+    );
+  }
+
+  late final _wire_list_channelsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_channels');
+  late final _wire_list_channels = _wire_list_channelsPtr.asFunction<void Function(int)>();
 
   void wire_node_info(
     int port_,
@@ -2827,26 +5318,67 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_node_infoPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_node_info');
   late final _wire_node_info = _wire_node_infoPtr.asFunction<void Function(int)>();
 
+  void wire_connection_status(
+    int port_,
+  ) {
+    return _wire_connection_status(
+      port_,
+    );
+  }
+
+  late final _wire_connection_statusPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_connection_status');
+  late final _wire_connection_status = _wire_connection_statusPtr.asFunction<void Function(int)>();
+
+  void wire_payment_by_hash(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> payment_hash,
+  ) {
+    return _wire_payment_by_hash(
+      port_,
+      payment_hash,
+    );
+  }
+
+  late final _wire_payment_by_hashPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_payment_by_hash');
+  late final _wire_payment_by_hash =
+      _wire_payment_by_hashPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_list_payments(
     int port_,
     int filter,
     ffi.Pointer<ffi.Int64> from_timestamp,
     ffi.Pointer<ffi.Int64> to_timestamp,
+    ffi.Pointer<ffi.Uint64> min_amount_msat,
+    ffi.Pointer<ffi.Uint64> max_amount_msat,
+    ffi.Pointer<wire_uint_8_list> description_contains,
   ) {
     return _wire_list_payments(
       port_,
       filter,
       from_timestamp,
       to_timestamp,
+      min_amount_msat,
+      max_amount_msat,
+      description_contains,
     );
   }
 
   late final _wire_list_paymentsPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(
-              ffi.Int64, ffi.Int32, ffi.Pointer<ffi.Int64>, ffi.Pointer<ffi.Int64>)>>('wire_list_payments');
-  late final _wire_list_payments = _wire_list_paymentsPtr
-      .asFunction<void Function(int, int, ffi.Pointer<ffi.Int64>, ffi.Pointer<ffi.Int64>)>();
+              ffi.Int64,
+              ffi.Int32,
+              ffi.Pointer<ffi.Int64>,
+              ffi.Pointer<ffi.Int64>,
+              ffi.Pointer<ffi.Uint64>,
+              ffi.Pointer<ffi.Uint64>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_list_payments');
+  late final _wire_list_payments = _wire_list_paymentsPtr.asFunction<
+      void Function(int, int, ffi.Pointer<ffi.Int64>, ffi.Pointer<ffi.Int64>, ffi.Pointer<ffi.Uint64>,
+          ffi.Pointer<ffi.Uint64>, ffi.Pointer<wire_uint_8_list>)>();
 
   void wire_list_lsps(
     int port_,
@@ -2891,6 +5423,21 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_fetch_lsp_info =
       _wire_fetch_lsp_infoPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_lsp_liquidity_available(
+    int port_,
+    int amount_msat,
+  ) {
+    return _wire_lsp_liquidity_available(
+      port_,
+      amount_msat,
+    );
+  }
+
+  late final _wire_lsp_liquidity_availablePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_lsp_liquidity_available');
+  late final _wire_lsp_liquidity_available =
+      _wire_lsp_liquidity_availablePtr.asFunction<void Function(int, int)>();
+
   void wire_lsp_id(
     int port_,
   ) {
@@ -2914,6 +5461,69 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_fetch_fiat_rates');
   late final _wire_fetch_fiat_rates = _wire_fetch_fiat_ratesPtr.asFunction<void Function(int)>();
 
+  void wire_fiat_rate(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> coin,
+  ) {
+    return _wire_fiat_rate(
+      port_,
+      coin,
+    );
+  }
+
+  late final _wire_fiat_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_fiat_rate');
+  late final _wire_fiat_rate =
+      _wire_fiat_ratePtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_fiat_rate_at(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> coin,
+    int timestamp,
+  ) {
+    return _wire_fiat_rate_at(
+      port_,
+      coin,
+      timestamp,
+    );
+  }
+
+  late final _wire_fiat_rate_atPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Int64)>>(
+          'wire_fiat_rate_at');
+  late final _wire_fiat_rate_at =
+      _wire_fiat_rate_atPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_preferred_fiat_currency(
+    int port_,
+  ) {
+    return _wire_preferred_fiat_currency(
+      port_,
+    );
+  }
+
+  late final _wire_preferred_fiat_currencyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_preferred_fiat_currency');
+  late final _wire_preferred_fiat_currency =
+      _wire_preferred_fiat_currencyPtr.asFunction<void Function(int)>();
+
+  void wire_select_fiat_currency(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> id,
+  ) {
+    return _wire_select_fiat_currency(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_select_fiat_currencyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_select_fiat_currency');
+  late final _wire_select_fiat_currency =
+      _wire_select_fiat_currencyPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_list_fiat_currencies(
     int port_,
   ) {
@@ -2926,119 +5536,329 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_fiat_currencies');
   late final _wire_list_fiat_currencies = _wire_list_fiat_currenciesPtr.asFunction<void Function(int)>();
 
-  void wire_close_lsp_channels(
+  void wire_list_fiat_currency_symbols(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> locale,
+  ) {
+    return _wire_list_fiat_currency_symbols(
+      port_,
+      locale,
+    );
+  }
+
+  late final _wire_list_fiat_currency_symbolsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_list_fiat_currency_symbols');
+  late final _wire_list_fiat_currency_symbols =
+      _wire_list_fiat_currency_symbolsPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_close_lsp_channels(
+    int port_,
+  ) {
+    return _wire_close_lsp_channels(
+      port_,
+    );
+  }
+
+  late final _wire_close_lsp_channelsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_close_lsp_channels');
+  late final _wire_close_lsp_channels = _wire_close_lsp_channelsPtr.asFunction<void Function(int)>();
+
+  void wire_sweep(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> to_address,
+    int sat_per_vbyte,
+    ffi.Pointer<wire_StringList> utxos,
+  ) {
+    return _wire_sweep(
+      port_,
+      to_address,
+      sat_per_vbyte,
+      utxos,
+    );
+  }
+
+  late final _wire_sweepPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint32,
+              ffi.Pointer<wire_StringList>)>>('wire_sweep');
+  late final _wire_sweep = _wire_sweepPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int, ffi.Pointer<wire_StringList>)>();
+
+  void wire_prepare_sweep(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> to_address,
+    int sat_per_vbyte,
+    ffi.Pointer<wire_StringList> utxos,
+  ) {
+    return _wire_prepare_sweep(
+      port_,
+      to_address,
+      sat_per_vbyte,
+      utxos,
+    );
+  }
+
+  late final _wire_prepare_sweepPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint32,
+              ffi.Pointer<wire_StringList>)>>('wire_prepare_sweep');
+  late final _wire_prepare_sweep = _wire_prepare_sweepPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int, ffi.Pointer<wire_StringList>)>();
+
+  void wire_list_sweeps(
+    int port_,
+  ) {
+    return _wire_list_sweeps(
+      port_,
+    );
+  }
+
+  late final _wire_list_sweepsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_sweeps');
+  late final _wire_list_sweeps = _wire_list_sweepsPtr.asFunction<void Function(int)>();
+
+  void wire_receive_onchain(
+    int port_,
+  ) {
+    return _wire_receive_onchain(
+      port_,
+    );
+  }
+
+  late final _wire_receive_onchainPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_receive_onchain');
+  late final _wire_receive_onchain = _wire_receive_onchainPtr.asFunction<void Function(int)>();
+
+  void wire_in_progress_swap(
+    int port_,
+  ) {
+    return _wire_in_progress_swap(
+      port_,
+    );
+  }
+
+  late final _wire_in_progress_swapPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_in_progress_swap');
+  late final _wire_in_progress_swap = _wire_in_progress_swapPtr.asFunction<void Function(int)>();
+
+  void wire_list_refundables(
+    int port_,
+  ) {
+    return _wire_list_refundables(
+      port_,
+    );
+  }
+
+  late final _wire_list_refundablesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_refundables');
+  late final _wire_list_refundables = _wire_list_refundablesPtr.asFunction<void Function(int)>();
+
+  void wire_refund(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> swap_address,
+    ffi.Pointer<wire_uint_8_list> to_address,
+    int sat_per_vbyte,
+  ) {
+    return _wire_refund(
+      port_,
+      swap_address,
+      to_address,
+      sat_per_vbyte,
+    );
+  }
+
+  late final _wire_refundPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>,
+              ffi.Uint32)>>('wire_refund');
+  late final _wire_refund = _wire_refundPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_prepare_refund(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> swap_address,
+    ffi.Pointer<wire_uint_8_list> to_address,
+    int sat_per_vbyte,
+  ) {
+    return _wire_prepare_refund(
+      port_,
+      swap_address,
+      to_address,
+      sat_per_vbyte,
+    );
+  }
+
+  late final _wire_prepare_refundPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>,
+              ffi.Uint32)>>('wire_prepare_refund');
+  late final _wire_prepare_refund = _wire_prepare_refundPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_refundable_amount(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> swap_address,
+    int sat_per_vbyte,
+  ) {
+    return _wire_refundable_amount(
+      port_,
+      swap_address,
+      sat_per_vbyte,
+    );
+  }
+
+  late final _wire_refundable_amountPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint32)>>(
+          'wire_refundable_amount');
+  late final _wire_refundable_amount =
+      _wire_refundable_amountPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_prune_terminal_swaps(
+    int port_,
+    int older_than_sec,
+  ) {
+    return _wire_prune_terminal_swaps(
+      port_,
+      older_than_sec,
+    );
+  }
+
+  late final _wire_prune_terminal_swapsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_prune_terminal_swaps');
+  late final _wire_prune_terminal_swaps = _wire_prune_terminal_swapsPtr.asFunction<void Function(int, int)>();
+
+  void wire_execute_command(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> command,
+    bool dry_run,
+  ) {
+    return _wire_execute_command(
+      port_,
+      command,
+      dry_run,
+    );
+  }
+
+  late final _wire_execute_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Bool)>>(
+          'wire_execute_command');
+  late final _wire_execute_command =
+      _wire_execute_commandPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, bool)>();
+
+  void wire_sync_node(
     int port_,
   ) {
-    return _wire_close_lsp_channels(
+    return _wire_sync_node(
       port_,
     );
   }
 
-  late final _wire_close_lsp_channelsPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_close_lsp_channels');
-  late final _wire_close_lsp_channels = _wire_close_lsp_channelsPtr.asFunction<void Function(int)>();
+  late final _wire_sync_nodePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_sync_node');
+  late final _wire_sync_node = _wire_sync_nodePtr.asFunction<void Function(int)>();
 
-  void wire_sweep(
+  void wire_wait_for_block_height(
     int port_,
-    ffi.Pointer<wire_uint_8_list> to_address,
-    int fee_rate_sats_per_byte,
+    int height,
+    int timeout_sec,
   ) {
-    return _wire_sweep(
+    return _wire_wait_for_block_height(
       port_,
-      to_address,
-      fee_rate_sats_per_byte,
+      height,
+      timeout_sec,
     );
   }
 
-  late final _wire_sweepPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint64)>>(
-          'wire_sweep');
-  late final _wire_sweep =
-      _wire_sweepPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+  late final _wire_wait_for_block_heightPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint64)>>(
+          'wire_wait_for_block_height');
+  late final _wire_wait_for_block_height =
+      _wire_wait_for_block_heightPtr.asFunction<void Function(int, int, int)>();
 
-  void wire_receive_onchain(
+  void wire_full_resync(
     int port_,
   ) {
-    return _wire_receive_onchain(
+    return _wire_full_resync(
       port_,
     );
   }
 
-  late final _wire_receive_onchainPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_receive_onchain');
-  late final _wire_receive_onchain = _wire_receive_onchainPtr.asFunction<void Function(int)>();
+  late final _wire_full_resyncPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_full_resync');
+  late final _wire_full_resync = _wire_full_resyncPtr.asFunction<void Function(int)>();
 
-  void wire_in_progress_swap(
+  void wire_optimize_storage(
     int port_,
   ) {
-    return _wire_in_progress_swap(
+    return _wire_optimize_storage(
       port_,
     );
   }
 
-  late final _wire_in_progress_swapPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_in_progress_swap');
-  late final _wire_in_progress_swap = _wire_in_progress_swapPtr.asFunction<void Function(int)>();
+  late final _wire_optimize_storagePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_optimize_storage');
+  late final _wire_optimize_storage = _wire_optimize_storagePtr.asFunction<void Function(int)>();
 
-  void wire_list_refundables(
+  void wire_backup(
     int port_,
   ) {
-    return _wire_list_refundables(
+    return _wire_backup(
       port_,
     );
   }
 
-  late final _wire_list_refundablesPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_refundables');
-  late final _wire_list_refundables = _wire_list_refundablesPtr.asFunction<void Function(int)>();
+  late final _wire_backupPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_backup');
+  late final _wire_backup = _wire_backupPtr.asFunction<void Function(int)>();
 
-  void wire_refund(
+  void wire_restore(
     int port_,
-    ffi.Pointer<wire_uint_8_list> swap_address,
-    ffi.Pointer<wire_uint_8_list> to_address,
-    int sat_per_vbyte,
+    ffi.Pointer<wire_uint_8_list> data,
   ) {
-    return _wire_refund(
+    return _wire_restore(
       port_,
-      swap_address,
-      to_address,
-      sat_per_vbyte,
+      data,
     );
   }
 
-  late final _wire_refundPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>,
-              ffi.Uint32)>>('wire_refund');
-  late final _wire_refund = _wire_refundPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>, int)>();
+  late final _wire_restorePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_restore');
+  late final _wire_restore = _wire_restorePtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_execute_command(
+  void wire_set_setting(
     int port_,
-    ffi.Pointer<wire_uint_8_list> command,
+    ffi.Pointer<wire_uint_8_list> key,
+    ffi.Pointer<wire_uint_8_list> value,
   ) {
-    return _wire_execute_command(
+    return _wire_set_setting(
       port_,
-      command,
+      key,
+      value,
     );
   }
 
-  late final _wire_execute_commandPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_execute_command');
-  late final _wire_execute_command =
-      _wire_execute_commandPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_set_settingPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>>('wire_set_setting');
+  late final _wire_set_setting = _wire_set_settingPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_sync_node(
+  void wire_get_setting(
     int port_,
+    ffi.Pointer<wire_uint_8_list> key,
   ) {
-    return _wire_sync_node(
+    return _wire_get_setting(
       port_,
+      key,
     );
   }
 
-  late final _wire_sync_nodePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_sync_node');
-  late final _wire_sync_node = _wire_sync_nodePtr.asFunction<void Function(int)>();
+  late final _wire_get_settingPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_get_setting');
+  late final _wire_get_setting =
+      _wire_get_settingPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
   void wire_parse_invoice(
     int port_,
@@ -3056,6 +5876,40 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_parse_invoice =
       _wire_parse_invoicePtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_parse_amount(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> input,
+    int default_unit,
+  ) {
+    return _wire_parse_amount(
+      port_,
+      input,
+      default_unit,
+    );
+  }
+
+  late final _wire_parse_amountPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Int32)>>(
+          'wire_parse_amount');
+  late final _wire_parse_amount =
+      _wire_parse_amountPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_extract_lnurl_pay_metadata(
+    int port_,
+    ffi.Pointer<wire_LnUrlPayRequestData> req_data,
+  ) {
+    return _wire_extract_lnurl_pay_metadata(
+      port_,
+      req_data,
+    );
+  }
+
+  late final _wire_extract_lnurl_pay_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_LnUrlPayRequestData>)>>(
+          'wire_extract_lnurl_pay_metadata');
+  late final _wire_extract_lnurl_pay_metadata = _wire_extract_lnurl_pay_metadataPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_LnUrlPayRequestData>)>();
+
   void wire_parse(
     int port_,
     ffi.Pointer<wire_uint_8_list> s,
@@ -3112,6 +5966,25 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_lnurl_withdraw = _wire_lnurl_withdrawPtr.asFunction<
       void Function(int, ffi.Pointer<wire_LnUrlWithdrawRequestData>, int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_lnurl_withdraw_max(
+    int port_,
+    ffi.Pointer<wire_LnUrlWithdrawRequestData> req_data,
+    ffi.Pointer<wire_uint_8_list> description,
+  ) {
+    return _wire_lnurl_withdraw_max(
+      port_,
+      req_data,
+      description,
+    );
+  }
+
+  late final _wire_lnurl_withdraw_maxPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_LnUrlWithdrawRequestData>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_lnurl_withdraw_max');
+  late final _wire_lnurl_withdraw_max = _wire_lnurl_withdraw_maxPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_LnUrlWithdrawRequestData>, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_lnurl_auth(
     int port_,
     ffi.Pointer<wire_LnUrlAuthRequestData> req_data,
@@ -3128,6 +6001,18 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_lnurl_auth =
       _wire_lnurl_authPtr.asFunction<void Function(int, ffi.Pointer<wire_LnUrlAuthRequestData>)>();
 
+  void wire_list_lnurl_auth_logins(
+    int port_,
+  ) {
+    return _wire_list_lnurl_auth_logins(
+      port_,
+    );
+  }
+
+  late final _wire_list_lnurl_auth_loginsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_lnurl_auth_logins');
+  late final _wire_list_lnurl_auth_logins = _wire_list_lnurl_auth_loginsPtr.asFunction<void Function(int)>();
+
   void wire_mnemonic_to_seed(
     int port_,
     ffi.Pointer<wire_uint_8_list> phrase,
@@ -3156,19 +6041,141 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_recommended_fees');
   late final _wire_recommended_fees = _wire_recommended_feesPtr.asFunction<void Function(int)>();
 
+  void wire_preferred_fee_tier(
+    int port_,
+  ) {
+    return _wire_preferred_fee_tier(
+      port_,
+    );
+  }
+
+  late final _wire_preferred_fee_tierPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_preferred_fee_tier');
+  late final _wire_preferred_fee_tier = _wire_preferred_fee_tierPtr.asFunction<void Function(int)>();
+
+  void wire_set_preferred_fee_tier(
+    int port_,
+    ffi.Pointer<wire_FeeTier> tier,
+  ) {
+    return _wire_set_preferred_fee_tier(
+      port_,
+      tier,
+    );
+  }
+
+  late final _wire_set_preferred_fee_tierPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_FeeTier>)>>(
+          'wire_set_preferred_fee_tier');
+  late final _wire_set_preferred_fee_tier =
+      _wire_set_preferred_fee_tierPtr.asFunction<void Function(int, ffi.Pointer<wire_FeeTier>)>();
+
+  void wire_resolve_fee_rate(
+    int port_,
+  ) {
+    return _wire_resolve_fee_rate(
+      port_,
+    );
+  }
+
+  late final _wire_resolve_fee_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_resolve_fee_rate');
+  late final _wire_resolve_fee_rate = _wire_resolve_fee_ratePtr.asFunction<void Function(int)>();
+
+  void wire_buy_bitcoin(
+    int port_,
+    int provider,
+    ffi.Pointer<wire_uint_8_list> base_currency_code,
+  ) {
+    return _wire_buy_bitcoin(
+      port_,
+      provider,
+      base_currency_code,
+    );
+  }
+
+  late final _wire_buy_bitcoinPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_buy_bitcoin');
+  late final _wire_buy_bitcoin =
+      _wire_buy_bitcoinPtr.asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_diagnostics(
+    int port_,
+  ) {
+    return _wire_diagnostics(
+      port_,
+    );
+  }
+
+  late final _wire_diagnosticsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_diagnostics');
+  late final _wire_diagnostics = _wire_diagnosticsPtr.asFunction<void Function(int)>();
+
+  void wire_connectivity_check(
+    int port_,
+  ) {
+    return _wire_connectivity_check(
+      port_,
+    );
+  }
+
+  late final _wire_connectivity_checkPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_connectivity_check');
+  late final _wire_connectivity_check = _wire_connectivity_checkPtr.asFunction<void Function(int)>();
+
+  void wire_check_clock_skew(
+    int port_,
+  ) {
+    return _wire_check_clock_skew(
+      port_,
+    );
+  }
+
+  late final _wire_check_clock_skewPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_check_clock_skew');
+  late final _wire_check_clock_skew = _wire_check_clock_skewPtr.asFunction<void Function(int)>();
+
   void wire_default_config(
     int port_,
     int config_type,
+    int network,
   ) {
     return _wire_default_config(
       port_,
       config_type,
+      network,
     );
   }
 
   late final _wire_default_configPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_default_config');
-  late final _wire_default_config = _wire_default_configPtr.asFunction<void Function(int, int)>();
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.Int32)>>('wire_default_config');
+  late final _wire_default_config = _wire_default_configPtr.asFunction<void Function(int, int, int)>();
+
+  ffi.Pointer<wire_StringList> new_StringList_0(
+    int len,
+  ) {
+    return _new_StringList_0(
+      len,
+    );
+  }
+
+  late final _new_StringList_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_StringList> Function(ffi.Int32)>>('new_StringList_0');
+  late final _new_StringList_0 =
+      _new_StringList_0Ptr.asFunction<ffi.Pointer<wire_StringList> Function(int)>();
+
+  ffi.Pointer<ffi.Bool> new_box_autoadd_bool_0(
+    bool value,
+  ) {
+    return _new_box_autoadd_bool_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_bool_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Bool> Function(ffi.Bool)>>('new_box_autoadd_bool_0');
+  late final _new_box_autoadd_bool_0 =
+      _new_box_autoadd_bool_0Ptr.asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
 
   ffi.Pointer<wire_Config> new_box_autoadd_config_0() {
     return _new_box_autoadd_config_0();
@@ -3179,6 +6186,15 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_config_0 =
       _new_box_autoadd_config_0Ptr.asFunction<ffi.Pointer<wire_Config> Function()>();
 
+  ffi.Pointer<wire_FeeTier> new_box_autoadd_fee_tier_0() {
+    return _new_box_autoadd_fee_tier_0();
+  }
+
+  late final _new_box_autoadd_fee_tier_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_FeeTier> Function()>>('new_box_autoadd_fee_tier_0');
+  late final _new_box_autoadd_fee_tier_0 =
+      _new_box_autoadd_fee_tier_0Ptr.asFunction<ffi.Pointer<wire_FeeTier> Function()>();
+
   ffi.Pointer<wire_GreenlightCredentials> new_box_autoadd_greenlight_credentials_0() {
     return _new_box_autoadd_greenlight_credentials_0();
   }
@@ -3233,6 +6249,19 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _new_box_autoadd_ln_url_withdraw_request_data_0Ptr
           .asFunction<ffi.Pointer<wire_LnUrlWithdrawRequestData> Function()>();
 
+  ffi.Pointer<ffi.Uint32> new_box_autoadd_u32_0(
+    int value,
+  ) {
+    return _new_box_autoadd_u32_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_u32_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>('new_box_autoadd_u32_0');
+  late final _new_box_autoadd_u32_0 =
+      _new_box_autoadd_u32_0Ptr.asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
   ffi.Pointer<ffi.Uint64> new_box_autoadd_u64_0(
     int value,
   ) {
@@ -3246,6 +6275,34 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_u64_0 =
       _new_box_autoadd_u64_0Ptr.asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
 
+  ffi.Pointer<wire_list_template_value> new_list_template_value_0(
+    int len,
+  ) {
+    return _new_list_template_value_0(
+      len,
+    );
+  }
+
+  late final _new_list_template_value_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_template_value> Function(ffi.Int32)>>(
+          'new_list_template_value_0');
+  late final _new_list_template_value_0 =
+      _new_list_template_value_0Ptr.asFunction<ffi.Pointer<wire_list_template_value> Function(int)>();
+
+  ffi.Pointer<wire_list_tlv_entry> new_list_tlv_entry_0(
+    int len,
+  ) {
+    return _new_list_tlv_entry_0(
+      len,
+    );
+  }
+
+  late final _new_list_tlv_entry_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_tlv_entry> Function(ffi.Int32)>>(
+          'new_list_tlv_entry_0');
+  late final _new_list_tlv_entry_0 =
+      _new_list_tlv_entry_0Ptr.asFunction<ffi.Pointer<wire_list_tlv_entry> Function(int)>();
+
   ffi.Pointer<wire_uint_8_list> new_uint_8_list_0(
     int len,
   ) {
@@ -3259,6 +6316,15 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_uint_8_list_0 =
       _new_uint_8_list_0Ptr.asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
 
+  ffi.Pointer<FeeTierKind> inflate_FeeTier_Custom() {
+    return _inflate_FeeTier_Custom();
+  }
+
+  late final _inflate_FeeTier_CustomPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<FeeTierKind> Function()>>('inflate_FeeTier_Custom');
+  late final _inflate_FeeTier_Custom =
+      _inflate_FeeTier_CustomPtr.asFunction<ffi.Pointer<FeeTierKind> Function()>();
+
   void free_WireSyncReturn(
     WireSyncReturn ptr,
   ) {
@@ -3300,6 +6366,53 @@ class wire_Config extends ffi.Struct {
 
   @ffi.Double()
   external double maxfee_percent;
+
+  @ffi.Bool()
+  external bool auto_connect_lsp;
+
+  @ffi.Uint64()
+  external int fiat_rate_cache_ttl_sec;
+
+  @ffi.Uint32()
+  external int max_concurrent_chain_requests;
+
+  @ffi.Uint32()
+  external int onchain_min_confirmations;
+
+  external ffi.Pointer<wire_uint_8_list> default_fiat_currency;
+
+  @ffi.Uint32()
+  external int event_buffer_size;
+
+  @ffi.Bool()
+  external bool allow_receive_split;
+
+  @ffi.Bool()
+  external bool correct_clock_skew;
+
+  @ffi.Bool()
+  external bool redact_logs;
+
+  @ffi.Uint32()
+  external int max_reconnect_backoff_sec;
+
+  @ffi.Int32()
+  external int overpaid_swap_handling;
+
+  external ffi.Pointer<wire_uint_8_list> mempoolspace_api_token;
+
+  external ffi.Pointer<ffi.Uint64> swap_retention_sec;
+
+  @ffi.Int32()
+  external int chain_backend;
+
+  @ffi.Uint32()
+  external int sync_workers;
+
+  @ffi.Bool()
+  external bool reconcile_expired_swaps_on_start;
+
+  external ffi.Pointer<wire_uint_8_list> db_encryption_key;
 }
 
 class wire_GreenlightCredentials extends ffi.Struct {
@@ -3308,6 +6421,40 @@ class wire_GreenlightCredentials extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> device_cert;
 }
 
+class wire_TlvEntry extends ffi.Struct {
+  @ffi.Uint64()
+  external int type_num;
+
+  external ffi.Pointer<wire_uint_8_list> value;
+}
+
+class wire_list_tlv_entry extends ffi.Struct {
+  external ffi.Pointer<wire_TlvEntry> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_TemplateValue extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> key;
+
+  external ffi.Pointer<wire_uint_8_list> value;
+}
+
+class wire_list_template_value extends ffi.Struct {
+  external ffi.Pointer<wire_TemplateValue> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_StringList extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 class wire_LnUrlPayRequestData extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> callback;
 
@@ -3351,6 +6498,42 @@ class wire_LnUrlAuthRequestData extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> url;
 }
 
+class wire_FeeTier_Fastest extends ffi.Opaque {}
+
+class wire_FeeTier_HalfHour extends ffi.Opaque {}
+
+class wire_FeeTier_Hour extends ffi.Opaque {}
+
+class wire_FeeTier_Economy extends ffi.Opaque {}
+
+class wire_FeeTier_Minimum extends ffi.Opaque {}
+
+class wire_FeeTier_Custom extends ffi.Struct {
+  @ffi.Uint64()
+  external int sat_per_vbyte;
+}
+
+class FeeTierKind extends ffi.Union {
+  external ffi.Pointer<wire_FeeTier_Fastest> Fastest;
+
+  external ffi.Pointer<wire_FeeTier_HalfHour> HalfHour;
+
+  external ffi.Pointer<wire_FeeTier_Hour> Hour;
+
+  external ffi.Pointer<wire_FeeTier_Economy> Economy;
+
+  external ffi.Pointer<wire_FeeTier_Minimum> Minimum;
+
+  external ffi.Pointer<wire_FeeTier_Custom> Custom;
+}
+
+class wire_FeeTier extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<FeeTierKind> kind;
+}
+
 typedef DartPostCObjectFnType
     = ffi.Pointer<ffi.NativeFunction<ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message)>>;
 typedef DartPort = ffi.Int64;
@@ -15,74 +15,57 @@ final _privateConstructorUsedError = UnsupportedError(
     'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#custom-getters-and-methods');
 
 /// @nodoc
-mixin _$BreezEvent {
+mixin _$AesDecryptedOrError {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(AesSuccessActionDataDecrypted data) decrypted,
+    required TResult Function(String reason, String description, String ciphertext, String iv) errorStatus,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(AesSuccessActionDataDecrypted data)? decrypted,
+    TResult? Function(String reason, String description, String ciphertext, String iv)? errorStatus,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(AesSuccessActionDataDecrypted data)? decrypted,
+    TResult Function(String reason, String description, String ciphertext, String iv)? errorStatus,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(AesDecryptedOrError_Decrypted value) decrypted,
+    required TResult Function(AesDecryptedOrError_ErrorStatus value) errorStatus,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(AesDecryptedOrError_Decrypted value)? decrypted,
+    TResult? Function(AesDecryptedOrError_ErrorStatus value)? errorStatus,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(AesDecryptedOrError_Decrypted value)? decrypted,
+    TResult Function(AesDecryptedOrError_ErrorStatus value)? errorStatus,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $BreezEventCopyWith<$Res> {
-  factory $BreezEventCopyWith(BreezEvent value, $Res Function(BreezEvent) then) =
-      _$BreezEventCopyWithImpl<$Res, BreezEvent>;
+abstract class $AesDecryptedOrErrorCopyWith<$Res> {
+  factory $AesDecryptedOrErrorCopyWith(AesDecryptedOrError value, $Res Function(AesDecryptedOrError) then) =
+      _$AesDecryptedOrErrorCopyWithImpl<$Res, AesDecryptedOrError>;
 }
 
 /// @nodoc
-class _$BreezEventCopyWithImpl<$Res, $Val extends BreezEvent> implements $BreezEventCopyWith<$Res> {
-  _$BreezEventCopyWithImpl(this._value, this._then);
+class _$AesDecryptedOrErrorCopyWithImpl<$Res, $Val extends AesDecryptedOrError>
+    implements $AesDecryptedOrErrorCopyWith<$Res> {
+  _$AesDecryptedOrErrorCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -91,101 +74,93 @@ class _$BreezEventCopyWithImpl<$Res, $Val extends BreezEvent> implements $BreezE
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_NewBlockCopyWith<$Res> {
-  factory _$$BreezEvent_NewBlockCopyWith(
-          _$BreezEvent_NewBlock value, $Res Function(_$BreezEvent_NewBlock) then) =
-      __$$BreezEvent_NewBlockCopyWithImpl<$Res>;
+abstract class _$$AesDecryptedOrError_DecryptedCopyWith<$Res> {
+  factory _$$AesDecryptedOrError_DecryptedCopyWith(
+          _$AesDecryptedOrError_Decrypted value, $Res Function(_$AesDecryptedOrError_Decrypted) then) =
+      __$$AesDecryptedOrError_DecryptedCopyWithImpl<$Res>;
   @useResult
-  $Res call({int block});
+  $Res call({AesSuccessActionDataDecrypted data});
 }
 
 /// @nodoc
-class __$$BreezEvent_NewBlockCopyWithImpl<$Res> extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_NewBlock>
-    implements _$$BreezEvent_NewBlockCopyWith<$Res> {
-  __$$BreezEvent_NewBlockCopyWithImpl(
-      _$BreezEvent_NewBlock _value, $Res Function(_$BreezEvent_NewBlock) _then)
+class __$$AesDecryptedOrError_DecryptedCopyWithImpl<$Res>
+    extends _$AesDecryptedOrErrorCopyWithImpl<$Res, _$AesDecryptedOrError_Decrypted>
+    implements _$$AesDecryptedOrError_DecryptedCopyWith<$Res> {
+  __$$AesDecryptedOrError_DecryptedCopyWithImpl(
+      _$AesDecryptedOrError_Decrypted _value, $Res Function(_$AesDecryptedOrError_Decrypted) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? block = null,
+    Object? data = null,
   }) {
-    return _then(_$BreezEvent_NewBlock(
-      block: null == block
-          ? _value.block
-          : block // ignore: cast_nullable_to_non_nullable
-              as int,
+    return _then(_$AesDecryptedOrError_Decrypted(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as AesSuccessActionDataDecrypted,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
-  const _$BreezEvent_NewBlock({required this.block});
+class _$AesDecryptedOrError_Decrypted implements AesDecryptedOrError_Decrypted {
+  const _$AesDecryptedOrError_Decrypted({required this.data});
 
   @override
-  final int block;
+  final AesSuccessActionDataDecrypted data;
 
   @override
   String toString() {
-    return 'BreezEvent.newBlock(block: $block)';
+    return 'AesDecryptedOrError.decrypted(data: $data)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_NewBlock &&
-            (identical(other.block, block) || other.block == block));
+            other is _$AesDecryptedOrError_Decrypted &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, block);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_NewBlockCopyWith<_$BreezEvent_NewBlock> get copyWith =>
-      __$$BreezEvent_NewBlockCopyWithImpl<_$BreezEvent_NewBlock>(this, _$identity);
+  _$$AesDecryptedOrError_DecryptedCopyWith<_$AesDecryptedOrError_Decrypted> get copyWith =>
+      __$$AesDecryptedOrError_DecryptedCopyWithImpl<_$AesDecryptedOrError_Decrypted>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(AesSuccessActionDataDecrypted data) decrypted,
+    required TResult Function(String reason, String description, String ciphertext, String iv) errorStatus,
   }) {
-    return newBlock(block);
+    return decrypted(data);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(AesSuccessActionDataDecrypted data)? decrypted,
+    TResult? Function(String reason, String description, String ciphertext, String iv)? errorStatus,
   }) {
-    return newBlock?.call(block);
+    return decrypted?.call(data);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(AesSuccessActionDataDecrypted data)? decrypted,
+    TResult Function(String reason, String description, String ciphertext, String iv)? errorStatus,
     required TResult orElse(),
   }) {
-    if (newBlock != null) {
-      return newBlock(block);
+    if (decrypted != null) {
+      return decrypted(data);
     }
     return orElse();
   }
@@ -193,149 +168,158 @@ class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(AesDecryptedOrError_Decrypted value) decrypted,
+    required TResult Function(AesDecryptedOrError_ErrorStatus value) errorStatus,
   }) {
-    return newBlock(this);
+    return decrypted(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(AesDecryptedOrError_Decrypted value)? decrypted,
+    TResult? Function(AesDecryptedOrError_ErrorStatus value)? errorStatus,
   }) {
-    return newBlock?.call(this);
+    return decrypted?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(AesDecryptedOrError_Decrypted value)? decrypted,
+    TResult Function(AesDecryptedOrError_ErrorStatus value)? errorStatus,
     required TResult orElse(),
   }) {
-    if (newBlock != null) {
-      return newBlock(this);
+    if (decrypted != null) {
+      return decrypted(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_NewBlock implements BreezEvent {
-  const factory BreezEvent_NewBlock({required final int block}) = _$BreezEvent_NewBlock;
+abstract class AesDecryptedOrError_Decrypted implements AesDecryptedOrError {
+  const factory AesDecryptedOrError_Decrypted({required final AesSuccessActionDataDecrypted data}) =
+      _$AesDecryptedOrError_Decrypted;
 
-  int get block;
+  AesSuccessActionDataDecrypted get data;
   @JsonKey(ignore: true)
-  _$$BreezEvent_NewBlockCopyWith<_$BreezEvent_NewBlock> get copyWith => throw _privateConstructorUsedError;
+  _$$AesDecryptedOrError_DecryptedCopyWith<_$AesDecryptedOrError_Decrypted> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_InvoicePaidCopyWith<$Res> {
-  factory _$$BreezEvent_InvoicePaidCopyWith(
-          _$BreezEvent_InvoicePaid value, $Res Function(_$BreezEvent_InvoicePaid) then) =
-      __$$BreezEvent_InvoicePaidCopyWithImpl<$Res>;
+abstract class _$$AesDecryptedOrError_ErrorStatusCopyWith<$Res> {
+  factory _$$AesDecryptedOrError_ErrorStatusCopyWith(
+          _$AesDecryptedOrError_ErrorStatus value, $Res Function(_$AesDecryptedOrError_ErrorStatus) then) =
+      __$$AesDecryptedOrError_ErrorStatusCopyWithImpl<$Res>;
   @useResult
-  $Res call({InvoicePaidDetails details});
+  $Res call({String reason, String description, String ciphertext, String iv});
 }
 
 /// @nodoc
-class __$$BreezEvent_InvoicePaidCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_InvoicePaid>
-    implements _$$BreezEvent_InvoicePaidCopyWith<$Res> {
-  __$$BreezEvent_InvoicePaidCopyWithImpl(
-      _$BreezEvent_InvoicePaid _value, $Res Function(_$BreezEvent_InvoicePaid) _then)
+class __$$AesDecryptedOrError_ErrorStatusCopyWithImpl<$Res>
+    extends _$AesDecryptedOrErrorCopyWithImpl<$Res, _$AesDecryptedOrError_ErrorStatus>
+    implements _$$AesDecryptedOrError_ErrorStatusCopyWith<$Res> {
+  __$$AesDecryptedOrError_ErrorStatusCopyWithImpl(
+      _$AesDecryptedOrError_ErrorStatus _value, $Res Function(_$AesDecryptedOrError_ErrorStatus) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
-  }) {
-    return _then(_$BreezEvent_InvoicePaid(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as InvoicePaidDetails,
+    Object? reason = null,
+    Object? description = null,
+    Object? ciphertext = null,
+    Object? iv = null,
+  }) {
+    return _then(_$AesDecryptedOrError_ErrorStatus(
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+      description: null == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String,
+      ciphertext: null == ciphertext
+          ? _value.ciphertext
+          : ciphertext // ignore: cast_nullable_to_non_nullable
+              as String,
+      iv: null == iv
+          ? _value.iv
+          : iv // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
-  const _$BreezEvent_InvoicePaid({required this.details});
+class _$AesDecryptedOrError_ErrorStatus implements AesDecryptedOrError_ErrorStatus {
+  const _$AesDecryptedOrError_ErrorStatus(
+      {required this.reason, required this.description, required this.ciphertext, required this.iv});
 
   @override
-  final InvoicePaidDetails details;
+  final String reason;
+  @override
+  final String description;
+  @override
+  final String ciphertext;
+  @override
+  final String iv;
 
   @override
   String toString() {
-    return 'BreezEvent.invoicePaid(details: $details)';
+    return 'AesDecryptedOrError.errorStatus(reason: $reason, description: $description, ciphertext: $ciphertext, iv: $iv)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_InvoicePaid &&
-            (identical(other.details, details) || other.details == details));
+            other is _$AesDecryptedOrError_ErrorStatus &&
+            (identical(other.reason, reason) || other.reason == reason) &&
+            (identical(other.description, description) || other.description == description) &&
+            (identical(other.ciphertext, ciphertext) || other.ciphertext == ciphertext) &&
+            (identical(other.iv, iv) || other.iv == iv));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, reason, description, ciphertext, iv);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_InvoicePaidCopyWith<_$BreezEvent_InvoicePaid> get copyWith =>
-      __$$BreezEvent_InvoicePaidCopyWithImpl<_$BreezEvent_InvoicePaid>(this, _$identity);
+  _$$AesDecryptedOrError_ErrorStatusCopyWith<_$AesDecryptedOrError_ErrorStatus> get copyWith =>
+      __$$AesDecryptedOrError_ErrorStatusCopyWithImpl<_$AesDecryptedOrError_ErrorStatus>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(AesSuccessActionDataDecrypted data) decrypted,
+    required TResult Function(String reason, String description, String ciphertext, String iv) errorStatus,
   }) {
-    return invoicePaid(details);
+    return errorStatus(reason, description, ciphertext, iv);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(AesSuccessActionDataDecrypted data)? decrypted,
+    TResult? Function(String reason, String description, String ciphertext, String iv)? errorStatus,
   }) {
-    return invoicePaid?.call(details);
+    return errorStatus?.call(reason, description, ciphertext, iv);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(AesSuccessActionDataDecrypted data)? decrypted,
+    TResult Function(String reason, String description, String ciphertext, String iv)? errorStatus,
     required TResult orElse(),
   }) {
-    if (invoicePaid != null) {
-      return invoicePaid(details);
+    if (errorStatus != null) {
+      return errorStatus(reason, description, ciphertext, iv);
     }
     return orElse();
   }
@@ -343,121 +327,207 @@ class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(AesDecryptedOrError_Decrypted value) decrypted,
+    required TResult Function(AesDecryptedOrError_ErrorStatus value) errorStatus,
   }) {
-    return invoicePaid(this);
+    return errorStatus(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(AesDecryptedOrError_Decrypted value)? decrypted,
+    TResult? Function(AesDecryptedOrError_ErrorStatus value)? errorStatus,
   }) {
-    return invoicePaid?.call(this);
+    return errorStatus?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(AesDecryptedOrError_Decrypted value)? decrypted,
+    TResult Function(AesDecryptedOrError_ErrorStatus value)? errorStatus,
     required TResult orElse(),
   }) {
-    if (invoicePaid != null) {
-      return invoicePaid(this);
+    if (errorStatus != null) {
+      return errorStatus(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_InvoicePaid implements BreezEvent {
-  const factory BreezEvent_InvoicePaid({required final InvoicePaidDetails details}) =
-      _$BreezEvent_InvoicePaid;
-
-  InvoicePaidDetails get details;
+abstract class AesDecryptedOrError_ErrorStatus implements AesDecryptedOrError {
+  const factory AesDecryptedOrError_ErrorStatus(
+      {required final String reason,
+      required final String description,
+      required final String ciphertext,
+      required final String iv}) = _$AesDecryptedOrError_ErrorStatus;
+
+  String get reason;
+  String get description;
+  String get ciphertext;
+  String get iv;
   @JsonKey(ignore: true)
-  _$$BreezEvent_InvoicePaidCopyWith<_$BreezEvent_InvoicePaid> get copyWith =>
+  _$$AesDecryptedOrError_ErrorStatusCopyWith<_$AesDecryptedOrError_ErrorStatus> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_SyncedCopyWith<$Res> {
-  factory _$$BreezEvent_SyncedCopyWith(_$BreezEvent_Synced value, $Res Function(_$BreezEvent_Synced) then) =
-      __$$BreezEvent_SyncedCopyWithImpl<$Res>;
+mixin _$AmountViolation {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minMsat) belowMinimum,
+    required TResult Function(int maxMsat) aboveMaximum,
+    required TResult Function(int maxPayableMsat) insufficientBalance,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minMsat)? belowMinimum,
+    TResult? Function(int maxMsat)? aboveMaximum,
+    TResult? Function(int maxPayableMsat)? insufficientBalance,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minMsat)? belowMinimum,
+    TResult Function(int maxMsat)? aboveMaximum,
+    TResult Function(int maxPayableMsat)? insufficientBalance,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AmountViolation_BelowMinimum value) belowMinimum,
+    required TResult Function(AmountViolation_AboveMaximum value) aboveMaximum,
+    required TResult Function(AmountViolation_InsufficientBalance value) insufficientBalance,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AmountViolation_BelowMinimum value)? belowMinimum,
+    TResult? Function(AmountViolation_AboveMaximum value)? aboveMaximum,
+    TResult? Function(AmountViolation_InsufficientBalance value)? insufficientBalance,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AmountViolation_BelowMinimum value)? belowMinimum,
+    TResult Function(AmountViolation_AboveMaximum value)? aboveMaximum,
+    TResult Function(AmountViolation_InsufficientBalance value)? insufficientBalance,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-class __$$BreezEvent_SyncedCopyWithImpl<$Res> extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_Synced>
-    implements _$$BreezEvent_SyncedCopyWith<$Res> {
-  __$$BreezEvent_SyncedCopyWithImpl(_$BreezEvent_Synced _value, $Res Function(_$BreezEvent_Synced) _then)
+abstract class $AmountViolationCopyWith<$Res> {
+  factory $AmountViolationCopyWith(AmountViolation value, $Res Function(AmountViolation) then) =
+      _$AmountViolationCopyWithImpl<$Res, AmountViolation>;
+}
+
+/// @nodoc
+class _$AmountViolationCopyWithImpl<$Res, $Val extends AmountViolation>
+    implements $AmountViolationCopyWith<$Res> {
+  _$AmountViolationCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$AmountViolation_BelowMinimumCopyWith<$Res> {
+  factory _$$AmountViolation_BelowMinimumCopyWith(
+          _$AmountViolation_BelowMinimum value, $Res Function(_$AmountViolation_BelowMinimum) then) =
+      __$$AmountViolation_BelowMinimumCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int minMsat});
+}
+
+/// @nodoc
+class __$$AmountViolation_BelowMinimumCopyWithImpl<$Res>
+    extends _$AmountViolationCopyWithImpl<$Res, _$AmountViolation_BelowMinimum>
+    implements _$$AmountViolation_BelowMinimumCopyWith<$Res> {
+  __$$AmountViolation_BelowMinimumCopyWithImpl(
+      _$AmountViolation_BelowMinimum _value, $Res Function(_$AmountViolation_BelowMinimum) _then)
       : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? minMsat = null,
+  }) {
+    return _then(_$AmountViolation_BelowMinimum(
+      minMsat: null == minMsat
+          ? _value.minMsat
+          : minMsat // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
 }
 
 /// @nodoc
 
-class _$BreezEvent_Synced implements BreezEvent_Synced {
-  const _$BreezEvent_Synced();
+class _$AmountViolation_BelowMinimum implements AmountViolation_BelowMinimum {
+  const _$AmountViolation_BelowMinimum({required this.minMsat});
+
+  @override
+  final int minMsat;
 
   @override
   String toString() {
-    return 'BreezEvent.synced()';
+    return 'AmountViolation.belowMinimum(minMsat: $minMsat)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$BreezEvent_Synced);
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AmountViolation_BelowMinimum &&
+            (identical(other.minMsat, minMsat) || other.minMsat == minMsat));
   }
 
   @override
-  int get hashCode => runtimeType.hashCode;
+  int get hashCode => Object.hash(runtimeType, minMsat);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AmountViolation_BelowMinimumCopyWith<_$AmountViolation_BelowMinimum> get copyWith =>
+      __$$AmountViolation_BelowMinimumCopyWithImpl<_$AmountViolation_BelowMinimum>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(int minMsat) belowMinimum,
+    required TResult Function(int maxMsat) aboveMaximum,
+    required TResult Function(int maxPayableMsat) insufficientBalance,
   }) {
-    return synced();
+    return belowMinimum(minMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(int minMsat)? belowMinimum,
+    TResult? Function(int maxMsat)? aboveMaximum,
+    TResult? Function(int maxPayableMsat)? insufficientBalance,
   }) {
-    return synced?.call();
+    return belowMinimum?.call(minMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(int minMsat)? belowMinimum,
+    TResult Function(int maxMsat)? aboveMaximum,
+    TResult Function(int maxPayableMsat)? insufficientBalance,
     required TResult orElse(),
   }) {
-    if (synced != null) {
-      return synced();
+    if (belowMinimum != null) {
+      return belowMinimum(minMsat);
     }
     return orElse();
   }
@@ -465,145 +535,138 @@ class _$BreezEvent_Synced implements BreezEvent_Synced {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(AmountViolation_BelowMinimum value) belowMinimum,
+    required TResult Function(AmountViolation_AboveMaximum value) aboveMaximum,
+    required TResult Function(AmountViolation_InsufficientBalance value) insufficientBalance,
   }) {
-    return synced(this);
+    return belowMinimum(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(AmountViolation_BelowMinimum value)? belowMinimum,
+    TResult? Function(AmountViolation_AboveMaximum value)? aboveMaximum,
+    TResult? Function(AmountViolation_InsufficientBalance value)? insufficientBalance,
   }) {
-    return synced?.call(this);
+    return belowMinimum?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(AmountViolation_BelowMinimum value)? belowMinimum,
+    TResult Function(AmountViolation_AboveMaximum value)? aboveMaximum,
+    TResult Function(AmountViolation_InsufficientBalance value)? insufficientBalance,
     required TResult orElse(),
   }) {
-    if (synced != null) {
-      return synced(this);
+    if (belowMinimum != null) {
+      return belowMinimum(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_Synced implements BreezEvent {
-  const factory BreezEvent_Synced() = _$BreezEvent_Synced;
+abstract class AmountViolation_BelowMinimum implements AmountViolation {
+  const factory AmountViolation_BelowMinimum({required final int minMsat}) = _$AmountViolation_BelowMinimum;
+
+  int get minMsat;
+  @JsonKey(ignore: true)
+  _$$AmountViolation_BelowMinimumCopyWith<_$AmountViolation_BelowMinimum> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_PaymentSucceedCopyWith<$Res> {
-  factory _$$BreezEvent_PaymentSucceedCopyWith(
-          _$BreezEvent_PaymentSucceed value, $Res Function(_$BreezEvent_PaymentSucceed) then) =
-      __$$BreezEvent_PaymentSucceedCopyWithImpl<$Res>;
+abstract class _$$AmountViolation_AboveMaximumCopyWith<$Res> {
+  factory _$$AmountViolation_AboveMaximumCopyWith(
+          _$AmountViolation_AboveMaximum value, $Res Function(_$AmountViolation_AboveMaximum) then) =
+      __$$AmountViolation_AboveMaximumCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({int maxMsat});
 }
 
 /// @nodoc
-class __$$BreezEvent_PaymentSucceedCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentSucceed>
-    implements _$$BreezEvent_PaymentSucceedCopyWith<$Res> {
-  __$$BreezEvent_PaymentSucceedCopyWithImpl(
-      _$BreezEvent_PaymentSucceed _value, $Res Function(_$BreezEvent_PaymentSucceed) _then)
+class __$$AmountViolation_AboveMaximumCopyWithImpl<$Res>
+    extends _$AmountViolationCopyWithImpl<$Res, _$AmountViolation_AboveMaximum>
+    implements _$$AmountViolation_AboveMaximumCopyWith<$Res> {
+  __$$AmountViolation_AboveMaximumCopyWithImpl(
+      _$AmountViolation_AboveMaximum _value, $Res Function(_$AmountViolation_AboveMaximum) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? maxMsat = null,
   }) {
-    return _then(_$BreezEvent_PaymentSucceed(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$AmountViolation_AboveMaximum(
+      maxMsat: null == maxMsat
+          ? _value.maxMsat
+          : maxMsat // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
-  const _$BreezEvent_PaymentSucceed({required this.details});
+class _$AmountViolation_AboveMaximum implements AmountViolation_AboveMaximum {
+  const _$AmountViolation_AboveMaximum({required this.maxMsat});
 
   @override
-  final Payment details;
+  final int maxMsat;
 
   @override
   String toString() {
-    return 'BreezEvent.paymentSucceed(details: $details)';
+    return 'AmountViolation.aboveMaximum(maxMsat: $maxMsat)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_PaymentSucceed &&
-            (identical(other.details, details) || other.details == details));
+            other is _$AmountViolation_AboveMaximum &&
+            (identical(other.maxMsat, maxMsat) || other.maxMsat == maxMsat));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, maxMsat);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_PaymentSucceedCopyWith<_$BreezEvent_PaymentSucceed> get copyWith =>
-      __$$BreezEvent_PaymentSucceedCopyWithImpl<_$BreezEvent_PaymentSucceed>(this, _$identity);
+  _$$AmountViolation_AboveMaximumCopyWith<_$AmountViolation_AboveMaximum> get copyWith =>
+      __$$AmountViolation_AboveMaximumCopyWithImpl<_$AmountViolation_AboveMaximum>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(int minMsat) belowMinimum,
+    required TResult Function(int maxMsat) aboveMaximum,
+    required TResult Function(int maxPayableMsat) insufficientBalance,
   }) {
-    return paymentSucceed(details);
+    return aboveMaximum(maxMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(int minMsat)? belowMinimum,
+    TResult? Function(int maxMsat)? aboveMaximum,
+    TResult? Function(int maxPayableMsat)? insufficientBalance,
   }) {
-    return paymentSucceed?.call(details);
+    return aboveMaximum?.call(maxMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(int minMsat)? belowMinimum,
+    TResult Function(int maxMsat)? aboveMaximum,
+    TResult Function(int maxPayableMsat)? insufficientBalance,
     required TResult orElse(),
   }) {
-    if (paymentSucceed != null) {
-      return paymentSucceed(details);
+    if (aboveMaximum != null) {
+      return aboveMaximum(maxMsat);
     }
     return orElse();
   }
@@ -611,115 +674,192 @@ class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(AmountViolation_BelowMinimum value) belowMinimum,
+    required TResult Function(AmountViolation_AboveMaximum value) aboveMaximum,
+    required TResult Function(AmountViolation_InsufficientBalance value) insufficientBalance,
   }) {
-    return paymentSucceed(this);
+    return aboveMaximum(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(AmountViolation_BelowMinimum value)? belowMinimum,
+    TResult? Function(AmountViolation_AboveMaximum value)? aboveMaximum,
+    TResult? Function(AmountViolation_InsufficientBalance value)? insufficientBalance,
   }) {
-    return paymentSucceed?.call(this);
+    return aboveMaximum?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(AmountViolation_BelowMinimum value)? belowMinimum,
+    TResult Function(AmountViolation_AboveMaximum value)? aboveMaximum,
+    TResult Function(AmountViolation_InsufficientBalance value)? insufficientBalance,
     required TResult orElse(),
   }) {
-    if (paymentSucceed != null) {
-      return paymentSucceed(this);
+    if (aboveMaximum != null) {
+      return aboveMaximum(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_PaymentSucceed implements BreezEvent {
-  const factory BreezEvent_PaymentSucceed({required final Payment details}) = _$BreezEvent_PaymentSucceed;
+abstract class AmountViolation_AboveMaximum implements AmountViolation {
+  const factory AmountViolation_AboveMaximum({required final int maxMsat}) = _$AmountViolation_AboveMaximum;
 
-  Payment get details;
+  int get maxMsat;
   @JsonKey(ignore: true)
-  _$$BreezEvent_PaymentSucceedCopyWith<_$BreezEvent_PaymentSucceed> get copyWith =>
+  _$$AmountViolation_AboveMaximumCopyWith<_$AmountViolation_AboveMaximum> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_PaymentFailedCopyWith<$Res> {
-  factory _$$BreezEvent_PaymentFailedCopyWith(
-          _$BreezEvent_PaymentFailed value, $Res Function(_$BreezEvent_PaymentFailed) then) =
-      __$$BreezEvent_PaymentFailedCopyWithImpl<$Res>;
+abstract class _$$AmountViolation_InsufficientBalanceCopyWith<$Res> {
+  factory _$$AmountViolation_InsufficientBalanceCopyWith(
+          _$AmountViolation_InsufficientBalance value,
+          $Res Function(_$AmountViolation_InsufficientBalance) then) =
+      __$$AmountViolation_InsufficientBalanceCopyWithImpl<$Res>;
   @useResult
-  $Res call({PaymentFailedData details});
+  $Res call({int maxPayableMsat});
 }
 
 /// @nodoc
-class __$$BreezEvent_PaymentFailedCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentFailed>
-    implements _$$BreezEvent_PaymentFailedCopyWith<$Res> {
-  __$$BreezEvent_PaymentFailedCopyWithImpl(
-      _$BreezEvent_PaymentFailed _value, $Res Function(_$BreezEvent_PaymentFailed) _then)
+class __$$AmountViolation_InsufficientBalanceCopyWithImpl<$Res>
+    extends _$AmountViolationCopyWithImpl<$Res, _$AmountViolation_InsufficientBalance>
+    implements _$$AmountViolation_InsufficientBalanceCopyWith<$Res> {
+  __$$AmountViolation_InsufficientBalanceCopyWithImpl(
+      _$AmountViolation_InsufficientBalance _value,
+      $Res Function(_$AmountViolation_InsufficientBalance) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? maxPayableMsat = null,
   }) {
-    return _then(_$BreezEvent_PaymentFailed(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as PaymentFailedData,
+    return _then(_$AmountViolation_InsufficientBalance(
+      maxPayableMsat: null == maxPayableMsat
+          ? _value.maxPayableMsat
+          : maxPayableMsat // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
-  const _$BreezEvent_PaymentFailed({required this.details});
+class _$AmountViolation_InsufficientBalance implements AmountViolation_InsufficientBalance {
+  const _$AmountViolation_InsufficientBalance({required this.maxPayableMsat});
 
   @override
-  final PaymentFailedData details;
+  final int maxPayableMsat;
 
   @override
   String toString() {
-    return 'BreezEvent.paymentFailed(details: $details)';
+    return 'AmountViolation.insufficientBalance(maxPayableMsat: $maxPayableMsat)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_PaymentFailed &&
-            (identical(other.details, details) || other.details == details));
+            other is _$AmountViolation_InsufficientBalance &&
+            (identical(other.maxPayableMsat, maxPayableMsat) || other.maxPayableMsat == maxPayableMsat));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, maxPayableMsat);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_PaymentFailedCopyWith<_$BreezEvent_PaymentFailed> get copyWith =>
-      __$$BreezEvent_PaymentFailedCopyWithImpl<_$BreezEvent_PaymentFailed>(this, _$identity);
+  _$$AmountViolation_InsufficientBalanceCopyWith<_$AmountViolation_InsufficientBalance> get copyWith =>
+      __$$AmountViolation_InsufficientBalanceCopyWithImpl<_$AmountViolation_InsufficientBalance>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minMsat) belowMinimum,
+    required TResult Function(int maxMsat) aboveMaximum,
+    required TResult Function(int maxPayableMsat) insufficientBalance,
+  }) {
+    return insufficientBalance(maxPayableMsat);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minMsat)? belowMinimum,
+    TResult? Function(int maxMsat)? aboveMaximum,
+    TResult? Function(int maxPayableMsat)? insufficientBalance,
+  }) {
+    return insufficientBalance?.call(maxPayableMsat);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minMsat)? belowMinimum,
+    TResult Function(int maxMsat)? aboveMaximum,
+    TResult Function(int maxPayableMsat)? insufficientBalance,
+    required TResult orElse(),
+  }) {
+    if (insufficientBalance != null) {
+      return insufficientBalance(maxPayableMsat);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AmountViolation_BelowMinimum value) belowMinimum,
+    required TResult Function(AmountViolation_AboveMaximum value) aboveMaximum,
+    required TResult Function(AmountViolation_InsufficientBalance value) insufficientBalance,
+  }) {
+    return insufficientBalance(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AmountViolation_BelowMinimum value)? belowMinimum,
+    TResult? Function(AmountViolation_AboveMaximum value)? aboveMaximum,
+    TResult? Function(AmountViolation_InsufficientBalance value)? insufficientBalance,
+  }) {
+    return insufficientBalance?.call(this);
+  }
 
   @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AmountViolation_BelowMinimum value)? belowMinimum,
+    TResult Function(AmountViolation_AboveMaximum value)? aboveMaximum,
+    TResult Function(AmountViolation_InsufficientBalance value)? insufficientBalance,
+    required TResult orElse(),
+  }) {
+    if (insufficientBalance != null) {
+      return insufficientBalance(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AmountViolation_InsufficientBalance implements AmountViolation {
+  const factory AmountViolation_InsufficientBalance({required final int maxPayableMsat}) =
+      _$AmountViolation_InsufficientBalance;
+
+  int get maxPayableMsat;
+  @JsonKey(ignore: true)
+  _$$AmountViolation_InsufficientBalanceCopyWith<_$AmountViolation_InsufficientBalance> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$BreezEvent {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
     required TResult Function(int block) newBlock,
@@ -727,11 +867,16 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     required TResult Function() synced,
     required TResult Function(Payment details) paymentSucceed,
     required TResult Function(PaymentFailedData details) paymentFailed,
-  }) {
-    return paymentFailed(details);
-  }
-
-  @override
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
     TResult? Function(int block)? newBlock,
@@ -739,11 +884,16 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     TResult? Function()? synced,
     TResult? Function(Payment details)? paymentSucceed,
     TResult? Function(PaymentFailedData details)? paymentFailed,
-  }) {
-    return paymentFailed?.call(details);
-  }
-
-  @override
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
     TResult Function(int block)? newBlock,
@@ -751,15 +901,17 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     TResult Function()? synced,
     TResult Function(Payment details)? paymentSucceed,
     TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
     required TResult orElse(),
-  }) {
-    if (paymentFailed != null) {
-      return paymentFailed(details);
-    }
-    return orElse();
-  }
-
-  @override
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
     required TResult Function(BreezEvent_NewBlock value) newBlock,
@@ -767,11 +919,16 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     required TResult Function(BreezEvent_Synced value) synced,
     required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
     required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
-  }) {
-    return paymentFailed(this);
-  }
-
-  @override
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
     TResult? Function(BreezEvent_NewBlock value)? newBlock,
@@ -779,11 +936,16 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     TResult? Function(BreezEvent_Synced value)? synced,
     TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
     TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
-  }) {
-    return paymentFailed?.call(this);
-  }
-
-  @override
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
     TResult Function(BreezEvent_NewBlock value)? newBlock,
@@ -791,27 +953,4215 @@ class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
     TResult Function(BreezEvent_Synced value)? synced,
     TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
     TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
     required TResult orElse(),
-  }) {
-    if (paymentFailed != null) {
-      return paymentFailed(this);
-    }
-    return orElse();
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BreezEventCopyWith<$Res> {
+  factory $BreezEventCopyWith(BreezEvent value, $Res Function(BreezEvent) then) =
+      _$BreezEventCopyWithImpl<$Res, BreezEvent>;
+}
+
+/// @nodoc
+class _$BreezEventCopyWithImpl<$Res, $Val extends BreezEvent> implements $BreezEventCopyWith<$Res> {
+  _$BreezEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_NewBlockCopyWith<$Res> {
+  factory _$$BreezEvent_NewBlockCopyWith(
+          _$BreezEvent_NewBlock value, $Res Function(_$BreezEvent_NewBlock) then) =
+      __$$BreezEvent_NewBlockCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int block});
+}
+
+/// @nodoc
+class __$$BreezEvent_NewBlockCopyWithImpl<$Res> extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_NewBlock>
+    implements _$$BreezEvent_NewBlockCopyWith<$Res> {
+  __$$BreezEvent_NewBlockCopyWithImpl(
+      _$BreezEvent_NewBlock _value, $Res Function(_$BreezEvent_NewBlock) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? block = null,
+  }) {
+    return _then(_$BreezEvent_NewBlock(
+      block: null == block
+          ? _value.block
+          : block // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_NewBlock implements BreezEvent_NewBlock {
+  const _$BreezEvent_NewBlock({required this.block});
+
+  @override
+  final int block;
+
+  @override
+  String toString() {
+    return 'BreezEvent.newBlock(block: $block)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_NewBlock &&
+            (identical(other.block, block) || other.block == block));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, block);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_NewBlockCopyWith<_$BreezEvent_NewBlock> get copyWith =>
+      __$$BreezEvent_NewBlockCopyWithImpl<_$BreezEvent_NewBlock>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return newBlock(block);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return newBlock?.call(block);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (newBlock != null) {
+      return newBlock(block);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return newBlock(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return newBlock?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (newBlock != null) {
+      return newBlock(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_NewBlock implements BreezEvent {
+  const factory BreezEvent_NewBlock({required final int block}) = _$BreezEvent_NewBlock;
+
+  int get block;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_NewBlockCopyWith<_$BreezEvent_NewBlock> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_InvoicePaidCopyWith<$Res> {
+  factory _$$BreezEvent_InvoicePaidCopyWith(
+          _$BreezEvent_InvoicePaid value, $Res Function(_$BreezEvent_InvoicePaid) then) =
+      __$$BreezEvent_InvoicePaidCopyWithImpl<$Res>;
+  @useResult
+  $Res call({InvoicePaidDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_InvoicePaidCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_InvoicePaid>
+    implements _$$BreezEvent_InvoicePaidCopyWith<$Res> {
+  __$$BreezEvent_InvoicePaidCopyWithImpl(
+      _$BreezEvent_InvoicePaid _value, $Res Function(_$BreezEvent_InvoicePaid) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_InvoicePaid(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as InvoicePaidDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_InvoicePaid implements BreezEvent_InvoicePaid {
+  const _$BreezEvent_InvoicePaid({required this.details});
+
+  @override
+  final InvoicePaidDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.invoicePaid(details: $details)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_InvoicePaid &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_InvoicePaidCopyWith<_$BreezEvent_InvoicePaid> get copyWith =>
+      __$$BreezEvent_InvoicePaidCopyWithImpl<_$BreezEvent_InvoicePaid>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return invoicePaid(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return invoicePaid?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (invoicePaid != null) {
+      return invoicePaid(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return invoicePaid(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return invoicePaid?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (invoicePaid != null) {
+      return invoicePaid(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_InvoicePaid implements BreezEvent {
+  const factory BreezEvent_InvoicePaid({required final InvoicePaidDetails details}) =
+      _$BreezEvent_InvoicePaid;
+
+  InvoicePaidDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_InvoicePaidCopyWith<_$BreezEvent_InvoicePaid> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_SyncedCopyWith<$Res> {
+  factory _$$BreezEvent_SyncedCopyWith(_$BreezEvent_Synced value, $Res Function(_$BreezEvent_Synced) then) =
+      __$$BreezEvent_SyncedCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BreezEvent_SyncedCopyWithImpl<$Res> extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_Synced>
+    implements _$$BreezEvent_SyncedCopyWith<$Res> {
+  __$$BreezEvent_SyncedCopyWithImpl(_$BreezEvent_Synced _value, $Res Function(_$BreezEvent_Synced) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$BreezEvent_Synced implements BreezEvent_Synced {
+  const _$BreezEvent_Synced();
+
+  @override
+  String toString() {
+    return 'BreezEvent.synced()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$BreezEvent_Synced);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return synced();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return synced?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (synced != null) {
+      return synced();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return synced(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return synced?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (synced != null) {
+      return synced(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_Synced implements BreezEvent {
+  const factory BreezEvent_Synced() = _$BreezEvent_Synced;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_PaymentSucceedCopyWith<$Res> {
+  factory _$$BreezEvent_PaymentSucceedCopyWith(
+          _$BreezEvent_PaymentSucceed value, $Res Function(_$BreezEvent_PaymentSucceed) then) =
+      __$$BreezEvent_PaymentSucceedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$BreezEvent_PaymentSucceedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentSucceed>
+    implements _$$BreezEvent_PaymentSucceedCopyWith<$Res> {
+  __$$BreezEvent_PaymentSucceedCopyWithImpl(
+      _$BreezEvent_PaymentSucceed _value, $Res Function(_$BreezEvent_PaymentSucceed) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_PaymentSucceed(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_PaymentSucceed implements BreezEvent_PaymentSucceed {
+  const _$BreezEvent_PaymentSucceed({required this.details});
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.paymentSucceed(details: $details)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_PaymentSucceed &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_PaymentSucceedCopyWith<_$BreezEvent_PaymentSucceed> get copyWith =>
+      __$$BreezEvent_PaymentSucceedCopyWithImpl<_$BreezEvent_PaymentSucceed>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return paymentSucceed(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return paymentSucceed?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (paymentSucceed != null) {
+      return paymentSucceed(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return paymentSucceed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return paymentSucceed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (paymentSucceed != null) {
+      return paymentSucceed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_PaymentSucceed implements BreezEvent {
+  const factory BreezEvent_PaymentSucceed({required final Payment details}) = _$BreezEvent_PaymentSucceed;
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_PaymentSucceedCopyWith<_$BreezEvent_PaymentSucceed> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_PaymentFailedCopyWith<$Res> {
+  factory _$$BreezEvent_PaymentFailedCopyWith(
+          _$BreezEvent_PaymentFailed value, $Res Function(_$BreezEvent_PaymentFailed) then) =
+      __$$BreezEvent_PaymentFailedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PaymentFailedData details});
+}
+
+/// @nodoc
+class __$$BreezEvent_PaymentFailedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentFailed>
+    implements _$$BreezEvent_PaymentFailedCopyWith<$Res> {
+  __$$BreezEvent_PaymentFailedCopyWithImpl(
+      _$BreezEvent_PaymentFailed _value, $Res Function(_$BreezEvent_PaymentFailed) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_PaymentFailed(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as PaymentFailedData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_PaymentFailed implements BreezEvent_PaymentFailed {
+  const _$BreezEvent_PaymentFailed({required this.details});
+
+  @override
+  final PaymentFailedData details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.paymentFailed(details: $details)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_PaymentFailed &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_PaymentFailedCopyWith<_$BreezEvent_PaymentFailed> get copyWith =>
+      __$$BreezEvent_PaymentFailedCopyWithImpl<_$BreezEvent_PaymentFailed>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return paymentFailed(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return paymentFailed?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (paymentFailed != null) {
+      return paymentFailed(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return paymentFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return paymentFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (paymentFailed != null) {
+      return paymentFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_PaymentFailed implements BreezEvent {
+  const factory BreezEvent_PaymentFailed({required final PaymentFailedData details}) =
+      _$BreezEvent_PaymentFailed;
+
+  PaymentFailedData get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_PaymentFailedCopyWith<_$BreezEvent_PaymentFailed> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_DuplicateIncomingPaymentCopyWith<$Res> {
+  factory _$$BreezEvent_DuplicateIncomingPaymentCopyWith(
+          _$BreezEvent_DuplicateIncomingPayment value,
+          $Res Function(_$BreezEvent_DuplicateIncomingPayment) then) =
+      __$$BreezEvent_DuplicateIncomingPaymentCopyWithImpl<$Res>;
+  @useResult
+  $Res call({InvoicePaidDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_DuplicateIncomingPaymentCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_DuplicateIncomingPayment>
+    implements _$$BreezEvent_DuplicateIncomingPaymentCopyWith<$Res> {
+  __$$BreezEvent_DuplicateIncomingPaymentCopyWithImpl(
+      _$BreezEvent_DuplicateIncomingPayment _value,
+      $Res Function(_$BreezEvent_DuplicateIncomingPayment) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_DuplicateIncomingPayment(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as InvoicePaidDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_DuplicateIncomingPayment implements BreezEvent_DuplicateIncomingPayment {
+  const _$BreezEvent_DuplicateIncomingPayment({required this.details});
+
+  @override
+  final InvoicePaidDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.duplicateIncomingPayment(details: $details)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_DuplicateIncomingPayment &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_DuplicateIncomingPaymentCopyWith<_$BreezEvent_DuplicateIncomingPayment> get copyWith =>
+      __$$BreezEvent_DuplicateIncomingPaymentCopyWithImpl<_$BreezEvent_DuplicateIncomingPayment>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return duplicateIncomingPayment(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return duplicateIncomingPayment?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (duplicateIncomingPayment != null) {
+      return duplicateIncomingPayment(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return duplicateIncomingPayment(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return duplicateIncomingPayment?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (duplicateIncomingPayment != null) {
+      return duplicateIncomingPayment(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_DuplicateIncomingPayment implements BreezEvent {
+  const factory BreezEvent_DuplicateIncomingPayment({required final InvoicePaidDetails details}) =
+      _$BreezEvent_DuplicateIncomingPayment;
+
+  InvoicePaidDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_DuplicateIncomingPaymentCopyWith<_$BreezEvent_DuplicateIncomingPayment> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_LspConnectedCopyWith<$Res> {
+  factory _$$BreezEvent_LspConnectedCopyWith(
+          _$BreezEvent_LspConnected value, $Res Function(_$BreezEvent_LspConnected) then) =
+      __$$BreezEvent_LspConnectedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String lspId});
+}
+
+/// @nodoc
+class __$$BreezEvent_LspConnectedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_LspConnected>
+    implements _$$BreezEvent_LspConnectedCopyWith<$Res> {
+  __$$BreezEvent_LspConnectedCopyWithImpl(
+      _$BreezEvent_LspConnected _value, $Res Function(_$BreezEvent_LspConnected) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? lspId = null,
+  }) {
+    return _then(_$BreezEvent_LspConnected(
+      lspId: null == lspId
+          ? _value.lspId
+          : lspId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_LspConnected implements BreezEvent_LspConnected {
+  const _$BreezEvent_LspConnected({required this.lspId});
+
+  @override
+  final String lspId;
+
+  @override
+  String toString() {
+    return 'BreezEvent.lspConnected(lspId: $lspId)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_LspConnected &&
+            (identical(other.lspId, lspId) || other.lspId == lspId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, lspId);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_LspConnectedCopyWith<_$BreezEvent_LspConnected> get copyWith =>
+      __$$BreezEvent_LspConnectedCopyWithImpl<_$BreezEvent_LspConnected>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return lspConnected(lspId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return lspConnected?.call(lspId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (lspConnected != null) {
+      return lspConnected(lspId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return lspConnected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return lspConnected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (lspConnected != null) {
+      return lspConnected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_LspConnected implements BreezEvent {
+  const factory BreezEvent_LspConnected({required final String lspId}) = _$BreezEvent_LspConnected;
+
+  String get lspId;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_LspConnectedCopyWith<_$BreezEvent_LspConnected> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_PaymentPendingCopyWith<$Res> {
+  factory _$$BreezEvent_PaymentPendingCopyWith(
+          _$BreezEvent_PaymentPending value, $Res Function(_$BreezEvent_PaymentPending) then) =
+      __$$BreezEvent_PaymentPendingCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PaymentPendingData details});
+}
+
+/// @nodoc
+class __$$BreezEvent_PaymentPendingCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentPending>
+    implements _$$BreezEvent_PaymentPendingCopyWith<$Res> {
+  __$$BreezEvent_PaymentPendingCopyWithImpl(
+      _$BreezEvent_PaymentPending _value, $Res Function(_$BreezEvent_PaymentPending) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_PaymentPending(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as PaymentPendingData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_PaymentPending implements BreezEvent_PaymentPending {
+  const _$BreezEvent_PaymentPending({required this.details});
+
+  @override
+  final PaymentPendingData details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.paymentPending(details: $details)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_PaymentPending &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_PaymentPendingCopyWith<_$BreezEvent_PaymentPending> get copyWith =>
+      __$$BreezEvent_PaymentPendingCopyWithImpl<_$BreezEvent_PaymentPending>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return paymentPending(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return paymentPending?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (paymentPending != null) {
+      return paymentPending(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return paymentPending(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return paymentPending?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (paymentPending != null) {
+      return paymentPending(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_PaymentPending implements BreezEvent {
+  const factory BreezEvent_PaymentPending({required final PaymentPendingData details}) =
+      _$BreezEvent_PaymentPending;
+
+  PaymentPendingData get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_PaymentPendingCopyWith<_$BreezEvent_PaymentPending> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_SwapUpdatedCopyWith<$Res> {
+  factory _$$BreezEvent_SwapUpdatedCopyWith(
+          _$BreezEvent_SwapUpdated value, $Res Function(_$BreezEvent_SwapUpdated) then) =
+      __$$BreezEvent_SwapUpdatedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({SwapInfo details});
+}
+
+/// @nodoc
+class __$$BreezEvent_SwapUpdatedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SwapUpdated>
+    implements _$$BreezEvent_SwapUpdatedCopyWith<$Res> {
+  __$$BreezEvent_SwapUpdatedCopyWithImpl(
+      _$BreezEvent_SwapUpdated _value, $Res Function(_$BreezEvent_SwapUpdated) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_SwapUpdated(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as SwapInfo,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_SwapUpdated implements BreezEvent_SwapUpdated {
+  const _$BreezEvent_SwapUpdated({required this.details});
+
+  @override
+  final SwapInfo details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.swapUpdated(details: $details)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_SwapUpdated &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_SwapUpdatedCopyWith<_$BreezEvent_SwapUpdated> get copyWith =>
+      __$$BreezEvent_SwapUpdatedCopyWithImpl<_$BreezEvent_SwapUpdated>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return swapUpdated(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return swapUpdated?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (swapUpdated != null) {
+      return swapUpdated(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return swapUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return swapUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (swapUpdated != null) {
+      return swapUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_SwapUpdated implements BreezEvent {
+  const factory BreezEvent_SwapUpdated({required final SwapInfo details}) = _$BreezEvent_SwapUpdated;
+
+  SwapInfo get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_SwapUpdatedCopyWith<_$BreezEvent_SwapUpdated> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ConnectionStatusChangedCopyWith<$Res> {
+  factory _$$BreezEvent_ConnectionStatusChangedCopyWith(
+          _$BreezEvent_ConnectionStatusChanged value,
+          $Res Function(_$BreezEvent_ConnectionStatusChanged) then) =
+      __$$BreezEvent_ConnectionStatusChangedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({bool connected});
+}
+
+/// @nodoc
+class __$$BreezEvent_ConnectionStatusChangedCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ConnectionStatusChanged>
+    implements _$$BreezEvent_ConnectionStatusChangedCopyWith<$Res> {
+  __$$BreezEvent_ConnectionStatusChangedCopyWithImpl(
+      _$BreezEvent_ConnectionStatusChanged _value, $Res Function(_$BreezEvent_ConnectionStatusChanged) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? connected = null,
+  }) {
+    return _then(_$BreezEvent_ConnectionStatusChanged(
+      connected: null == connected
+          ? _value.connected
+          : connected // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ConnectionStatusChanged implements BreezEvent_ConnectionStatusChanged {
+  const _$BreezEvent_ConnectionStatusChanged({required this.connected});
+
+  @override
+  final bool connected;
+
+  @override
+  String toString() {
+    return 'BreezEvent.connectionStatusChanged(connected: $connected)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ConnectionStatusChanged &&
+            (identical(other.connected, connected) || other.connected == connected));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, connected);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ConnectionStatusChangedCopyWith<_$BreezEvent_ConnectionStatusChanged> get copyWith =>
+      __$$BreezEvent_ConnectionStatusChangedCopyWithImpl<_$BreezEvent_ConnectionStatusChanged>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return connectionStatusChanged(connected);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return connectionStatusChanged?.call(connected);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (connectionStatusChanged != null) {
+      return connectionStatusChanged(connected);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return connectionStatusChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return connectionStatusChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (connectionStatusChanged != null) {
+      return connectionStatusChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ConnectionStatusChanged implements BreezEvent {
+  const factory BreezEvent_ConnectionStatusChanged({required final bool connected}) =
+      _$BreezEvent_ConnectionStatusChanged;
+
+  bool get connected;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ConnectionStatusChangedCopyWith<_$BreezEvent_ConnectionStatusChanged> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_SwapOverpaidCopyWith<$Res> {
+  factory _$$BreezEvent_SwapOverpaidCopyWith(
+          _$BreezEvent_SwapOverpaid value, $Res Function(_$BreezEvent_SwapOverpaid) then) =
+      __$$BreezEvent_SwapOverpaidCopyWithImpl<$Res>;
+  @useResult
+  $Res call({SwapInfo details, OverpaidSwapHandling handling});
+}
+
+/// @nodoc
+class __$$BreezEvent_SwapOverpaidCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SwapOverpaid>
+    implements _$$BreezEvent_SwapOverpaidCopyWith<$Res> {
+  __$$BreezEvent_SwapOverpaidCopyWithImpl(
+      _$BreezEvent_SwapOverpaid _value, $Res Function(_$BreezEvent_SwapOverpaid) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+    Object? handling = null,
+  }) {
+    return _then(_$BreezEvent_SwapOverpaid(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as SwapInfo,
+      handling: null == handling
+          ? _value.handling
+          : handling // ignore: cast_nullable_to_non_nullable
+              as OverpaidSwapHandling,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_SwapOverpaid implements BreezEvent_SwapOverpaid {
+  const _$BreezEvent_SwapOverpaid({required this.details, required this.handling});
+
+  @override
+  final SwapInfo details;
+  @override
+  final OverpaidSwapHandling handling;
+
+  @override
+  String toString() {
+    return 'BreezEvent.swapOverpaid(details: $details, handling: $handling)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_SwapOverpaid &&
+            (identical(other.details, details) || other.details == details) &&
+            (identical(other.handling, handling) || other.handling == handling));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details, handling);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_SwapOverpaidCopyWith<_$BreezEvent_SwapOverpaid> get copyWith =>
+      __$$BreezEvent_SwapOverpaidCopyWithImpl<_$BreezEvent_SwapOverpaid>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return swapOverpaid(details, handling);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return swapOverpaid?.call(details, handling);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (swapOverpaid != null) {
+      return swapOverpaid(details, handling);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return swapOverpaid(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return swapOverpaid?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (swapOverpaid != null) {
+      return swapOverpaid(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_SwapOverpaid implements BreezEvent {
+  const factory BreezEvent_SwapOverpaid(
+      {required final SwapInfo details,
+      required final OverpaidSwapHandling handling}) = _$BreezEvent_SwapOverpaid;
+
+  SwapInfo get details;
+  OverpaidSwapHandling get handling;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_SwapOverpaidCopyWith<_$BreezEvent_SwapOverpaid> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_SwapsReconciledCopyWith<$Res> {
+  factory _$$BreezEvent_SwapsReconciledCopyWith(
+          _$BreezEvent_SwapsReconciled value, $Res Function(_$BreezEvent_SwapsReconciled) then) =
+      __$$BreezEvent_SwapsReconciledCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<SwapInfo> expired});
+}
+
+/// @nodoc
+class __$$BreezEvent_SwapsReconciledCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SwapsReconciled>
+    implements _$$BreezEvent_SwapsReconciledCopyWith<$Res> {
+  __$$BreezEvent_SwapsReconciledCopyWithImpl(
+      _$BreezEvent_SwapsReconciled _value, $Res Function(_$BreezEvent_SwapsReconciled) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? expired = null,
+  }) {
+    return _then(_$BreezEvent_SwapsReconciled(
+      expired: null == expired
+          ? _value._expired
+          : expired // ignore: cast_nullable_to_non_nullable
+              as List<SwapInfo>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_SwapsReconciled implements BreezEvent_SwapsReconciled {
+  const _$BreezEvent_SwapsReconciled({required final List<SwapInfo> expired}) : _expired = expired;
+
+  final List<SwapInfo> _expired;
+  @override
+  List<SwapInfo> get expired {
+    if (_expired is EqualUnmodifiableListView) return _expired;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_expired);
+  }
+
+  @override
+  String toString() {
+    return 'BreezEvent.swapsReconciled(expired: $expired)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_SwapsReconciled &&
+            const DeepCollectionEquality().equals(other._expired, _expired));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(_expired));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_SwapsReconciledCopyWith<_$BreezEvent_SwapsReconciled> get copyWith =>
+      __$$BreezEvent_SwapsReconciledCopyWithImpl<_$BreezEvent_SwapsReconciled>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return swapsReconciled(expired);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return swapsReconciled?.call(expired);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (swapsReconciled != null) {
+      return swapsReconciled(expired);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return swapsReconciled(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return swapsReconciled?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (swapsReconciled != null) {
+      return swapsReconciled(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_SwapsReconciled implements BreezEvent {
+  const factory BreezEvent_SwapsReconciled({required final List<SwapInfo> expired}) =
+      _$BreezEvent_SwapsReconciled;
+
+  List<SwapInfo> get expired;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_SwapsReconciledCopyWith<_$BreezEvent_SwapsReconciled> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_SyncProgressCopyWith<$Res> {
+  factory _$$BreezEvent_SyncProgressCopyWith(
+          _$BreezEvent_SyncProgress value, $Res Function(_$BreezEvent_SyncProgress) then) =
+      __$$BreezEvent_SyncProgressCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int processed, int total});
+}
+
+/// @nodoc
+class __$$BreezEvent_SyncProgressCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SyncProgress>
+    implements _$$BreezEvent_SyncProgressCopyWith<$Res> {
+  __$$BreezEvent_SyncProgressCopyWithImpl(
+      _$BreezEvent_SyncProgress _value, $Res Function(_$BreezEvent_SyncProgress) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? processed = null,
+    Object? total = null,
+  }) {
+    return _then(_$BreezEvent_SyncProgress(
+      processed: null == processed
+          ? _value.processed
+          : processed // ignore: cast_nullable_to_non_nullable
+              as int,
+      total: null == total
+          ? _value.total
+          : total // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_SyncProgress implements BreezEvent_SyncProgress {
+  const _$BreezEvent_SyncProgress({required this.processed, required this.total});
+
+  @override
+  final int processed;
+  @override
+  final int total;
+
+  @override
+  String toString() {
+    return 'BreezEvent.syncProgress(processed: $processed, total: $total)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_SyncProgress &&
+            (identical(other.processed, processed) || other.processed == processed) &&
+            (identical(other.total, total) || other.total == total));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, processed, total);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_SyncProgressCopyWith<_$BreezEvent_SyncProgress> get copyWith =>
+      __$$BreezEvent_SyncProgressCopyWithImpl<_$BreezEvent_SyncProgress>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function(InvoicePaidDetails details) duplicateIncomingPayment,
+    required TResult Function(String lspId) lspConnected,
+    required TResult Function(PaymentPendingData details) paymentPending,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(bool connected) connectionStatusChanged,
+    required TResult Function(SwapInfo details, OverpaidSwapHandling handling) swapOverpaid,
+    required TResult Function(List<SwapInfo> expired) swapsReconciled,
+    required TResult Function(int processed, int total) syncProgress,
+  }) {
+    return syncProgress(processed, total);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult? Function(String lspId)? lspConnected,
+    TResult? Function(PaymentPendingData details)? paymentPending,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(bool connected)? connectionStatusChanged,
+    TResult? Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult? Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult? Function(int processed, int total)? syncProgress,
+  }) {
+    return syncProgress?.call(processed, total);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function(InvoicePaidDetails details)? duplicateIncomingPayment,
+    TResult Function(String lspId)? lspConnected,
+    TResult Function(PaymentPendingData details)? paymentPending,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(bool connected)? connectionStatusChanged,
+    TResult Function(SwapInfo details, OverpaidSwapHandling handling)? swapOverpaid,
+    TResult Function(List<SwapInfo> expired)? swapsReconciled,
+    TResult Function(int processed, int total)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (syncProgress != null) {
+      return syncProgress(processed, total);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_DuplicateIncomingPayment value) duplicateIncomingPayment,
+    required TResult Function(BreezEvent_LspConnected value) lspConnected,
+    required TResult Function(BreezEvent_PaymentPending value) paymentPending,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectionStatusChanged value) connectionStatusChanged,
+    required TResult Function(BreezEvent_SwapOverpaid value) swapOverpaid,
+    required TResult Function(BreezEvent_SwapsReconciled value) swapsReconciled,
+    required TResult Function(BreezEvent_SyncProgress value) syncProgress,
+  }) {
+    return syncProgress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult? Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult? Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult? Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult? Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult? Function(BreezEvent_SyncProgress value)? syncProgress,
+  }) {
+    return syncProgress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_DuplicateIncomingPayment value)? duplicateIncomingPayment,
+    TResult Function(BreezEvent_LspConnected value)? lspConnected,
+    TResult Function(BreezEvent_PaymentPending value)? paymentPending,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectionStatusChanged value)? connectionStatusChanged,
+    TResult Function(BreezEvent_SwapOverpaid value)? swapOverpaid,
+    TResult Function(BreezEvent_SwapsReconciled value)? swapsReconciled,
+    TResult Function(BreezEvent_SyncProgress value)? syncProgress,
+    required TResult orElse(),
+  }) {
+    if (syncProgress != null) {
+      return syncProgress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_SyncProgress implements BreezEvent {
+  const factory BreezEvent_SyncProgress({required final int processed, required final int total}) =
+      _$BreezEvent_SyncProgress;
+
+  int get processed;
+  int get total;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_SyncProgressCopyWith<_$BreezEvent_SyncProgress> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$FeeTier {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() fastest,
+    required TResult Function() halfHour,
+    required TResult Function() hour,
+    required TResult Function() economy,
+    required TResult Function() minimum,
+    required TResult Function(int satPerVbyte) custom,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? fastest,
+    TResult? Function()? halfHour,
+    TResult? Function()? hour,
+    TResult? Function()? economy,
+    TResult? Function()? minimum,
+    TResult? Function(int satPerVbyte)? custom,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? fastest,
+    TResult Function()? halfHour,
+    TResult Function()? hour,
+    TResult Function()? economy,
+    TResult Function()? minimum,
+    TResult Function(int satPerVbyte)? custom,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FeeTier_Fastest value) fastest,
+    required TResult Function(FeeTier_HalfHour value) halfHour,
+    required TResult Function(FeeTier_Hour value) hour,
+    required TResult Function(FeeTier_Economy value) economy,
+    required TResult Function(FeeTier_Minimum value) minimum,
+    required TResult Function(FeeTier_Custom value) custom,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FeeTier_Fastest value)? fastest,
+    TResult? Function(FeeTier_HalfHour value)? halfHour,
+    TResult? Function(FeeTier_Hour value)? hour,
+    TResult? Function(FeeTier_Economy value)? economy,
+    TResult? Function(FeeTier_Minimum value)? minimum,
+    TResult? Function(FeeTier_Custom value)? custom,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FeeTier_Fastest value)? fastest,
+    TResult Function(FeeTier_HalfHour value)? halfHour,
+    TResult Function(FeeTier_Hour value)? hour,
+    TResult Function(FeeTier_Economy value)? economy,
+    TResult Function(FeeTier_Minimum value)? minimum,
+    TResult Function(FeeTier_Custom value)? custom,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $FeeTierCopyWith<$Res> {
+  factory $FeeTierCopyWith(FeeTier value, $Res Function(FeeTier) then) = _$FeeTierCopyWithImpl<$Res, FeeTier>;
+}
+
+/// @nodoc
+class _$FeeTierCopyWithImpl<$Res, $Val extends FeeTier> implements $FeeTierCopyWith<$Res> {
+  _$FeeTierCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$FeeTier_FastestCopyWith<$Res> {
+  factory _$$FeeTier_FastestCopyWith(_$FeeTier_Fastest value, $Res Function(_$FeeTier_Fastest) then) =
+      __$$FeeTier_FastestCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FeeTier_FastestCopyWithImpl<$Res> extends _$FeeTierCopyWithImpl<$Res, _$FeeTier_Fastest>
+    implements _$$FeeTier_FastestCopyWith<$Res> {
+  __$$FeeTier_FastestCopyWithImpl(_$FeeTier_Fastest _value, $Res Function(_$FeeTier_Fastest) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$FeeTier_Fastest implements FeeTier_Fastest {
+  const _$FeeTier_Fastest();
+
+  @override
+  String toString() {
+    return 'FeeTier.fastest()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$FeeTier_Fastest);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() fastest,
+    required TResult Function() halfHour,
+    required TResult Function() hour,
+    required TResult Function() economy,
+    required TResult Function() minimum,
+    required TResult Function(int satPerVbyte) custom,
+  }) {
+    return fastest();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? fastest,
+    TResult? Function()? halfHour,
+    TResult? Function()? hour,
+    TResult? Function()? economy,
+    TResult? Function()? minimum,
+    TResult? Function(int satPerVbyte)? custom,
+  }) {
+    return fastest?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? fastest,
+    TResult Function()? halfHour,
+    TResult Function()? hour,
+    TResult Function()? economy,
+    TResult Function()? minimum,
+    TResult Function(int satPerVbyte)? custom,
+    required TResult orElse(),
+  }) {
+    if (fastest != null) {
+      return fastest();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FeeTier_Fastest value) fastest,
+    required TResult Function(FeeTier_HalfHour value) halfHour,
+    required TResult Function(FeeTier_Hour value) hour,
+    required TResult Function(FeeTier_Economy value) economy,
+    required TResult Function(FeeTier_Minimum value) minimum,
+    required TResult Function(FeeTier_Custom value) custom,
+  }) {
+    return fastest(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FeeTier_Fastest value)? fastest,
+    TResult? Function(FeeTier_HalfHour value)? halfHour,
+    TResult? Function(FeeTier_Hour value)? hour,
+    TResult? Function(FeeTier_Economy value)? economy,
+    TResult? Function(FeeTier_Minimum value)? minimum,
+    TResult? Function(FeeTier_Custom value)? custom,
+  }) {
+    return fastest?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FeeTier_Fastest value)? fastest,
+    TResult Function(FeeTier_HalfHour value)? halfHour,
+    TResult Function(FeeTier_Hour value)? hour,
+    TResult Function(FeeTier_Economy value)? economy,
+    TResult Function(FeeTier_Minimum value)? minimum,
+    TResult Function(FeeTier_Custom value)? custom,
+    required TResult orElse(),
+  }) {
+    if (fastest != null) {
+      return fastest(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FeeTier_Fastest implements FeeTier {
+  const factory FeeTier_Fastest() = _$FeeTier_Fastest;
+}
+
+/// @nodoc
+abstract class _$$FeeTier_HalfHourCopyWith<$Res> {
+  factory _$$FeeTier_HalfHourCopyWith(_$FeeTier_HalfHour value, $Res Function(_$FeeTier_HalfHour) then) =
+      __$$FeeTier_HalfHourCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FeeTier_HalfHourCopyWithImpl<$Res> extends _$FeeTierCopyWithImpl<$Res, _$FeeTier_HalfHour>
+    implements _$$FeeTier_HalfHourCopyWith<$Res> {
+  __$$FeeTier_HalfHourCopyWithImpl(_$FeeTier_HalfHour _value, $Res Function(_$FeeTier_HalfHour) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$FeeTier_HalfHour implements FeeTier_HalfHour {
+  const _$FeeTier_HalfHour();
+
+  @override
+  String toString() {
+    return 'FeeTier.halfHour()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$FeeTier_HalfHour);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() fastest,
+    required TResult Function() halfHour,
+    required TResult Function() hour,
+    required TResult Function() economy,
+    required TResult Function() minimum,
+    required TResult Function(int satPerVbyte) custom,
+  }) {
+    return halfHour();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? fastest,
+    TResult? Function()? halfHour,
+    TResult? Function()? hour,
+    TResult? Function()? economy,
+    TResult? Function()? minimum,
+    TResult? Function(int satPerVbyte)? custom,
+  }) {
+    return halfHour?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? fastest,
+    TResult Function()? halfHour,
+    TResult Function()? hour,
+    TResult Function()? economy,
+    TResult Function()? minimum,
+    TResult Function(int satPerVbyte)? custom,
+    required TResult orElse(),
+  }) {
+    if (halfHour != null) {
+      return halfHour();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FeeTier_Fastest value) fastest,
+    required TResult Function(FeeTier_HalfHour value) halfHour,
+    required TResult Function(FeeTier_Hour value) hour,
+    required TResult Function(FeeTier_Economy value) economy,
+    required TResult Function(FeeTier_Minimum value) minimum,
+    required TResult Function(FeeTier_Custom value) custom,
+  }) {
+    return halfHour(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FeeTier_Fastest value)? fastest,
+    TResult? Function(FeeTier_HalfHour value)? halfHour,
+    TResult? Function(FeeTier_Hour value)? hour,
+    TResult? Function(FeeTier_Economy value)? economy,
+    TResult? Function(FeeTier_Minimum value)? minimum,
+    TResult? Function(FeeTier_Custom value)? custom,
+  }) {
+    return halfHour?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FeeTier_Fastest value)? fastest,
+    TResult Function(FeeTier_HalfHour value)? halfHour,
+    TResult Function(FeeTier_Hour value)? hour,
+    TResult Function(FeeTier_Economy value)? economy,
+    TResult Function(FeeTier_Minimum value)? minimum,
+    TResult Function(FeeTier_Custom value)? custom,
+    required TResult orElse(),
+  }) {
+    if (halfHour != null) {
+      return halfHour(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FeeTier_HalfHour implements FeeTier {
+  const factory FeeTier_HalfHour() = _$FeeTier_HalfHour;
+}
+
+/// @nodoc
+abstract class _$$FeeTier_HourCopyWith<$Res> {
+  factory _$$FeeTier_HourCopyWith(_$FeeTier_Hour value, $Res Function(_$FeeTier_Hour) then) =
+      __$$FeeTier_HourCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FeeTier_HourCopyWithImpl<$Res> extends _$FeeTierCopyWithImpl<$Res, _$FeeTier_Hour>
+    implements _$$FeeTier_HourCopyWith<$Res> {
+  __$$FeeTier_HourCopyWithImpl(_$FeeTier_Hour _value, $Res Function(_$FeeTier_Hour) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$FeeTier_Hour implements FeeTier_Hour {
+  const _$FeeTier_Hour();
+
+  @override
+  String toString() {
+    return 'FeeTier.hour()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$FeeTier_Hour);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() fastest,
+    required TResult Function() halfHour,
+    required TResult Function() hour,
+    required TResult Function() economy,
+    required TResult Function() minimum,
+    required TResult Function(int satPerVbyte) custom,
+  }) {
+    return hour();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? fastest,
+    TResult? Function()? halfHour,
+    TResult? Function()? hour,
+    TResult? Function()? economy,
+    TResult? Function()? minimum,
+    TResult? Function(int satPerVbyte)? custom,
+  }) {
+    return hour?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? fastest,
+    TResult Function()? halfHour,
+    TResult Function()? hour,
+    TResult Function()? economy,
+    TResult Function()? minimum,
+    TResult Function(int satPerVbyte)? custom,
+    required TResult orElse(),
+  }) {
+    if (hour != null) {
+      return hour();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FeeTier_Fastest value) fastest,
+    required TResult Function(FeeTier_HalfHour value) halfHour,
+    required TResult Function(FeeTier_Hour value) hour,
+    required TResult Function(FeeTier_Economy value) economy,
+    required TResult Function(FeeTier_Minimum value) minimum,
+    required TResult Function(FeeTier_Custom value) custom,
+  }) {
+    return hour(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FeeTier_Fastest value)? fastest,
+    TResult? Function(FeeTier_HalfHour value)? halfHour,
+    TResult? Function(FeeTier_Hour value)? hour,
+    TResult? Function(FeeTier_Economy value)? economy,
+    TResult? Function(FeeTier_Minimum value)? minimum,
+    TResult? Function(FeeTier_Custom value)? custom,
+  }) {
+    return hour?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FeeTier_Fastest value)? fastest,
+    TResult Function(FeeTier_HalfHour value)? halfHour,
+    TResult Function(FeeTier_Hour value)? hour,
+    TResult Function(FeeTier_Economy value)? economy,
+    TResult Function(FeeTier_Minimum value)? minimum,
+    TResult Function(FeeTier_Custom value)? custom,
+    required TResult orElse(),
+  }) {
+    if (hour != null) {
+      return hour(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FeeTier_Hour implements FeeTier {
+  const factory FeeTier_Hour() = _$FeeTier_Hour;
+}
+
+/// @nodoc
+abstract class _$$FeeTier_EconomyCopyWith<$Res> {
+  factory _$$FeeTier_EconomyCopyWith(_$FeeTier_Economy value, $Res Function(_$FeeTier_Economy) then) =
+      __$$FeeTier_EconomyCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FeeTier_EconomyCopyWithImpl<$Res> extends _$FeeTierCopyWithImpl<$Res, _$FeeTier_Economy>
+    implements _$$FeeTier_EconomyCopyWith<$Res> {
+  __$$FeeTier_EconomyCopyWithImpl(_$FeeTier_Economy _value, $Res Function(_$FeeTier_Economy) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$FeeTier_Economy implements FeeTier_Economy {
+  const _$FeeTier_Economy();
+
+  @override
+  String toString() {
+    return 'FeeTier.economy()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$FeeTier_Economy);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() fastest,
+    required TResult Function() halfHour,
+    required TResult Function() hour,
+    required TResult Function() economy,
+    required TResult Function() minimum,
+    required TResult Function(int satPerVbyte) custom,
+  }) {
+    return economy();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? fastest,
+    TResult? Function()? halfHour,
+    TResult? Function()? hour,
+    TResult? Function()? economy,
+    TResult? Function()? minimum,
+    TResult? Function(int satPerVbyte)? custom,
+  }) {
+    return economy?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? fastest,
+    TResult Function()? halfHour,
+    TResult Function()? hour,
+    TResult Function()? economy,
+    TResult Function()? minimum,
+    TResult Function(int satPerVbyte)? custom,
+    required TResult orElse(),
+  }) {
+    if (economy != null) {
+      return economy();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FeeTier_Fastest value) fastest,
+    required TResult Function(FeeTier_HalfHour value) halfHour,
+    required TResult Function(FeeTier_Hour value) hour,
+    required TResult Function(FeeTier_Economy value) economy,
+    required TResult Function(FeeTier_Minimum value) minimum,
+    required TResult Function(FeeTier_Custom value) custom,
+  }) {
+    return economy(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FeeTier_Fastest value)? fastest,
+    TResult? Function(FeeTier_HalfHour value)? halfHour,
+    TResult? Function(FeeTier_Hour value)? hour,
+    TResult? Function(FeeTier_Economy value)? economy,
+    TResult? Function(FeeTier_Minimum value)? minimum,
+    TResult? Function(FeeTier_Custom value)? custom,
+  }) {
+    return economy?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FeeTier_Fastest value)? fastest,
+    TResult Function(FeeTier_HalfHour value)? halfHour,
+    TResult Function(FeeTier_Hour value)? hour,
+    TResult Function(FeeTier_Economy value)? economy,
+    TResult Function(FeeTier_Minimum value)? minimum,
+    TResult Function(FeeTier_Custom value)? custom,
+    required TResult orElse(),
+  }) {
+    if (economy != null) {
+      return economy(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FeeTier_Economy implements FeeTier {
+  const factory FeeTier_Economy() = _$FeeTier_Economy;
+}
+
+/// @nodoc
+abstract class _$$FeeTier_MinimumCopyWith<$Res> {
+  factory _$$FeeTier_MinimumCopyWith(_$FeeTier_Minimum value, $Res Function(_$FeeTier_Minimum) then) =
+      __$$FeeTier_MinimumCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FeeTier_MinimumCopyWithImpl<$Res> extends _$FeeTierCopyWithImpl<$Res, _$FeeTier_Minimum>
+    implements _$$FeeTier_MinimumCopyWith<$Res> {
+  __$$FeeTier_MinimumCopyWithImpl(_$FeeTier_Minimum _value, $Res Function(_$FeeTier_Minimum) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$FeeTier_Minimum implements FeeTier_Minimum {
+  const _$FeeTier_Minimum();
+
+  @override
+  String toString() {
+    return 'FeeTier.minimum()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$FeeTier_Minimum);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() fastest,
+    required TResult Function() halfHour,
+    required TResult Function() hour,
+    required TResult Function() economy,
+    required TResult Function() minimum,
+    required TResult Function(int satPerVbyte) custom,
+  }) {
+    return minimum();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? fastest,
+    TResult? Function()? halfHour,
+    TResult? Function()? hour,
+    TResult? Function()? economy,
+    TResult? Function()? minimum,
+    TResult? Function(int satPerVbyte)? custom,
+  }) {
+    return minimum?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? fastest,
+    TResult Function()? halfHour,
+    TResult Function()? hour,
+    TResult Function()? economy,
+    TResult Function()? minimum,
+    TResult Function(int satPerVbyte)? custom,
+    required TResult orElse(),
+  }) {
+    if (minimum != null) {
+      return minimum();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FeeTier_Fastest value) fastest,
+    required TResult Function(FeeTier_HalfHour value) halfHour,
+    required TResult Function(FeeTier_Hour value) hour,
+    required TResult Function(FeeTier_Economy value) economy,
+    required TResult Function(FeeTier_Minimum value) minimum,
+    required TResult Function(FeeTier_Custom value) custom,
+  }) {
+    return minimum(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FeeTier_Fastest value)? fastest,
+    TResult? Function(FeeTier_HalfHour value)? halfHour,
+    TResult? Function(FeeTier_Hour value)? hour,
+    TResult? Function(FeeTier_Economy value)? economy,
+    TResult? Function(FeeTier_Minimum value)? minimum,
+    TResult? Function(FeeTier_Custom value)? custom,
+  }) {
+    return minimum?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FeeTier_Fastest value)? fastest,
+    TResult Function(FeeTier_HalfHour value)? halfHour,
+    TResult Function(FeeTier_Hour value)? hour,
+    TResult Function(FeeTier_Economy value)? economy,
+    TResult Function(FeeTier_Minimum value)? minimum,
+    TResult Function(FeeTier_Custom value)? custom,
+    required TResult orElse(),
+  }) {
+    if (minimum != null) {
+      return minimum(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FeeTier_Minimum implements FeeTier {
+  const factory FeeTier_Minimum() = _$FeeTier_Minimum;
+}
+
+/// @nodoc
+abstract class _$$FeeTier_CustomCopyWith<$Res> {
+  factory _$$FeeTier_CustomCopyWith(_$FeeTier_Custom value, $Res Function(_$FeeTier_Custom) then) =
+      __$$FeeTier_CustomCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int satPerVbyte});
+}
+
+/// @nodoc
+class __$$FeeTier_CustomCopyWithImpl<$Res> extends _$FeeTierCopyWithImpl<$Res, _$FeeTier_Custom>
+    implements _$$FeeTier_CustomCopyWith<$Res> {
+  __$$FeeTier_CustomCopyWithImpl(_$FeeTier_Custom _value, $Res Function(_$FeeTier_Custom) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? satPerVbyte = null,
+  }) {
+    return _then(_$FeeTier_Custom(
+      satPerVbyte: null == satPerVbyte
+          ? _value.satPerVbyte
+          : satPerVbyte // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$FeeTier_Custom implements FeeTier_Custom {
+  const _$FeeTier_Custom({required this.satPerVbyte});
+
+  @override
+  final int satPerVbyte;
+
+  @override
+  String toString() {
+    return 'FeeTier.custom(satPerVbyte: $satPerVbyte)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FeeTier_Custom &&
+            (identical(other.satPerVbyte, satPerVbyte) || other.satPerVbyte == satPerVbyte));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, satPerVbyte);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FeeTier_CustomCopyWith<_$FeeTier_Custom> get copyWith =>
+      __$$FeeTier_CustomCopyWithImpl<_$FeeTier_Custom>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() fastest,
+    required TResult Function() halfHour,
+    required TResult Function() hour,
+    required TResult Function() economy,
+    required TResult Function() minimum,
+    required TResult Function(int satPerVbyte) custom,
+  }) {
+    return custom(satPerVbyte);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? fastest,
+    TResult? Function()? halfHour,
+    TResult? Function()? hour,
+    TResult? Function()? economy,
+    TResult? Function()? minimum,
+    TResult? Function(int satPerVbyte)? custom,
+  }) {
+    return custom?.call(satPerVbyte);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? fastest,
+    TResult Function()? halfHour,
+    TResult Function()? hour,
+    TResult Function()? economy,
+    TResult Function()? minimum,
+    TResult Function(int satPerVbyte)? custom,
+    required TResult orElse(),
+  }) {
+    if (custom != null) {
+      return custom(satPerVbyte);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FeeTier_Fastest value) fastest,
+    required TResult Function(FeeTier_HalfHour value) halfHour,
+    required TResult Function(FeeTier_Hour value) hour,
+    required TResult Function(FeeTier_Economy value) economy,
+    required TResult Function(FeeTier_Minimum value) minimum,
+    required TResult Function(FeeTier_Custom value) custom,
+  }) {
+    return custom(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FeeTier_Fastest value)? fastest,
+    TResult? Function(FeeTier_HalfHour value)? halfHour,
+    TResult? Function(FeeTier_Hour value)? hour,
+    TResult? Function(FeeTier_Economy value)? economy,
+    TResult? Function(FeeTier_Minimum value)? minimum,
+    TResult? Function(FeeTier_Custom value)? custom,
+  }) {
+    return custom?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FeeTier_Fastest value)? fastest,
+    TResult Function(FeeTier_HalfHour value)? halfHour,
+    TResult Function(FeeTier_Hour value)? hour,
+    TResult Function(FeeTier_Economy value)? economy,
+    TResult Function(FeeTier_Minimum value)? minimum,
+    TResult Function(FeeTier_Custom value)? custom,
+    required TResult orElse(),
+  }) {
+    if (custom != null) {
+      return custom(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FeeTier_Custom implements FeeTier {
+  const factory FeeTier_Custom({required final int satPerVbyte}) = _$FeeTier_Custom;
+
+  int get satPerVbyte;
+  @JsonKey(ignore: true)
+  _$$FeeTier_CustomCopyWith<_$FeeTier_Custom> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$InputType {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(LnUrlPayRequestData data) lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data)? lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data)? lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $InputTypeCopyWith<$Res> {
+  factory $InputTypeCopyWith(InputType value, $Res Function(InputType) then) =
+      _$InputTypeCopyWithImpl<$Res, InputType>;
+}
+
+/// @nodoc
+class _$InputTypeCopyWithImpl<$Res, $Val extends InputType> implements $InputTypeCopyWith<$Res> {
+  _$InputTypeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$InputType_BitcoinAddressCopyWith<$Res> {
+  factory _$$InputType_BitcoinAddressCopyWith(
+          _$InputType_BitcoinAddress value, $Res Function(_$InputType_BitcoinAddress) then) =
+      __$$InputType_BitcoinAddressCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BitcoinAddressData address});
+}
+
+/// @nodoc
+class __$$InputType_BitcoinAddressCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_BitcoinAddress>
+    implements _$$InputType_BitcoinAddressCopyWith<$Res> {
+  __$$InputType_BitcoinAddressCopyWithImpl(
+      _$InputType_BitcoinAddress _value, $Res Function(_$InputType_BitcoinAddress) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? address = null,
+  }) {
+    return _then(_$InputType_BitcoinAddress(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as BitcoinAddressData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_BitcoinAddress implements InputType_BitcoinAddress {
+  const _$InputType_BitcoinAddress({required this.address});
+
+  @override
+  final BitcoinAddressData address;
+
+  @override
+  String toString() {
+    return 'InputType.bitcoinAddress(address: $address)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_BitcoinAddress &&
+            (identical(other.address, address) || other.address == address));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, address);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_BitcoinAddressCopyWith<_$InputType_BitcoinAddress> get copyWith =>
+      __$$InputType_BitcoinAddressCopyWithImpl<_$InputType_BitcoinAddress>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(LnUrlPayRequestData data) lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
+  }) {
+    return bitcoinAddress(address);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data)? lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
+  }) {
+    return bitcoinAddress?.call(address);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data)? lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
+    required TResult orElse(),
+  }) {
+    if (bitcoinAddress != null) {
+      return bitcoinAddress(address);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
+  }) {
+    return bitcoinAddress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
+  }) {
+    return bitcoinAddress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
+    required TResult orElse(),
+  }) {
+    if (bitcoinAddress != null) {
+      return bitcoinAddress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_BitcoinAddress implements InputType {
+  const factory InputType_BitcoinAddress({required final BitcoinAddressData address}) =
+      _$InputType_BitcoinAddress;
+
+  BitcoinAddressData get address;
+  @JsonKey(ignore: true)
+  _$$InputType_BitcoinAddressCopyWith<_$InputType_BitcoinAddress> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_Bolt11CopyWith<$Res> {
+  factory _$$InputType_Bolt11CopyWith(_$InputType_Bolt11 value, $Res Function(_$InputType_Bolt11) then) =
+      __$$InputType_Bolt11CopyWithImpl<$Res>;
+  @useResult
+  $Res call({LNInvoice invoice});
+}
+
+/// @nodoc
+class __$$InputType_Bolt11CopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_Bolt11>
+    implements _$$InputType_Bolt11CopyWith<$Res> {
+  __$$InputType_Bolt11CopyWithImpl(_$InputType_Bolt11 _value, $Res Function(_$InputType_Bolt11) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? invoice = null,
+  }) {
+    return _then(_$InputType_Bolt11(
+      invoice: null == invoice
+          ? _value.invoice
+          : invoice // ignore: cast_nullable_to_non_nullable
+              as LNInvoice,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_Bolt11 implements InputType_Bolt11 {
+  const _$InputType_Bolt11({required this.invoice});
+
+  @override
+  final LNInvoice invoice;
+
+  @override
+  String toString() {
+    return 'InputType.bolt11(invoice: $invoice)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_Bolt11 &&
+            (identical(other.invoice, invoice) || other.invoice == invoice));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, invoice);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_Bolt11CopyWith<_$InputType_Bolt11> get copyWith =>
+      __$$InputType_Bolt11CopyWithImpl<_$InputType_Bolt11>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(LnUrlPayRequestData data) lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
+  }) {
+    return bolt11(invoice);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data)? lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
+  }) {
+    return bolt11?.call(invoice);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data)? lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
+    required TResult orElse(),
+  }) {
+    if (bolt11 != null) {
+      return bolt11(invoice);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
+  }) {
+    return bolt11(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
+  }) {
+    return bolt11?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
+    required TResult orElse(),
+  }) {
+    if (bolt11 != null) {
+      return bolt11(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_Bolt11 implements InputType {
+  const factory InputType_Bolt11({required final LNInvoice invoice}) = _$InputType_Bolt11;
+
+  LNInvoice get invoice;
+  @JsonKey(ignore: true)
+  _$$InputType_Bolt11CopyWith<_$InputType_Bolt11> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_NodeIdCopyWith<$Res> {
+  factory _$$InputType_NodeIdCopyWith(_$InputType_NodeId value, $Res Function(_$InputType_NodeId) then) =
+      __$$InputType_NodeIdCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String nodeId});
+}
+
+/// @nodoc
+class __$$InputType_NodeIdCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_NodeId>
+    implements _$$InputType_NodeIdCopyWith<$Res> {
+  __$$InputType_NodeIdCopyWithImpl(_$InputType_NodeId _value, $Res Function(_$InputType_NodeId) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? nodeId = null,
+  }) {
+    return _then(_$InputType_NodeId(
+      nodeId: null == nodeId
+          ? _value.nodeId
+          : nodeId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_NodeId implements InputType_NodeId {
+  const _$InputType_NodeId({required this.nodeId});
+
+  @override
+  final String nodeId;
+
+  @override
+  String toString() {
+    return 'InputType.nodeId(nodeId: $nodeId)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_NodeId &&
+            (identical(other.nodeId, nodeId) || other.nodeId == nodeId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, nodeId);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_NodeIdCopyWith<_$InputType_NodeId> get copyWith =>
+      __$$InputType_NodeIdCopyWithImpl<_$InputType_NodeId>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(LnUrlPayRequestData data) lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
+  }) {
+    return nodeId(this.nodeId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data)? lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
+  }) {
+    return nodeId?.call(this.nodeId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data)? lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
+    required TResult orElse(),
+  }) {
+    if (nodeId != null) {
+      return nodeId(this.nodeId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
+  }) {
+    return nodeId(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
+  }) {
+    return nodeId?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
+    required TResult orElse(),
+  }) {
+    if (nodeId != null) {
+      return nodeId(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_NodeId implements InputType {
+  const factory InputType_NodeId({required final String nodeId}) = _$InputType_NodeId;
+
+  String get nodeId;
+  @JsonKey(ignore: true)
+  _$$InputType_NodeIdCopyWith<_$InputType_NodeId> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_UrlCopyWith<$Res> {
+  factory _$$InputType_UrlCopyWith(_$InputType_Url value, $Res Function(_$InputType_Url) then) =
+      __$$InputType_UrlCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class __$$InputType_UrlCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_Url>
+    implements _$$InputType_UrlCopyWith<$Res> {
+  __$$InputType_UrlCopyWithImpl(_$InputType_Url _value, $Res Function(_$InputType_Url) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+  }) {
+    return _then(_$InputType_Url(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_Url implements InputType_Url {
+  const _$InputType_Url({required this.url});
+
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'InputType.url(url: $url)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_Url &&
+            (identical(other.url, url) || other.url == url));
   }
-}
 
-abstract class BreezEvent_PaymentFailed implements BreezEvent {
-  const factory BreezEvent_PaymentFailed({required final PaymentFailedData details}) =
-      _$BreezEvent_PaymentFailed;
+  @override
+  int get hashCode => Object.hash(runtimeType, url);
 
-  PaymentFailedData get details;
   @JsonKey(ignore: true)
-  _$$BreezEvent_PaymentFailedCopyWith<_$BreezEvent_PaymentFailed> get copyWith =>
-      throw _privateConstructorUsedError;
-}
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_UrlCopyWith<_$InputType_Url> get copyWith =>
+      __$$InputType_UrlCopyWithImpl<_$InputType_Url>(this, _$identity);
 
-/// @nodoc
-mixin _$InputType {
+  @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
     required TResult Function(BitcoinAddressData address) bitcoinAddress,
@@ -822,8 +5172,15 @@ mixin _$InputType {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
-  }) =>
-      throw _privateConstructorUsedError;
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
+  }) {
+    return url(this.url);
+  }
+
+  @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
     TResult? Function(BitcoinAddressData address)? bitcoinAddress,
@@ -834,8 +5191,15 @@ mixin _$InputType {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
-  }) =>
-      throw _privateConstructorUsedError;
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
+  }) {
+    return url?.call(this.url);
+  }
+
+  @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
     TResult Function(BitcoinAddressData address)? bitcoinAddress,
@@ -846,9 +5210,19 @@ mixin _$InputType {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
-  }) =>
-      throw _privateConstructorUsedError;
+  }) {
+    if (url != null) {
+      return url(this.url);
+    }
+    return orElse();
+  }
+
+  @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
     required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
@@ -859,8 +5233,15 @@ mixin _$InputType {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
-  }) =>
-      throw _privateConstructorUsedError;
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
+  }) {
+    return url(this);
+  }
+
+  @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
     TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
@@ -871,8 +5252,15 @@ mixin _$InputType {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
-  }) =>
-      throw _privateConstructorUsedError;
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
+  }) {
+    return url?.call(this);
+  }
+
+  @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
     TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
@@ -883,87 +5271,85 @@ mixin _$InputType {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
-  }) =>
-      throw _privateConstructorUsedError;
-}
-
-/// @nodoc
-abstract class $InputTypeCopyWith<$Res> {
-  factory $InputTypeCopyWith(InputType value, $Res Function(InputType) then) =
-      _$InputTypeCopyWithImpl<$Res, InputType>;
+  }) {
+    if (url != null) {
+      return url(this);
+    }
+    return orElse();
+  }
 }
 
-/// @nodoc
-class _$InputTypeCopyWithImpl<$Res, $Val extends InputType> implements $InputTypeCopyWith<$Res> {
-  _$InputTypeCopyWithImpl(this._value, this._then);
+abstract class InputType_Url implements InputType {
+  const factory InputType_Url({required final String url}) = _$InputType_Url;
 
-  // ignore: unused_field
-  final $Val _value;
-  // ignore: unused_field
-  final $Res Function($Val) _then;
+  String get url;
+  @JsonKey(ignore: true)
+  _$$InputType_UrlCopyWith<_$InputType_Url> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_BitcoinAddressCopyWith<$Res> {
-  factory _$$InputType_BitcoinAddressCopyWith(
-          _$InputType_BitcoinAddress value, $Res Function(_$InputType_BitcoinAddress) then) =
-      __$$InputType_BitcoinAddressCopyWithImpl<$Res>;
+abstract class _$$InputType_LnUrlPayCopyWith<$Res> {
+  factory _$$InputType_LnUrlPayCopyWith(
+          _$InputType_LnUrlPay value, $Res Function(_$InputType_LnUrlPay) then) =
+      __$$InputType_LnUrlPayCopyWithImpl<$Res>;
   @useResult
-  $Res call({BitcoinAddressData address});
+  $Res call({LnUrlPayRequestData data});
 }
 
 /// @nodoc
-class __$$InputType_BitcoinAddressCopyWithImpl<$Res>
-    extends _$InputTypeCopyWithImpl<$Res, _$InputType_BitcoinAddress>
-    implements _$$InputType_BitcoinAddressCopyWith<$Res> {
-  __$$InputType_BitcoinAddressCopyWithImpl(
-      _$InputType_BitcoinAddress _value, $Res Function(_$InputType_BitcoinAddress) _then)
+class __$$InputType_LnUrlPayCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlPay>
+    implements _$$InputType_LnUrlPayCopyWith<$Res> {
+  __$$InputType_LnUrlPayCopyWithImpl(_$InputType_LnUrlPay _value, $Res Function(_$InputType_LnUrlPay) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? address = null,
+    Object? data = null,
   }) {
-    return _then(_$InputType_BitcoinAddress(
-      address: null == address
-          ? _value.address
-          : address // ignore: cast_nullable_to_non_nullable
-              as BitcoinAddressData,
+    return _then(_$InputType_LnUrlPay(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlPayRequestData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_BitcoinAddress implements InputType_BitcoinAddress {
-  const _$InputType_BitcoinAddress({required this.address});
+class _$InputType_LnUrlPay implements InputType_LnUrlPay {
+  const _$InputType_LnUrlPay({required this.data});
 
   @override
-  final BitcoinAddressData address;
+  final LnUrlPayRequestData data;
 
   @override
   String toString() {
-    return 'InputType.bitcoinAddress(address: $address)';
+    return 'InputType.lnUrlPay(data: $data)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_BitcoinAddress &&
-            (identical(other.address, address) || other.address == address));
+            other is _$InputType_LnUrlPay &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, address);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_BitcoinAddressCopyWith<_$InputType_BitcoinAddress> get copyWith =>
-      __$$InputType_BitcoinAddressCopyWithImpl<_$InputType_BitcoinAddress>(this, _$identity);
+  _$$InputType_LnUrlPayCopyWith<_$InputType_LnUrlPay> get copyWith =>
+      __$$InputType_LnUrlPayCopyWithImpl<_$InputType_LnUrlPay>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -976,8 +5362,12 @@ class _$InputType_BitcoinAddress implements InputType_BitcoinAddress {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
   }) {
-    return bitcoinAddress(address);
+    return lnUrlPay(data);
   }
 
   @override
@@ -991,8 +5381,12 @@ class _$InputType_BitcoinAddress implements InputType_BitcoinAddress {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
   }) {
-    return bitcoinAddress?.call(address);
+    return lnUrlPay?.call(data);
   }
 
   @override
@@ -1006,10 +5400,14 @@ class _$InputType_BitcoinAddress implements InputType_BitcoinAddress {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (bitcoinAddress != null) {
-      return bitcoinAddress(address);
+    if (lnUrlPay != null) {
+      return lnUrlPay(data);
     }
     return orElse();
   }
@@ -1025,8 +5423,12 @@ class _$InputType_BitcoinAddress implements InputType_BitcoinAddress {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
   }) {
-    return bitcoinAddress(this);
+    return lnUrlPay(this);
   }
 
   @override
@@ -1040,8 +5442,12 @@ class _$InputType_BitcoinAddress implements InputType_BitcoinAddress {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
   }) {
-    return bitcoinAddress?.call(this);
+    return lnUrlPay?.call(this);
   }
 
   @override
@@ -1055,82 +5461,87 @@ class _$InputType_BitcoinAddress implements InputType_BitcoinAddress {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (bitcoinAddress != null) {
-      return bitcoinAddress(this);
+    if (lnUrlPay != null) {
+      return lnUrlPay(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_BitcoinAddress implements InputType {
-  const factory InputType_BitcoinAddress({required final BitcoinAddressData address}) =
-      _$InputType_BitcoinAddress;
+abstract class InputType_LnUrlPay implements InputType {
+  const factory InputType_LnUrlPay({required final LnUrlPayRequestData data}) = _$InputType_LnUrlPay;
 
-  BitcoinAddressData get address;
+  LnUrlPayRequestData get data;
   @JsonKey(ignore: true)
-  _$$InputType_BitcoinAddressCopyWith<_$InputType_BitcoinAddress> get copyWith =>
-      throw _privateConstructorUsedError;
+  _$$InputType_LnUrlPayCopyWith<_$InputType_LnUrlPay> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_Bolt11CopyWith<$Res> {
-  factory _$$InputType_Bolt11CopyWith(_$InputType_Bolt11 value, $Res Function(_$InputType_Bolt11) then) =
-      __$$InputType_Bolt11CopyWithImpl<$Res>;
+abstract class _$$InputType_LnUrlWithdrawCopyWith<$Res> {
+  factory _$$InputType_LnUrlWithdrawCopyWith(
+          _$InputType_LnUrlWithdraw value, $Res Function(_$InputType_LnUrlWithdraw) then) =
+      __$$InputType_LnUrlWithdrawCopyWithImpl<$Res>;
   @useResult
-  $Res call({LNInvoice invoice});
+  $Res call({LnUrlWithdrawRequestData data});
 }
 
 /// @nodoc
-class __$$InputType_Bolt11CopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_Bolt11>
-    implements _$$InputType_Bolt11CopyWith<$Res> {
-  __$$InputType_Bolt11CopyWithImpl(_$InputType_Bolt11 _value, $Res Function(_$InputType_Bolt11) _then)
+class __$$InputType_LnUrlWithdrawCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlWithdraw>
+    implements _$$InputType_LnUrlWithdrawCopyWith<$Res> {
+  __$$InputType_LnUrlWithdrawCopyWithImpl(
+      _$InputType_LnUrlWithdraw _value, $Res Function(_$InputType_LnUrlWithdraw) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? invoice = null,
+    Object? data = null,
   }) {
-    return _then(_$InputType_Bolt11(
-      invoice: null == invoice
-          ? _value.invoice
-          : invoice // ignore: cast_nullable_to_non_nullable
-              as LNInvoice,
+    return _then(_$InputType_LnUrlWithdraw(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlWithdrawRequestData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_Bolt11 implements InputType_Bolt11 {
-  const _$InputType_Bolt11({required this.invoice});
+class _$InputType_LnUrlWithdraw implements InputType_LnUrlWithdraw {
+  const _$InputType_LnUrlWithdraw({required this.data});
 
   @override
-  final LNInvoice invoice;
+  final LnUrlWithdrawRequestData data;
 
   @override
   String toString() {
-    return 'InputType.bolt11(invoice: $invoice)';
+    return 'InputType.lnUrlWithdraw(data: $data)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_Bolt11 &&
-            (identical(other.invoice, invoice) || other.invoice == invoice));
+            other is _$InputType_LnUrlWithdraw &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, invoice);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_Bolt11CopyWith<_$InputType_Bolt11> get copyWith =>
-      __$$InputType_Bolt11CopyWithImpl<_$InputType_Bolt11>(this, _$identity);
+  _$$InputType_LnUrlWithdrawCopyWith<_$InputType_LnUrlWithdraw> get copyWith =>
+      __$$InputType_LnUrlWithdrawCopyWithImpl<_$InputType_LnUrlWithdraw>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1143,8 +5554,12 @@ class _$InputType_Bolt11 implements InputType_Bolt11 {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
   }) {
-    return bolt11(invoice);
+    return lnUrlWithdraw(data);
   }
 
   @override
@@ -1158,8 +5573,12 @@ class _$InputType_Bolt11 implements InputType_Bolt11 {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
   }) {
-    return bolt11?.call(invoice);
+    return lnUrlWithdraw?.call(data);
   }
 
   @override
@@ -1173,10 +5592,14 @@ class _$InputType_Bolt11 implements InputType_Bolt11 {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (bolt11 != null) {
-      return bolt11(invoice);
+    if (lnUrlWithdraw != null) {
+      return lnUrlWithdraw(data);
     }
     return orElse();
   }
@@ -1192,8 +5615,12 @@ class _$InputType_Bolt11 implements InputType_Bolt11 {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
   }) {
-    return bolt11(this);
+    return lnUrlWithdraw(this);
   }
 
   @override
@@ -1207,8 +5634,12 @@ class _$InputType_Bolt11 implements InputType_Bolt11 {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
   }) {
-    return bolt11?.call(this);
+    return lnUrlWithdraw?.call(this);
   }
 
   @override
@@ -1222,80 +5653,88 @@ class _$InputType_Bolt11 implements InputType_Bolt11 {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (bolt11 != null) {
-      return bolt11(this);
+    if (lnUrlWithdraw != null) {
+      return lnUrlWithdraw(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_Bolt11 implements InputType {
-  const factory InputType_Bolt11({required final LNInvoice invoice}) = _$InputType_Bolt11;
+abstract class InputType_LnUrlWithdraw implements InputType {
+  const factory InputType_LnUrlWithdraw({required final LnUrlWithdrawRequestData data}) =
+      _$InputType_LnUrlWithdraw;
 
-  LNInvoice get invoice;
+  LnUrlWithdrawRequestData get data;
   @JsonKey(ignore: true)
-  _$$InputType_Bolt11CopyWith<_$InputType_Bolt11> get copyWith => throw _privateConstructorUsedError;
+  _$$InputType_LnUrlWithdrawCopyWith<_$InputType_LnUrlWithdraw> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_NodeIdCopyWith<$Res> {
-  factory _$$InputType_NodeIdCopyWith(_$InputType_NodeId value, $Res Function(_$InputType_NodeId) then) =
-      __$$InputType_NodeIdCopyWithImpl<$Res>;
+abstract class _$$InputType_LnUrlAuthCopyWith<$Res> {
+  factory _$$InputType_LnUrlAuthCopyWith(
+          _$InputType_LnUrlAuth value, $Res Function(_$InputType_LnUrlAuth) then) =
+      __$$InputType_LnUrlAuthCopyWithImpl<$Res>;
   @useResult
-  $Res call({String nodeId});
+  $Res call({LnUrlAuthRequestData data});
 }
 
 /// @nodoc
-class __$$InputType_NodeIdCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_NodeId>
-    implements _$$InputType_NodeIdCopyWith<$Res> {
-  __$$InputType_NodeIdCopyWithImpl(_$InputType_NodeId _value, $Res Function(_$InputType_NodeId) _then)
+class __$$InputType_LnUrlAuthCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlAuth>
+    implements _$$InputType_LnUrlAuthCopyWith<$Res> {
+  __$$InputType_LnUrlAuthCopyWithImpl(
+      _$InputType_LnUrlAuth _value, $Res Function(_$InputType_LnUrlAuth) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? nodeId = null,
+    Object? data = null,
   }) {
-    return _then(_$InputType_NodeId(
-      nodeId: null == nodeId
-          ? _value.nodeId
-          : nodeId // ignore: cast_nullable_to_non_nullable
-              as String,
+    return _then(_$InputType_LnUrlAuth(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlAuthRequestData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_NodeId implements InputType_NodeId {
-  const _$InputType_NodeId({required this.nodeId});
+class _$InputType_LnUrlAuth implements InputType_LnUrlAuth {
+  const _$InputType_LnUrlAuth({required this.data});
 
   @override
-  final String nodeId;
+  final LnUrlAuthRequestData data;
 
   @override
   String toString() {
-    return 'InputType.nodeId(nodeId: $nodeId)';
+    return 'InputType.lnUrlAuth(data: $data)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_NodeId &&
-            (identical(other.nodeId, nodeId) || other.nodeId == nodeId));
+            other is _$InputType_LnUrlAuth &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, nodeId);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_NodeIdCopyWith<_$InputType_NodeId> get copyWith =>
-      __$$InputType_NodeIdCopyWithImpl<_$InputType_NodeId>(this, _$identity);
+  _$$InputType_LnUrlAuthCopyWith<_$InputType_LnUrlAuth> get copyWith =>
+      __$$InputType_LnUrlAuthCopyWithImpl<_$InputType_LnUrlAuth>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1308,8 +5747,12 @@ class _$InputType_NodeId implements InputType_NodeId {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
   }) {
-    return nodeId(this.nodeId);
+    return lnUrlAuth(data);
   }
 
   @override
@@ -1323,8 +5766,12 @@ class _$InputType_NodeId implements InputType_NodeId {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
   }) {
-    return nodeId?.call(this.nodeId);
+    return lnUrlAuth?.call(data);
   }
 
   @override
@@ -1338,10 +5785,14 @@ class _$InputType_NodeId implements InputType_NodeId {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (nodeId != null) {
-      return nodeId(this.nodeId);
+    if (lnUrlAuth != null) {
+      return lnUrlAuth(data);
     }
     return orElse();
   }
@@ -1357,8 +5808,12 @@ class _$InputType_NodeId implements InputType_NodeId {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
   }) {
-    return nodeId(this);
+    return lnUrlAuth(this);
   }
 
   @override
@@ -1372,8 +5827,12 @@ class _$InputType_NodeId implements InputType_NodeId {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
   }) {
-    return nodeId?.call(this);
+    return lnUrlAuth?.call(this);
   }
 
   @override
@@ -1387,80 +5846,86 @@ class _$InputType_NodeId implements InputType_NodeId {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (nodeId != null) {
-      return nodeId(this);
+    if (lnUrlAuth != null) {
+      return lnUrlAuth(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_NodeId implements InputType {
-  const factory InputType_NodeId({required final String nodeId}) = _$InputType_NodeId;
+abstract class InputType_LnUrlAuth implements InputType {
+  const factory InputType_LnUrlAuth({required final LnUrlAuthRequestData data}) = _$InputType_LnUrlAuth;
 
-  String get nodeId;
+  LnUrlAuthRequestData get data;
   @JsonKey(ignore: true)
-  _$$InputType_NodeIdCopyWith<_$InputType_NodeId> get copyWith => throw _privateConstructorUsedError;
+  _$$InputType_LnUrlAuthCopyWith<_$InputType_LnUrlAuth> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_UrlCopyWith<$Res> {
-  factory _$$InputType_UrlCopyWith(_$InputType_Url value, $Res Function(_$InputType_Url) then) =
-      __$$InputType_UrlCopyWithImpl<$Res>;
+abstract class _$$InputType_LnUrlErrorCopyWith<$Res> {
+  factory _$$InputType_LnUrlErrorCopyWith(
+          _$InputType_LnUrlError value, $Res Function(_$InputType_LnUrlError) then) =
+      __$$InputType_LnUrlErrorCopyWithImpl<$Res>;
   @useResult
-  $Res call({String url});
+  $Res call({LnUrlErrorData data});
 }
 
 /// @nodoc
-class __$$InputType_UrlCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_Url>
-    implements _$$InputType_UrlCopyWith<$Res> {
-  __$$InputType_UrlCopyWithImpl(_$InputType_Url _value, $Res Function(_$InputType_Url) _then)
+class __$$InputType_LnUrlErrorCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlError>
+    implements _$$InputType_LnUrlErrorCopyWith<$Res> {
+  __$$InputType_LnUrlErrorCopyWithImpl(
+      _$InputType_LnUrlError _value, $Res Function(_$InputType_LnUrlError) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? url = null,
+    Object? data = null,
   }) {
-    return _then(_$InputType_Url(
-      url: null == url
-          ? _value.url
-          : url // ignore: cast_nullable_to_non_nullable
-              as String,
+    return _then(_$InputType_LnUrlError(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlErrorData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_Url implements InputType_Url {
-  const _$InputType_Url({required this.url});
+class _$InputType_LnUrlError implements InputType_LnUrlError {
+  const _$InputType_LnUrlError({required this.data});
 
   @override
-  final String url;
+  final LnUrlErrorData data;
 
   @override
   String toString() {
-    return 'InputType.url(url: $url)';
+    return 'InputType.lnUrlError(data: $data)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_Url &&
-            (identical(other.url, url) || other.url == url));
+            other is _$InputType_LnUrlError &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, url);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_UrlCopyWith<_$InputType_Url> get copyWith =>
-      __$$InputType_UrlCopyWithImpl<_$InputType_Url>(this, _$identity);
+  _$$InputType_LnUrlErrorCopyWith<_$InputType_LnUrlError> get copyWith =>
+      __$$InputType_LnUrlErrorCopyWithImpl<_$InputType_LnUrlError>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1473,8 +5938,12 @@ class _$InputType_Url implements InputType_Url {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
   }) {
-    return url(this.url);
+    return lnUrlError(data);
   }
 
   @override
@@ -1488,8 +5957,12 @@ class _$InputType_Url implements InputType_Url {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
   }) {
-    return url?.call(this.url);
+    return lnUrlError?.call(data);
   }
 
   @override
@@ -1503,10 +5976,14 @@ class _$InputType_Url implements InputType_Url {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (url != null) {
-      return url(this.url);
+    if (lnUrlError != null) {
+      return lnUrlError(data);
     }
     return orElse();
   }
@@ -1522,8 +5999,12 @@ class _$InputType_Url implements InputType_Url {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
   }) {
-    return url(this);
+    return lnUrlError(this);
   }
 
   @override
@@ -1537,8 +6018,12 @@ class _$InputType_Url implements InputType_Url {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
   }) {
-    return url?.call(this);
+    return lnUrlError?.call(this);
   }
 
   @override
@@ -1552,36 +6037,42 @@ class _$InputType_Url implements InputType_Url {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (url != null) {
-      return url(this);
+    if (lnUrlError != null) {
+      return lnUrlError(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_Url implements InputType {
-  const factory InputType_Url({required final String url}) = _$InputType_Url;
+abstract class InputType_LnUrlError implements InputType {
+  const factory InputType_LnUrlError({required final LnUrlErrorData data}) = _$InputType_LnUrlError;
 
-  String get url;
+  LnUrlErrorData get data;
   @JsonKey(ignore: true)
-  _$$InputType_UrlCopyWith<_$InputType_Url> get copyWith => throw _privateConstructorUsedError;
+  _$$InputType_LnUrlErrorCopyWith<_$InputType_LnUrlError> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_LnUrlPayCopyWith<$Res> {
-  factory _$$InputType_LnUrlPayCopyWith(
-          _$InputType_LnUrlPay value, $Res Function(_$InputType_LnUrlPay) then) =
-      __$$InputType_LnUrlPayCopyWithImpl<$Res>;
+abstract class _$$InputType_LnUrlChannelCopyWith<$Res> {
+  factory _$$InputType_LnUrlChannelCopyWith(
+          _$InputType_LnUrlChannel value, $Res Function(_$InputType_LnUrlChannel) then) =
+      __$$InputType_LnUrlChannelCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlPayRequestData data});
+  $Res call({LnUrlChannelRequestData data});
 }
 
 /// @nodoc
-class __$$InputType_LnUrlPayCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlPay>
-    implements _$$InputType_LnUrlPayCopyWith<$Res> {
-  __$$InputType_LnUrlPayCopyWithImpl(_$InputType_LnUrlPay _value, $Res Function(_$InputType_LnUrlPay) _then)
+class __$$InputType_LnUrlChannelCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlChannel>
+    implements _$$InputType_LnUrlChannelCopyWith<$Res> {
+  __$$InputType_LnUrlChannelCopyWithImpl(
+      _$InputType_LnUrlChannel _value, $Res Function(_$InputType_LnUrlChannel) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
@@ -1589,33 +6080,33 @@ class __$$InputType_LnUrlPayCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$
   $Res call({
     Object? data = null,
   }) {
-    return _then(_$InputType_LnUrlPay(
+    return _then(_$InputType_LnUrlChannel(
       data: null == data
           ? _value.data
           : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlPayRequestData,
+              as LnUrlChannelRequestData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_LnUrlPay implements InputType_LnUrlPay {
-  const _$InputType_LnUrlPay({required this.data});
+class _$InputType_LnUrlChannel implements InputType_LnUrlChannel {
+  const _$InputType_LnUrlChannel({required this.data});
 
   @override
-  final LnUrlPayRequestData data;
+  final LnUrlChannelRequestData data;
 
   @override
   String toString() {
-    return 'InputType.lnUrlPay(data: $data)';
+    return 'InputType.lnUrlChannel(data: $data)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_LnUrlPay &&
+            other is _$InputType_LnUrlChannel &&
             (identical(other.data, data) || other.data == data));
   }
 
@@ -1625,8 +6116,8 @@ class _$InputType_LnUrlPay implements InputType_LnUrlPay {
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_LnUrlPayCopyWith<_$InputType_LnUrlPay> get copyWith =>
-      __$$InputType_LnUrlPayCopyWithImpl<_$InputType_LnUrlPay>(this, _$identity);
+  _$$InputType_LnUrlChannelCopyWith<_$InputType_LnUrlChannel> get copyWith =>
+      __$$InputType_LnUrlChannelCopyWithImpl<_$InputType_LnUrlChannel>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1639,8 +6130,12 @@ class _$InputType_LnUrlPay implements InputType_LnUrlPay {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
   }) {
-    return lnUrlPay(data);
+    return lnUrlChannel(data);
   }
 
   @override
@@ -1654,8 +6149,12 @@ class _$InputType_LnUrlPay implements InputType_LnUrlPay {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
   }) {
-    return lnUrlPay?.call(data);
+    return lnUrlChannel?.call(data);
   }
 
   @override
@@ -1669,10 +6168,14 @@ class _$InputType_LnUrlPay implements InputType_LnUrlPay {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (lnUrlPay != null) {
-      return lnUrlPay(data);
+    if (lnUrlChannel != null) {
+      return lnUrlChannel(data);
     }
     return orElse();
   }
@@ -1688,8 +6191,12 @@ class _$InputType_LnUrlPay implements InputType_LnUrlPay {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
   }) {
-    return lnUrlPay(this);
+    return lnUrlChannel(this);
   }
 
   @override
@@ -1703,8 +6210,12 @@ class _$InputType_LnUrlPay implements InputType_LnUrlPay {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
   }) {
-    return lnUrlPay?.call(this);
+    return lnUrlChannel?.call(this);
   }
 
   @override
@@ -1718,83 +6229,94 @@ class _$InputType_LnUrlPay implements InputType_LnUrlPay {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (lnUrlPay != null) {
-      return lnUrlPay(this);
+    if (lnUrlChannel != null) {
+      return lnUrlChannel(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_LnUrlPay implements InputType {
-  const factory InputType_LnUrlPay({required final LnUrlPayRequestData data}) = _$InputType_LnUrlPay;
+abstract class InputType_LnUrlChannel implements InputType {
+  const factory InputType_LnUrlChannel({required final LnUrlChannelRequestData data}) =
+      _$InputType_LnUrlChannel;
 
-  LnUrlPayRequestData get data;
+  LnUrlChannelRequestData get data;
   @JsonKey(ignore: true)
-  _$$InputType_LnUrlPayCopyWith<_$InputType_LnUrlPay> get copyWith => throw _privateConstructorUsedError;
+  _$$InputType_LnUrlChannelCopyWith<_$InputType_LnUrlChannel> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_LnUrlWithdrawCopyWith<$Res> {
-  factory _$$InputType_LnUrlWithdrawCopyWith(
-          _$InputType_LnUrlWithdraw value, $Res Function(_$InputType_LnUrlWithdraw) then) =
-      __$$InputType_LnUrlWithdrawCopyWithImpl<$Res>;
+abstract class _$$InputType_UnifiedCopyWith<$Res> {
+  factory _$$InputType_UnifiedCopyWith(_$InputType_Unified value, $Res Function(_$InputType_Unified) then) =
+      __$$InputType_UnifiedCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlWithdrawRequestData data});
+  $Res call({BitcoinAddressData address, LNInvoice invoice});
 }
 
 /// @nodoc
-class __$$InputType_LnUrlWithdrawCopyWithImpl<$Res>
-    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlWithdraw>
-    implements _$$InputType_LnUrlWithdrawCopyWith<$Res> {
-  __$$InputType_LnUrlWithdrawCopyWithImpl(
-      _$InputType_LnUrlWithdraw _value, $Res Function(_$InputType_LnUrlWithdraw) _then)
+class __$$InputType_UnifiedCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_Unified>
+    implements _$$InputType_UnifiedCopyWith<$Res> {
+  __$$InputType_UnifiedCopyWithImpl(_$InputType_Unified _value, $Res Function(_$InputType_Unified) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? address = null,
+    Object? invoice = null,
   }) {
-    return _then(_$InputType_LnUrlWithdraw(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlWithdrawRequestData,
+    return _then(_$InputType_Unified(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as BitcoinAddressData,
+      invoice: null == invoice
+          ? _value.invoice
+          : invoice // ignore: cast_nullable_to_non_nullable
+              as LNInvoice,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_LnUrlWithdraw implements InputType_LnUrlWithdraw {
-  const _$InputType_LnUrlWithdraw({required this.data});
+class _$InputType_Unified implements InputType_Unified {
+  const _$InputType_Unified({required this.address, required this.invoice});
 
   @override
-  final LnUrlWithdrawRequestData data;
+  final BitcoinAddressData address;
+  @override
+  final LNInvoice invoice;
 
   @override
   String toString() {
-    return 'InputType.lnUrlWithdraw(data: $data)';
+    return 'InputType.unified(address: $address, invoice: $invoice)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_LnUrlWithdraw &&
-            (identical(other.data, data) || other.data == data));
+            other is _$InputType_Unified &&
+            (identical(other.address, address) || other.address == address) &&
+            (identical(other.invoice, invoice) || other.invoice == invoice));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, address, invoice);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_LnUrlWithdrawCopyWith<_$InputType_LnUrlWithdraw> get copyWith =>
-      __$$InputType_LnUrlWithdrawCopyWithImpl<_$InputType_LnUrlWithdraw>(this, _$identity);
+  _$$InputType_UnifiedCopyWith<_$InputType_Unified> get copyWith =>
+      __$$InputType_UnifiedCopyWithImpl<_$InputType_Unified>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1807,8 +6329,12 @@ class _$InputType_LnUrlWithdraw implements InputType_LnUrlWithdraw {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
   }) {
-    return lnUrlWithdraw(data);
+    return unified(address, invoice);
   }
 
   @override
@@ -1822,8 +6348,12 @@ class _$InputType_LnUrlWithdraw implements InputType_LnUrlWithdraw {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
   }) {
-    return lnUrlWithdraw?.call(data);
+    return unified?.call(address, invoice);
   }
 
   @override
@@ -1837,10 +6367,14 @@ class _$InputType_LnUrlWithdraw implements InputType_LnUrlWithdraw {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (lnUrlWithdraw != null) {
-      return lnUrlWithdraw(data);
+    if (unified != null) {
+      return unified(address, invoice);
     }
     return orElse();
   }
@@ -1856,8 +6390,12 @@ class _$InputType_LnUrlWithdraw implements InputType_LnUrlWithdraw {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
   }) {
-    return lnUrlWithdraw(this);
+    return unified(this);
   }
 
   @override
@@ -1871,8 +6409,12 @@ class _$InputType_LnUrlWithdraw implements InputType_LnUrlWithdraw {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
   }) {
-    return lnUrlWithdraw?.call(this);
+    return unified?.call(this);
   }
 
   @override
@@ -1886,84 +6428,89 @@ class _$InputType_LnUrlWithdraw implements InputType_LnUrlWithdraw {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (lnUrlWithdraw != null) {
-      return lnUrlWithdraw(this);
+    if (unified != null) {
+      return unified(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_LnUrlWithdraw implements InputType {
-  const factory InputType_LnUrlWithdraw({required final LnUrlWithdrawRequestData data}) =
-      _$InputType_LnUrlWithdraw;
+abstract class InputType_Unified implements InputType {
+  const factory InputType_Unified(
+      {required final BitcoinAddressData address, required final LNInvoice invoice}) = _$InputType_Unified;
 
-  LnUrlWithdrawRequestData get data;
+  BitcoinAddressData get address;
+  LNInvoice get invoice;
   @JsonKey(ignore: true)
-  _$$InputType_LnUrlWithdrawCopyWith<_$InputType_LnUrlWithdraw> get copyWith =>
-      throw _privateConstructorUsedError;
+  _$$InputType_UnifiedCopyWith<_$InputType_Unified> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_LnUrlAuthCopyWith<$Res> {
-  factory _$$InputType_LnUrlAuthCopyWith(
-          _$InputType_LnUrlAuth value, $Res Function(_$InputType_LnUrlAuth) then) =
-      __$$InputType_LnUrlAuthCopyWithImpl<$Res>;
+abstract class _$$InputType_Bolt12OfferCopyWith<$Res> {
+  factory _$$InputType_Bolt12OfferCopyWith(
+          _$InputType_Bolt12Offer value, $Res Function(_$InputType_Bolt12Offer) then) =
+      __$$InputType_Bolt12OfferCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlAuthRequestData data});
+  $Res call({LNOffer offer});
 }
 
 /// @nodoc
-class __$$InputType_LnUrlAuthCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlAuth>
-    implements _$$InputType_LnUrlAuthCopyWith<$Res> {
-  __$$InputType_LnUrlAuthCopyWithImpl(
-      _$InputType_LnUrlAuth _value, $Res Function(_$InputType_LnUrlAuth) _then)
+class __$$InputType_Bolt12OfferCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_Bolt12Offer>
+    implements _$$InputType_Bolt12OfferCopyWith<$Res> {
+  __$$InputType_Bolt12OfferCopyWithImpl(
+      _$InputType_Bolt12Offer _value, $Res Function(_$InputType_Bolt12Offer) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? offer = null,
   }) {
-    return _then(_$InputType_LnUrlAuth(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlAuthRequestData,
+    return _then(_$InputType_Bolt12Offer(
+      offer: null == offer
+          ? _value.offer
+          : offer // ignore: cast_nullable_to_non_nullable
+              as LNOffer,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_LnUrlAuth implements InputType_LnUrlAuth {
-  const _$InputType_LnUrlAuth({required this.data});
+class _$InputType_Bolt12Offer implements InputType_Bolt12Offer {
+  const _$InputType_Bolt12Offer({required this.offer});
 
   @override
-  final LnUrlAuthRequestData data;
+  final LNOffer offer;
 
   @override
   String toString() {
-    return 'InputType.lnUrlAuth(data: $data)';
+    return 'InputType.bolt12Offer(offer: $offer)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_LnUrlAuth &&
-            (identical(other.data, data) || other.data == data));
+            other is _$InputType_Bolt12Offer &&
+            (identical(other.offer, offer) || other.offer == offer));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, offer);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_LnUrlAuthCopyWith<_$InputType_LnUrlAuth> get copyWith =>
-      __$$InputType_LnUrlAuthCopyWithImpl<_$InputType_LnUrlAuth>(this, _$identity);
+  _$$InputType_Bolt12OfferCopyWith<_$InputType_Bolt12Offer> get copyWith =>
+      __$$InputType_Bolt12OfferCopyWithImpl<_$InputType_Bolt12Offer>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1976,8 +6523,12 @@ class _$InputType_LnUrlAuth implements InputType_LnUrlAuth {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
   }) {
-    return lnUrlAuth(data);
+    return bolt12Offer(offer);
   }
 
   @override
@@ -1991,8 +6542,12 @@ class _$InputType_LnUrlAuth implements InputType_LnUrlAuth {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
   }) {
-    return lnUrlAuth?.call(data);
+    return bolt12Offer?.call(offer);
   }
 
   @override
@@ -2006,10 +6561,14 @@ class _$InputType_LnUrlAuth implements InputType_LnUrlAuth {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (lnUrlAuth != null) {
-      return lnUrlAuth(data);
+    if (bolt12Offer != null) {
+      return bolt12Offer(offer);
     }
     return orElse();
   }
@@ -2025,8 +6584,12 @@ class _$InputType_LnUrlAuth implements InputType_LnUrlAuth {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
   }) {
-    return lnUrlAuth(this);
+    return bolt12Offer(this);
   }
 
   @override
@@ -2040,8 +6603,12 @@ class _$InputType_LnUrlAuth implements InputType_LnUrlAuth {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
   }) {
-    return lnUrlAuth?.call(this);
+    return bolt12Offer?.call(this);
   }
 
   @override
@@ -2055,82 +6622,104 @@ class _$InputType_LnUrlAuth implements InputType_LnUrlAuth {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (lnUrlAuth != null) {
-      return lnUrlAuth(this);
+    if (bolt12Offer != null) {
+      return bolt12Offer(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_LnUrlAuth implements InputType {
-  const factory InputType_LnUrlAuth({required final LnUrlAuthRequestData data}) = _$InputType_LnUrlAuth;
+abstract class InputType_Bolt12Offer implements InputType {
+  const factory InputType_Bolt12Offer({required final LNOffer offer}) = _$InputType_Bolt12Offer;
 
-  LnUrlAuthRequestData get data;
+  LNOffer get offer;
   @JsonKey(ignore: true)
-  _$$InputType_LnUrlAuthCopyWith<_$InputType_LnUrlAuth> get copyWith => throw _privateConstructorUsedError;
+  _$$InputType_Bolt12OfferCopyWith<_$InputType_Bolt12Offer> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_LnUrlErrorCopyWith<$Res> {
-  factory _$$InputType_LnUrlErrorCopyWith(
-          _$InputType_LnUrlError value, $Res Function(_$InputType_LnUrlError) then) =
-      __$$InputType_LnUrlErrorCopyWithImpl<$Res>;
+abstract class _$$InputType_NodeConnectionCopyWith<$Res> {
+  factory _$$InputType_NodeConnectionCopyWith(
+          _$InputType_NodeConnection value, $Res Function(_$InputType_NodeConnection) then) =
+      __$$InputType_NodeConnectionCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlErrorData data});
+  $Res call({String nodeId, String host, int port});
 }
 
 /// @nodoc
-class __$$InputType_LnUrlErrorCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlError>
-    implements _$$InputType_LnUrlErrorCopyWith<$Res> {
-  __$$InputType_LnUrlErrorCopyWithImpl(
-      _$InputType_LnUrlError _value, $Res Function(_$InputType_LnUrlError) _then)
+class __$$InputType_NodeConnectionCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_NodeConnection>
+    implements _$$InputType_NodeConnectionCopyWith<$Res> {
+  __$$InputType_NodeConnectionCopyWithImpl(
+      _$InputType_NodeConnection _value, $Res Function(_$InputType_NodeConnection) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? nodeId = null,
+    Object? host = null,
+    Object? port = null,
   }) {
-    return _then(_$InputType_LnUrlError(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlErrorData,
+    return _then(_$InputType_NodeConnection(
+      nodeId: null == nodeId
+          ? _value.nodeId
+          : nodeId // ignore: cast_nullable_to_non_nullable
+              as String,
+      host: null == host
+          ? _value.host
+          : host // ignore: cast_nullable_to_non_nullable
+              as String,
+      port: null == port
+          ? _value.port
+          : port // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_LnUrlError implements InputType_LnUrlError {
-  const _$InputType_LnUrlError({required this.data});
+class _$InputType_NodeConnection implements InputType_NodeConnection {
+  const _$InputType_NodeConnection({required this.nodeId, required this.host, required this.port});
 
   @override
-  final LnUrlErrorData data;
+  final String nodeId;
+  @override
+  final String host;
+  @override
+  final int port;
 
   @override
   String toString() {
-    return 'InputType.lnUrlError(data: $data)';
+    return 'InputType.nodeConnection(nodeId: $nodeId, host: $host, port: $port)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_LnUrlError &&
-            (identical(other.data, data) || other.data == data));
+            other is _$InputType_NodeConnection &&
+            (identical(other.nodeId, nodeId) || other.nodeId == nodeId) &&
+            (identical(other.host, host) || other.host == host) &&
+            (identical(other.port, port) || other.port == port));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, nodeId, host, port);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_LnUrlErrorCopyWith<_$InputType_LnUrlError> get copyWith =>
-      __$$InputType_LnUrlErrorCopyWithImpl<_$InputType_LnUrlError>(this, _$identity);
+  _$$InputType_NodeConnectionCopyWith<_$InputType_NodeConnection> get copyWith =>
+      __$$InputType_NodeConnectionCopyWithImpl<_$InputType_NodeConnection>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -2143,8 +6732,12 @@ class _$InputType_LnUrlError implements InputType_LnUrlError {
     required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
     required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
     required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlChannelRequestData data) lnUrlChannel,
+    required TResult Function(BitcoinAddressData address, LNInvoice invoice) unified,
+    required TResult Function(LNOffer offer) bolt12Offer,
+    required TResult Function(String nodeId, String host, int port) nodeConnection,
   }) {
-    return lnUrlError(data);
+    return nodeConnection(this.nodeId, host, port);
   }
 
   @override
@@ -2158,8 +6751,12 @@ class _$InputType_LnUrlError implements InputType_LnUrlError {
     TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult? Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult? Function(LNOffer offer)? bolt12Offer,
+    TResult? Function(String nodeId, String host, int port)? nodeConnection,
   }) {
-    return lnUrlError?.call(data);
+    return nodeConnection?.call(this.nodeId, host, port);
   }
 
   @override
@@ -2173,10 +6770,14 @@ class _$InputType_LnUrlError implements InputType_LnUrlError {
     TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
     TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
     TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlChannelRequestData data)? lnUrlChannel,
+    TResult Function(BitcoinAddressData address, LNInvoice invoice)? unified,
+    TResult Function(LNOffer offer)? bolt12Offer,
+    TResult Function(String nodeId, String host, int port)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (lnUrlError != null) {
-      return lnUrlError(data);
+    if (nodeConnection != null) {
+      return nodeConnection(this.nodeId, host, port);
     }
     return orElse();
   }
@@ -2192,8 +6793,12 @@ class _$InputType_LnUrlError implements InputType_LnUrlError {
     required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
     required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
     required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_LnUrlChannel value) lnUrlChannel,
+    required TResult Function(InputType_Unified value) unified,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeConnection value) nodeConnection,
   }) {
-    return lnUrlError(this);
+    return nodeConnection(this);
   }
 
   @override
@@ -2207,8 +6812,12 @@ class _$InputType_LnUrlError implements InputType_LnUrlError {
     TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult? Function(InputType_Unified value)? unified,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeConnection value)? nodeConnection,
   }) {
-    return lnUrlError?.call(this);
+    return nodeConnection?.call(this);
   }
 
   @override
@@ -2222,21 +6831,31 @@ class _$InputType_LnUrlError implements InputType_LnUrlError {
     TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
     TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
     TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_LnUrlChannel value)? lnUrlChannel,
+    TResult Function(InputType_Unified value)? unified,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeConnection value)? nodeConnection,
     required TResult orElse(),
   }) {
-    if (lnUrlError != null) {
-      return lnUrlError(this);
+    if (nodeConnection != null) {
+      return nodeConnection(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_LnUrlError implements InputType {
-  const factory InputType_LnUrlError({required final LnUrlErrorData data}) = _$InputType_LnUrlError;
+abstract class InputType_NodeConnection implements InputType {
+  const factory InputType_NodeConnection(
+      {required final String nodeId,
+      required final String host,
+      required final int port}) = _$InputType_NodeConnection;
 
-  LnUrlErrorData get data;
+  String get nodeId;
+  String get host;
+  int get port;
   @JsonKey(ignore: true)
-  _$$InputType_LnUrlErrorCopyWith<_$InputType_LnUrlError> get copyWith => throw _privateConstructorUsedError;
+  _$$InputType_NodeConnectionCopyWith<_$InputType_NodeConnection> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
@@ -2538,19 +7157,19 @@ mixin _$LnUrlPayResult {
   Object? get data => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(SuccessActionProcessed? data) endpointSuccess,
+    required TResult Function(SuccessActionProcessed? data, Payment payment) endpointSuccess,
     required TResult Function(LnUrlErrorData data) endpointError,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(SuccessActionProcessed? data)? endpointSuccess,
+    TResult? Function(SuccessActionProcessed? data, Payment payment)? endpointSuccess,
     TResult? Function(LnUrlErrorData data)? endpointError,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(SuccessActionProcessed? data)? endpointSuccess,
+    TResult Function(SuccessActionProcessed? data, Payment payment)? endpointSuccess,
     TResult Function(LnUrlErrorData data)? endpointError,
     required TResult orElse(),
   }) =>
@@ -2599,7 +7218,7 @@ abstract class _$$LnUrlPayResult_EndpointSuccessCopyWith<$Res> {
           _$LnUrlPayResult_EndpointSuccess value, $Res Function(_$LnUrlPayResult_EndpointSuccess) then) =
       __$$LnUrlPayResult_EndpointSuccessCopyWithImpl<$Res>;
   @useResult
-  $Res call({SuccessActionProcessed? data});
+  $Res call({SuccessActionProcessed? data, Payment payment});
 
   $SuccessActionProcessedCopyWith<$Res>? get data;
 }
@@ -2616,12 +7235,17 @@ class __$$LnUrlPayResult_EndpointSuccessCopyWithImpl<$Res>
   @override
   $Res call({
     Object? data = freezed,
+    Object? payment = null,
   }) {
     return _then(_$LnUrlPayResult_EndpointSuccess(
       data: freezed == data
           ? _value.data
           : data // ignore: cast_nullable_to_non_nullable
               as SuccessActionProcessed?,
+      payment: null == payment
+          ? _value.payment
+          : payment // ignore: cast_nullable_to_non_nullable
+              as Payment,
     ));
   }
 
@@ -2641,14 +7265,16 @@ class __$$LnUrlPayResult_EndpointSuccessCopyWithImpl<$Res>
 /// @nodoc
 
 class _$LnUrlPayResult_EndpointSuccess implements LnUrlPayResult_EndpointSuccess {
-  const _$LnUrlPayResult_EndpointSuccess({this.data});
+  const _$LnUrlPayResult_EndpointSuccess({this.data, required this.payment});
 
   @override
   final SuccessActionProcessed? data;
+  @override
+  final Payment payment;
 
   @override
   String toString() {
-    return 'LnUrlPayResult.endpointSuccess(data: $data)';
+    return 'LnUrlPayResult.endpointSuccess(data: $data, payment: $payment)';
   }
 
   @override
@@ -2656,11 +7282,12 @@ class _$LnUrlPayResult_EndpointSuccess implements LnUrlPayResult_EndpointSuccess
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
             other is _$LnUrlPayResult_EndpointSuccess &&
-            (identical(other.data, data) || other.data == data));
+            (identical(other.data, data) || other.data == data) &&
+            (identical(other.payment, payment) || other.payment == payment));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, data, payment);
 
   @JsonKey(ignore: true)
   @override
@@ -2671,30 +7298,30 @@ class _$LnUrlPayResult_EndpointSuccess implements LnUrlPayResult_EndpointSuccess
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(SuccessActionProcessed? data) endpointSuccess,
+    required TResult Function(SuccessActionProcessed? data, Payment payment) endpointSuccess,
     required TResult Function(LnUrlErrorData data) endpointError,
   }) {
-    return endpointSuccess(data);
+    return endpointSuccess(data, payment);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(SuccessActionProcessed? data)? endpointSuccess,
+    TResult? Function(SuccessActionProcessed? data, Payment payment)? endpointSuccess,
     TResult? Function(LnUrlErrorData data)? endpointError,
   }) {
-    return endpointSuccess?.call(data);
+    return endpointSuccess?.call(data, payment);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(SuccessActionProcessed? data)? endpointSuccess,
+    TResult Function(SuccessActionProcessed? data, Payment payment)? endpointSuccess,
     TResult Function(LnUrlErrorData data)? endpointError,
     required TResult orElse(),
   }) {
     if (endpointSuccess != null) {
-      return endpointSuccess(data);
+      return endpointSuccess(data, payment);
     }
     return orElse();
   }
@@ -2732,11 +7359,13 @@ class _$LnUrlPayResult_EndpointSuccess implements LnUrlPayResult_EndpointSuccess
 }
 
 abstract class LnUrlPayResult_EndpointSuccess implements LnUrlPayResult {
-  const factory LnUrlPayResult_EndpointSuccess({final SuccessActionProcessed? data}) =
-      _$LnUrlPayResult_EndpointSuccess;
+  const factory LnUrlPayResult_EndpointSuccess(
+      {final SuccessActionProcessed? data,
+      required final Payment payment}) = _$LnUrlPayResult_EndpointSuccess;
 
   @override
   SuccessActionProcessed? get data;
+  Payment get payment;
   @JsonKey(ignore: true)
   _$$LnUrlPayResult_EndpointSuccessCopyWith<_$LnUrlPayResult_EndpointSuccess> get copyWith =>
       throw _privateConstructorUsedError;
@@ -2806,7 +7435,7 @@ class _$LnUrlPayResult_EndpointError implements LnUrlPayResult_EndpointError {
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(SuccessActionProcessed? data) endpointSuccess,
+    required TResult Function(SuccessActionProcessed? data, Payment payment) endpointSuccess,
     required TResult Function(LnUrlErrorData data) endpointError,
   }) {
     return endpointError(data);
@@ -2815,7 +7444,7 @@ class _$LnUrlPayResult_EndpointError implements LnUrlPayResult_EndpointError {
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(SuccessActionProcessed? data)? endpointSuccess,
+    TResult? Function(SuccessActionProcessed? data, Payment payment)? endpointSuccess,
     TResult? Function(LnUrlErrorData data)? endpointError,
   }) {
     return endpointError?.call(data);
@@ -2824,7 +7453,7 @@ class _$LnUrlPayResult_EndpointError implements LnUrlPayResult_EndpointError {
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(SuccessActionProcessed? data)? endpointSuccess,
+    TResult Function(SuccessActionProcessed? data, Payment payment)? endpointSuccess,
     TResult Function(LnUrlErrorData data)? endpointError,
     required TResult orElse(),
   }) {
@@ -2877,6 +7506,338 @@ abstract class LnUrlPayResult_EndpointError implements LnUrlPayResult {
       throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+mixin _$OfferAmount {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int amountMsat) bitcoin,
+    required TResult Function(String iso4217Code, int fractionalAmount) currency,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int amountMsat)? bitcoin,
+    TResult? Function(String iso4217Code, int fractionalAmount)? currency,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int amountMsat)? bitcoin,
+    TResult Function(String iso4217Code, int fractionalAmount)? currency,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(OfferAmount_Bitcoin value) bitcoin,
+    required TResult Function(OfferAmount_Currency value) currency,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(OfferAmount_Bitcoin value)? bitcoin,
+    TResult? Function(OfferAmount_Currency value)? currency,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(OfferAmount_Bitcoin value)? bitcoin,
+    TResult Function(OfferAmount_Currency value)? currency,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $OfferAmountCopyWith<$Res> {
+  factory $OfferAmountCopyWith(OfferAmount value, $Res Function(OfferAmount) then) =
+      _$OfferAmountCopyWithImpl<$Res, OfferAmount>;
+}
+
+/// @nodoc
+class _$OfferAmountCopyWithImpl<$Res, $Val extends OfferAmount> implements $OfferAmountCopyWith<$Res> {
+  _$OfferAmountCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$OfferAmount_BitcoinCopyWith<$Res> {
+  factory _$$OfferAmount_BitcoinCopyWith(
+          _$OfferAmount_Bitcoin value, $Res Function(_$OfferAmount_Bitcoin) then) =
+      __$$OfferAmount_BitcoinCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int amountMsat});
+}
+
+/// @nodoc
+class __$$OfferAmount_BitcoinCopyWithImpl<$Res> extends _$OfferAmountCopyWithImpl<$Res, _$OfferAmount_Bitcoin>
+    implements _$$OfferAmount_BitcoinCopyWith<$Res> {
+  __$$OfferAmount_BitcoinCopyWithImpl(
+      _$OfferAmount_Bitcoin _value, $Res Function(_$OfferAmount_Bitcoin) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? amountMsat = null,
+  }) {
+    return _then(_$OfferAmount_Bitcoin(
+      amountMsat: null == amountMsat
+          ? _value.amountMsat
+          : amountMsat // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$OfferAmount_Bitcoin implements OfferAmount_Bitcoin {
+  const _$OfferAmount_Bitcoin({required this.amountMsat});
+
+  @override
+  final int amountMsat;
+
+  @override
+  String toString() {
+    return 'OfferAmount.bitcoin(amountMsat: $amountMsat)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$OfferAmount_Bitcoin &&
+            (identical(other.amountMsat, amountMsat) || other.amountMsat == amountMsat));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, amountMsat);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$OfferAmount_BitcoinCopyWith<_$OfferAmount_Bitcoin> get copyWith =>
+      __$$OfferAmount_BitcoinCopyWithImpl<_$OfferAmount_Bitcoin>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int amountMsat) bitcoin,
+    required TResult Function(String iso4217Code, int fractionalAmount) currency,
+  }) {
+    return bitcoin(amountMsat);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int amountMsat)? bitcoin,
+    TResult? Function(String iso4217Code, int fractionalAmount)? currency,
+  }) {
+    return bitcoin?.call(amountMsat);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int amountMsat)? bitcoin,
+    TResult Function(String iso4217Code, int fractionalAmount)? currency,
+    required TResult orElse(),
+  }) {
+    if (bitcoin != null) {
+      return bitcoin(amountMsat);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(OfferAmount_Bitcoin value) bitcoin,
+    required TResult Function(OfferAmount_Currency value) currency,
+  }) {
+    return bitcoin(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(OfferAmount_Bitcoin value)? bitcoin,
+    TResult? Function(OfferAmount_Currency value)? currency,
+  }) {
+    return bitcoin?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(OfferAmount_Bitcoin value)? bitcoin,
+    TResult Function(OfferAmount_Currency value)? currency,
+    required TResult orElse(),
+  }) {
+    if (bitcoin != null) {
+      return bitcoin(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class OfferAmount_Bitcoin implements OfferAmount {
+  const factory OfferAmount_Bitcoin({required final int amountMsat}) = _$OfferAmount_Bitcoin;
+
+  int get amountMsat;
+  @JsonKey(ignore: true)
+  _$$OfferAmount_BitcoinCopyWith<_$OfferAmount_Bitcoin> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$OfferAmount_CurrencyCopyWith<$Res> {
+  factory _$$OfferAmount_CurrencyCopyWith(
+          _$OfferAmount_Currency value, $Res Function(_$OfferAmount_Currency) then) =
+      __$$OfferAmount_CurrencyCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String iso4217Code, int fractionalAmount});
+}
+
+/// @nodoc
+class __$$OfferAmount_CurrencyCopyWithImpl<$Res>
+    extends _$OfferAmountCopyWithImpl<$Res, _$OfferAmount_Currency>
+    implements _$$OfferAmount_CurrencyCopyWith<$Res> {
+  __$$OfferAmount_CurrencyCopyWithImpl(
+      _$OfferAmount_Currency _value, $Res Function(_$OfferAmount_Currency) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? iso4217Code = null,
+    Object? fractionalAmount = null,
+  }) {
+    return _then(_$OfferAmount_Currency(
+      iso4217Code: null == iso4217Code
+          ? _value.iso4217Code
+          : iso4217Code // ignore: cast_nullable_to_non_nullable
+              as String,
+      fractionalAmount: null == fractionalAmount
+          ? _value.fractionalAmount
+          : fractionalAmount // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$OfferAmount_Currency implements OfferAmount_Currency {
+  const _$OfferAmount_Currency({required this.iso4217Code, required this.fractionalAmount});
+
+  @override
+  final String iso4217Code;
+  @override
+  final int fractionalAmount;
+
+  @override
+  String toString() {
+    return 'OfferAmount.currency(iso4217Code: $iso4217Code, fractionalAmount: $fractionalAmount)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$OfferAmount_Currency &&
+            (identical(other.iso4217Code, iso4217Code) || other.iso4217Code == iso4217Code) &&
+            (identical(other.fractionalAmount, fractionalAmount) ||
+                other.fractionalAmount == fractionalAmount));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, iso4217Code, fractionalAmount);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$OfferAmount_CurrencyCopyWith<_$OfferAmount_Currency> get copyWith =>
+      __$$OfferAmount_CurrencyCopyWithImpl<_$OfferAmount_Currency>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int amountMsat) bitcoin,
+    required TResult Function(String iso4217Code, int fractionalAmount) currency,
+  }) {
+    return currency(iso4217Code, fractionalAmount);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int amountMsat)? bitcoin,
+    TResult? Function(String iso4217Code, int fractionalAmount)? currency,
+  }) {
+    return currency?.call(iso4217Code, fractionalAmount);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int amountMsat)? bitcoin,
+    TResult Function(String iso4217Code, int fractionalAmount)? currency,
+    required TResult orElse(),
+  }) {
+    if (currency != null) {
+      return currency(iso4217Code, fractionalAmount);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(OfferAmount_Bitcoin value) bitcoin,
+    required TResult Function(OfferAmount_Currency value) currency,
+  }) {
+    return currency(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(OfferAmount_Bitcoin value)? bitcoin,
+    TResult? Function(OfferAmount_Currency value)? currency,
+  }) {
+    return currency?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(OfferAmount_Bitcoin value)? bitcoin,
+    TResult Function(OfferAmount_Currency value)? currency,
+    required TResult orElse(),
+  }) {
+    if (currency != null) {
+      return currency(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class OfferAmount_Currency implements OfferAmount {
+  const factory OfferAmount_Currency(
+      {required final String iso4217Code, required final int fractionalAmount}) = _$OfferAmount_Currency;
+
+  String get iso4217Code;
+  int get fractionalAmount;
+  @JsonKey(ignore: true)
+  _$$OfferAmount_CurrencyCopyWith<_$OfferAmount_Currency> get copyWith => throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 mixin _$PaymentDetails {
   Object get data => throw _privateConstructorUsedError;
@@ -3207,21 +8168,21 @@ mixin _$SuccessActionProcessed {
   Object get data => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(AesSuccessActionDataDecrypted data) aes,
+    required TResult Function(AesDecryptedOrError data) aes,
     required TResult Function(MessageSuccessActionData data) message,
     required TResult Function(UrlSuccessActionData data) url,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(AesSuccessActionDataDecrypted data)? aes,
+    TResult? Function(AesDecryptedOrError data)? aes,
     TResult? Function(MessageSuccessActionData data)? message,
     TResult? Function(UrlSuccessActionData data)? url,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(AesSuccessActionDataDecrypted data)? aes,
+    TResult Function(AesDecryptedOrError data)? aes,
     TResult Function(MessageSuccessActionData data)? message,
     TResult Function(UrlSuccessActionData data)? url,
     required TResult orElse(),
@@ -3275,7 +8236,9 @@ abstract class _$$SuccessActionProcessed_AesCopyWith<$Res> {
           _$SuccessActionProcessed_Aes value, $Res Function(_$SuccessActionProcessed_Aes) then) =
       __$$SuccessActionProcessed_AesCopyWithImpl<$Res>;
   @useResult
-  $Res call({AesSuccessActionDataDecrypted data});
+  $Res call({AesDecryptedOrError data});
+
+  $AesDecryptedOrErrorCopyWith<$Res> get data;
 }
 
 /// @nodoc
@@ -3295,9 +8258,17 @@ class __$$SuccessActionProcessed_AesCopyWithImpl<$Res>
       data: null == data
           ? _value.data
           : data // ignore: cast_nullable_to_non_nullable
-              as AesSuccessActionDataDecrypted,
+              as AesDecryptedOrError,
     ));
   }
+
+  @override
+  @pragma('vm:prefer-inline')
+  $AesDecryptedOrErrorCopyWith<$Res> get data {
+    return $AesDecryptedOrErrorCopyWith<$Res>(_value.data, (value) {
+      return _then(_value.copyWith(data: value));
+    });
+  }
 }
 
 /// @nodoc
@@ -3306,7 +8277,7 @@ class _$SuccessActionProcessed_Aes implements SuccessActionProcessed_Aes {
   const _$SuccessActionProcessed_Aes({required this.data});
 
   @override
-  final AesSuccessActionDataDecrypted data;
+  final AesDecryptedOrError data;
 
   @override
   String toString() {
@@ -3333,7 +8304,7 @@ class _$SuccessActionProcessed_Aes implements SuccessActionProcessed_Aes {
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(AesSuccessActionDataDecrypted data) aes,
+    required TResult Function(AesDecryptedOrError data) aes,
     required TResult Function(MessageSuccessActionData data) message,
     required TResult Function(UrlSuccessActionData data) url,
   }) {
@@ -3343,7 +8314,7 @@ class _$SuccessActionProcessed_Aes implements SuccessActionProcessed_Aes {
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(AesSuccessActionDataDecrypted data)? aes,
+    TResult? Function(AesDecryptedOrError data)? aes,
     TResult? Function(MessageSuccessActionData data)? message,
     TResult? Function(UrlSuccessActionData data)? url,
   }) {
@@ -3353,7 +8324,7 @@ class _$SuccessActionProcessed_Aes implements SuccessActionProcessed_Aes {
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(AesSuccessActionDataDecrypted data)? aes,
+    TResult Function(AesDecryptedOrError data)? aes,
     TResult Function(MessageSuccessActionData data)? message,
     TResult Function(UrlSuccessActionData data)? url,
     required TResult orElse(),
@@ -3400,11 +8371,11 @@ class _$SuccessActionProcessed_Aes implements SuccessActionProcessed_Aes {
 }
 
 abstract class SuccessActionProcessed_Aes implements SuccessActionProcessed {
-  const factory SuccessActionProcessed_Aes({required final AesSuccessActionDataDecrypted data}) =
+  const factory SuccessActionProcessed_Aes({required final AesDecryptedOrError data}) =
       _$SuccessActionProcessed_Aes;
 
   @override
-  AesSuccessActionDataDecrypted get data;
+  AesDecryptedOrError get data;
   @JsonKey(ignore: true)
   _$$SuccessActionProcessed_AesCopyWith<_$SuccessActionProcessed_Aes> get copyWith =>
       throw _privateConstructorUsedError;
@@ -3474,7 +8445,7 @@ class _$SuccessActionProcessed_Message implements SuccessActionProcessed_Message
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(AesSuccessActionDataDecrypted data) aes,
+    required TResult Function(AesDecryptedOrError data) aes,
     required TResult Function(MessageSuccessActionData data) message,
     required TResult Function(UrlSuccessActionData data) url,
   }) {
@@ -3484,7 +8455,7 @@ class _$SuccessActionProcessed_Message implements SuccessActionProcessed_Message
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(AesSuccessActionDataDecrypted data)? aes,
+    TResult? Function(AesDecryptedOrError data)? aes,
     TResult? Function(MessageSuccessActionData data)? message,
     TResult? Function(UrlSuccessActionData data)? url,
   }) {
@@ -3494,7 +8465,7 @@ class _$SuccessActionProcessed_Message implements SuccessActionProcessed_Message
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(AesSuccessActionDataDecrypted data)? aes,
+    TResult Function(AesDecryptedOrError data)? aes,
     TResult Function(MessageSuccessActionData data)? message,
     TResult Function(UrlSuccessActionData data)? url,
     required TResult orElse(),
@@ -3615,7 +8586,7 @@ class _$SuccessActionProcessed_Url implements SuccessActionProcessed_Url {
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(AesSuccessActionDataDecrypted data) aes,
+    required TResult Function(AesDecryptedOrError data) aes,
     required TResult Function(MessageSuccessActionData data) message,
     required TResult Function(UrlSuccessActionData data) url,
   }) {
@@ -3625,7 +8596,7 @@ class _$SuccessActionProcessed_Url implements SuccessActionProcessed_Url {
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(AesSuccessActionDataDecrypted data)? aes,
+    TResult? Function(AesDecryptedOrError data)? aes,
     TResult? Function(MessageSuccessActionData data)? message,
     TResult? Function(UrlSuccessActionData data)? url,
   }) {
@@ -3635,7 +8606,7 @@ class _$SuccessActionProcessed_Url implements SuccessActionProcessed_Url {
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(AesSuccessActionDataDecrypted data)? aes,
+    TResult Function(AesDecryptedOrError data)? aes,
     TResult Function(MessageSuccessActionData data)? message,
     TResult Function(UrlSuccessActionData data)? url,
     required TResult orElse(),
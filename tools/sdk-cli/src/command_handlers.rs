@@ -129,6 +129,10 @@ pub(crate) async fn handle_command(
             sdk()?.sync().await?;
             Ok("Sync finished succesfully".to_string())
         }
+        Commands::FullResync {} => {
+            sdk()?.full_resync().await?;
+            Ok("Full resync finished successfully".to_string())
+        }
         Commands::Parse { input } => parse(&input)
             .await
             .map(|res| serde_json::to_string_pretty(&res))?
@@ -136,30 +140,74 @@ pub(crate) async fn handle_command(
         Commands::ReceivePayment {
             amount,
             description,
+            expiry_secs,
+            no_lsp_hint,
+            fallback_address,
         } => {
-            let res = sdk()?.receive_payment(amount, description).await?;
+            let res = sdk()?
+                .receive_payment(
+                    amount,
+                    description,
+                    expiry_secs,
+                    None,
+                    Some(!no_lsp_hint),
+                    fallback_address,
+                )
+                .await?;
             serde_json::to_string_pretty(&res).map_err(|e| e.into())
         }
-        Commands::SendPayment { bolt11, amount } => {
-            let payment = sdk()?.send_payment(bolt11, amount).await?;
+        Commands::ReceiveDonation { description } => {
+            let res = sdk()?.receive_donation(description).await?;
+            serde_json::to_string_pretty(&res).map_err(|e| e.into())
+        }
+        Commands::SendPayment {
+            bolt11,
+            amount,
+            max_fee_sat,
+            timeout_sec,
+        } => {
+            let payment = sdk()?
+                .send_payment(bolt11, amount, max_fee_sat, timeout_sec)
+                .await?;
             serde_json::to_string_pretty(&payment).map_err(|e| e.into())
         }
-        Commands::SendSpontaneousPayment { node_id, amount } => {
-            let payment = sdk()?.send_spontaneous_payment(node_id, amount).await?;
+        Commands::SendSpontaneousPayment {
+            node_id,
+            amount,
+            timeout_sec,
+        } => {
+            let payment = sdk()?
+                .send_spontaneous_payment(node_id, amount, timeout_sec, None)
+                .await?;
             serde_json::to_string_pretty(&payment).map_err(|e| e.into())
         }
         Commands::ListPayments {} => {
             let payments = sdk()?
-                .list_payments(PaymentTypeFilter::All, None, None)
+                .list_payments(PaymentTypeFilter::All, None, None, None, None, None)
                 .await?;
             serde_json::to_string_pretty(&payments).map_err(|e| e.into())
         }
+        Commands::PaymentByHash { hash } => {
+            let payment = sdk()?.payment_by_hash(hash).await?;
+            serde_json::to_string_pretty(&payment).map_err(|e| e.into())
+        }
         Commands::Sweep {
             to_address,
-            sat_per_byte,
+            sat_per_vbyte,
+            utxos,
         } => {
-            sdk()?.sweep(to_address, sat_per_byte).await?;
-            Ok("Onchain funds were swept succesfully".to_string())
+            let res = sdk()?.sweep(to_address, sat_per_vbyte, utxos).await?;
+            serde_json::to_string_pretty(&res).map_err(|e| e.into())
+        }
+        Commands::PrepareSweep {
+            to_address,
+            sat_per_vbyte,
+            utxos,
+        } => {
+            let res = sdk()?
+                .prepare_sweep(to_address, sat_per_vbyte, utxos)
+                .await?;
+            serde_json::to_string_pretty(&res).map_err(|e| e.into())
         }
         Commands::ListLsps {} => {
             let lsps = sdk()?.list_lsps().await?;
@@ -172,6 +220,16 @@ pub(crate) async fn handle_command(
         Commands::NodeInfo {} => {
             serde_json::to_string_pretty(&sdk()?.node_info()?).map_err(|e| e.into())
         }
+        Commands::ReceiveLimits {} => {
+            serde_json::to_string_pretty(&sdk()?.receive_payment_limits().await?)
+                .map_err(|e| e.into())
+        }
+        Commands::ListPeers {} => {
+            serde_json::to_string_pretty(&sdk()?.list_peers().await?).map_err(|e| e.into())
+        }
+        Commands::ListChannels {} => {
+            serde_json::to_string_pretty(&sdk()?.list_channels().await?).map_err(|e| e.into())
+        }
         Commands::ListFiat {} => {
             serde_json::to_string_pretty(&sdk()?.list_fiat_currencies().await?)
                 .map_err(|e| e.into())
@@ -209,6 +267,29 @@ pub(crate) async fn handle_command(
                 .await?;
             Ok(format!("Refund tx: {}", res))
         }
+        Commands::PrepareRefund {
+            swap_address,
+            to_address,
+            sat_per_vbyte,
+        } => {
+            let res = sdk()?
+                .prepare_refund(swap_address, to_address, sat_per_vbyte)
+                .await?;
+            serde_json::to_string_pretty(&res).map_err(|e| e.into())
+        }
+        Commands::RefundableAmount {
+            swap_address,
+            sat_per_vbyte,
+        } => {
+            let res = sdk()?
+                .refundable_amount(swap_address, sat_per_vbyte)
+                .await?;
+            Ok(format!("Refundable amount: {res} sats"))
+        }
+        Commands::PruneTerminalSwaps { older_than_sec } => {
+            let pruned = sdk()?.prune_terminal_swaps(older_than_sec)?;
+            Ok(format!("Pruned {pruned} swaps"))
+        }
         Commands::LnurlPay { lnurl } => match parse(&lnurl).await? {
             LnUrlPay { data: pd } => {
                 let prompt = format!(
@@ -277,12 +358,17 @@ pub(crate) async fn handle_command(
                 _ => Err(anyhow!("Unexpected result type")),
             }
         }
-        Commands::ExecuteDevCommand { command } => {
-            serde_json::to_string_pretty(&sdk()?.execute_dev_command(command).await?)
+        Commands::ExecuteDevCommand { command, dry_run } => {
+            serde_json::to_string_pretty(&sdk()?.execute_dev_command(command, dry_run).await?)
                 .map_err(|e| e.into())
         }
-        Commands::BuyBitcoin { provider } => {
-            let res = sdk()?.buy_bitcoin(provider.clone()).await?;
+        Commands::BuyBitcoin {
+            provider,
+            base_currency_code,
+        } => {
+            let res = sdk()?
+                .buy_bitcoin(provider.clone(), base_currency_code)
+                .await?;
             Ok(format!("Here your {:?} url: {}", provider, res))
         }
     }
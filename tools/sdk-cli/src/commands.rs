@@ -46,6 +46,9 @@ pub(crate) enum Commands {
     /// Sync local data with remote node
     Sync {},
 
+    /// Rebuild the local payments and channels from the remote node
+    FullResync {},
+
     /// Parse a generic string to get its type and relevant metadata
     Parse {
         /// Generic input (URL, LNURL, BIP-21 BTC Address, LN invoice, etc)
@@ -53,7 +56,25 @@ pub(crate) enum Commands {
     },
 
     /// Generate a bolt11 invoice
-    ReceivePayment { amount: u64, description: String },
+    ReceivePayment {
+        amount: u64,
+        description: String,
+
+        /// Expiry of the invoice, in seconds
+        #[clap(long = "expiry")]
+        expiry_secs: Option<u32>,
+
+        /// Don't add the route hint through the LSP, for payers that are direct peers
+        #[clap(long = "no-lsp-hint")]
+        no_lsp_hint: bool,
+
+        /// Segwit address added as on-chain fallback to the invoice
+        #[clap(long = "fallback-address")]
+        fallback_address: Option<String>,
+    },
+
+    /// Generate a bolt11 invoice without an amount, chosen by the payer
+    ReceiveDonation { description: String },
 
     /// Pay using lnurl pay
     LnurlPay { lnurl: String },
@@ -70,21 +91,56 @@ pub(crate) enum Commands {
 
         #[clap(name = "amount", short = 'a', long = "amt")]
         amount: Option<u64>,
+
+        /// Maximum routing fee, in sats
+        #[clap(long = "max-fee")]
+        max_fee_sat: Option<u64>,
+
+        /// How long to retry the payment, in seconds
+        #[clap(long = "timeout")]
+        timeout_sec: Option<u32>,
     },
 
     /// Send a spontaneous (keysend) payment
-    SendSpontaneousPayment { node_id: String, amount: u64 },
+    SendSpontaneousPayment {
+        node_id: String,
+        amount: u64,
+
+        /// How long to retry the payment, in seconds
+        #[clap(long = "timeout")]
+        timeout_sec: Option<u32>,
+    },
 
     /// List all payments
     ListPayments {},
 
+    /// Get a single payment by its hash
+    PaymentByHash { hash: String },
+
     /// Send on-chain funds to an external address
     Sweep {
         /// The sweep destination address
         to_address: String,
 
-        /// The fee rate for the sweep transaction
-        sat_per_byte: u64,
+        /// The fee rate for the sweep transaction, in sat/vbyte
+        sat_per_vbyte: u32,
+
+        /// Only spend the UTXO with this outpoint, as txid:vout. Can be repeated
+        #[clap(name = "utxo", long = "utxo")]
+        utxos: Option<Vec<String>>,
+    },
+
+    /// Estimate the fee and the amount sent by a sweep, without broadcasting it
+    PrepareSweep {
+        /// The sweep destination address
+        to_address: String,
+
+        /// The fee rate for the sweep transaction, in sat/vbyte
+        sat_per_vbyte: u32,
+
+        /// Only spend the UTXO with this outpoint, as txid:vout. Can be repeated
+        #[clap(name = "utxo", long = "utxo")]
+        utxos: Option<Vec<String>>,
     },
 
     /// List available LSPs
@@ -99,6 +155,15 @@ pub(crate) enum Commands {
     /// The up to date node information
     NodeInfo {},
 
+    /// The smallest and largest amounts that can be received, and the LSP fee for the largest
+    ReceiveLimits {},
+
+    /// List the peers of the node
+    ListPeers {},
+
+    /// List the channels of the node, including the closed ones
+    ListChannels {},
+
     /// List fiat currencies
     ListFiat {},
 
@@ -130,9 +195,37 @@ pub(crate) enum Commands {
         sat_per_vbyte: u32,
     },
 
+    /// Estimate the fee and amount of a refund transaction for an incomplete swap
+    PrepareRefund {
+        swap_address: String,
+        to_address: String,
+        sat_per_vbyte: u32,
+    },
+
+    /// Get the amount a refund of an incomplete swap would currently return
+    RefundableAmount {
+        swap_address: String,
+        sat_per_vbyte: u32,
+    },
+
+    /// Delete completed swaps whose expiry passed more than the given seconds ago
+    PruneTerminalSwaps { older_than_sec: u64 },
+
     /// Execute a low level node command (used for debugging)
-    ExecuteDevCommand { command: String },
+    ExecuteDevCommand {
+        command: String,
+
+        /// Only describe what a state-changing command would do, without running it
+        #[clap(long = "dry-run")]
+        dry_run: bool,
+    },
 
     /// Generates an URL to buy bitcoin from a 3rd party provider
-    BuyBitcoin { provider: BuyBitcoinProvider },
+    BuyBitcoin {
+        provider: BuyBitcoinProvider,
+
+        /// The optional fiat currency to pay with (e.g. EUR), defaults to USD
+        #[clap(name = "base_currency_code", short = 'c', long = "base_currency_code")]
+        base_currency_code: Option<String>,
+    },
 }
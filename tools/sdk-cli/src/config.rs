@@ -1,4 +1,4 @@
-use breez_sdk_core::{BreezServices, Config, EnvironmentType};
+use breez_sdk_core::{BreezServices, Config, EnvironmentType, Network};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -18,7 +18,7 @@ impl Default for CliConfig {
 
 impl CliConfig {
     pub(crate) fn to_sdk_config(&self, data_dir: &str) -> Config {
-        let mut config = BreezServices::default_config(self.env.clone());
+        let mut config = BreezServices::default_config(self.env.clone(), Network::Bitcoin);
         config.api_key = self.api_key.clone();
         config.working_dir = data_dir.to_string();
         config